@@ -629,10 +629,6 @@ fn parse_float_literal(raw: &str) -> Option<f64> {
     cleaned.parse::<f64>().ok()
 }
 
-#[cfg(test)]
-#[path = "tests/expr.rs"]
-mod tests;
-
 /// Helper names `include`d or `template`d OUTSIDE every control region of
 /// the template. Helm parses and renders each manifest template whole, so
 /// reaching the document at all executes these calls; `if`/`range`/`with`
@@ -676,3 +672,61 @@ pub fn unconditional_include_names(source: &str) -> std::collections::BTreeSet<S
     }
     names
 }
+
+/// Helpers and chart files a template source can reach.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TemplateDependencies {
+    /// Helper names `include`d or `template`d by literal name, anywhere in
+    /// the source (control bodies and `define` bodies included).
+    pub helper_names: std::collections::BTreeSet<String>,
+    /// Whether the source can reach helpers or files it does not name
+    /// literally: a computed `include` name, `tpl`, `.Files`, an expression
+    /// the parser could not model, or a source that does not parse.
+    pub dynamic: bool,
+}
+
+/// Collects the [`TemplateDependencies`] of a template or helper source.
+#[must_use]
+pub fn template_dependencies(source: &str) -> TemplateDependencies {
+    let mut dependencies = TemplateDependencies::default();
+    let Some(tree) = crate::parse_go_template(source) else {
+        dependencies.dynamic = true;
+        return dependencies;
+    };
+    let mut exprs = Vec::new();
+    collect_from_node(tree.root_node(), source, &mut exprs);
+    for expr in exprs {
+        expr.walk(|inner| match inner {
+            TemplateExpr::Call { function, args }
+                if matches!(function.as_str(), "include" | "template") =>
+            {
+                if let Some(TemplateExpr::Literal(
+                    Literal::String(name) | Literal::RawString(name),
+                )) = args.first().map(TemplateExpr::deparen)
+                {
+                    dependencies.helper_names.insert(name.clone());
+                } else {
+                    dependencies.dynamic = true;
+                }
+            }
+            // Method calls keep their receiver in the name: `.Files.Get`.
+            TemplateExpr::Call { function, .. }
+                if function == "tpl" || function.split('.').any(|segment| segment == "Files") =>
+            {
+                dependencies.dynamic = true;
+            }
+            TemplateExpr::Field(path) | TemplateExpr::Selector { path, .. }
+                if path.first().is_some_and(|segment| segment == "Files") =>
+            {
+                dependencies.dynamic = true;
+            }
+            TemplateExpr::Unknown(_) => dependencies.dynamic = true,
+            _ => {}
+        });
+    }
+    dependencies
+}
+
+#[cfg(test)]
+#[path = "tests/expr.rs"]
+mod tests;
@@ -12,8 +12,8 @@ mod tree_sitter_utils;
 mod values_comments;
 
 pub use capability_branch::{decode_guard, decode_guard_expr};
-pub use expr::unconditional_include_names;
 pub use expr::{Literal, TemplateExpr, parse_action_expressions};
+pub use expr::{TemplateDependencies, template_dependencies, unconditional_include_names};
 pub(crate) use helm_schema_syntax::structural_mapping_colon;
 pub use helm_schema_syntax::{parse_yaml_key, unquote_yaml_scalar};
 pub use printf_eval::{
@@ -30,7 +30,7 @@ pub use template_action::contains_template_action;
 pub use tree_sitter_utils::{
     children_with_field, parse_expr_text, parse_go_template, parse_helm_template,
 };
-pub use values_comments::extract_values_yaml_descriptions;
+pub use values_comments::{extract_values_yaml_descriptions, extract_values_yaml_key_lines};
 
 use std::collections::HashMap;
 
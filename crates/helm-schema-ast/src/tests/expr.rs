@@ -680,3 +680,23 @@ fn parenthesized_pipeline_argument_stays_an_argument() {
         "parenthesized pipeline argument survives: {exprs:?}"
     );
 }
+
+#[test]
+fn template_dependencies_record_literal_helpers_and_dynamic_reach() {
+    let literal = template_dependencies(
+        r#"{{ include "chart.labels" . }}{{- template "chart.name" . -}}{{ .Values.port }}"#,
+    );
+    sim_assert_eq!(
+        have: literal.helper_names.into_iter().collect::<Vec<_>>(),
+        want: vec!["chart.labels".to_string(), "chart.name".to_string()]
+    );
+    assert!(!literal.dynamic);
+
+    for source in [
+        "{{ tpl .Values.extra . }}",
+        r#"{{ include (printf "chart.%s" .Values.kind) . }}"#,
+        r#"{{ .Files.Get "config.ini" }}"#,
+    ] {
+        assert!(template_dependencies(source).dynamic, "{source}");
+    }
+}
@@ -3,7 +3,7 @@ use test_util::prelude::sim_assert_eq;
 
 use indoc::indoc;
 
-use super::extract_values_yaml_descriptions;
+use super::{extract_values_yaml_descriptions, extract_values_yaml_key_lines};
 
 #[test]
 fn extracts_leading_inline_and_nested_values_comments() {
@@ -224,3 +224,32 @@ fn helm_docs_param_comments_attach_to_explicit_paths() {
         "helm-docs section comments must not attach to the parent object"
     );
 }
+
+#[test]
+fn locates_first_occurrence_of_each_mapping_key() {
+    let yaml = indoc! {"
+        # -- Replica count
+        replicas: 1
+
+        image:
+          repository: nginx # inline
+          tag: latest
+        ports:
+          - name: http
+            port: 80
+          - name: https
+    "};
+
+    sim_assert_eq!(
+        have: extract_values_yaml_key_lines(yaml),
+        want: BTreeMap::from([
+            ("replicas".to_string(), 2),
+            ("image".to_string(), 4),
+            ("image.repository".to_string(), 5),
+            ("image.tag".to_string(), 6),
+            ("ports".to_string(), 7),
+            ("ports[*].name".to_string(), 8),
+            ("ports[*].port".to_string(), 9),
+        ])
+    );
+}
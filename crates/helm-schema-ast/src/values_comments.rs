@@ -17,6 +17,21 @@ pub fn extract_values_yaml_descriptions(src: &str) -> BTreeMap<String, String> {
     scanner.descriptions
 }
 
+/// Locate the mapping keys of a values YAML document.
+///
+/// The returned map is keyed like [`extract_values_yaml_descriptions`] and
+/// holds the one-based line of each key's first occurrence. Keys under
+/// sequence items share one `[*]` path, so every item resolves to the line
+/// of the first item that declares the key.
+#[must_use]
+pub fn extract_values_yaml_key_lines(src: &str) -> BTreeMap<String, usize> {
+    let mut scanner = CommentScanner::default();
+    for line in src.lines() {
+        scanner.visit_line(line);
+    }
+    scanner.key_lines
+}
+
 #[derive(Default)]
 struct CommentScanner {
     descriptions: BTreeMap<String, String>,
@@ -24,6 +39,8 @@ struct CommentScanner {
     pending: Vec<String>,
     pending_can_describe_previous: bool,
     previous_path: Option<Vec<String>>,
+    key_lines: BTreeMap<String, usize>,
+    line_number: usize,
 }
 
 #[derive(Clone)]
@@ -34,6 +51,7 @@ struct PathFrame {
 
 impl CommentScanner {
     fn visit_line(&mut self, line: &str) {
+        self.line_number += 1;
         let trimmed = line.trim_start();
         if trimmed.is_empty() {
             self.flush_trailing_comments();
@@ -97,9 +115,17 @@ impl CommentScanner {
             .unwrap_or_default();
         if mapping.sequence_item {
             push_sequence_segment(&mut path);
+            // Sibling keys of the item's first key continue the same item.
+            self.path_stack.push(PathFrame {
+                indent,
+                path: path.clone(),
+            });
         }
         path.push(mapping.key);
 
+        self.key_lines
+            .entry(helm_schema_core::join_value_path(&path))
+            .or_insert(self.line_number);
         self.attach_pending_to(&path);
         if let Some(comment) = mapping.inline_comment {
             insert_description(&mut self.descriptions, &path, comment);
@@ -0,0 +1,62 @@
+use std::io::Write;
+use std::sync::Arc;
+
+use helm_schema::EngineResult;
+use helm_schema::output::{FetchPolicy, LoadBudget, PolicyInputOptions};
+use helm_schema::provider::{SchemaBundleRecorder, write_schema_bundle};
+
+use crate::chart_session::OpenedChart;
+use crate::cli::{BundleCommand, BundleExportArgs, Cli};
+use crate::diag_emit;
+
+/// Run `helm-schema bundle`.
+///
+/// # Errors
+///
+/// Returns an error when the subcommand fails.
+pub(crate) fn run(cli: &Cli, command: &BundleCommand) -> EngineResult<()> {
+    match command {
+        BundleCommand::Export(args) => export(cli, args),
+    }
+}
+
+/// Generates the chart's schema with the same options as schema generation,
+/// then writes the schema documents its lookups consulted to `--out` and a
+/// one-line summary to standard output.
+fn export(cli: &Cli, args: &BundleExportArgs) -> EngineResult<()> {
+    let run_span = tracing::info_span!(
+        "helm_schema_bundle_export",
+        chart_dir = %args.chart.display()
+    );
+    let _entered = run_span.enter();
+
+    let recorder = Arc::new(SchemaBundleRecorder::new());
+    let opened = OpenedChart::open(cli, &args.chart)?;
+    let diagnostics = opened.diagnostics.clone();
+    let generated = opened
+        .recording_session(cli, Arc::clone(&recorder))
+        .and_then(|session| {
+            session.emit_with_policy_paths(
+                &cli.override_schema,
+                PolicyInputOptions {
+                    fetch_policy: FetchPolicy::input_assembly(!cli.k8s.offline),
+                    load_budget: LoadBudget::default(),
+                },
+                cli.output.emit_request(),
+            )
+        });
+    diag_emit::emit_to_stderr(&diagnostics, cli.diag.diag_format);
+    generated?;
+
+    let documents = write_schema_bundle(&recorder, &args.out)?;
+    let stdout = std::io::stdout();
+    let mut out = stdout.lock();
+    writeln!(
+        out,
+        "{}: bundled {documents} schema document{}",
+        args.out.display(),
+        if documents == 1 { "" } else { "s" }
+    )?;
+    out.flush()?;
+    Ok(())
+}
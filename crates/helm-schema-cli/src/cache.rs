@@ -0,0 +1,398 @@
+use std::collections::BTreeMap;
+use std::fmt::Write as _;
+use std::io::{BufWriter, Write};
+use std::path::PathBuf;
+use std::time::SystemTime;
+
+use helm_schema::cache::{
+    CacheEntry, CacheEntryKind, CacheVerification, cache_entries, default_crd_schema_cache_dir,
+    default_k8s_schema_cache_dir, default_source_id, prune_cache, source_id_for_url,
+    verify_cache_entry,
+};
+use helm_schema::contract::ResourceRef;
+use helm_schema::diagnostics::DiagnosticSink;
+use helm_schema::provider::prefetch_resource_schemas;
+use helm_schema::{CliError, EngineResult};
+use serde::Serialize;
+
+use crate::chart_session::{OpenedChart, provider_options};
+use crate::cli::{
+    CacheCommand, CachePrefetchArgs, CachePruneArgs, CacheReportArgs, Cli, ReportFormat,
+};
+use crate::diag_emit;
+
+/// Run `helm-schema cache`.
+///
+/// # Errors
+///
+/// Returns an error when a cache cannot be walked or modified, `verify`
+/// finds damaged entries, or `prefetch` cannot resolve every resource.
+pub(crate) fn run(cli: &Cli, command: &CacheCommand) -> EngineResult<()> {
+    let run_span = tracing::info_span!("helm_schema_cache");
+    let _entered = run_span.enter();
+
+    match command {
+        CacheCommand::Ls(args) => list(cli, args),
+        CacheCommand::Du(args) => usage(cli, args),
+        CacheCommand::Prune(args) => prune(cli, args),
+        CacheCommand::Verify(args) => verify(cli, args),
+        CacheCommand::Prefetch(args) => prefetch(cli, args),
+    }
+}
+
+/// One managed cache root and the mirrors the flags configure for it.
+struct ManagedCache {
+    name: &'static str,
+    root: PathBuf,
+    /// Mirror URL per configured source namespace.
+    mirrors: BTreeMap<String, String>,
+}
+
+impl ManagedCache {
+    fn entries(&self) -> EngineResult<Vec<CacheEntry>> {
+        cache_entries(&self.root).map_err(|source| CliError::SchemaCacheMaintenance {
+            path: self.root.clone(),
+            source,
+        })
+    }
+
+    /// The upstream that answered `entry`: the URL its sidecar recorded, or
+    /// the configured mirror owning its namespace.
+    fn origin(&self, entry: &CacheEntry) -> Option<String> {
+        entry
+            .meta
+            .as_ref()
+            .and_then(|meta| meta.source_url.clone())
+            .or_else(|| self.mirrors.get(&entry.source_id).cloned())
+    }
+}
+
+fn managed_caches(cli: &Cli) -> [ManagedCache; 2] {
+    let mirrors = |urls: &[String]| -> BTreeMap<String, String> {
+        urls.iter()
+            .map(|url| (source_id_for_url(url), url.clone()))
+            .collect()
+    };
+    [
+        ManagedCache {
+            name: "kubernetes",
+            root: cli
+                .k8s
+                .k8s_schema_cache_dir
+                .clone()
+                .unwrap_or_else(default_k8s_schema_cache_dir),
+            mirrors: mirrors(&cli.k8s.k8s_schema_mirror),
+        },
+        ManagedCache {
+            name: "crd-catalog",
+            root: cli
+                .crd
+                .crd_catalog_cache_dir
+                .clone()
+                .unwrap_or_else(default_crd_schema_cache_dir),
+            mirrors: mirrors(&cli.crd.crd_catalog_mirror),
+        },
+    ]
+}
+
+#[derive(Serialize)]
+struct ListedEntry<'a> {
+    cache: &'static str,
+    #[serde(flatten)]
+    entry: &'a CacheEntry,
+    /// Upstream that answered the entry, when known.
+    origin: Option<String>,
+}
+
+fn list(cli: &Cli, args: &CacheReportArgs) -> EngineResult<()> {
+    let mut text = String::new();
+    let mut listed = Vec::new();
+    let caches = managed_caches(cli);
+    let entries = caches
+        .iter()
+        .map(ManagedCache::entries)
+        .collect::<EngineResult<Vec<_>>>()?;
+    for (cache, entries) in caches.iter().zip(&entries) {
+        for entry in entries {
+            let origin = cache.origin(entry);
+            let _ = writeln!(
+                text,
+                "{}\t{}\t{}\t{}\t{}",
+                cache.name,
+                kind_label(entry.kind),
+                entry.bytes,
+                entry.path,
+                origin
+                    .as_deref()
+                    .unwrap_or(if entry.source_id == default_source_id() {
+                        "default"
+                    } else {
+                        "unknown mirror"
+                    }),
+            );
+            listed.push(ListedEntry {
+                cache: cache.name,
+                entry,
+                origin,
+            });
+        }
+    }
+    write_report(args.format, &text, &listed)
+}
+
+#[derive(Default, Serialize)]
+struct SourceUsage {
+    documents: usize,
+    not_found: usize,
+    bytes: u64,
+}
+
+#[derive(Serialize)]
+struct CacheUsage {
+    cache: &'static str,
+    root: PathBuf,
+    /// Usage per source namespace.
+    sources: BTreeMap<String, SourceUsage>,
+    bytes: u64,
+}
+
+fn usage(cli: &Cli, args: &CacheReportArgs) -> EngineResult<()> {
+    let mut text = String::new();
+    let mut report = Vec::new();
+    for cache in managed_caches(cli) {
+        let mut sources = BTreeMap::<String, SourceUsage>::new();
+        for entry in cache.entries()? {
+            let usage = sources.entry(entry.source_id.clone()).or_default();
+            match entry.kind {
+                CacheEntryKind::Schema => usage.documents += 1,
+                CacheEntryKind::NotFound => usage.not_found += 1,
+            }
+            usage.bytes += entry.bytes;
+        }
+        let bytes = sources.values().map(|usage| usage.bytes).sum();
+        let _ = writeln!(
+            text,
+            "{} ({}): {}",
+            cache.name,
+            cache.root.display(),
+            human_bytes(bytes)
+        );
+        for (source_id, usage) in &sources {
+            let _ = writeln!(
+                text,
+                "  {source_id}: {} document(s), {} not-found marker(s), {}",
+                usage.documents,
+                usage.not_found,
+                human_bytes(usage.bytes)
+            );
+        }
+        report.push(CacheUsage {
+            cache: cache.name,
+            root: cache.root,
+            sources,
+            bytes,
+        });
+    }
+    write_report(args.format, &text, &report)
+}
+
+fn prune(cli: &Cli, args: &CachePruneArgs) -> EngineResult<()> {
+    let cutoff = SystemTime::now()
+        .checked_sub(args.older_than.0)
+        .unwrap_or(SystemTime::UNIX_EPOCH);
+    let stdout = std::io::stdout();
+    let mut out = BufWriter::new(stdout.lock());
+    for cache in managed_caches(cli) {
+        let removed = if args.dry_run {
+            cache
+                .entries()?
+                .into_iter()
+                .filter(|entry| entry.modified.is_some_and(|modified| modified < cutoff))
+                .collect()
+        } else {
+            prune_cache(&cache.root, cutoff).map_err(|source| CliError::SchemaCacheMaintenance {
+                path: cache.root.clone(),
+                source,
+            })?
+        };
+        for entry in &removed {
+            writeln!(out, "{}\t{}", cache.name, entry.path)?;
+        }
+        let bytes = removed.iter().map(|entry| entry.bytes).sum();
+        writeln!(
+            out,
+            "{}: {} {} entr{} ({})",
+            cache.name,
+            if args.dry_run {
+                "would remove"
+            } else {
+                "removed"
+            },
+            removed.len(),
+            if removed.len() == 1 { "y" } else { "ies" },
+            human_bytes(bytes)
+        )?;
+    }
+    out.flush()?;
+    Ok(())
+}
+
+#[derive(Serialize)]
+struct VerifiedEntry<'a> {
+    cache: &'static str,
+    path: &'a str,
+    #[serde(flatten)]
+    verification: CacheVerification,
+}
+
+fn verify(cli: &Cli, args: &CacheReportArgs) -> EngineResult<()> {
+    let mut text = String::new();
+    let mut report = Vec::new();
+    let mut checked = 0;
+    let caches = managed_caches(cli);
+    let entries = caches
+        .iter()
+        .map(ManagedCache::entries)
+        .collect::<EngineResult<Vec<_>>>()?;
+    for (cache, entries) in caches.iter().zip(&entries) {
+        for entry in entries
+            .iter()
+            .filter(|entry| entry.kind == CacheEntryKind::Schema)
+        {
+            checked += 1;
+            let verification = verify_cache_entry(&cache.root, entry);
+            if !verification.is_failure() {
+                continue;
+            }
+            let reason = match &verification {
+                CacheVerification::Mismatch { recorded, actual } => {
+                    format!("sha256 {actual} does not match recorded {recorded}")
+                }
+                CacheVerification::Unparseable => "not valid JSON".to_string(),
+                CacheVerification::Unreadable => "unreadable".to_string(),
+                CacheVerification::Verified | CacheVerification::Unrecorded => continue,
+            };
+            let _ = writeln!(text, "{}\t{}\t{reason}", cache.name, entry.path);
+            report.push(VerifiedEntry {
+                cache: cache.name,
+                path: &entry.path,
+                verification,
+            });
+        }
+    }
+    let _ = writeln!(
+        text,
+        "checked {checked} schema document(s), {} damaged",
+        report.len()
+    );
+    write_report(args.format, &text, &report)?;
+    if report.is_empty() {
+        Ok(())
+    } else {
+        Err(CliError::SchemaCacheDamaged {
+            count: report.len(),
+        })
+    }
+}
+
+fn prefetch(cli: &Cli, args: &CachePrefetchArgs) -> EngineResult<()> {
+    let resources = args
+        .kinds
+        .iter()
+        .map(|spec| parse_resource(spec))
+        .collect::<EngineResult<Vec<_>>>()?;
+
+    let stdout = std::io::stdout();
+    let mut out = BufWriter::new(stdout.lock());
+    let mut unresolved = 0;
+    if !resources.is_empty() {
+        let diagnostics = DiagnosticSink::new();
+        let resolved = prefetch_resource_schemas(
+            &provider_options(cli, None)?,
+            &resources,
+            Some(&diagnostics),
+        );
+        diag_emit::emit_to_stderr(&diagnostics, cli.diag.diag_format);
+        for (spec, resolved) in args.kinds.iter().zip(resolved) {
+            writeln!(
+                out,
+                "{} {spec}",
+                if resolved { "cached" } else { "unresolved" }
+            )?;
+            unresolved += usize::from(!resolved);
+        }
+    }
+    for chart in &args.charts {
+        let opened = OpenedChart::open(cli, chart)?;
+        let diagnostics = opened.diagnostics.clone();
+        let generated = opened
+            .session(cli)
+            .and_then(|session| session.generated_schema());
+        diag_emit::emit_to_stderr(&diagnostics, cli.diag.diag_format);
+        generated?;
+        writeln!(out, "cached chart {}", chart.display())?;
+    }
+    out.flush()?;
+
+    if unresolved == 0 {
+        Ok(())
+    } else {
+        Err(CliError::SchemaPrefetchIncomplete { count: unresolved })
+    }
+}
+
+/// Parses `apiVersion/Kind`, e.g. `apps/v1/Deployment` or `v1/ConfigMap`.
+fn parse_resource(spec: &str) -> EngineResult<ResourceRef> {
+    match spec.trim().rsplit_once('/') {
+        Some((api_version, kind)) if !api_version.is_empty() && !kind.is_empty() => Ok(
+            ResourceRef::concrete(api_version.to_string(), kind.to_string()),
+        ),
+        _ => Err(CliError::CliValidation(format!(
+            "--kinds expects `apiVersion/Kind` such as `apps/v1/Deployment`, got `{spec}`"
+        ))),
+    }
+}
+
+fn kind_label(kind: CacheEntryKind) -> &'static str {
+    match kind {
+        CacheEntryKind::Schema => "schema",
+        CacheEntryKind::NotFound => "not-found",
+    }
+}
+
+fn human_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+    if bytes < 1024 {
+        return format!("{bytes} B");
+    }
+    let bytes = u128::from(bytes);
+    let mut scale = 1024;
+    let mut units = UNITS.iter();
+    let mut unit = units.next().copied().unwrap_or_default();
+    while bytes / scale >= 1024
+        && let Some(next) = units.next()
+    {
+        scale *= 1024;
+        unit = next;
+    }
+    let tenths = bytes * 10 / scale;
+    format!("{}.{} {}", tenths / 10, tenths % 10, unit)
+}
+
+fn write_report<T: Serialize + ?Sized>(
+    format: ReportFormat,
+    text: &str,
+    report: &T,
+) -> EngineResult<()> {
+    let stdout = std::io::stdout();
+    let mut out = BufWriter::new(stdout.lock());
+    match format {
+        ReportFormat::Text => out.write_all(text.as_bytes())?,
+        ReportFormat::Json => {
+            serde_json::to_writer_pretty(&mut out, report)?;
+            out.write_all(b"\n")?;
+        }
+    }
+    out.flush()?;
+    Ok(())
+}
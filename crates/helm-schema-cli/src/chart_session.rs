@@ -0,0 +1,191 @@
+use std::path::Path;
+use std::sync::Arc;
+
+use helm_schema::chart_source::RootChartSource;
+use helm_schema::cluster_state::load_cluster_state;
+use helm_schema::diagnostics::{Diagnostic, DiagnosticSink};
+use helm_schema::generation::{EmissionSelection, EnumInference};
+use helm_schema::output::LoadBudget;
+use helm_schema::provider::{
+    FieldSchemaOverrides, LocalSchemaUniverse, ProviderOptions, SchemaBundleRecorder,
+    load_capability_profile, load_cluster_openapi, load_field_schema_overrides, load_schema_bundle,
+};
+use helm_schema::{AnalysisSession, CliError, EngineResult, GenerateOptions};
+
+use crate::cli::{self, Cli};
+use crate::config::{self, EffectiveConfig};
+
+/// A chart opened for analysis together with its resolved policy.
+///
+/// Opening is separate from session construction so `--print-effective-config`
+/// can report policy without validating provider flags or analyzing templates.
+pub(crate) struct OpenedChart {
+    pub(crate) root_source: RootChartSource,
+    pub(crate) effective_config: EffectiveConfig,
+    pub(crate) diagnostics: DiagnosticSink,
+}
+
+impl OpenedChart {
+    /// Opens `chart_dir` and resolves chart policy against the CLI flags.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when the chart source cannot be opened or its policy
+    /// config cannot be read or resolved.
+    pub(crate) fn open(cli: &Cli, chart_dir: &Path) -> EngineResult<Self> {
+        let root_source = RootChartSource::open(chart_dir, LoadBudget::default())?;
+        let effective_config = config::resolve(
+            &root_source,
+            chart_dir,
+            cli.config.as_deref(),
+            cli.no_config,
+            cli.profile,
+            cli.emission,
+        )?;
+
+        let diagnostics = DiagnosticSink::new();
+        if !effective_config.file_weakening.is_empty() {
+            diagnostics.push(Diagnostic::DiscoveredConfigWeakensEmission {
+                disabled_knobs: effective_config
+                    .file_weakening
+                    .iter()
+                    .map(|knob| (*knob).to_string())
+                    .collect(),
+                explicit: effective_config.file_weakening_is_explicit,
+            });
+        }
+
+        Ok(Self {
+            root_source,
+            effective_config,
+            diagnostics,
+        })
+    }
+
+    /// Validates provider flags and builds the analysis session.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error for invalid CRD or Kubernetes version flag combinations.
+    pub(crate) fn session(self, cli: &Cli) -> EngineResult<AnalysisSession> {
+        analysis_session(
+            cli,
+            self.root_source,
+            self.effective_config.selection,
+            self.diagnostics,
+            None,
+        )
+    }
+
+    /// Like [`Self::session`], with every cache-backed schema document the
+    /// session's lookups consult recorded into `recorder`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error for invalid CRD or Kubernetes version flag combinations.
+    pub(crate) fn recording_session(
+        self,
+        cli: &Cli,
+        recorder: Arc<SchemaBundleRecorder>,
+    ) -> EngineResult<AnalysisSession> {
+        analysis_session(
+            cli,
+            self.root_source,
+            self.effective_config.selection,
+            self.diagnostics,
+            Some(recorder),
+        )
+    }
+}
+
+fn analysis_session(
+    cli: &Cli,
+    root_source: RootChartSource,
+    selection: EmissionSelection,
+    diagnostics: DiagnosticSink,
+    schema_bundle_recorder: Option<Arc<SchemaBundleRecorder>>,
+) -> EngineResult<AnalysisSession> {
+    let chart_location = root_source.location().map(Path::to_path_buf);
+    let chart_dir = root_source.into_chart_dir();
+    let opts = GenerateOptions {
+        chart_dir,
+        include_tests: !cli.chart.exclude_tests,
+        include_subchart_values: !cli.chart.no_subchart_values,
+        values_files: cli.chart.values_files.clone(),
+        infer_required: cli.chart.infer_required,
+        infer_enums: cli.chart.infer_enums.map_or(EnumInference::Off, Into::into),
+        emission: selection,
+        provider: provider_options(cli, schema_bundle_recorder)?,
+        cluster_state: match &cli.chart.cluster_state {
+            Some(dir) => Some(Arc::new(load_cluster_state(dir)?)),
+            None => None,
+        },
+    };
+    let mut session = AnalysisSession::with_diagnostics(opts, diagnostics);
+    if let Some(location) = chart_location {
+        session = session.with_chart_location(location);
+    }
+    if let Some(cache) = cli.chart.chart_repository_cache() {
+        session = session.with_chart_repository_cache(cache);
+    }
+    Ok(match cli.perf.analysis_cache() {
+        Some(cache) => session.with_analysis_cache(cache),
+        None => session,
+    })
+}
+
+/// Kubernetes and CRD provider options selected by the CLI flags.
+///
+/// # Errors
+///
+/// Returns an error for invalid CRD or Kubernetes version flag combinations,
+/// or when a capability profile, cluster discovery, or schema bundle cannot
+/// be loaded.
+pub(crate) fn provider_options(
+    cli: &Cli,
+    schema_bundle_recorder: Option<Arc<SchemaBundleRecorder>>,
+) -> EngineResult<ProviderOptions> {
+    cli.crd.validate().map_err(CliError::CliValidation)?;
+    let fallback_window = cli
+        .k8s
+        .resolved_fallback_window()
+        .map_err(CliError::CliValidation)?;
+    Ok(ProviderOptions {
+        k8s_versions: cli.k8s.k8s_version.clone(),
+        k8s_version_fallback_window: fallback_window,
+        k8s_schema_mirrors: cli.k8s.k8s_schema_mirror.clone(),
+        k8s_schema_cache_dir: cli.k8s.k8s_schema_cache_dir.clone(),
+        no_cache: cli.k8s.no_cache,
+        allow_net: !cli.k8s.offline,
+        disable_k8s_schemas: cli.k8s.no_k8s_schemas,
+        crd_lookup_loose: matches!(cli.crd.lookup_mode(), cli::CrdVersionLookup::Loose),
+        crd_catalog_mirrors: cli.crd.crd_catalog_mirror.clone(),
+        crd_catalog_cache_dir: cli.crd.crd_catalog_cache_dir.clone(),
+        crd_override_dir: cli.crd.crd_override_dir.clone(),
+        cluster_openapi: match &cli.k8s.cluster_openapi {
+            Some(dir) => Some(Arc::new(load_cluster_openapi(dir)?)),
+            None => None,
+        },
+        local_schema_universe: LocalSchemaUniverse::default(),
+        crd_cache_record_source: cli.crd.crd_cache_record_source,
+        api_version_guess: cli.inference.enabled(),
+        capability_profile: match &cli.k8s.capabilities {
+            Some(path) => Some(Arc::new(load_capability_profile(path)?)),
+            None => None,
+        },
+        field_schema_overrides: if cli.k8s.field_schema_override.is_empty() {
+            None
+        } else {
+            let mut overrides = FieldSchemaOverrides::default();
+            for path in &cli.k8s.field_schema_override {
+                overrides.extend(load_field_schema_overrides(path)?);
+            }
+            Some(Arc::new(overrides))
+        },
+        schema_bundle: match &cli.k8s.schema_bundle {
+            Some(path) => Some(Arc::new(load_schema_bundle(path)?)),
+            None => None,
+        },
+        schema_bundle_recorder,
+    })
+}
@@ -0,0 +1,170 @@
+use std::fmt::Write as _;
+use std::io::{BufWriter, Write};
+use std::path::{Path, PathBuf};
+
+use helm_schema::diff::{SchemaChange, diff_schemas, generator_version, policy_fingerprint};
+use helm_schema::{CliError, EngineResult};
+use serde_json::Value;
+
+use crate::diff::render_change;
+
+/// Schema file `--check` compares against when no `-o` path is given.
+const COMMITTED_SCHEMA_FILE: &str = "values.schema.json";
+
+/// Path of the committed schema `--check` compares against.
+///
+/// # Errors
+///
+/// Returns an error when the chart is an archive and no `-o` path names the
+/// committed schema.
+pub(crate) fn committed_schema_path(
+    chart_dir: &Path,
+    output: Option<&Path>,
+) -> EngineResult<PathBuf> {
+    if let Some(output) = output {
+        return Ok(output.to_path_buf());
+    }
+    if !chart_dir.is_dir() {
+        return Err(CliError::CliValidation(
+            "--check on a chart archive requires -o/--output naming the committed schema"
+                .to_string(),
+        ));
+    }
+    Ok(chart_dir.join(COMMITTED_SCHEMA_FILE))
+}
+
+/// Compares the regenerated schema with the committed one and reports drift
+/// on standard output. Nothing is written to `committed_path`.
+///
+/// Drift is semantic: the committed schema is current unless
+/// [`diff_schemas`] finds a values contract change or the policy
+/// fingerprints differ. A schema written by another helm-schema version
+/// with the same contract and policy still passes.
+///
+/// # Errors
+///
+/// Returns an error when the committed schema cannot be read or parsed, or
+/// when it drifted from `generated`.
+pub(crate) fn run(committed_path: &Path, generated: &Value) -> EngineResult<()> {
+    let committed = match std::fs::read_to_string(committed_path) {
+        Ok(source) => Some(serde_json::from_str::<Value>(&source).map_err(|source| {
+            CliError::InvalidCommittedSchema {
+                path: committed_path.to_path_buf(),
+                source,
+            }
+        })?),
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => None,
+        Err(source) => {
+            return Err(CliError::ReadCommittedSchema {
+                path: committed_path.to_path_buf(),
+                source,
+            });
+        }
+    };
+
+    let (report, drifted) = match &committed {
+        Some(committed) => {
+            let changes = diff_schemas(committed, generated);
+            if changes.is_empty() && policy_fingerprint(committed) == policy_fingerprint(generated)
+            {
+                (
+                    format!("{} is up to date\n", committed_path.display()),
+                    false,
+                )
+            } else {
+                (
+                    render_drift(committed_path, committed, generated, &changes),
+                    true,
+                )
+            }
+        }
+        None => (
+            format!(
+                "{} is missing; run helm-schema without --check to generate it\n",
+                committed_path.display()
+            ),
+            true,
+        ),
+    };
+    let stdout = std::io::stdout();
+    let mut out = BufWriter::new(stdout.lock());
+    out.write_all(report.as_bytes())?;
+    out.flush()?;
+
+    if drifted {
+        Err(CliError::SchemaDrift {
+            path: committed_path.to_path_buf(),
+        })
+    } else {
+        Ok(())
+    }
+}
+
+fn render_drift(
+    committed_path: &Path,
+    committed: &Value,
+    generated: &Value,
+    changes: &[SchemaChange],
+) -> String {
+    let mut out = String::new();
+    let _ = writeln!(out, "{} is out of date", committed_path.display());
+    write_causes(&mut out, committed, generated);
+
+    if changes.is_empty() {
+        let _ = writeln!(out, "no values contract changes");
+    }
+    for change in changes {
+        let _ = writeln!(out, "  {}", render_change(change));
+    }
+    out
+}
+
+/// One `cause:` line per input that moved between the two schemas: the
+/// helm-schema version, the emission policy, or, when neither did, the
+/// templates and values.
+fn write_causes(out: &mut String, committed: &Value, generated: &Value) {
+    let (Some(old_policy), Some(new_policy)) =
+        (policy_fingerprint(committed), policy_fingerprint(generated))
+    else {
+        let schema = if policy_fingerprint(committed).is_none() {
+            "committed"
+        } else {
+            "regenerated"
+        };
+        let _ = writeln!(
+            out,
+            "cause: unknown; the {schema} schema carries no helm-schema policy annotation"
+        );
+        return;
+    };
+    let old_version = generator_version(committed);
+    let new_version = generator_version(generated);
+    if old_version != new_version {
+        let _ = writeln!(
+            out,
+            "cause: helm-schema version change ({} -> {})",
+            old_version.unwrap_or("unrecorded"),
+            new_version.unwrap_or("unrecorded")
+        );
+    }
+    if old_policy != new_policy {
+        let _ = writeln!(
+            out,
+            "cause: policy drift (policy fingerprint {} -> {})",
+            short_fingerprint(old_policy),
+            short_fingerprint(new_policy)
+        );
+    }
+    if old_version == new_version && old_policy == new_policy {
+        let _ = writeln!(
+            out,
+            "cause: template drift (policy fingerprint {} and helm-schema {} unchanged)",
+            short_fingerprint(new_policy),
+            new_version.unwrap_or("version")
+        );
+    }
+}
+
+fn short_fingerprint(fingerprint: &str) -> &str {
+    fingerprint.get(..12).unwrap_or(fingerprint)
+}
@@ -1,22 +1,31 @@
 use std::path::PathBuf;
 
 use clap::Args;
+use helm_schema::chart_source::ChartRepositoryCache;
+
+use crate::cli::EnumInference;
 
 /// Chart discovery, values composition, and requiredness options.
 #[derive(Args, Debug, Clone)]
 pub struct ChartArgs {
     /// Excludes chart test templates from analysis.
-    #[arg(long)]
+    #[arg(long, global = true)]
     pub exclude_tests: bool,
 
     /// Omits dependency values beneath their subchart keys.
-    #[arg(long)]
+    #[arg(long, global = true)]
     pub no_subchart_values: bool,
 
-    /// Additional values files whose comments should be layered into
-    /// schema descriptions. These files are documentation metadata only
-    /// and do not contribute type hints or accepted value paths.
-    #[arg(short = 'f', long = "values", value_name = "VALUES_FILE")]
+    /// Additional values files. Schema generation layers their comments
+    /// into schema descriptions but takes no type hints or accepted value
+    /// paths from them; `validate` checks them against the contract after
+    /// its positional values files.
+    #[arg(
+        short = 'f',
+        long = "values",
+        value_name = "VALUES_FILE",
+        global = true
+    )]
     pub values_files: Vec<PathBuf>,
 
     /// Mark paths used in unconditional template guards
@@ -26,6 +35,46 @@ pub struct ChartArgs {
     /// expression can be a literal (`default "x" .Values.X`), an
     /// identifier (`default .Chart.Name .Values.X`), or a parenthesized
     /// expression (`default (printf "%s" .Y) .Values.X`).
-    #[arg(long)]
+    #[arg(long, global = true)]
     pub infer_required: bool,
+
+    /// Publish the literals each value is compared against with `eq`, `ne`,
+    /// or `has` across every template, joined with any enum the provider
+    /// schema declares. `examples` (the default) annotates the property;
+    /// `strict` also emits `enum` where an `else` branch calls `fail`.
+    #[arg(
+        long,
+        value_enum,
+        value_name = "MODE",
+        num_args = 0..=1,
+        default_missing_value = "examples",
+        global = true
+    )]
+    pub infer_enums: Option<EnumInference>,
+
+    /// Directory of YAML manifests standing for the target cluster's
+    /// objects. `lookup` calls resolve against it, so `if $existing`
+    /// branches become decidable and typed downstream; an object missing
+    /// from the directory is absent, as `helm template` assumes for every
+    /// object. Without it, `lookup` results stay opaque.
+    #[arg(long = "cluster-state", value_name = "DIR", global = true)]
+    pub cluster_state: Option<PathBuf>,
+
+    /// Helm repository cache holding the `Chart.lock`-pinned archives of
+    /// dependencies not vendored under `charts/`. Defaults to Helm's own
+    /// cache (`HELM_REPOSITORY_CACHE`, else `~/.cache/helm/repository` or
+    /// the platform equivalent).
+    #[arg(long = "chart-repo-cache", value_name = "DIR", global = true)]
+    pub chart_repo_cache: Option<PathBuf>,
+}
+
+impl ChartArgs {
+    /// The repository cache non-vendored dependencies are loaded from.
+    #[must_use]
+    pub fn chart_repository_cache(&self) -> Option<ChartRepositoryCache> {
+        self.chart_repo_cache
+            .clone()
+            .map(ChartRepositoryCache::new)
+            .or_else(ChartRepositoryCache::helm_default)
+    }
 }
@@ -0,0 +1,304 @@
+use std::path::PathBuf;
+use std::time::Duration;
+
+use clap::{Args, Subcommand, ValueEnum};
+
+/// Analysis queries that run instead of writing a schema.
+///
+/// Every subcommand accepts the same global analysis options (Kubernetes and
+/// CRD providers, chart composition, policy config) as schema generation, so
+/// a query observes the exact contract the generated schema was built from.
+#[derive(Subcommand, Debug, Clone)]
+pub enum Command {
+    /// Explain where templates use a values path, under which guards, which
+    /// Kubernetes fields it flows into, and why its schema has its type.
+    Explain(ExplainArgs),
+    /// Validate values files against the inferred contract without writing
+    /// a schema, reporting each violation with its values-file line and the
+    /// templates that read the offending path.
+    Validate(ValidateArgs),
+    /// Compare the values contracts of two chart versions and classify each
+    /// change as breaking (path removed, type narrowed, new required key,
+    /// enum shrunk, guard added) or compatible.
+    Diff(DiffArgs),
+    /// Print a values reference table (key, type, default, description,
+    /// required, and read guard) for the chart README, including values the
+    /// templates read but `values.yaml` never declares.
+    Docs(DocsArgs),
+    /// Print an annotated `values.yaml` listing every values path the
+    /// contract knows about: composed defaults, commented placeholders for
+    /// paths read without a default, and schema descriptions as comments.
+    Values(ValuesArgs),
+    /// Print TypeScript, Go, Python, CUE, or KCL types for the chart's
+    /// values, with keys read only under a boolean or string switch split
+    /// into a discriminated union on that switch.
+    Types(TypesArgs),
+    /// Serve `values.yaml` completion, hover, diagnostics, and go-to-template
+    /// definitions over the Language Server Protocol on stdio.
+    Lsp(LspArgs),
+    /// Capture the Kubernetes and CRD catalog schemas a chart needs into a
+    /// reproducible bundle that `--schema-bundle` serves offline.
+    #[command(subcommand)]
+    Bundle(BundleCommand),
+    /// Inspect, prune, verify, and warm the managed Kubernetes and CRD
+    /// catalog schema caches.
+    #[command(subcommand)]
+    Cache(CacheCommand),
+}
+
+/// Subcommands of `bundle`.
+#[derive(Subcommand, Debug, Clone)]
+pub enum BundleCommand {
+    /// Generate the chart's schema once and write the schema documents its
+    /// lookups consulted, with the `$ref` targets they reach, as `tar.zst`.
+    Export(BundleExportArgs),
+}
+
+/// Subcommands of `cache`.
+///
+/// Both caches are the roots `--k8s-schema-cache-dir` and
+/// `--crd-catalog-cache-dir` select, or their defaults.
+#[derive(Subcommand, Debug, Clone)]
+pub enum CacheCommand {
+    /// List every cached schema document and not-found marker with its
+    /// source namespace, the mirror that answered, and its size.
+    Ls(CacheReportArgs),
+    /// Summarize entry counts and disk usage per cache and source namespace.
+    Du(CacheReportArgs),
+    /// Remove entries fetched longer ago than `--older-than`.
+    Prune(CachePruneArgs),
+    /// Re-hash schema documents against the digests their `.meta` sidecars
+    /// recorded, and check that every document parses. Fails when any
+    /// entry is damaged.
+    Verify(CacheReportArgs),
+    /// Download into the caches the schemas of the `--kinds` resources, or
+    /// of every resource the `--chart` charts render, at the
+    /// `--k8s-version` versions.
+    Prefetch(CachePrefetchArgs),
+}
+
+/// Minimum age of the entries `cache prune` removes: a whole number with a
+/// `s`, `m`, `h`, or `d` unit, e.g. `30d`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CacheAge(pub Duration);
+
+impl std::str::FromStr for CacheAge {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let split = s.find(|c: char| !c.is_ascii_digit()).unwrap_or(s.len());
+        let (amount, unit) = s.split_at(split);
+        let amount: u64 = amount
+            .parse()
+            .map_err(|err| format!("expected an age such as `30d`: {err}"))?;
+        let seconds = match unit {
+            "s" => 1,
+            "m" => 60,
+            "h" => 60 * 60,
+            "d" => 24 * 60 * 60,
+            _ => return Err(format!("unknown age unit `{unit}`; use s, m, h, or d")),
+        };
+        Ok(Self(Duration::from_secs(amount.saturating_mul(seconds))))
+    }
+}
+
+/// Serialization format for query reports.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, ValueEnum)]
+pub enum ReportFormat {
+    /// Human-readable report.
+    #[default]
+    Text,
+    /// One structured JSON document.
+    Json,
+}
+
+/// Markup of the `docs` values reference.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, ValueEnum)]
+pub enum DocsFormat {
+    /// GitHub-flavored Markdown table.
+    #[default]
+    Markdown,
+    /// HTML `<table>`.
+    Html,
+}
+
+/// Target language of the `types` subcommand.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, ValueEnum)]
+pub enum BindingLanguage {
+    /// TypeScript interfaces and union type aliases.
+    #[default]
+    #[value(name = "typescript")]
+    TypeScript,
+    /// Go structs with `json` and `yaml` tags.
+    Go,
+    /// Python `TypedDict` classes.
+    Python,
+    /// CUE definitions; guarded keys become embedded disjunctions.
+    Cue,
+    /// KCL schemas; guarded keys become union type aliases.
+    Kcl,
+}
+
+impl From<BindingLanguage> for helm_schema::bindings::BindingLanguage {
+    fn from(language: BindingLanguage) -> Self {
+        match language {
+            BindingLanguage::TypeScript => Self::TypeScript,
+            BindingLanguage::Go => Self::Go,
+            BindingLanguage::Python => Self::Python,
+            BindingLanguage::Cue => Self::Cue,
+            BindingLanguage::Kcl => Self::Kcl,
+        }
+    }
+}
+
+/// Arguments of the `explain` subcommand.
+#[derive(Args, Debug, Clone)]
+pub struct ExplainArgs {
+    /// Chart directory or packaged chart archive to analyze.
+    #[arg(value_name = "CHART_DIR")]
+    pub chart_dir: PathBuf,
+
+    /// Values path to explain, with or without a leading `.Values.`.
+    #[arg(value_name = "VALUES_PATH")]
+    pub values_path: String,
+
+    /// Output format of the explanation.
+    #[arg(long, value_enum, default_value_t = ReportFormat::Text)]
+    pub format: ReportFormat,
+}
+
+/// Arguments of the `validate` subcommand.
+#[derive(Args, Debug, Clone)]
+pub struct ValidateArgs {
+    /// Chart directory or packaged chart archive to analyze.
+    #[arg(value_name = "CHART_DIR")]
+    pub chart_dir: PathBuf,
+
+    /// Values files to check, coalesced over the chart defaults in order as
+    /// `helm install -f` would. Files given with `-f/--values` are checked
+    /// too, after the positional ones. The distinct id keeps the global
+    /// `-f/--values` from being shadowed by this argument.
+    #[arg(id = "validate_values_files", value_name = "VALUES_FILE")]
+    pub values_files: Vec<PathBuf>,
+
+    /// Output format of the violation report.
+    #[arg(long, value_enum, default_value_t = ReportFormat::Text)]
+    pub format: ReportFormat,
+}
+
+/// Arguments of the `diff` subcommand.
+#[derive(Args, Debug, Clone)]
+pub struct DiffArgs {
+    /// Chart directory or packaged chart archive of the current version.
+    #[arg(value_name = "OLD_CHART")]
+    pub old_chart: PathBuf,
+
+    /// Chart directory or packaged chart archive of the candidate version.
+    #[arg(value_name = "NEW_CHART")]
+    pub new_chart: PathBuf,
+
+    /// Output format of the change report.
+    #[arg(long, value_enum, default_value_t = ReportFormat::Text)]
+    pub format: ReportFormat,
+}
+
+/// Arguments of the `docs` subcommand.
+#[derive(Args, Debug, Clone)]
+pub struct DocsArgs {
+    /// Chart directory or packaged chart archive to document.
+    #[arg(value_name = "CHART_DIR")]
+    pub chart_dir: PathBuf,
+
+    /// Markup of the reference table.
+    #[arg(long, value_enum, default_value_t = DocsFormat::Markdown)]
+    pub format: DocsFormat,
+}
+
+/// Arguments of the `values` subcommand.
+#[derive(Args, Debug, Clone)]
+pub struct ValuesArgs {
+    /// Chart directory or packaged chart archive to analyze.
+    #[arg(value_name = "CHART_DIR")]
+    pub chart_dir: PathBuf,
+}
+
+/// Arguments of the `types` subcommand.
+#[derive(Args, Debug, Clone)]
+pub struct TypesArgs {
+    /// Chart directory or packaged chart archive to analyze.
+    #[arg(value_name = "CHART_DIR")]
+    pub chart_dir: PathBuf,
+
+    /// Language of the generated types.
+    #[arg(long = "lang", value_enum, default_value_t = BindingLanguage::TypeScript)]
+    pub language: BindingLanguage,
+
+    /// Name of the root values type; nested types are prefixed with it.
+    #[arg(long, default_value = "Values")]
+    pub root_name: String,
+
+    /// Package clause of generated Go code.
+    #[arg(long, default_value = "values")]
+    pub go_package: String,
+}
+
+/// Arguments of the `lsp` subcommand.
+#[derive(Args, Debug, Clone)]
+pub struct LspArgs {
+    /// Chart that values files outside any chart directory are checked
+    /// against; defaults to the chart at the workspace root.
+    #[arg(value_name = "CHART_DIR")]
+    pub chart_dir: Option<PathBuf>,
+}
+
+/// Arguments of the `bundle export` subcommand.
+#[derive(Args, Debug, Clone)]
+pub struct BundleExportArgs {
+    /// Chart directory or packaged chart archive whose lookups are captured.
+    #[arg(long, value_name = "CHART")]
+    pub chart: PathBuf,
+
+    /// Bundle archive to write.
+    #[arg(long, value_name = "FILE")]
+    pub out: PathBuf,
+}
+
+/// Arguments of the `cache ls`, `cache du`, and `cache verify` subcommands.
+#[derive(Args, Debug, Clone)]
+pub struct CacheReportArgs {
+    /// Output format of the report.
+    #[arg(long, value_enum, default_value_t = ReportFormat::Text)]
+    pub format: ReportFormat,
+}
+
+/// Arguments of the `cache prune` subcommand.
+#[derive(Args, Debug, Clone)]
+pub struct CachePruneArgs {
+    /// Remove entries last fetched longer ago than this age, e.g. `30d`.
+    #[arg(long = "older-than", value_name = "AGE")]
+    pub older_than: CacheAge,
+
+    /// List what would be removed without deleting anything.
+    #[arg(long = "dry-run")]
+    pub dry_run: bool,
+}
+
+/// Arguments of the `cache prefetch` subcommand.
+#[derive(Args, Debug, Clone)]
+#[command(group = clap::ArgGroup::new("prefetch_targets").required(true).multiple(true))]
+pub struct CachePrefetchArgs {
+    /// Resources to prefetch as `apiVersion/Kind`, e.g. `apps/v1/Deployment`
+    /// or `v1/ConfigMap`. Repeat or comma-separate.
+    #[arg(
+        long,
+        value_name = "API_VERSION/KIND",
+        value_delimiter = ',',
+        group = "prefetch_targets"
+    )]
+    pub kinds: Vec<String>,
+
+    /// Chart directory or packaged chart archive whose rendered resources
+    /// are prefetched. Repeatable.
+    #[arg(long = "chart", value_name = "CHART", group = "prefetch_targets")]
+    pub charts: Vec<PathBuf>,
+}
@@ -18,39 +18,39 @@ pub struct CrdArgs {
     /// CRD version lookup mode. Default `strict`: only the exact
     /// `(group, kind, version)` is consulted. `loose` enables cross-scan
     /// + informational hints; mirrors are available in BOTH modes.
-    #[arg(long = "crd-version-lookup", value_enum, default_value_t = CrdVersionLookup::Strict)]
+    #[arg(long = "crd-version-lookup", value_enum, default_value_t = CrdVersionLookup::Strict, global = true)]
     pub crd_version_lookup: CrdVersionLookup,
 
     /// Short alias for `--crd-version-lookup=strict`. Kept for
     /// symmetry with `--strict-k8s-version` / `--strict-api-versions`
     /// and to keep CI opt-out flags short.
-    #[arg(long = "strict-crd-version")]
+    #[arg(long = "strict-crd-version", global = true)]
     pub strict_crd_version: bool,
 
     /// Additional upstream CRD catalog mirror URL. Repeatable.
     /// Per-source cache namespacing keeps mirror entries from masking
     /// the default.
-    #[arg(long = "crd-catalog-mirror")]
+    #[arg(long = "crd-catalog-mirror", global = true)]
     pub crd_catalog_mirror: Vec<String>,
 
     /// Managed cache root for CRD schemas. Subject to the cache
     /// invalidation contract.
-    #[arg(long = "crd-catalog-cache-dir")]
+    #[arg(long = "crd-catalog-cache-dir", global = true)]
     pub crd_catalog_cache_dir: Option<PathBuf>,
 
     /// Hand-maintained CRD schema overrides. Never wiped, never
     /// subject to the cache invalidation contract.
-    #[arg(long = "crd-override-dir")]
+    #[arg(long = "crd-override-dir", global = true)]
     pub crd_override_dir: Option<PathBuf>,
 
     /// Write a `<schema>.json.meta` sidecar alongside every CRD cache
     /// entry recording the fetch URL and timestamp.
-    #[arg(long = "crd-cache-record-source")]
+    #[arg(long = "crd-cache-record-source", global = true)]
     pub crd_cache_record_source: bool,
 
     /// Removed in this alpha — use `--crd-override-dir` and/or
     /// `--crd-catalog-cache-dir` instead.
-    #[arg(long = "crd-catalog-dir", hide = true)]
+    #[arg(long = "crd-catalog-dir", hide = true, global = true)]
     pub crd_catalog_dir_removed: Option<PathBuf>,
 }
 
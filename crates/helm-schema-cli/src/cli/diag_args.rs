@@ -14,6 +14,6 @@ pub enum DiagFormat {
 #[derive(Args, Debug, Clone)]
 pub struct DiagArgs {
     /// Format used for emitted diagnostics.
-    #[arg(long = "diag-format", value_enum, default_value_t = DiagFormat::Text)]
+    #[arg(long = "diag-format", value_enum, default_value_t = DiagFormat::Text, global = true)]
     pub diag_format: DiagFormat,
 }
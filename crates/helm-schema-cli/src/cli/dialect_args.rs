@@ -0,0 +1,23 @@
+use clap::ValueEnum;
+
+/// JSON Schema dialects accepted by the command line.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, ValueEnum)]
+pub enum SchemaDialect {
+    /// Draft-07, the dialect Helm validates `values.schema.json` with.
+    #[default]
+    #[value(name = "draft-07")]
+    Draft07,
+    /// Draft 2020-12, for editors and validators beyond Helm; closed objects
+    /// use `unevaluatedProperties` so conditional branches can add keys.
+    #[value(name = "2020-12")]
+    Draft2020_12,
+}
+
+impl From<SchemaDialect> for helm_schema::output::SchemaDialect {
+    fn from(dialect: SchemaDialect) -> Self {
+        match dialect {
+            SchemaDialect::Draft07 => Self::Draft07,
+            SchemaDialect::Draft2020_12 => Self::Draft2020_12,
+        }
+    }
+}
@@ -20,19 +20,19 @@ impl From<PolicyToggle> for bool {
 #[derive(Args, Debug, Clone, Copy, Default)]
 pub struct EmissionArgs {
     /// Override root-anchored ordinary conditional emission (W-class).
-    #[arg(long, value_enum, value_name = "STATE")]
+    #[arg(long, value_enum, value_name = "STATE", global = true)]
     pub root_anchored_conditionals: Option<PolicyToggle>,
 
     /// Override locally anchored ordinary conditional emission (W-class).
-    #[arg(long, value_enum, value_name = "STATE")]
+    #[arg(long, value_enum, value_name = "STATE", global = true)]
     pub local_conditionals: Option<PolicyToggle>,
 
     /// Override unconditional and guarded terminal-clause emission (W-class).
-    #[arg(long, value_enum, value_name = "STATE")]
+    #[arg(long, value_enum, value_name = "STATE", global = true)]
     pub terminal_clauses: Option<PolicyToggle>,
 
     /// Override kind partitions; at least one matching anchor lane must be on.
-    #[arg(long, value_enum, value_name = "STATE")]
+    #[arg(long, value_enum, value_name = "STATE", global = true)]
     pub kind_partitions: Option<PolicyToggle>,
 }
 
@@ -0,0 +1,21 @@
+use clap::ValueEnum;
+
+/// Enum-inference modes accepted by the command line.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, ValueEnum)]
+pub enum EnumInference {
+    /// Publish compared literals as `examples` without narrowing acceptance.
+    #[default]
+    Examples,
+    /// Also emit `enum` where an `else` branch calls `fail`, proving the set
+    /// is closed.
+    Strict,
+}
+
+impl From<EnumInference> for helm_schema::generation::EnumInference {
+    fn from(mode: EnumInference) -> Self {
+        match mode {
+            EnumInference::Examples => Self::Examples,
+            EnumInference::Strict => Self::Strict,
+        }
+    }
+}
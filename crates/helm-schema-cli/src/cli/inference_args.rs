@@ -5,12 +5,16 @@ use clap::Args;
 pub struct InferenceArgs {
     /// Enable Feature D apiVersion guessing for kinds whose
     /// apiVersion the IR couldn't pin.
-    #[arg(long = "api-version-guess", conflicts_with = "strict_api_versions")]
+    #[arg(
+        long = "api-version-guess",
+        conflicts_with = "strict_api_versions",
+        global = true
+    )]
     pub api_version_guess: bool,
 
     /// Disable Feature D inference entirely, regardless of
     /// `--api-version-guess`.
-    #[arg(long = "strict-api-versions")]
+    #[arg(long = "strict-api-versions", global = true)]
     pub strict_api_versions: bool,
 }
 
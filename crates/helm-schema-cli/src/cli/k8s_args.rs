@@ -32,43 +32,85 @@ pub struct K8sArgs {
     /// Kubernetes minor version directory(s) to consult, in
     /// user-supplied priority order. The first value is the primary;
     /// any further values are explicit fallbacks.
-    #[arg(long = "k8s-version", default_values_t = vec![String::from("v1.35.0")])]
+    #[arg(long = "k8s-version", default_values_t = vec![String::from("v1.35.0")], global = true)]
     pub k8s_version: Vec<String>,
 
+    /// YAML file describing the target cluster's `.Capabilities`, like
+    /// `helm template --api-versions ... --kube-version ...`: `apiVersions`
+    /// lists served `group/version` or `group/version/Kind` entries on top
+    /// of the vanilla release, and `kubeVersion` replaces the primary
+    /// `--k8s-version`. Decides `.Capabilities.APIVersions.Has` branches;
+    /// groups outside the built-in Kubernetes API that it does not list
+    /// are absent.
+    #[arg(long = "capabilities", value_name = "FILE", global = true)]
+    pub capabilities: Option<PathBuf>,
+
+    /// Directory holding the target cluster's `/openapi/v3` discovery as
+    /// saved by `kubectl get --raw`: the index and one `.json` document per
+    /// group/version. Built-in kinds, aggregated APIs, and installed CRDs
+    /// resolve from it ahead of the chart's CRDs and the upstream catalogs;
+    /// only `--crd-override-dir` takes precedence.
+    #[arg(long = "cluster-openapi", value_name = "DIR", global = true)]
+    pub cluster_openapi: Option<PathBuf>,
+
+    /// YAML or JSON file of schemas keyed by resource field: each entry
+    /// names a `group` (empty for the core group), a `kind`, a dotted
+    /// `path` such as `spec.template.spec.containers[*].resources`, and a
+    /// `schema` merged onto whatever provider resolves that field, so every
+    /// values path reaching it in any chart sees the merged schema.
+    /// Repeatable; later files merge after earlier ones.
+    #[arg(long = "field-schema-override", value_name = "FILE", global = true)]
+    pub field_schema_override: Vec<PathBuf>,
+
+    /// `tar.zst` schema bundle written by `helm-schema bundle export`.
+    /// Every Kubernetes and CRD catalog schema lookup is served from it in
+    /// place of the managed caches, and nothing is downloaded.
+    #[arg(
+        long = "schema-bundle",
+        value_name = "FILE",
+        global = true,
+        conflicts_with_all = ["k8s_schema_cache_dir", "crd_catalog_cache_dir"]
+    )]
+    pub schema_bundle: Option<PathBuf>,
+
     /// Auto-extend the (single explicit) `--k8s-version` with older
     /// minors. `auto` uses the default window; `<n>` selects an
     /// explicit window size.
-    #[arg(long = "k8s-version-fallback", conflicts_with = "strict_k8s_version")]
+    #[arg(
+        long = "k8s-version-fallback",
+        conflicts_with = "strict_k8s_version",
+        global = true
+    )]
     pub k8s_version_fallback: Option<K8sVersionFallback>,
 
     /// Additional upstream K8s schema mirror URL. Repeatable. Per-source
     /// cache namespacing keeps mirror entries from masking the default.
-    #[arg(long = "k8s-schema-mirror")]
+    #[arg(long = "k8s-schema-mirror", global = true)]
     pub k8s_schema_mirror: Vec<String>,
 
     /// Managed cache root for K8s schemas. Subject to the cache
     /// invalidation contract.
-    #[arg(long = "k8s-schema-cache-dir")]
+    #[arg(long = "k8s-schema-cache-dir", global = true)]
     pub k8s_schema_cache_dir: Option<PathBuf>,
 
     /// Bypass K8s schema cache reads and re-check upstream directly.
     ///
     /// Successful responses and authoritative 404s still refresh cache
     /// state, so this can repair stale local entries.
-    #[arg(long = "no-cache")]
+    #[arg(long = "no-cache", global = true)]
     pub no_cache: bool,
 
     /// Suppress auto-fallback version semantics. Conflicts only with
     /// `--k8s-version-fallback`; orthogonal to `--k8s-schema-mirror`.
-    #[arg(long = "strict-k8s-version")]
+    #[arg(long = "strict-k8s-version", global = true)]
     pub strict_k8s_version: bool,
 
     /// Force offline. Equivalent to setting `HELM_SCHEMA_ALLOW_NET=0`.
-    #[arg(long)]
+    #[arg(long, global = true)]
     pub offline: bool,
 
     /// Skip K8s upstream schemas entirely.
-    #[arg(long = "no-k8s-schemas")]
+    #[arg(long = "no-k8s-schemas", global = true)]
     pub no_k8s_schemas: bool,
 }
 
@@ -1,7 +1,10 @@
 mod chart_args;
+mod command;
 mod crd_args;
 mod diag_args;
+mod dialect_args;
 mod emission_args;
+mod enum_args;
 mod inference_args;
 mod k8s_args;
 mod output_args;
@@ -13,9 +16,16 @@ use std::path::PathBuf;
 use clap::Parser;
 
 pub use chart_args::ChartArgs;
+pub use command::{
+    BindingLanguage, BundleCommand, BundleExportArgs, CacheAge, CacheCommand, CachePrefetchArgs,
+    CachePruneArgs, CacheReportArgs, Command, DiffArgs, DocsArgs, DocsFormat, ExplainArgs, LspArgs,
+    ReportFormat, TypesArgs, ValidateArgs, ValuesArgs,
+};
 pub use crd_args::{CrdArgs, CrdVersionLookup};
 pub use diag_args::{DiagArgs, DiagFormat};
+pub use dialect_args::SchemaDialect;
 pub use emission_args::{EmissionArgs, PolicyToggle};
+pub use enum_args::EnumInference;
 pub use inference_args::InferenceArgs;
 pub use k8s_args::{DEFAULT_AUTO_WINDOW, K8sArgs, K8sVersionFallback};
 pub use output_args::OutputArgs;
@@ -27,12 +37,17 @@ pub use profile_args::SchemaProfile;
 #[command(
     name = "helm-schema",
     about = "Generate JSON schema for Helm values.yaml",
+    subcommand_negates_reqs = true,
     after_long_help = "EMISSION RETENTION:\n  full  keeps mandatory facts, root/local ordinary conditionals, terminal clauses,\n        and kind partitions.\n  lean  keeps every mandatory fact and every locally anchored ordinary conditional;\n        it drops root-anchored ordinary conditionals, terminal clauses, and kind\n        partitions.\n\nMandatory facts cannot be disabled. The four emission override flags only change\nW-class refinements and therefore only widen acceptance when switched off."
 )]
 pub struct Cli {
+    /// Analysis query to run instead of schema generation.
+    #[command(subcommand)]
+    pub command: Option<Command>,
+
     /// Chart directory or packaged chart archive to analyze.
-    #[arg(value_name = "CHART_DIR")]
-    pub chart_dir: PathBuf,
+    #[arg(value_name = "CHART_DIR", required = true)]
+    pub chart_dir: Option<PathBuf>,
 
     /// Final output and reference-processing options.
     #[command(flatten)]
@@ -64,11 +79,11 @@ pub struct Cli {
 
     /// Read policy from this file instead of discovering `helm-schema.yaml`.
     /// Relative paths are resolved from the invocation working directory.
-    #[arg(long, value_name = "PATH", conflicts_with = "no_config")]
+    #[arg(long, value_name = "PATH", conflicts_with = "no_config", global = true)]
     pub config: Option<PathBuf>,
 
     /// Ignore both discovered and explicit chart policy configuration.
-    #[arg(long, conflicts_with = "config")]
+    #[arg(long, conflicts_with = "config", global = true)]
     pub no_config: bool,
 
     /// Print resolved policy values and their sources without analyzing the chart.
@@ -80,7 +95,7 @@ pub struct Cli {
     /// `lean` retains mandatory and locally anchored ordinary facts. It drops
     /// root ordinary conditionals, terminals, and kind partitions, so it only
     /// widens acceptance while reducing Helm's schema compilation cost.
-    #[arg(long, value_enum)]
+    #[arg(long, value_enum, global = true)]
     pub profile: Option<SchemaProfile>,
 
     /// W-class emission-policy overrides.
@@ -92,6 +107,76 @@ pub struct Cli {
     /// flags to layer (e.g. a shared cross-chart top-level schema followed by
     /// a chart-specific override). Overrides must carry their own definitions
     /// rather than reference helm-schema's private `$defs` names.
-    #[arg(long)]
+    #[arg(long, global = true)]
     pub override_schema: Vec<PathBuf>,
 }
+
+impl Cli {
+    /// Reject schema-writing flags given together with a subcommand.
+    ///
+    /// Subcommands share the analysis options but never write the generated
+    /// schema, so flags that only shape that write would otherwise be
+    /// accepted and silently ignored. `bundle export` keeps the output
+    /// transforms, which decide the `$ref` documents its schema reaches.
+    /// `validate` also needs at least one values file to check, positional
+    /// or given with `-f/--values`. A top-level `CHART_DIR` is rejected too,
+    /// since every subcommand takes its chart as its own argument.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error naming the offending flags when a subcommand is
+    /// selected, when a top-level `CHART_DIR` precedes it, or when
+    /// `validate` has no values file.
+    pub fn validate_subcommand_flags(&self) -> Result<(), String> {
+        let Some(command) = &self.command else {
+            return Ok(());
+        };
+        if let Some(chart_dir) = &self.chart_dir {
+            return Err(format!(
+                "CHART_DIR {} cannot precede a subcommand; pass the chart to the subcommand instead",
+                chart_dir.display()
+            ));
+        }
+        if let Command::Validate(args) = command
+            && args.values_files.is_empty()
+            && self.chart.values_files.is_empty()
+        {
+            return Err(
+                "validate needs at least one VALUES_FILE, given positionally or with -f/--values"
+                    .to_string(),
+            );
+        }
+        let output = &self.output;
+        let mut flags = [
+            (output.output.is_some(), "--output"),
+            (output.compact, "--compact"),
+            (output.check, "--check"),
+            (output.watch, "--watch"),
+            (self.print_effective_config, "--print-effective-config"),
+        ]
+        .into_iter()
+        .filter_map(|(set, flag)| set.then_some(flag))
+        .collect::<Vec<_>>();
+        if !matches!(command, Command::Bundle(_)) {
+            flags.extend(
+                [
+                    (output.strip_descriptions, "--strip-descriptions"),
+                    (output.keep_refs, "--keep-refs"),
+                    (output.inline_refs, "--inline-refs"),
+                    (!output.minimize, "--no-minimize"),
+                    (output.source_annotations, "--source-annotations"),
+                    (output.dialect != SchemaDialect::Draft07, "--dialect"),
+                ]
+                .into_iter()
+                .filter_map(|(set, flag)| set.then_some(flag)),
+            );
+        }
+        if flags.is_empty() {
+            return Ok(());
+        }
+        Err(format!(
+            "{} only apply to schema generation and cannot be combined with a subcommand",
+            flags.join(", ")
+        ))
+    }
+}
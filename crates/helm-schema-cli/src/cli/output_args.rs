@@ -4,6 +4,8 @@ use clap::Args;
 
 use helm_schema::output::{EmitRequest, JsonOutputFormat, OutputPipelineOptions, ReferencePolicy};
 
+use crate::cli::SchemaDialect;
+
 /// Destination, serialization, reference, and minimization options.
 #[derive(Args, Debug, Clone)]
 pub struct OutputArgs {
@@ -42,6 +44,45 @@ pub struct OutputArgs {
     /// downstream validator.
     #[arg(long = "no-minimize", action = clap::ArgAction::SetFalse)]
     pub minimize: bool,
+
+    /// Annotate each property with `x-helm-schema-sources`: the template
+    /// file, line, column, and helper chain of every use that reads it.
+    ///
+    /// Validators ignore the annotation; editors and reviewers can use it to
+    /// jump from a schema property to the template that produced it.
+    #[arg(long)]
+    pub source_annotations: bool,
+
+    /// JSON Schema dialect of the written schema.
+    ///
+    /// `2020-12` rewrites the Draft-07 output keyword by keyword and closes
+    /// objects with `unevaluatedProperties`, so keys a conditional branch
+    /// declares are accepted where that branch applies. Helm itself validates
+    /// with Draft-07.
+    #[arg(long, value_enum, default_value_t = SchemaDialect::Draft07)]
+    pub dialect: SchemaDialect,
+
+    /// Compare the regenerated schema with the committed one instead of
+    /// writing it, and fail when they differ.
+    ///
+    /// The committed schema is the `-o` path when given, otherwise the
+    /// chart's `values.schema.json`. The report lists added and removed
+    /// properties, widened and narrowed types, and required changes, and
+    /// says whether the emission policy or the templates moved.
+    #[arg(long)]
+    pub check: bool,
+
+    /// Keep running and regenerate the `-o` file whenever the chart's
+    /// templates, values, `Chart.yaml`, subcharts, CRDs, or policy change.
+    ///
+    /// Each run writes its diagnostics to standard error and a one-line
+    /// summary of the schema delta to standard output.
+    #[arg(
+        long,
+        requires = "output",
+        conflicts_with_all = ["check", "print_effective_config"]
+    )]
+    pub watch: bool,
 }
 
 impl OutputArgs {
@@ -51,6 +92,8 @@ impl OutputArgs {
             output: OutputPipelineOptions {
                 strip_descriptions: self.strip_descriptions,
                 minimize: self.minimize,
+                source_annotations: self.source_annotations,
+                dialect: self.dialect.into(),
             },
         }
     }
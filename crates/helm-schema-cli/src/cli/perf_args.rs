@@ -1,11 +1,40 @@
 use std::path::PathBuf;
 
 use clap::Args;
+use helm_schema::cache::AnalysisCache;
 
-/// Optional performance tracing output.
+/// Performance tracing and incremental analysis options.
 #[derive(Args, Debug, Clone, Default)]
 pub struct PerfArgs {
     /// Write a Perfetto-readable trace file for the run.
-    #[arg(long = "trace-output")]
+    #[arg(long = "trace-output", global = true)]
     pub trace_output: Option<PathBuf>,
+
+    /// Reuse cached per-template analysis results across runs.
+    ///
+    /// Entries are keyed by the release version, so a development build
+    /// whose analysis changed without a version bump can read stale results;
+    /// the cache is therefore off unless requested.
+    #[arg(long = "analysis-cache", global = true)]
+    pub analysis_cache: bool,
+
+    /// Directory for cached per-template analysis results; implies
+    /// `--analysis-cache`.
+    ///
+    /// Defaults to `HELM_SCHEMA_ANALYSIS_CACHE`, else `helm-schema/analysis`
+    /// under the per-user cache directory.
+    #[arg(long = "analysis-cache-dir", global = true, value_name = "DIR")]
+    pub analysis_cache_dir: Option<PathBuf>,
+}
+
+impl PerfArgs {
+    /// The analysis cache to use, or `None` unless one was requested.
+    #[must_use]
+    pub fn analysis_cache(&self) -> Option<AnalysisCache> {
+        if let Some(root) = &self.analysis_cache_dir {
+            return Some(AnalysisCache::new(root.clone()));
+        }
+        self.analysis_cache
+            .then(|| AnalysisCache::new(AnalysisCache::default_root()))
+    }
 }
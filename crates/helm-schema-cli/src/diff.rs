@@ -0,0 +1,196 @@
+use std::fmt::Write as _;
+use std::io::{BufWriter, Write};
+use std::path::Path;
+
+use helm_schema::diff::{SchemaChange, SchemaChangeKind, diff_schemas};
+use helm_schema::output::{
+    EmitRequest, FetchPolicy, LoadBudget, OutputPipelineOptions, PolicyInputOptions,
+    ReferencePolicy, SchemaDialect,
+};
+use helm_schema::{AnalysisSession, CliError, EngineResult};
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::chart_session::OpenedChart;
+use crate::cli::{Cli, DiffArgs, ReportFormat};
+use crate::diag_emit;
+use crate::explain::render_guard;
+
+/// Run `helm-schema diff`.
+///
+/// # Errors
+///
+/// Returns an error when either chart cannot be analyzed, the report cannot
+/// be written, or any breaking change is found.
+pub(crate) fn run(cli: &Cli, args: &DiffArgs) -> EngineResult<()> {
+    let run_span = tracing::info_span!(
+        "helm_schema_diff",
+        old_chart = %args.old_chart.display(),
+        new_chart = %args.new_chart.display()
+    );
+    let _entered = run_span.enter();
+
+    let old = open_session(cli, &args.old_chart)?;
+    let new = open_session(cli, &args.new_chart)?;
+    let mut changes = diff_schemas(&emit(cli, &old)?, &emit(cli, &new)?);
+    changes.extend(new.guard_changes_since(&old)?);
+    changes.sort_by(|left, right| left.path.cmp(&right.path));
+    let (breaking, compatible): (Vec<_>, Vec<_>) = changes
+        .into_iter()
+        .partition(|change| change.kind.is_breaking());
+
+    let stdout = std::io::stdout();
+    let mut out = BufWriter::new(stdout.lock());
+    match args.format {
+        ReportFormat::Text => out.write_all(render_text(&breaking, &compatible).as_bytes())?,
+        ReportFormat::Json => {
+            serde_json::to_writer_pretty(
+                &mut out,
+                &DiffReport {
+                    breaking: &breaking,
+                    compatible: &compatible,
+                },
+            )?;
+            out.write_all(b"\n")?;
+        }
+    }
+    out.flush()?;
+
+    if breaking.is_empty() {
+        Ok(())
+    } else {
+        Err(CliError::BreakingSchemaChanges {
+            count: breaking.len(),
+        })
+    }
+}
+
+fn open_session(cli: &Cli, chart: &Path) -> EngineResult<AnalysisSession> {
+    let opened = OpenedChart::open(cli, chart)?;
+    let diagnostics = opened.diagnostics.clone();
+    let session = opened.session(cli);
+    diag_emit::emit_to_stderr(&diagnostics, cli.diag.diag_format);
+    session
+}
+
+/// Both charts are emitted with the same self-contained request so the
+/// comparison only sees what the charts themselves changed.
+fn emit(cli: &Cli, session: &AnalysisSession) -> EngineResult<Value> {
+    session.emit_with_policy_paths(
+        &cli.override_schema,
+        PolicyInputOptions {
+            fetch_policy: FetchPolicy::input_assembly(!cli.k8s.offline),
+            load_budget: LoadBudget::default(),
+        },
+        EmitRequest {
+            reference_policy: ReferencePolicy::SelfContained,
+            output: OutputPipelineOptions {
+                strip_descriptions: true,
+                minimize: false,
+                source_annotations: false,
+                dialect: SchemaDialect::Draft07,
+            },
+        },
+    )
+}
+
+#[derive(Serialize)]
+struct DiffReport<'a> {
+    breaking: &'a [SchemaChange],
+    compatible: &'a [SchemaChange],
+}
+
+fn render_text(breaking: &[SchemaChange], compatible: &[SchemaChange]) -> String {
+    let mut out = String::new();
+    if breaking.is_empty() && compatible.is_empty() {
+        let _ = writeln!(out, "no values contract changes");
+        return out;
+    }
+    for (heading, changes) in [("breaking", breaking), ("compatible", compatible)] {
+        if changes.is_empty() {
+            continue;
+        }
+        let _ = writeln!(out, "{heading} changes ({}):", changes.len());
+        for change in changes {
+            let _ = writeln!(out, "  {}", render_change(change));
+        }
+    }
+    out
+}
+
+/// One-line rendering of a schema change, prefixed with `-` when it can
+/// break an existing values override and `+` when it only widens acceptance.
+pub(crate) fn render_change(change: &SchemaChange) -> String {
+    let marker = if change.kind.is_breaking() { '-' } else { '+' };
+    let path = if change.path.is_empty() {
+        ".Values".to_string()
+    } else {
+        format!(".Values.{}", change.path)
+    };
+    let detail = match &change.kind {
+        SchemaChangeKind::PropertyAdded => "property added".to_string(),
+        SchemaChangeKind::PropertyRemoved => "property removed".to_string(),
+        SchemaChangeKind::TypeWidened { from, to } => {
+            format!("type widened from {} to {}", types(from), types(to))
+        }
+        SchemaChangeKind::TypeNarrowed { from, to } => {
+            format!("type narrowed from {} to {}", types(from), types(to))
+        }
+        SchemaChangeKind::RequiredAdded => "now required".to_string(),
+        SchemaChangeKind::RequiredRemoved => "no longer required".to_string(),
+        SchemaChangeKind::EnumShrunk { removed } if removed.is_empty() => {
+            "enum introduced".to_string()
+        }
+        SchemaChangeKind::EnumShrunk { removed } => {
+            format!("enum no longer allows {}", literals(removed))
+        }
+        SchemaChangeKind::EnumGrown { added } if added.is_empty() => "enum removed".to_string(),
+        SchemaChangeKind::EnumGrown { added } => format!("enum now allows {}", literals(added)),
+        SchemaChangeKind::ConstraintNarrowed { keyword, from, to } => {
+            format!(
+                "{keyword} narrowed from {} to {}",
+                bound(from.as_ref()),
+                bound(to.as_ref())
+            )
+        }
+        SchemaChangeKind::ConstraintWidened { keyword, from, to } => {
+            format!(
+                "{keyword} widened from {} to {}",
+                bound(from.as_ref()),
+                bound(to.as_ref())
+            )
+        }
+        SchemaChangeKind::ConditionalAdded => "conditional overlay added".to_string(),
+        SchemaChangeKind::ConditionalRemoved => "conditional overlay removed".to_string(),
+        SchemaChangeKind::GuardAdded { guards } => {
+            let guards: Vec<String> = guards.iter().map(render_guard).collect();
+            format!("now read only under {}", guards.join(", "))
+        }
+    };
+    let scope = if change.conditional {
+        " (in a conditional branch)"
+    } else {
+        ""
+    };
+    format!("{marker} {path}: {detail}{scope}")
+}
+
+fn bound(value: Option<&Value>) -> String {
+    value.map_or_else(|| "none".to_string(), Value::to_string)
+}
+
+fn types(types: &[String]) -> String {
+    if types.is_empty() {
+        "any".to_string()
+    } else {
+        types.join("|")
+    }
+}
+
+fn literals(values: &[Value]) -> String {
+    values
+        .iter()
+        .map(Value::to_string)
+        .collect::<Vec<_>>()
+        .join(", ")
+}
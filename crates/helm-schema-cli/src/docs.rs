@@ -0,0 +1,202 @@
+use std::fmt::Write as _;
+use std::io::{BufWriter, Write};
+
+use helm_schema::EngineResult;
+use helm_schema::contract::Guard;
+use helm_schema::output::{
+    EmitRequest, FetchPolicy, LoadBudget, OutputPipelineOptions, PolicyInputOptions,
+    ReferencePolicy, SchemaDialect,
+};
+use helm_schema::reference::ValuesReferenceEntry;
+
+use crate::chart_session::OpenedChart;
+use crate::cli::{Cli, DocsArgs, DocsFormat};
+use crate::diag_emit;
+use crate::explain::render_guard;
+
+const HEADINGS: [&str; 6] = [
+    "Key",
+    "Type",
+    "Default",
+    "Description",
+    "Required",
+    "Condition",
+];
+
+/// Run `helm-schema docs`.
+///
+/// # Errors
+///
+/// Returns an error when the chart cannot be analyzed or the reference cannot
+/// be written to stdout.
+pub(crate) fn run(cli: &Cli, args: &DocsArgs) -> EngineResult<()> {
+    let run_span = tracing::info_span!(
+        "helm_schema_docs",
+        chart_dir = %args.chart_dir.display()
+    );
+    let _entered = run_span.enter();
+
+    let opened = OpenedChart::open(cli, &args.chart_dir)?;
+    let diagnostics = opened.diagnostics.clone();
+    let entries = opened.session(cli).and_then(|session| {
+        let schema = session.emit_with_policy_paths(
+            &cli.override_schema,
+            PolicyInputOptions {
+                fetch_policy: FetchPolicy::input_assembly(!cli.k8s.offline),
+                load_budget: LoadBudget::default(),
+            },
+            EmitRequest {
+                reference_policy: ReferencePolicy::SelfContained,
+                output: OutputPipelineOptions {
+                    strip_descriptions: false,
+                    minimize: false,
+                    source_annotations: false,
+                    dialect: SchemaDialect::Draft07,
+                },
+            },
+        )?;
+        session.values_reference(&schema)
+    });
+    diag_emit::emit_to_stderr(&diagnostics, cli.diag.diag_format);
+    let rows: Vec<[String; 6]> = entries?.iter().map(cells).collect();
+
+    let stdout = std::io::stdout();
+    let mut out = BufWriter::new(stdout.lock());
+    let rendered = match args.format {
+        DocsFormat::Markdown => render_markdown(&rows),
+        DocsFormat::Html => render_html(&rows),
+    };
+    out.write_all(rendered.as_bytes())?;
+    out.flush()?;
+    Ok(())
+}
+
+/// Plain-text cells of one row, in [`HEADINGS`] order.
+fn cells(entry: &ValuesReferenceEntry) -> [String; 6] {
+    let types = if entry.types.is_empty() {
+        "any".to_string()
+    } else {
+        entry.types.join(" | ")
+    };
+    // An empty cell marks a path no values.yaml declares; a declared empty
+    // string renders as `""`.
+    let default = entry
+        .default
+        .as_ref()
+        .map(ToString::to_string)
+        .unwrap_or_default();
+    let condition = entry
+        .conditions
+        .iter()
+        .map(|conjunction| render_conjunction(conjunction))
+        .collect::<Vec<_>>()
+        .join(" or ");
+    [
+        entry.path.clone(),
+        types,
+        default,
+        entry.description.clone().unwrap_or_default(),
+        if entry.required { "yes" } else { "no" }.to_string(),
+        condition,
+    ]
+}
+
+fn render_conjunction(conjunction: &[Guard]) -> String {
+    let guards: Vec<String> = conjunction.iter().map(render_guard).collect();
+    if conjunction.len() > 1 {
+        format!("({})", guards.join(" and "))
+    } else {
+        guards.join(" and ")
+    }
+}
+
+fn render_markdown(rows: &[[String; 6]]) -> String {
+    let mut out = String::new();
+    let _ = writeln!(out, "| {} |", HEADINGS.join(" | "));
+    let _ = writeln!(out, "|{}", "---|".repeat(HEADINGS.len()));
+    for [key, types, default, description, required, condition] in rows {
+        let _ = writeln!(
+            out,
+            "| {} | {} | {} | {} | {} | {} |",
+            markdown_code(key),
+            markdown_text(types),
+            if default.is_empty() {
+                "*(not set)*".to_string()
+            } else {
+                markdown_code(default)
+            },
+            markdown_text(description),
+            required,
+            if condition.is_empty() {
+                String::new()
+            } else {
+                markdown_code(condition)
+            },
+        );
+    }
+    out
+}
+
+fn markdown_code(text: &str) -> String {
+    format!("`{}`", text.replace('|', "\\|").replace('\n', " "))
+}
+
+fn markdown_text(text: &str) -> String {
+    text.trim().replace('|', "\\|").replace('\n', "<br>")
+}
+
+fn render_html(rows: &[[String; 6]]) -> String {
+    let mut out = String::new();
+    let _ = writeln!(out, "<table>");
+    let _ = writeln!(out, "  <thead>");
+    let _ = writeln!(out, "    <tr>");
+    for heading in HEADINGS {
+        let _ = writeln!(out, "      <th>{heading}</th>");
+    }
+    let _ = writeln!(out, "    </tr>");
+    let _ = writeln!(out, "  </thead>");
+    let _ = writeln!(out, "  <tbody>");
+    for [key, types, default, description, required, condition] in rows {
+        let _ = writeln!(out, "    <tr>");
+        let _ = writeln!(out, "      <td><code>{}</code></td>", html_escape(key));
+        let _ = writeln!(out, "      <td>{}</td>", html_escape(types));
+        if default.is_empty() {
+            let _ = writeln!(out, "      <td><em>not set</em></td>");
+        } else {
+            let _ = writeln!(out, "      <td><code>{}</code></td>", html_escape(default));
+        }
+        let _ = writeln!(
+            out,
+            "      <td>{}</td>",
+            html_escape(description.trim()).replace('\n', "<br>")
+        );
+        let _ = writeln!(out, "      <td>{required}</td>");
+        if condition.is_empty() {
+            let _ = writeln!(out, "      <td></td>");
+        } else {
+            let _ = writeln!(
+                out,
+                "      <td><code>{}</code></td>",
+                html_escape(condition)
+            );
+        }
+        let _ = writeln!(out, "    </tr>");
+    }
+    let _ = writeln!(out, "  </tbody>");
+    let _ = writeln!(out, "</table>");
+    out
+}
+
+fn html_escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for character in text.chars() {
+        match character {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            other => escaped.push(other),
+        }
+    }
+    escaped
+}
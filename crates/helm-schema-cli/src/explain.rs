@@ -0,0 +1,533 @@
+use std::collections::BTreeSet;
+use std::fmt::Write as _;
+use std::io::{BufWriter, Write};
+
+use helm_schema::contract::{
+    ConditionalGuard, ContractUse, ContractValuePathFacts, Guard, GuardValue, MetadataFieldKind,
+    SourceMap, ValueKind, split_value_path,
+};
+use helm_schema::{EngineResult, ValuePathExplanation};
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::chart_session::OpenedChart;
+use crate::cli::{Cli, ExplainArgs, ReportFormat};
+use crate::diag_emit;
+
+/// Maximum `$ref` hops followed while looking up the emitted type of a path.
+const MAX_REF_DEPTH: usize = 32;
+
+/// Run `helm-schema explain`.
+///
+/// # Errors
+///
+/// Returns an error when the chart cannot be analyzed or the report cannot be
+/// written to stdout.
+pub(crate) fn run(cli: &Cli, args: &ExplainArgs) -> EngineResult<()> {
+    let run_span = tracing::info_span!(
+        "helm_schema_explain",
+        chart_dir = %args.chart_dir.display(),
+        values_path = %args.values_path
+    );
+    let _entered = run_span.enter();
+
+    let opened = OpenedChart::open(cli, &args.chart_dir)?;
+    let diagnostics = opened.diagnostics.clone();
+    let report = opened.session(cli).and_then(|session| {
+        let explanation = session.explain(&args.values_path)?;
+        let schema = session.generated_schema()?.schema;
+        Ok(ExplainReport::new(
+            explanation,
+            &schema,
+            &mut session.source_map(),
+        ))
+    });
+    diag_emit::emit_to_stderr(&diagnostics, cli.diag.diag_format);
+    let report = report?;
+
+    let stdout = std::io::stdout();
+    let mut out = BufWriter::new(stdout.lock());
+    match args.format {
+        ReportFormat::Text => out.write_all(report.to_text().as_bytes())?,
+        ReportFormat::Json => {
+            serde_json::to_writer_pretty(&mut out, &report)?;
+            out.write_all(b"\n")?;
+        }
+    }
+    out.flush()?;
+    Ok(())
+}
+
+/// Self-contained explanation of one values path, shared by both formats.
+#[derive(Debug, Serialize)]
+struct ExplainReport {
+    path: String,
+    /// JSON Schema types the emitted schema accepts at the path.
+    schema_types: Vec<String>,
+    type_hints: Vec<Value>,
+    has_default_fallback: bool,
+    /// Names of the behavioral facts that hold for the path.
+    facts: Vec<String>,
+    guard_predicates: Vec<ConditionalGuard>,
+    metadata_fields: Vec<MetadataFieldKind>,
+    uses: Vec<UseReport>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "snake_case")]
+enum UseRelation {
+    /// The template reads the explained path itself.
+    Exact,
+    /// The template reads a path below the explained path.
+    Descendant,
+}
+
+#[derive(Debug, Serialize)]
+struct UseReport {
+    source_expr: String,
+    relation: UseRelation,
+    kind: ValueKind,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    resource: Option<ResourceSummary>,
+    /// Rendered manifest field the value flows into.
+    rendered_path: String,
+    /// Guard conjunctions under which the use renders; empty when the use
+    /// renders unconditionally.
+    conditions: Vec<Vec<Guard>>,
+    locations: Vec<UseLocation>,
+}
+
+#[derive(Debug, Serialize)]
+struct ResourceSummary {
+    api_version: String,
+    kind: String,
+}
+
+#[derive(Debug, Serialize)]
+struct UseLocation {
+    file: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    line: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    column: Option<usize>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    helper_chain: Vec<String>,
+}
+
+impl ExplainReport {
+    fn new(explanation: ValuePathExplanation, schema: &Value, source_map: &mut SourceMap) -> Self {
+        let ValuePathExplanation {
+            path,
+            exact_uses,
+            descendant_uses,
+            value_path_facts,
+            guard_predicates,
+            metadata_fields,
+            type_hints,
+            has_default_fallback,
+        } = explanation;
+        let uses = exact_uses
+            .into_iter()
+            .map(|use_| (use_, UseRelation::Exact))
+            .chain(
+                descendant_uses
+                    .into_iter()
+                    .map(|use_| (use_, UseRelation::Descendant)),
+            )
+            .map(|(use_, relation)| UseReport::new(use_, relation, source_map))
+            .collect();
+        Self {
+            schema_types: schema_types_at(schema, &path),
+            path,
+            type_hints,
+            has_default_fallback,
+            facts: value_path_facts
+                .as_ref()
+                .map(true_facts)
+                .unwrap_or_default(),
+            guard_predicates,
+            metadata_fields,
+            uses,
+        }
+    }
+
+    fn to_text(&self) -> String {
+        let mut out = String::new();
+        let path = if self.path.is_empty() {
+            ".Values".to_string()
+        } else {
+            format!(".Values.{}", self.path)
+        };
+        let _ = writeln!(out, "{path}");
+        let schema_types = if self.schema_types.is_empty() {
+            "any".to_string()
+        } else {
+            self.schema_types.join(" | ")
+        };
+        let _ = writeln!(out, "  schema type: {schema_types}");
+        if !self.type_hints.is_empty() {
+            let hints: Vec<String> = self
+                .type_hints
+                .iter()
+                .map(|hint| {
+                    hint.get("type")
+                        .and_then(Value::as_str)
+                        .map_or_else(|| hint.to_string(), str::to_string)
+                })
+                .collect();
+            let _ = writeln!(out, "  type hints from consumers: {}", hints.join(", "));
+        }
+        if self.has_default_fallback {
+            let _ = writeln!(out, "  a template `default` supplies a value when unset");
+        }
+        if !self.facts.is_empty() {
+            let _ = writeln!(out, "  facts: {}", self.facts.join(", "));
+        }
+        if !self.metadata_fields.is_empty() {
+            let fields: Vec<&str> = self
+                .metadata_fields
+                .iter()
+                .map(|kind| match kind {
+                    MetadataFieldKind::StringMap => "labels/annotations",
+                    MetadataFieldKind::Name => "name",
+                    MetadataFieldKind::Namespace => "namespace",
+                })
+                .collect();
+            let _ = writeln!(out, "  metadata fields: {}", fields.join(", "));
+        }
+        if !self.guard_predicates.is_empty() {
+            let _ = writeln!(out, "  guards:");
+            for guard in &self.guard_predicates {
+                let _ = writeln!(out, "    {}", render_conditional_guard(guard));
+            }
+        }
+        if self.uses.is_empty() {
+            let _ = writeln!(out, "  no template reads this path");
+            return out;
+        }
+        let _ = writeln!(out, "  uses:");
+        for use_ in &self.uses {
+            use_.write_text(&mut out);
+        }
+        out
+    }
+}
+
+impl UseReport {
+    fn new(use_: ContractUse, relation: UseRelation, source_map: &mut SourceMap) -> Self {
+        let locations = use_
+            .provenance
+            .iter()
+            .map(|provenance| match source_map.locate(provenance) {
+                Some(location) => UseLocation {
+                    file: location.file,
+                    line: Some(location.line),
+                    column: Some(location.column),
+                    helper_chain: location.helper_chain,
+                },
+                None => UseLocation {
+                    file: source_map.relative_file(&provenance.template_path),
+                    line: None,
+                    column: None,
+                    helper_chain: provenance.helper_chain.clone(),
+                },
+            })
+            .collect();
+        let conditions = if use_.condition.is_unconditional() {
+            Vec::new()
+        } else {
+            use_.condition.guard_conjunctions()
+        };
+        Self {
+            source_expr: use_.source_expr,
+            relation,
+            kind: use_.kind,
+            resource: use_.resource.map(|resource| ResourceSummary {
+                api_version: resource.api_version,
+                kind: resource.kind,
+            }),
+            rendered_path: use_.path.0.join("."),
+            conditions,
+            locations,
+        }
+    }
+
+    fn write_text(&self, out: &mut String) {
+        let relation = match self.relation {
+            UseRelation::Exact => "",
+            UseRelation::Descendant => " (descendant)",
+        };
+        let _ = writeln!(out, "  - .Values.{}{relation}", self.source_expr);
+        match &self.resource {
+            Some(resource) if !self.rendered_path.is_empty() => {
+                let _ = writeln!(
+                    out,
+                    "      renders into: {} {} {} ({:?})",
+                    resource.api_version, resource.kind, self.rendered_path, self.kind
+                );
+            }
+            _ if self.rendered_path.is_empty() => {
+                let _ = writeln!(out, "      read by template logic only");
+            }
+            _ => {
+                let _ = writeln!(
+                    out,
+                    "      renders into: {} ({:?})",
+                    self.rendered_path, self.kind
+                );
+            }
+        }
+        if self.conditions.is_empty() {
+            let _ = writeln!(out, "      when: always");
+        } else {
+            for (index, conjunction) in self.conditions.iter().enumerate() {
+                let label = if index == 0 { "when:" } else { "  or:" };
+                let rendered: Vec<String> = conjunction.iter().map(render_guard).collect();
+                let _ = writeln!(out, "      {label} {}", rendered.join(" and "));
+            }
+        }
+        for location in &self.locations {
+            let mut line = location.file.clone();
+            if let (Some(row), Some(column)) = (location.line, location.column) {
+                let _ = write!(line, ":{row}:{column}");
+            }
+            if !location.helper_chain.is_empty() {
+                let _ = write!(line, " via {}", location.helper_chain.join(" -> "));
+            }
+            let _ = writeln!(out, "      at {line}");
+        }
+    }
+}
+
+/// Names of the facts that hold, in declaration order.
+fn true_facts(facts: &ContractValuePathFacts) -> Vec<String> {
+    let Ok(Value::Object(map)) = serde_json::to_value(facts) else {
+        return Vec::new();
+    };
+    map.into_iter()
+        .filter(|(_, value)| value.as_bool() == Some(true))
+        .map(|(name, _)| name)
+        .collect()
+}
+
+/// Schema nodes that constrain the value at `path`, with `$ref`s and
+/// `allOf`/`anyOf`/`oneOf` arms expanded.
+pub(crate) fn schema_nodes_at<'a>(schema: &'a Value, path: &str) -> Vec<&'a Value> {
+    let mut nodes = expand(schema, schema, 0);
+    for segment in split_value_path(path) {
+        nodes = nodes
+            .into_iter()
+            .flat_map(|node| child(node, &segment))
+            .flat_map(|node| expand(schema, node, 0))
+            .collect();
+    }
+    nodes
+}
+
+/// JSON Schema types the emitted schema declares at a values path.
+///
+/// Composition arms are unioned and local `$ref`s followed, so the result
+/// describes every type some branch of the schema admits. Conditional
+/// `if`/`then`/`else` overlays are ignored: they refine, never widen, the
+/// base declaration.
+pub(crate) fn schema_types_at(schema: &Value, path: &str) -> Vec<String> {
+    let mut types = BTreeSet::new();
+    for node in schema_nodes_at(schema, path) {
+        match node.get("type") {
+            Some(Value::String(ty)) => {
+                types.insert(ty.clone());
+            }
+            Some(Value::Array(tys)) => {
+                types.extend(tys.iter().filter_map(Value::as_str).map(str::to_string));
+            }
+            _ => {}
+        }
+    }
+    types.into_iter().collect()
+}
+
+fn child<'a>(node: &'a Value, segment: &str) -> Vec<&'a Value> {
+    if segment == "*" {
+        return ["items", "additionalProperties"]
+            .into_iter()
+            .filter_map(|key| node.get(key))
+            .filter(|child| child.is_object())
+            .collect();
+    }
+    if let Some(property) = node.get("properties").and_then(|props| props.get(segment)) {
+        return vec![property];
+    }
+    node.get("additionalProperties")
+        .filter(|child| child.is_object())
+        .into_iter()
+        .collect()
+}
+
+fn expand<'a>(root: &'a Value, node: &'a Value, depth: usize) -> Vec<&'a Value> {
+    if depth > MAX_REF_DEPTH {
+        return Vec::new();
+    }
+    let mut out = vec![node];
+    if let Some(target) = node
+        .get("$ref")
+        .and_then(Value::as_str)
+        .and_then(|reference| reference.strip_prefix('#'))
+        .and_then(|pointer| root.pointer(pointer))
+    {
+        out.extend(expand(root, target, depth + 1));
+    }
+    for keyword in ["allOf", "anyOf", "oneOf"] {
+        for arm in node
+            .get(keyword)
+            .and_then(Value::as_array)
+            .into_iter()
+            .flatten()
+        {
+            out.extend(expand(root, arm, depth + 1));
+        }
+    }
+    out
+}
+
+fn values_ref(path: &str) -> String {
+    format!(".Values.{path}")
+}
+
+fn literal(value: &GuardValue) -> String {
+    match value {
+        GuardValue::String(value) => format!("{value:?}"),
+        other => other.to_string(),
+    }
+}
+
+pub(crate) fn render_guard(guard: &Guard) -> String {
+    match guard {
+        Guard::Truthy { path } => values_ref(path),
+        Guard::Not { path } => format!("not {}", values_ref(path)),
+        Guard::Eq { path, value } => format!("eq {} {}", values_ref(path), literal(value)),
+        Guard::NotEq { path, value } => format!("ne {} {}", values_ref(path), literal(value)),
+        Guard::Absent { path } => format!("{} is absent", values_ref(path)),
+        Guard::MatchesPattern {
+            path,
+            pattern,
+            templated,
+        } => {
+            let subject = if *templated {
+                format!("(tpl {})", values_ref(path))
+            } else {
+                values_ref(path)
+            };
+            format!("regexMatch {pattern:?} {subject}")
+        }
+        Guard::NotMatchesPattern { path, pattern } => {
+            format!("not (regexMatch {pattern:?} {})", values_ref(path))
+        }
+        Guard::RangeKeyPrefix { path, prefix } => {
+            format!("key of {} has prefix {prefix:?}", values_ref(path))
+        }
+        Guard::RangeKeyEquals { path, key } => {
+            format!("key of {} is {key:?}", values_ref(path))
+        }
+        Guard::RangeKeyMatches { path, pattern } => {
+            format!("key of {} matches {pattern:?}", values_ref(path))
+        }
+        Guard::Or { paths } => {
+            let paths: Vec<String> = paths.iter().map(|path| values_ref(path)).collect();
+            format!("or {}", paths.join(" "))
+        }
+        Guard::AnyOf { alternatives } => {
+            let alternatives: Vec<String> = alternatives
+                .iter()
+                .map(|conjunction| {
+                    let guards: Vec<String> = conjunction.iter().map(render_guard).collect();
+                    format!("({})", guards.join(" and "))
+                })
+                .collect();
+            alternatives.join(" or ")
+        }
+        Guard::Range { path } => format!("range {}", values_ref(path)),
+        Guard::With { path } => format!("with {}", values_ref(path)),
+        Guard::Default { path } => format!("default ... {}", values_ref(path)),
+        Guard::TypeIs { path, schema_type } => {
+            format!("typeIs {schema_type:?} {}", values_ref(path))
+        }
+        Guard::NotTypeIs { path, schema_type } => {
+            format!("not (typeIs {schema_type:?} {})", values_ref(path))
+        }
+        Guard::IntGt { path, bound } => format!("gt {} {bound}", values_ref(path)),
+        Guard::IntLt { path, bound } => format!("lt {} {bound}", values_ref(path)),
+        Guard::AtMostOneMember { path } => format!("len {} <= 1", values_ref(path)),
+        Guard::MinMembers { path, bound } | Guard::MinItems { path, bound } => {
+            format!("len {} >= {bound}", values_ref(path))
+        }
+        Guard::HasKey { path, key } => format!("hasKey {} {key:?}", values_ref(path)),
+        Guard::NotHasKey { path, key } => format!("not (hasKey {} {key:?})", values_ref(path)),
+        Guard::ContainsEquals { path, value } => {
+            format!("has {} {}", literal(value), values_ref(path))
+        }
+        Guard::ContainsMemberEquals {
+            path,
+            member,
+            value,
+        } => format!(
+            "some item of {} has {member} = {}",
+            values_ref(path),
+            literal(value)
+        ),
+        Guard::ContainsTruthyMember { path, member } => {
+            format!("some item of {} has truthy {member}", values_ref(path))
+        }
+    }
+}
+
+fn render_conditional_guard(guard: &ConditionalGuard) -> String {
+    match guard {
+        ConditionalGuard::Truthy { path } => values_ref(path),
+        ConditionalGuard::With { path } => format!("with {}", values_ref(path)),
+        ConditionalGuard::Eq { path, value } => {
+            format!("eq {} {}", values_ref(path), literal(value))
+        }
+        ConditionalGuard::NotEq { path, value } => {
+            format!("ne {} {}", values_ref(path), literal(value))
+        }
+        ConditionalGuard::Absent { path } => format!("{} is absent", values_ref(path)),
+        ConditionalGuard::TypeIs { path, schema_type } => {
+            format!("typeIs {schema_type:?} {}", values_ref(path))
+        }
+        ConditionalGuard::MatchesPattern { path, pattern } => {
+            format!("regexMatch {pattern:?} {}", values_ref(path))
+        }
+        ConditionalGuard::IntGt { path, bound } => format!("gt {} {bound}", values_ref(path)),
+        ConditionalGuard::IntLt { path, bound } => format!("lt {} {bound}", values_ref(path)),
+        ConditionalGuard::HasKey { path, key } => format!("hasKey {} {key:?}", values_ref(path)),
+        ConditionalGuard::ContainsMemberEquals {
+            path,
+            member,
+            value,
+        } => format!(
+            "some item of {} has {member} = {}",
+            values_ref(path),
+            literal(value)
+        ),
+        ConditionalGuard::ContainsTruthyMember { path, member } => {
+            format!("some item of {} has truthy {member}", values_ref(path))
+        }
+        ConditionalGuard::ContainsEquals { path, value } => {
+            format!("has {} {}", literal(value), values_ref(path))
+        }
+        ConditionalGuard::AtMostOneMember { path } => format!("len {} <= 1", values_ref(path)),
+        ConditionalGuard::MinMembers { path, bound }
+        | ConditionalGuard::MinItems { path, bound } => {
+            format!("len {} >= {bound}", values_ref(path))
+        }
+        ConditionalGuard::Not(inner) => format!("not ({})", render_conditional_guard(inner)),
+        ConditionalGuard::AllOf(guards) => {
+            let guards: Vec<String> = guards.iter().map(render_conditional_guard).collect();
+            format!("({})", guards.join(" and "))
+        }
+        ConditionalGuard::AnyOf(guards) => {
+            let guards: Vec<String> = guards.iter().map(render_conditional_guard).collect();
+            format!("({})", guards.join(" or "))
+        }
+    }
+}
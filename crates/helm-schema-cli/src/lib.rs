@@ -1,20 +1,35 @@
 //! Command-line argument model and invocation policy for `helm-schema`.
 
+mod bundle;
+mod cache;
+mod chart_session;
+mod check;
 /// Typed command-line arguments and option validation.
 pub mod cli;
 mod config;
 mod diag_emit;
+mod diff;
+mod docs;
+mod explain;
+mod lsp;
+mod types;
+mod validate;
+mod values;
+mod watch;
 
 use std::io::{BufWriter, Write};
 use std::path::Path;
 
-use helm_schema::chart_source::RootChartSource;
-use helm_schema::diagnostics::DiagnosticSink;
-use helm_schema::output::{FetchPolicy, LoadBudget, PolicyInputOptions, write_schema_json};
-use helm_schema::{AnalysisSession, EngineResult};
+use helm_schema::EngineResult;
+use helm_schema::output::{
+    FetchPolicy, JsonOutputFormat, LoadBudget, PolicyInputOptions, write_schema_json,
+};
 use tracing_subscriber::Layer as _;
 use tracing_subscriber::layer::SubscriberExt as _;
 
+use crate::chart_session::OpenedChart;
+use crate::cli::Command;
+
 pub use cli::Cli;
 pub use helm_schema::generation::{GenerateOptions, SchemaProfile};
 pub use helm_schema::provider::ProviderOptions;
@@ -48,77 +63,57 @@ pub fn run(cli: Cli) -> EngineResult<()> {
 }
 
 fn run_inner(cli: Cli) -> EngineResult<()> {
+    cli.validate_subcommand_flags()
+        .map_err(CliError::CliValidation)?;
+    match &cli.command {
+        Some(Command::Explain(args)) => explain::run(&cli, args),
+        Some(Command::Validate(args)) => validate::run(&cli, args),
+        Some(Command::Diff(args)) => diff::run(&cli, args),
+        Some(Command::Docs(args)) => docs::run(&cli, args),
+        Some(Command::Values(args)) => values::run(&cli, args),
+        Some(Command::Types(args)) => types::run(&cli, args),
+        Some(Command::Lsp(args)) => lsp::run(&cli, args),
+        Some(Command::Bundle(command)) => bundle::run(&cli, command),
+        Some(Command::Cache(command)) => cache::run(&cli, command),
+        None => generate(cli),
+    }
+}
+
+fn generate(cli: Cli) -> EngineResult<()> {
+    let chart_dir = cli.chart_dir.clone().ok_or_else(|| {
+        CliError::CliValidation("a CHART_DIR is required for schema generation".to_string())
+    })?;
     let run_span = tracing::info_span!(
         "helm_schema_run",
-        chart_dir = %cli.chart_dir.display()
+        chart_dir = %chart_dir.display()
     );
     let _entered = run_span.enter();
 
-    let root_source = RootChartSource::open(&cli.chart_dir, LoadBudget::default())?;
-    let effective_config = config::resolve(
-        &root_source,
-        &cli.chart_dir,
-        cli.config.as_deref(),
-        cli.no_config,
-        cli.profile,
-        cli.emission,
-    )?;
-
-    let diagnostics = DiagnosticSink::new();
-    if !effective_config.file_weakening.is_empty() {
-        diagnostics.push(
-            helm_schema::diagnostics::Diagnostic::DiscoveredConfigWeakensEmission {
-                disabled_knobs: effective_config
-                    .file_weakening
-                    .iter()
-                    .map(|knob| (*knob).to_string())
-                    .collect(),
-                explicit: effective_config.file_weakening_is_explicit,
-            },
-        );
+    let committed_path = if cli.output.check {
+        Some(check::committed_schema_path(
+            &chart_dir,
+            cli.output.output.as_deref(),
+        )?)
+    } else {
+        None
+    };
+
+    if let Some(output) = cli.output.output.as_deref().filter(|_| cli.output.watch) {
+        return watch::run(&cli, &chart_dir, output);
     }
+
+    let opened = OpenedChart::open(&cli, &chart_dir)?;
+    let diagnostics = opened.diagnostics.clone();
     if cli.print_effective_config {
         let stdout = std::io::stdout();
         let mut out = BufWriter::new(stdout.lock());
-        out.write_all(effective_config.to_yaml()?.as_bytes())?;
+        out.write_all(opened.effective_config.to_yaml()?.as_bytes())?;
         out.flush()?;
         diag_emit::emit_to_stderr(&diagnostics, cli.diag.diag_format);
         return Ok(());
     }
 
-    let generated = (|| {
-        cli.crd.validate().map_err(CliError::CliValidation)?;
-        let fallback_window = cli
-            .k8s
-            .resolved_fallback_window()
-            .map_err(CliError::CliValidation)?;
-        let chart_dir = root_source.into_chart_dir();
-        let provider_options = ProviderOptions {
-            k8s_versions: cli.k8s.k8s_version.clone(),
-            k8s_version_fallback_window: fallback_window,
-            k8s_schema_mirrors: cli.k8s.k8s_schema_mirror.clone(),
-            k8s_schema_cache_dir: cli.k8s.k8s_schema_cache_dir.clone(),
-            no_cache: cli.k8s.no_cache,
-            allow_net: !cli.k8s.offline,
-            disable_k8s_schemas: cli.k8s.no_k8s_schemas,
-            crd_lookup_loose: matches!(cli.crd.lookup_mode(), cli::CrdVersionLookup::Loose),
-            crd_catalog_mirrors: cli.crd.crd_catalog_mirror.clone(),
-            crd_catalog_cache_dir: cli.crd.crd_catalog_cache_dir.clone(),
-            crd_override_dir: cli.crd.crd_override_dir.clone(),
-            local_schema_universe: helm_schema::provider::LocalSchemaUniverse::default(),
-            crd_cache_record_source: cli.crd.crd_cache_record_source,
-            api_version_guess: cli.inference.enabled(),
-        };
-        let opts = GenerateOptions {
-            chart_dir,
-            include_tests: !cli.chart.exclude_tests,
-            include_subchart_values: !cli.chart.no_subchart_values,
-            values_files: cli.chart.values_files.clone(),
-            infer_required: cli.chart.infer_required,
-            emission: effective_config.selection,
-            provider: provider_options,
-        };
-        let session = AnalysisSession::with_diagnostics(opts, diagnostics.clone());
+    let generated = opened.session(&cli).and_then(|session| {
         let policy_input_options = PolicyInputOptions {
             fetch_policy: FetchPolicy::input_assembly(!cli.k8s.offline),
             load_budget: LoadBudget::default(),
@@ -128,20 +123,18 @@ fn run_inner(cli: Cli) -> EngineResult<()> {
             policy_input_options,
             cli.output.emit_request(),
         )
-    })();
+    });
     diag_emit::emit_to_stderr(&diagnostics, cli.diag.diag_format);
     let schema = generated?;
 
+    if let Some(committed_path) = committed_path {
+        return check::run(&committed_path, &schema);
+    }
+
     let json_format = cli.output.json_format();
 
     if let Some(path) = cli.output.output {
-        let mut out = BufWriter::new(create_output_file(&path)?);
-        write_schema_json(&mut out, &schema, json_format)
-            .map_err(|err| write_output_error_with_path(err, &path))?;
-        out.flush().map_err(|err| CliError::WriteOutput {
-            path: path.clone(),
-            source: err,
-        })?;
+        write_output_file(&path, &schema, json_format)?;
     } else {
         let stdout = std::io::stdout();
         let mut out = BufWriter::new(stdout.lock());
@@ -152,6 +145,20 @@ fn run_inner(cli: Cli) -> EngineResult<()> {
     Ok(())
 }
 
+fn write_output_file(
+    path: &Path,
+    schema: &serde_json::Value,
+    json_format: JsonOutputFormat,
+) -> EngineResult<()> {
+    let mut out = BufWriter::new(create_output_file(path)?);
+    write_schema_json(&mut out, schema, json_format)
+        .map_err(|err| write_output_error_with_path(err, path))?;
+    out.flush().map_err(|err| CliError::WriteOutput {
+        path: path.to_path_buf(),
+        source: err,
+    })
+}
+
 fn create_output_file(path: &Path) -> EngineResult<std::fs::File> {
     if let Some(parent) = path.parent() {
         std::fs::create_dir_all(parent).map_err(|err| CliError::CreateOutputDir {
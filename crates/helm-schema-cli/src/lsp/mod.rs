@@ -0,0 +1,618 @@
+//! `helm-schema lsp`: a Language Server Protocol server for values files.
+//!
+//! The server answers from the same [`AnalysisSession`] the other
+//! subcommands use, so hovers and definitions point at the template
+//! expressions that read a key instead of at a detached schema document.
+//! Charts are analyzed on first use and re-analyzed when any of their files
+//! is saved; values buffers are re-validated on every change.
+
+mod transport;
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::Write as _;
+use std::path::{Path, PathBuf};
+
+use helm_schema::contract::{ContractUse, SourceLocation, append_value_path};
+use helm_schema::output::{
+    EmitRequest, FetchPolicy, LoadBudget, OutputPipelineOptions, PolicyInputOptions,
+    ReferencePolicy, SchemaDialect,
+};
+use helm_schema::validation::{ValuesDocument, extract_values_yaml_key_lines};
+use helm_schema::{AnalysisSession, EngineResult};
+use serde_json::{Value, json};
+
+use crate::chart_session::OpenedChart;
+use crate::cli::{Cli, LspArgs};
+use crate::diag_emit;
+use crate::explain::{schema_nodes_at, schema_types_at};
+
+/// Diagnostic source reported to the editor.
+const DIAGNOSTIC_SOURCE: &str = "helm-schema";
+
+/// Diagnostic code of a key the chart's contract does not know.
+const UNKNOWN_KEY_CODE: &str = "unknown-key";
+
+/// Key spliced into the buffer to locate the mapping a completion targets.
+const COMPLETION_PLACEHOLDER: &str = "__helm_schema_completion__";
+
+/// Language id a client opens a document with to have it treated as a
+/// values file whatever its name.
+const VALUES_LANGUAGE_ID: &str = "helm-values";
+
+/// JSON-RPC error code for requests the server does not implement.
+const METHOD_NOT_FOUND: i64 = -32601;
+
+/// Run `helm-schema lsp` until the client sends `exit` or closes stdin.
+///
+/// # Errors
+///
+/// Returns an error when stdio fails or the client sends a malformed message.
+pub(crate) fn run(cli: &Cli, args: &LspArgs) -> EngineResult<()> {
+    let stdin = std::io::stdin();
+    let mut input = stdin.lock();
+    let stdout = std::io::stdout();
+    let mut output = stdout.lock();
+    let mut server = Server {
+        cli,
+        default_chart: args.chart_dir.clone(),
+        charts: BTreeMap::new(),
+        documents: BTreeMap::new(),
+        associated: BTreeSet::new(),
+        outbox: Vec::new(),
+    };
+    while let Some(message) = transport::read_message(&mut input)? {
+        let method = message.get("method").and_then(Value::as_str);
+        let params = message.get("params").unwrap_or(&Value::Null);
+        match (method, message.get("id")) {
+            (Some("exit"), _) => break,
+            (Some(method), Some(id)) => {
+                let response = match server.request(method, params) {
+                    Ok(result) => json!({ "jsonrpc": "2.0", "id": id, "result": result }),
+                    Err((code, message)) => json!({
+                        "jsonrpc": "2.0",
+                        "id": id,
+                        "error": { "code": code, "message": message },
+                    }),
+                };
+                server.outbox.push(response);
+            }
+            (Some(method), None) => server.notify(method, params),
+            // Responses to server-initiated requests; the server sends none.
+            (None, _) => {}
+        }
+        for message in server.outbox.drain(..) {
+            transport::write_message(&mut output, &message)?;
+        }
+    }
+    Ok(())
+}
+
+/// One analyzed chart.
+struct ChartState {
+    session: AnalysisSession,
+    /// Emitted schema with descriptions kept for hovers and completions.
+    schema: Value,
+}
+
+impl ChartState {
+    fn analyze(cli: &Cli, chart_dir: &Path) -> EngineResult<Self> {
+        let opened = OpenedChart::open(cli, chart_dir)?;
+        let diagnostics = opened.diagnostics.clone();
+        let analyzed = opened.session(cli).and_then(|session| {
+            let schema = session.emit_with_policy_paths(
+                &cli.override_schema,
+                PolicyInputOptions {
+                    fetch_policy: FetchPolicy::input_assembly(!cli.k8s.offline),
+                    load_budget: LoadBudget::default(),
+                },
+                EmitRequest {
+                    reference_policy: ReferencePolicy::SelfContained,
+                    output: OutputPipelineOptions {
+                        strip_descriptions: false,
+                        minimize: false,
+                        source_annotations: false,
+                        dialect: SchemaDialect::Draft07,
+                    },
+                },
+            )?;
+            Ok(Self { session, schema })
+        });
+        // stdout carries the protocol; chart diagnostics go to the client's
+        // server log through stderr.
+        diag_emit::emit_to_stderr(&diagnostics, cli.diag.diag_format);
+        analyzed
+    }
+
+    /// Template locations that read `path`, or read below it when no
+    /// template reads the path itself.
+    fn template_locations(&self, path: &str) -> EngineResult<BTreeSet<SourceLocation>> {
+        let explanation = self.session.explain(path)?;
+        let uses: &[ContractUse] = if explanation.exact_uses.is_empty() {
+            &explanation.descendant_uses
+        } else {
+            &explanation.exact_uses
+        };
+        let mut source_map = self.session.source_map();
+        Ok(uses
+            .iter()
+            .flat_map(|use_| &use_.provenance)
+            .filter_map(|provenance| source_map.locate(provenance))
+            .collect())
+    }
+}
+
+struct Server<'a> {
+    cli: &'a Cli,
+    /// Chart for values files outside every chart directory.
+    default_chart: Option<PathBuf>,
+    /// Analyzed charts by directory; `None` records a failed analysis until
+    /// the next save.
+    charts: BTreeMap<PathBuf, Option<ChartState>>,
+    /// Open buffers by document URI.
+    documents: BTreeMap<String, String>,
+    /// Open documents the client marked as values files through their
+    /// language id.
+    associated: BTreeSet<String>,
+    outbox: Vec<Value>,
+}
+
+impl Server<'_> {
+    fn request(&mut self, method: &str, params: &Value) -> Result<Value, (i64, String)> {
+        match method {
+            "initialize" => {
+                if self.default_chart.is_none() {
+                    self.default_chart = params
+                        .get("rootUri")
+                        .and_then(Value::as_str)
+                        .and_then(uri_to_path)
+                        .filter(|root| root.join("Chart.yaml").is_file());
+                }
+                Ok(json!({
+                    "capabilities": {
+                        "textDocumentSync": { "openClose": true, "change": 1, "save": true },
+                        "completionProvider": {},
+                        "hoverProvider": true,
+                        "definitionProvider": true,
+                    },
+                    "serverInfo": {
+                        "name": "helm-schema",
+                        "version": env!("CARGO_PKG_VERSION"),
+                    },
+                }))
+            }
+            "shutdown" => Ok(Value::Null),
+            "textDocument/hover" => Ok(self.hover(params).unwrap_or(Value::Null)),
+            "textDocument/definition" => Ok(self.definition(params).unwrap_or(Value::Null)),
+            "textDocument/completion" => Ok(self.completion(params).unwrap_or_else(|| json!([]))),
+            _ => Err((METHOD_NOT_FOUND, format!("unsupported method {method}"))),
+        }
+    }
+
+    fn notify(&mut self, method: &str, params: &Value) {
+        let Some(uri) = params
+            .pointer("/textDocument/uri")
+            .and_then(Value::as_str)
+            .map(str::to_string)
+        else {
+            return;
+        };
+        match method {
+            "textDocument/didOpen" => {
+                if let Some(text) = params.pointer("/textDocument/text").and_then(Value::as_str) {
+                    self.documents.insert(uri.clone(), text.to_string());
+                }
+                let language = params
+                    .pointer("/textDocument/languageId")
+                    .and_then(Value::as_str);
+                if language == Some(VALUES_LANGUAGE_ID) {
+                    self.associated.insert(uri.clone());
+                }
+                self.publish_diagnostics(&uri);
+            }
+            "textDocument/didChange" => {
+                // Full synchronization: the last change carries the buffer.
+                if let Some(text) = params
+                    .get("contentChanges")
+                    .and_then(Value::as_array)
+                    .and_then(|changes| changes.last())
+                    .and_then(|change| change.get("text"))
+                    .and_then(Value::as_str)
+                {
+                    self.documents.insert(uri.clone(), text.to_string());
+                }
+                self.publish_diagnostics(&uri);
+            }
+            "textDocument/didSave" => {
+                let Some(chart_dir) = uri_to_path(&uri).and_then(|path| self.chart_for(&path))
+                else {
+                    return;
+                };
+                self.charts.remove(&chart_dir);
+                let open: Vec<String> = self.documents.keys().cloned().collect();
+                for open_uri in open {
+                    let same_chart = uri_to_path(&open_uri)
+                        .and_then(|path| self.chart_for(&path))
+                        .is_some_and(|dir| dir == chart_dir);
+                    if same_chart {
+                        self.publish_diagnostics(&open_uri);
+                    }
+                }
+            }
+            "textDocument/didClose" => {
+                self.documents.remove(&uri);
+                self.associated.remove(&uri);
+                self.push_diagnostics(&uri, &[]);
+            }
+            _ => {}
+        }
+    }
+
+    /// Directory of the chart a file belongs to: the nearest ancestor with a
+    /// `Chart.yaml`, else the default chart.
+    fn chart_for(&self, path: &Path) -> Option<PathBuf> {
+        path.ancestors()
+            .skip(1)
+            .find(|dir| dir.join("Chart.yaml").is_file())
+            .map(Path::to_path_buf)
+            .or_else(|| self.default_chart.clone())
+    }
+
+    fn chart(&mut self, chart_dir: &Path) -> Option<&ChartState> {
+        if !self.charts.contains_key(chart_dir) {
+            let state = match ChartState::analyze(self.cli, chart_dir) {
+                Ok(state) => Some(state),
+                Err(err) => {
+                    self.log_error(&format!("analyzing {}: {err}", chart_dir.display()));
+                    None
+                }
+            };
+            self.charts.insert(chart_dir.to_path_buf(), state);
+        }
+        self.charts.get(chart_dir).and_then(Option::as_ref)
+    }
+
+    /// Values document at `uri` with its chart and current text, or `None`
+    /// when the document is a template, other chart file, or YAML file the
+    /// client did not associate with values.
+    fn values_document(&self, uri: &str) -> Option<(PathBuf, PathBuf, String)> {
+        let path = uri_to_path(uri)?;
+        let chart_dir = self.chart_for(&path)?;
+        if !self.associated.contains(uri) && !is_values_file(&path, &chart_dir) {
+            return None;
+        }
+        let text = match self.documents.get(uri) {
+            Some(text) => text.clone(),
+            None => std::fs::read_to_string(&path).ok()?,
+        };
+        Some((path, chart_dir, text))
+    }
+
+    fn publish_diagnostics(&mut self, uri: &str) {
+        let Some((path, chart_dir, text)) = self.values_document(uri) else {
+            return;
+        };
+        let name = path.display().to_string();
+        let Some(chart) = self.chart(&chart_dir) else {
+            return;
+        };
+        let documents = [ValuesDocument {
+            name: name.clone(),
+            source: text.clone(),
+        }];
+        let lines: Vec<&str> = text.lines().collect();
+        let checked = chart
+            .session
+            .validate_values(&chart.schema, &documents)
+            .and_then(|violations| {
+                let unknown_keys = chart.session.unknown_values_keys(&text)?;
+                Ok((violations, unknown_keys))
+            });
+        let diagnostics = match checked {
+            Ok((violations, unknown_keys)) => {
+                // Unknown keys come from the contract rather than from how
+                // closed the emitted schema happens to be.
+                let violations = violations
+                    .iter()
+                    .filter(|violation| violation.file.as_deref() == Some(name.as_str()))
+                    .filter(|violation| violation.keyword != "additionalProperties")
+                    .filter_map(|violation| {
+                        line_diagnostic(
+                            &lines,
+                            violation.line?,
+                            &violation.keyword,
+                            &violation.message,
+                        )
+                    });
+                let unknown_keys = unknown_keys.iter().filter_map(|key| {
+                    let message = format!(
+                        "unknown key .Values.{}: no template reads it and values.yaml does not declare it",
+                        key.path
+                    );
+                    line_diagnostic(&lines, key.line, UNKNOWN_KEY_CODE, &message)
+                });
+                violations.chain(unknown_keys).collect()
+            }
+            Err(err) => vec![json!({
+                "range": {
+                    "start": { "line": 0, "character": 0 },
+                    "end": { "line": 0, "character": 0 },
+                },
+                "severity": 1,
+                "source": DIAGNOSTIC_SOURCE,
+                "message": err.to_string(),
+            })],
+        };
+        self.push_diagnostics(uri, &diagnostics);
+    }
+
+    fn push_diagnostics(&mut self, uri: &str, diagnostics: &[Value]) {
+        self.outbox.push(json!({
+            "jsonrpc": "2.0",
+            "method": "textDocument/publishDiagnostics",
+            "params": { "uri": uri, "diagnostics": diagnostics },
+        }));
+    }
+
+    fn log_error(&mut self, message: &str) {
+        self.outbox.push(json!({
+            "jsonrpc": "2.0",
+            "method": "window/logMessage",
+            "params": { "type": 1, "message": message },
+        }));
+    }
+
+    /// Values document, chart, and contract path of the key under the
+    /// request position.
+    fn key_at(&mut self, params: &Value) -> Option<(PathBuf, String)> {
+        let uri = params
+            .pointer("/textDocument/uri")
+            .and_then(Value::as_str)?;
+        let line = params.pointer("/position/line").and_then(Value::as_u64)?;
+        let (_, chart_dir, text) = self.values_document(uri)?;
+        let line = usize::try_from(line).ok()? + 1;
+        // Sequence items put several keys on one line; the deepest one is
+        // the key the line declares.
+        let key = extract_values_yaml_key_lines(&text)
+            .into_iter()
+            .filter(|(_, key_line)| *key_line == line)
+            .map(|(path, _)| path)
+            .max_by_key(String::len)?;
+        Some((chart_dir, contract_path(&key)))
+    }
+
+    fn hover(&mut self, params: &Value) -> Option<Value> {
+        let (chart_dir, path) = self.key_at(params)?;
+        let chart = self.chart(&chart_dir)?;
+        let types = schema_types_at(&chart.schema, &path);
+        let types = if types.is_empty() {
+            "any".to_string()
+        } else {
+            types.join(" | ")
+        };
+        let mut markdown = format!("`.Values.{path}`: `{types}`");
+        if let Some(description) = description_at(&chart.schema, &path) {
+            markdown.push_str("\n\n");
+            markdown.push_str(description.trim());
+        }
+        let locations = chart.template_locations(&path).ok()?;
+        if locations.is_empty() {
+            markdown.push_str("\n\nNot read by any template.");
+        } else {
+            markdown.push_str("\n\nRead by:");
+            for location in locations {
+                let _ = write!(
+                    markdown,
+                    "\n- `{}:{}:{}`",
+                    location.file, location.line, location.column
+                );
+                if !location.helper_chain.is_empty() {
+                    let _ = write!(markdown, " via `{}`", location.helper_chain.join(" -> "));
+                }
+            }
+        }
+        Some(json!({ "contents": { "kind": "markdown", "value": markdown } }))
+    }
+
+    fn definition(&mut self, params: &Value) -> Option<Value> {
+        let (chart_dir, path) = self.key_at(params)?;
+        let chart = self.chart(&chart_dir)?;
+        let locations: Vec<Value> = chart
+            .template_locations(&path)
+            .ok()?
+            .into_iter()
+            .map(|location| {
+                let position = json!({
+                    "line": location.line.saturating_sub(1),
+                    "character": location.column.saturating_sub(1),
+                });
+                json!({
+                    "uri": path_to_uri(&chart_dir.join(&location.file)),
+                    "range": { "start": position, "end": position },
+                })
+            })
+            .collect();
+        Some(Value::Array(locations))
+    }
+
+    fn completion(&mut self, params: &Value) -> Option<Value> {
+        let uri = params
+            .pointer("/textDocument/uri")
+            .and_then(Value::as_str)?;
+        let line =
+            usize::try_from(params.pointer("/position/line").and_then(Value::as_u64)?).ok()?;
+        let character = usize::try_from(
+            params
+                .pointer("/position/character")
+                .and_then(Value::as_u64)?,
+        )
+        .ok()?;
+        let (_, chart_dir, text) = self.values_document(uri)?;
+        let parent = completion_parent(&text, line, character)?;
+        let chart = self.chart(&chart_dir)?;
+        let names: BTreeSet<&String> = schema_nodes_at(&chart.schema, &parent)
+            .into_iter()
+            .filter_map(|node| node.get("properties").and_then(Value::as_object))
+            .flat_map(serde_json::Map::keys)
+            .collect();
+        let items: Vec<Value> = names
+            .into_iter()
+            .map(|name| {
+                let path = append_value_path(&parent, name);
+                let mut item = json!({
+                    "label": name,
+                    "kind": 10,
+                    "detail": schema_types_at(&chart.schema, &path).join(" | "),
+                });
+                if let Some(description) = description_at(&chart.schema, &path)
+                    && let Some(object) = item.as_object_mut()
+                {
+                    object.insert(
+                        "documentation".to_string(),
+                        json!({ "kind": "markdown", "value": description }),
+                    );
+                }
+                item
+            })
+            .collect();
+        Some(Value::Array(items))
+    }
+}
+
+/// Error diagnostic spanning the key on the one-based `line` of a values
+/// buffer, from its indentation to the line end.
+fn line_diagnostic(lines: &[&str], line: usize, code: &str, message: &str) -> Option<Value> {
+    let line_index = line.checked_sub(1)?;
+    let text = lines.get(line_index).copied().unwrap_or_default();
+    let indent = text.len() - text.trim_start().len();
+    Some(json!({
+        "range": {
+            "start": { "line": line_index, "character": utf16_len(text.get(..indent).unwrap_or_default()) },
+            "end": { "line": line_index, "character": utf16_len(text) },
+        },
+        "severity": 1,
+        "source": DIAGNOSTIC_SOURCE,
+        "code": code,
+        "message": message,
+    }))
+}
+
+/// Contract path of the mapping a key typed at (`line`, `character`) would
+/// belong to.
+///
+/// The partial key under the cursor is replaced with a placeholder key so
+/// the values scanner, which understands indentation and sequence items,
+/// resolves its parent.
+fn completion_parent(text: &str, line: usize, character: usize) -> Option<String> {
+    let mut lines: Vec<String> = text.lines().map(str::to_string).collect();
+    if lines.len() <= line {
+        lines.resize(line + 1, String::new());
+    }
+    let current = lines.get(line)?;
+    let before = current.get(..byte_offset(current, character))?;
+    let word_start = before
+        .rfind(|c: char| !(c.is_alphanumeric() || matches!(c, '_' | '-' | '.')))
+        .map_or(0, |index| index + 1);
+    let lead = before.get(..word_start)?;
+    // Only complete keys: the cursor must follow indentation or a sequence
+    // item marker, not a value.
+    if !matches!(lead.trim(), "" | "-") {
+        return None;
+    }
+    let spliced = format!("{lead}{COMPLETION_PLACEHOLDER}:");
+    *lines.get_mut(line)? = spliced;
+    let key = extract_values_yaml_key_lines(&lines.join("\n"))
+        .into_keys()
+        .find(|path| path.ends_with(COMPLETION_PLACEHOLDER))?;
+    let parent = key
+        .strip_suffix(COMPLETION_PLACEHOLDER)?
+        .trim_end_matches('.');
+    Some(contract_path(parent))
+}
+
+/// First description among the schema nodes at `path`.
+fn description_at(schema: &Value, path: &str) -> Option<String> {
+    schema_nodes_at(schema, path)
+        .into_iter()
+        .find_map(|node| node.get("description").and_then(Value::as_str))
+        .map(str::to_string)
+}
+
+/// Contract spelling (`ports.*.port`) of a values-file path (`ports[*].port`).
+fn contract_path(values_path: &str) -> String {
+    values_path.replace("[*]", ".*")
+}
+
+/// Whether `path` is a values file of the chart at `chart_dir`: a
+/// `values*.yaml` or `values*.yml` file that is not one of the chart's
+/// templates, CRDs, or vendored subcharts. Environment values files kept
+/// outside the chart count too.
+fn is_values_file(path: &Path, chart_dir: &Path) -> bool {
+    let is_values_name = path
+        .file_name()
+        .and_then(|name| name.to_str())
+        .is_some_and(|name| {
+            name.starts_with("values")
+                && [".yaml", ".yml"]
+                    .iter()
+                    .any(|extension| name.ends_with(extension))
+        });
+    let in_chart_sources = path.strip_prefix(chart_dir).is_ok_and(|relative| {
+        relative
+            .components()
+            .next()
+            .and_then(|first| first.as_os_str().to_str())
+            .is_some_and(|first| matches!(first, "templates" | "crds" | "charts"))
+    });
+    is_values_name && !in_chart_sources
+}
+
+fn uri_to_path(uri: &str) -> Option<PathBuf> {
+    let encoded = uri.strip_prefix("file://")?;
+    let bytes = encoded.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut index = 0;
+    while let Some(&byte) = bytes.get(index) {
+        let escaped = (byte == b'%')
+            .then(|| bytes.get(index + 1..index + 3))
+            .flatten()
+            .and_then(|hex| std::str::from_utf8(hex).ok())
+            .and_then(|hex| u8::from_str_radix(hex, 16).ok());
+        if let Some(escaped) = escaped {
+            decoded.push(escaped);
+            index += 3;
+        } else {
+            decoded.push(byte);
+            index += 1;
+        }
+    }
+    String::from_utf8(decoded).ok().map(PathBuf::from)
+}
+
+fn path_to_uri(path: &Path) -> String {
+    let mut uri = "file://".to_string();
+    for byte in path.to_string_lossy().bytes() {
+        if byte.is_ascii_alphanumeric() || matches!(byte, b'/' | b'-' | b'_' | b'.' | b'~') {
+            uri.push(char::from(byte));
+        } else {
+            let _ = write!(uri, "%{byte:02X}");
+        }
+    }
+    uri
+}
+
+/// Byte offset of the UTF-16 column `character` in `line`, clamped to the
+/// line end.
+fn byte_offset(line: &str, character: usize) -> usize {
+    let mut units = 0;
+    for (offset, c) in line.char_indices() {
+        if units >= character {
+            return offset;
+        }
+        units += c.len_utf16();
+    }
+    line.len()
+}
+
+fn utf16_len(text: &str) -> usize {
+    text.encode_utf16().count()
+}
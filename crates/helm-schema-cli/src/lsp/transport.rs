@@ -0,0 +1,49 @@
+use std::io::{BufRead, Write};
+
+use helm_schema::{CliError, EngineResult};
+use serde_json::Value;
+
+/// Reads one base-protocol message; `None` at end of input.
+///
+/// # Errors
+///
+/// Returns an error when the header block lacks a `Content-Length`, the
+/// stream fails, or the body is not JSON.
+pub(super) fn read_message(input: &mut impl BufRead) -> EngineResult<Option<Value>> {
+    let mut content_length = None;
+    let mut line = String::new();
+    loop {
+        line.clear();
+        if input.read_line(&mut line)? == 0 {
+            return Ok(None);
+        }
+        let header = line.trim_end();
+        if header.is_empty() {
+            break;
+        }
+        if let Some((name, value)) = header.split_once(':')
+            && name.eq_ignore_ascii_case("content-length")
+        {
+            content_length = value.trim().parse::<usize>().ok();
+        }
+    }
+    let length = content_length.ok_or_else(|| {
+        CliError::CliValidation("language server message without Content-Length".to_string())
+    })?;
+    let mut body = vec![0; length];
+    input.read_exact(&mut body)?;
+    Ok(Some(serde_json::from_slice(&body)?))
+}
+
+/// Writes one base-protocol message.
+///
+/// # Errors
+///
+/// Returns an error when the stream fails.
+pub(super) fn write_message(output: &mut impl Write, message: &Value) -> EngineResult<()> {
+    let body = serde_json::to_vec(message)?;
+    write!(output, "Content-Length: {}\r\n\r\n", body.len())?;
+    output.write_all(&body)?;
+    output.flush()?;
+    Ok(())
+}
@@ -0,0 +1,43 @@
+use std::io::{BufWriter, Write};
+
+use helm_schema::EngineResult;
+use helm_schema::bindings::{BindingOptions, render_type_bindings};
+
+use crate::chart_session::OpenedChart;
+use crate::cli::{Cli, TypesArgs};
+use crate::diag_emit;
+
+/// Run `helm-schema types`.
+///
+/// # Errors
+///
+/// Returns an error when the chart cannot be analyzed or the bindings cannot
+/// be written to stdout.
+pub(crate) fn run(cli: &Cli, args: &TypesArgs) -> EngineResult<()> {
+    let run_span = tracing::info_span!(
+        "helm_schema_types",
+        chart_dir = %args.chart_dir.display()
+    );
+    let _entered = run_span.enter();
+
+    let opened = OpenedChart::open(cli, &args.chart_dir)?;
+    let diagnostics = opened.diagnostics.clone();
+    let model = opened
+        .session(cli)
+        .and_then(|session| session.values_type_model());
+    diag_emit::emit_to_stderr(&diagnostics, cli.diag.diag_format);
+    let bindings = render_type_bindings(
+        &model?,
+        &BindingOptions {
+            language: args.language.into(),
+            root_name: args.root_name.clone(),
+            go_package: args.go_package.clone(),
+        },
+    );
+
+    let stdout = std::io::stdout();
+    let mut out = BufWriter::new(stdout.lock());
+    out.write_all(bindings.as_bytes())?;
+    out.flush()?;
+    Ok(())
+}
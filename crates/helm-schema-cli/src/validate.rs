@@ -0,0 +1,138 @@
+use std::fmt::Write as _;
+use std::io::{BufWriter, Write};
+
+use helm_schema::output::{
+    EmitRequest, FetchPolicy, LoadBudget, OutputPipelineOptions, PolicyInputOptions,
+    ReferencePolicy, SchemaDialect,
+};
+use helm_schema::validation::{ValuesDocument, ValuesViolation};
+use helm_schema::{CliError, EngineResult};
+use serde::Serialize;
+
+use crate::chart_session::OpenedChart;
+use crate::cli::{Cli, ReportFormat, ValidateArgs};
+use crate::diag_emit;
+
+/// Run `helm-schema validate`.
+///
+/// # Errors
+///
+/// Returns an error when a values file cannot be read or parsed, the chart
+/// cannot be analyzed, or any violation is found.
+pub(crate) fn run(cli: &Cli, args: &ValidateArgs) -> EngineResult<()> {
+    let run_span = tracing::info_span!(
+        "helm_schema_validate",
+        chart_dir = %args.chart_dir.display()
+    );
+    let _entered = run_span.enter();
+
+    // `-f/--values` reads as `helm install -f` here: those files are checked
+    // too, layered after the positional ones.
+    let documents = args
+        .values_files
+        .iter()
+        .chain(&cli.chart.values_files)
+        .map(|path| {
+            let source =
+                std::fs::read_to_string(path).map_err(|source| CliError::ReadValuesFile {
+                    path: path.clone(),
+                    source,
+                })?;
+            Ok(ValuesDocument {
+                name: path.display().to_string(),
+                source,
+            })
+        })
+        .collect::<EngineResult<Vec<_>>>()?;
+
+    let opened = OpenedChart::open(cli, &args.chart_dir)?;
+    let diagnostics = opened.diagnostics.clone();
+    let violations = opened.session(cli).and_then(|session| {
+        let schema = session.emit_with_policy_paths(
+            &cli.override_schema,
+            PolicyInputOptions {
+                fetch_policy: FetchPolicy::input_assembly(!cli.k8s.offline),
+                load_budget: LoadBudget::default(),
+            },
+            EmitRequest {
+                reference_policy: ReferencePolicy::SelfContained,
+                output: OutputPipelineOptions {
+                    strip_descriptions: true,
+                    minimize: false,
+                    source_annotations: false,
+                    dialect: SchemaDialect::Draft07,
+                },
+            },
+        )?;
+        session.validate_values(&schema, &documents)
+    });
+    diag_emit::emit_to_stderr(&diagnostics, cli.diag.diag_format);
+    let violations = violations?;
+
+    let stdout = std::io::stdout();
+    let mut out = BufWriter::new(stdout.lock());
+    match args.format {
+        ReportFormat::Text => out.write_all(render_text(&violations).as_bytes())?,
+        ReportFormat::Json => {
+            serde_json::to_writer_pretty(
+                &mut out,
+                &ValidateReport {
+                    valid: violations.is_empty(),
+                    violations: &violations,
+                },
+            )?;
+            out.write_all(b"\n")?;
+        }
+    }
+    out.flush()?;
+
+    if violations.is_empty() {
+        Ok(())
+    } else {
+        Err(CliError::ValuesValidationFailed {
+            count: violations.len(),
+        })
+    }
+}
+
+#[derive(Serialize)]
+struct ValidateReport<'a> {
+    valid: bool,
+    violations: &'a [ValuesViolation],
+}
+
+fn render_text(violations: &[ValuesViolation]) -> String {
+    let mut out = String::new();
+    if violations.is_empty() {
+        let _ = writeln!(out, "values conform to the inferred contract");
+        return out;
+    }
+    for violation in violations {
+        let location = match (&violation.file, violation.line) {
+            (Some(file), Some(line)) => format!("{file}:{line}"),
+            _ => "<chart defaults>".to_string(),
+        };
+        let path = if violation.path.is_empty() {
+            ".Values".to_string()
+        } else {
+            format!(".Values.{}", violation.path)
+        };
+        let _ = writeln!(
+            out,
+            "{location}: {path}: {} ({})",
+            violation.message, violation.keyword
+        );
+        for source in &violation.template_sources {
+            let _ = write!(
+                out,
+                "    read at {}:{}:{}",
+                source.file, source.line, source.column
+            );
+            if !source.helper_chain.is_empty() {
+                let _ = write!(out, " via {}", source.helper_chain.join(" -> "));
+            }
+            out.push('\n');
+        }
+    }
+    out
+}
@@ -0,0 +1,55 @@
+use std::io::{BufWriter, Write};
+
+use helm_schema::EngineResult;
+use helm_schema::output::{
+    EmitRequest, FetchPolicy, LoadBudget, OutputPipelineOptions, PolicyInputOptions,
+    ReferencePolicy, SchemaDialect,
+};
+
+use crate::chart_session::OpenedChart;
+use crate::cli::{Cli, ValuesArgs};
+use crate::diag_emit;
+
+/// Run `helm-schema values`.
+///
+/// # Errors
+///
+/// Returns an error when the chart cannot be analyzed or the skeleton cannot
+/// be written to stdout.
+pub(crate) fn run(cli: &Cli, args: &ValuesArgs) -> EngineResult<()> {
+    let run_span = tracing::info_span!(
+        "helm_schema_values",
+        chart_dir = %args.chart_dir.display()
+    );
+    let _entered = run_span.enter();
+
+    let opened = OpenedChart::open(cli, &args.chart_dir)?;
+    let diagnostics = opened.diagnostics.clone();
+    let skeleton = opened.session(cli).and_then(|session| {
+        let schema = session.emit_with_policy_paths(
+            &cli.override_schema,
+            PolicyInputOptions {
+                fetch_policy: FetchPolicy::input_assembly(!cli.k8s.offline),
+                load_budget: LoadBudget::default(),
+            },
+            EmitRequest {
+                reference_policy: ReferencePolicy::SelfContained,
+                output: OutputPipelineOptions {
+                    strip_descriptions: false,
+                    minimize: false,
+                    source_annotations: false,
+                    dialect: SchemaDialect::Draft07,
+                },
+            },
+        )?;
+        session.values_skeleton(&schema)
+    });
+    diag_emit::emit_to_stderr(&diagnostics, cli.diag.diag_format);
+    let skeleton = skeleton?;
+
+    let stdout = std::io::stdout();
+    let mut out = BufWriter::new(stdout.lock());
+    out.write_all(skeleton.as_bytes())?;
+    out.flush()?;
+    Ok(())
+}
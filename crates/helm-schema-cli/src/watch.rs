@@ -0,0 +1,195 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
+
+use helm_schema::EngineResult;
+use helm_schema::cache::AnalysisCache;
+use helm_schema::diff::diff_schemas;
+use helm_schema::output::{FetchPolicy, LoadBudget, PolicyInputOptions};
+use serde_json::Value;
+
+use crate::chart_session::OpenedChart;
+use crate::cli::Cli;
+use crate::diag_emit;
+
+/// How often the watched inputs are polled for changes.
+const POLL_INTERVAL: Duration = Duration::from_millis(250);
+
+/// Chart entries whose changes trigger a regeneration; directories are
+/// watched recursively.
+const WATCHED_CHART_ENTRIES: [&str; 7] = [
+    "templates",
+    "values.yaml",
+    "Chart.yaml",
+    "Chart.lock",
+    "charts",
+    "crds",
+    "helm-schema.yaml",
+];
+
+/// Modification time and length of every watched file.
+type Snapshot = BTreeMap<PathBuf, (Option<SystemTime>, u64)>;
+
+/// Regenerate `output` now and after every change to the chart inputs,
+/// until the process is interrupted.
+///
+/// Each run writes its diagnostics to standard error and a one-line summary
+/// of the schema delta to standard output. A failed run is reported and the
+/// last written schema is kept.
+///
+/// Per-template analysis results outlive each run, in memory unless an
+/// on-disk analysis cache was requested, so a run re-evaluates only the
+/// templates whose files changed and the callers of changed helpers.
+///
+/// # Errors
+///
+/// Returns an error when standard output cannot be written.
+pub(crate) fn run(cli: &Cli, chart_dir: &Path, output: &Path) -> EngineResult<()> {
+    let mut previous = std::fs::read(output)
+        .ok()
+        .and_then(|bytes| serde_json::from_slice::<Value>(&bytes).ok());
+    let cache = cli
+        .perf
+        .analysis_cache()
+        .unwrap_or_else(AnalysisCache::in_memory);
+    let mut snapshot = snapshot(&watched_paths(cli, chart_dir));
+    loop {
+        let summary = match regenerate(cli, chart_dir, output, &cache) {
+            Ok(schema) => {
+                let summary = summarize(output, previous.as_ref(), &schema);
+                previous = Some(schema);
+                summary
+            }
+            Err(err) => format!("{}: regeneration failed: {err}", output.display()),
+        };
+        let stdout = std::io::stdout();
+        let mut out = stdout.lock();
+        writeln!(out, "{summary}")?;
+        out.flush()?;
+        let next = wait_for_change(cli, chart_dir, &snapshot);
+        invalidate_changed(&cache, chart_dir, &snapshot, &next);
+        snapshot = next;
+    }
+}
+
+fn regenerate(
+    cli: &Cli,
+    chart_dir: &Path,
+    output: &Path,
+    cache: &AnalysisCache,
+) -> EngineResult<Value> {
+    let opened = OpenedChart::open(cli, chart_dir)?;
+    let diagnostics = opened.diagnostics.clone();
+    let generated = opened.session(cli).and_then(|session| {
+        session
+            .with_analysis_cache(cache.clone())
+            .emit_with_policy_paths(
+                &cli.override_schema,
+                PolicyInputOptions {
+                    fetch_policy: FetchPolicy::input_assembly(!cli.k8s.offline),
+                    load_budget: LoadBudget::default(),
+                },
+                cli.output.emit_request(),
+            )
+    });
+    diag_emit::emit_to_stderr(&diagnostics, cli.diag.diag_format);
+    let schema = generated?;
+    crate::write_output_file(output, &schema, cli.output.json_format())?;
+    Ok(schema)
+}
+
+fn summarize(output: &Path, previous: Option<&Value>, schema: &Value) -> String {
+    let path = output.display();
+    let Some(previous) = previous else {
+        return format!("{path}: wrote schema");
+    };
+    let changes = diff_schemas(previous, schema);
+    let breaking = changes
+        .iter()
+        .filter(|change| change.kind.is_breaking())
+        .count();
+    match changes.len() {
+        0 if previous == schema => format!("{path}: schema unchanged"),
+        0 => format!("{path}: schema rewritten without values contract changes"),
+        total => format!(
+            "{path}: {total} schema change{} ({breaking} breaking, {} compatible)",
+            if total == 1 { "" } else { "s" },
+            total - breaking
+        ),
+    }
+}
+
+/// Chart inputs, value files, policy file, override schemas, capability
+/// profile, cluster state, and field schema overrides of a run.
+fn watched_paths(cli: &Cli, chart_dir: &Path) -> Vec<PathBuf> {
+    let mut paths = if chart_dir.is_dir() {
+        WATCHED_CHART_ENTRIES
+            .iter()
+            .map(|entry| chart_dir.join(entry))
+            .collect()
+    } else {
+        vec![chart_dir.to_path_buf()]
+    };
+    paths.extend(cli.chart.values_files.iter().cloned());
+    paths.extend(cli.config.iter().cloned());
+    paths.extend(cli.override_schema.iter().cloned());
+    paths.extend(cli.k8s.capabilities.iter().cloned());
+    paths.extend(cli.chart.cluster_state.iter().cloned());
+    paths.extend(cli.k8s.field_schema_override.iter().cloned());
+    paths
+}
+
+/// Drop the cached analysis of every chart file whose snapshot entry
+/// changed, matching the chart-relative paths analysis records; a changed
+/// chart archive drops everything.
+fn invalidate_changed(cache: &AnalysisCache, chart_dir: &Path, last: &Snapshot, next: &Snapshot) {
+    let changed: BTreeSet<String> = last
+        .keys()
+        .chain(next.keys())
+        .filter(|path| last.get(*path) != next.get(*path))
+        .filter_map(|path| path.strip_prefix(chart_dir).ok())
+        .map(|relative| relative.to_string_lossy().replace('\\', "/"))
+        .collect();
+    cache.invalidate(|source_path| {
+        let source_path = source_path.trim_start_matches('/');
+        changed.iter().any(|changed| {
+            changed.is_empty()
+                || source_path
+                    .strip_prefix(changed.as_str())
+                    .is_some_and(|rest| rest.is_empty() || rest.starts_with('/'))
+        })
+    });
+}
+
+fn snapshot(paths: &[PathBuf]) -> Snapshot {
+    let mut snapshot = Snapshot::new();
+    let mut pending: Vec<PathBuf> = paths.to_vec();
+    while let Some(path) = pending.pop() {
+        let Ok(metadata) = std::fs::metadata(&path) else {
+            continue;
+        };
+        if metadata.is_dir() {
+            if let Ok(entries) = std::fs::read_dir(&path) {
+                pending.extend(entries.filter_map(|entry| entry.ok().map(|entry| entry.path())));
+            }
+        } else {
+            snapshot.insert(path, (metadata.modified().ok(), metadata.len()));
+        }
+    }
+    snapshot
+}
+
+/// Block until the watched inputs differ from `last` and then hold still
+/// for one poll interval, so an editor's multi-file save triggers one run.
+fn wait_for_change(cli: &Cli, chart_dir: &Path, last: &Snapshot) -> Snapshot {
+    let mut current = last.clone();
+    loop {
+        std::thread::sleep(POLL_INTERVAL);
+        let next = snapshot(&watched_paths(cli, chart_dir));
+        if next == current && next != *last {
+            return next;
+        }
+        current = next;
+    }
+}
@@ -23,6 +23,7 @@ use color_eyre::eyre::{self, WrapErr};
 use flate2::Compression;
 use flate2::write::GzEncoder;
 use helm_schema::AnalysisSession;
+use helm_schema::generation::EnumInference;
 use helm_schema_cli::{GenerateOptions, ProviderOptions, SchemaProfile};
 use test_util::prelude::sim_assert_eq;
 use vfs::VfsPath;
@@ -146,6 +147,8 @@ fn wrapper_chart_with_subchart_tarball_containing_dir_entries() -> eyre::Result<
         include_subchart_values: true,
         values_files: Vec::new(),
         infer_required: false,
+        infer_enums: EnumInference::Off,
+        cluster_state: None,
         emission: SchemaProfile::default().into(),
         provider: ProviderOptions {
             k8s_versions: vec!["v1.35.0".to_string()],
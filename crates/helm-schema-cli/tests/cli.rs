@@ -3,6 +3,7 @@
 use clap::Parser;
 use color_eyre::eyre::{self, OptionExt as _, WrapErr, eyre};
 use helm_schema::AnalysisSession;
+use helm_schema::generation::EnumInference;
 use helm_schema_cli::{Cli, GenerateOptions, ProviderOptions, SchemaProfile};
 use indoc::indoc;
 use test_util::prelude::sim_assert_eq;
@@ -141,6 +142,8 @@ fn generates_schema_for_fixture_chart_without_k8s_provider() -> eyre::Result<()>
         include_subchart_values: true,
         values_files: Vec::new(),
         infer_required: false,
+        infer_enums: EnumInference::Off,
+        cluster_state: None,
         emission: SchemaProfile::default().into(),
         provider: ProviderOptions {
             k8s_versions: vec!["v1.35.0".to_string()],
@@ -270,6 +273,8 @@ fn values_yaml_comments_become_descriptions_without_creating_paths() -> eyre::Re
         include_subchart_values: true,
         values_files: Vec::new(),
         infer_required: false,
+        infer_enums: EnumInference::Off,
+        cluster_state: None,
         emission: SchemaProfile::default().into(),
         provider: ProviderOptions {
             k8s_versions: vec!["v1.35.0".to_string()],
@@ -342,6 +347,8 @@ fn chart_yaml_dependency_activation_paths_become_boolean_schema() -> eyre::Resul
         include_subchart_values: true,
         values_files: Vec::new(),
         infer_required: false,
+        infer_enums: EnumInference::Off,
+        cluster_state: None,
         emission: SchemaProfile::default().into(),
         provider: ProviderOptions {
             k8s_versions: vec!["v1.35.0".to_string()],
@@ -454,6 +461,8 @@ fn static_chart_crds_type_custom_resource_values() -> eyre::Result<()> {
         include_subchart_values: true,
         values_files: Vec::new(),
         infer_required: false,
+        infer_enums: EnumInference::Off,
+        cluster_state: None,
         emission: SchemaProfile::default().into(),
         provider: ProviderOptions {
             allow_net: false,
@@ -482,6 +491,123 @@ fn static_chart_crds_type_custom_resource_values() -> eyre::Result<()> {
     Ok(())
 }
 
+#[test]
+#[expect(
+    clippy::too_many_lines,
+    reason = "the complete fixture scenario is clearest as one contiguous test"
+)]
+fn templated_chart_crds_type_custom_resource_values() -> eyre::Result<()> {
+    let chart_dir = VfsPath::new(vfs::MemoryFS::new());
+
+    test_util::write(
+        &chart_dir.join("Chart.yaml")?,
+        indoc! {"
+            apiVersion: v2
+            name: root
+            version: 0.1.0
+        "},
+    )?;
+    test_util::write(
+        &chart_dir.join("values.yaml")?,
+        indoc! {"
+            crds:
+              install: true
+            widget:
+              spec: {}
+        "},
+    )?;
+    test_util::write(
+        &chart_dir.join("templates/_helpers.tpl")?,
+        indoc! {r#"
+            {{- define "root.labels" -}}
+            app.kubernetes.io/name: {{ .Chart.Name }}
+            {{- end }}
+        "#},
+    )?;
+    test_util::write(
+        &chart_dir.join("templates/crds/widgets.example.com.yaml")?,
+        indoc! {r#"
+            {{- if .Values.crds.install }}
+            apiVersion: apiextensions.k8s.io/v1
+            kind: CustomResourceDefinition
+            metadata:
+              name: widgets.example.com
+              labels:
+                {{- include "root.labels" . | nindent 4 }}
+            spec:
+              group: example.com
+              names:
+                kind: Widget
+                plural: widgets
+              scope: Namespaced
+              versions:
+                - name: v1
+                  served: true
+                  storage: true
+                  schema:
+                    openAPIV3Schema:
+                      type: object
+                      properties:
+                        spec:
+                          type: object
+                          properties:
+                            size:
+                              type: integer
+            {{- end }}
+        "#},
+    )?;
+    test_util::write(
+        &chart_dir.join("templates/widget.yaml")?,
+        indoc! {r"
+            apiVersion: example.com/v1
+            kind: Widget
+            metadata:
+              name: widget
+            spec:
+              size: {{ .Values.widget.spec.size }}
+        "},
+    )?;
+
+    let opts = GenerateOptions {
+        chart_dir,
+        include_tests: false,
+        include_subchart_values: true,
+        values_files: Vec::new(),
+        infer_required: false,
+        infer_enums: EnumInference::Off,
+        cluster_state: None,
+        emission: SchemaProfile::default().into(),
+        provider: ProviderOptions {
+            allow_net: false,
+            crd_catalog_cache_dir: Some(test_util::cold_provider_cache_root("crd")),
+            disable_k8s_schemas: true,
+            ..Default::default()
+        },
+    };
+
+    let schema = generate_values_schema_for_chart(&opts)
+        .map_err(into_eyre)
+        .wrap_err("generate schema")?;
+    let accepts = |install: bool, size: serde_json::Value| {
+        let instance = serde_json::json!({
+            "crds": { "install": install },
+            "widget": { "spec": { "size": size } },
+        });
+        schema_validates_instance(&schema, &instance)
+    };
+    assert!(accepts(true, 3.into())?, "{schema}");
+    assert!(
+        !accepts(true, vec![1].into())?,
+        "the chart's CRD should type widget.spec.size while crds.install holds: {schema}"
+    );
+    assert!(
+        accepts(false, vec![1].into())?,
+        "the chart's CRD must not type widget.spec.size while crds.install is off: {schema}"
+    );
+
+    Ok(())
+}
+
 #[test]
 fn reachable_helper_default_type_hint_applies_without_k8s_provider() -> eyre::Result<()> {
     let chart_dir = VfsPath::new(vfs::MemoryFS::new());
@@ -538,6 +664,8 @@ fn reachable_helper_default_type_hint_applies_without_k8s_provider() -> eyre::Re
         include_subchart_values: true,
         values_files: Vec::new(),
         infer_required: false,
+        infer_enums: EnumInference::Off,
+        cluster_state: None,
         emission: SchemaProfile::default().into(),
         provider: ProviderOptions {
             allow_net: false,
@@ -660,6 +788,8 @@ fn layered_values_file_comments_override_and_add_descriptions_only() -> eyre::Re
         include_subchart_values: true,
         values_files: vec![layer_one, layer_two],
         infer_required: false,
+        infer_enums: EnumInference::Off,
+        cluster_state: None,
         emission: SchemaProfile::default().into(),
         provider: ProviderOptions {
             k8s_versions: vec!["v1.35.0".to_string()],
@@ -767,6 +897,8 @@ fn subchart_values_are_scoped_to_the_coalesced_child_view() -> eyre::Result<()>
         include_subchart_values: true,
         values_files: Vec::new(),
         infer_required: false,
+        infer_enums: EnumInference::Off,
+        cluster_state: None,
         emission: SchemaProfile::default().into(),
         provider: ProviderOptions {
             k8s_versions: vec!["v1.35.0".to_string()],
@@ -924,6 +1056,8 @@ fn whole_global_range_accepts_a_child_default_when_the_parent_is_absent() -> eyr
         include_subchart_values: true,
         values_files: Vec::new(),
         infer_required: false,
+        infer_enums: EnumInference::Off,
+        cluster_state: None,
         emission: SchemaProfile::default().into(),
         provider: ProviderOptions {
             k8s_versions: vec!["v1.35.0".to_string()],
@@ -1062,6 +1196,8 @@ fn subchart_explicit_null_scalar_defaults_stay_nullable_after_string_context() -
         include_subchart_values: true,
         values_files: Vec::new(),
         infer_required: false,
+        infer_enums: EnumInference::Off,
+        cluster_state: None,
         emission: SchemaProfile::default().into(),
         provider: ProviderOptions {
             k8s_versions: vec!["v1.35.0".to_string()],
@@ -1168,6 +1304,8 @@ fn subchart_helper_descendant_access_does_not_widen_parent_objects() -> eyre::Re
         include_subchart_values: true,
         values_files: Vec::new(),
         infer_required: false,
+        infer_enums: EnumInference::Off,
+        cluster_state: None,
         emission: SchemaProfile::default().into(),
         provider: ProviderOptions {
             k8s_versions: vec!["v1.35.0".to_string()],
@@ -1302,6 +1440,8 @@ fn library_subchart_helper_descendant_access_does_not_widen_parent_objects() ->
         include_subchart_values: true,
         values_files: Vec::new(),
         infer_required: false,
+        infer_enums: EnumInference::Off,
+        cluster_state: None,
         emission: SchemaProfile::default().into(),
         provider: ProviderOptions {
             k8s_versions: vec!["v1.35.0".to_string()],
@@ -1395,6 +1535,8 @@ fn deployment_annotations_fragment_stays_annotations_map() -> eyre::Result<()> {
         include_subchart_values: true,
         values_files: Vec::new(),
         infer_required: false,
+        infer_enums: EnumInference::Off,
+        cluster_state: None,
         emission: SchemaProfile::default().into(),
         provider: ProviderOptions {
             k8s_versions: vec!["v1.35.0".to_string()],
@@ -1467,6 +1609,8 @@ fn defaulted_global_image_pull_secrets_do_not_widen_global_parent() -> eyre::Res
         include_subchart_values: true,
         values_files: Vec::new(),
         infer_required: false,
+        infer_enums: EnumInference::Off,
+        cluster_state: None,
         emission: SchemaProfile::default().into(),
         provider: ProviderOptions {
             k8s_versions: vec!["v1.35.0".to_string()],
@@ -1552,6 +1696,8 @@ fn parens_around_values_prefix_propagate_full_path_into_schema() -> eyre::Result
         include_subchart_values: true,
         values_files: Vec::new(),
         infer_required: false,
+        infer_enums: EnumInference::Off,
+        cluster_state: None,
         emission: SchemaProfile::default().into(),
         provider: ProviderOptions {
             k8s_versions: vec!["v1.35.0".to_string()],
@@ -1633,6 +1779,8 @@ fn parens_form_does_not_lose_default_driven_nullability_on_inner_field() -> eyre
         include_subchart_values: true,
         values_files: Vec::new(),
         infer_required: false,
+        infer_enums: EnumInference::Off,
+        cluster_state: None,
         emission: SchemaProfile::default().into(),
         provider: ProviderOptions {
             k8s_versions: vec!["v1.35.0".to_string()],
@@ -1731,6 +1879,8 @@ fn helper_set_default_mutation_widens_target_path_to_nullable() -> eyre::Result<
         include_subchart_values: true,
         values_files: Vec::new(),
         infer_required: false,
+        infer_enums: EnumInference::Off,
+        cluster_state: None,
         emission: SchemaProfile::default().into(),
         provider: ProviderOptions {
             k8s_versions: vec!["v1.35.0".to_string()],
@@ -1824,6 +1974,8 @@ fn helper_set_with_unrelated_default_does_not_widen_target_path() -> eyre::Resul
         include_subchart_values: true,
         values_files: Vec::new(),
         infer_required: false,
+        infer_enums: EnumInference::Off,
+        cluster_state: None,
         emission: SchemaProfile::default().into(),
         provider: ProviderOptions {
             k8s_versions: vec!["v1.35.0".to_string()],
@@ -1917,6 +2069,8 @@ fn helper_set_default_mutation_in_branch_does_not_leak_to_later_reads() -> eyre:
         include_subchart_values: true,
         values_files: Vec::new(),
         infer_required: false,
+        infer_enums: EnumInference::Off,
+        cluster_state: None,
         emission: SchemaProfile::default().into(),
         provider: ProviderOptions {
             k8s_versions: vec!["v1.35.0".to_string()],
@@ -2006,6 +2160,8 @@ fn nested_printf_around_common_fullname_keeps_name_overrides_nullable() -> eyre:
         include_subchart_values: true,
         values_files: Vec::new(),
         infer_required: false,
+        infer_enums: EnumInference::Off,
+        cluster_state: None,
         emission: SchemaProfile::default().into(),
         provider: ProviderOptions {
             k8s_versions: vec!["v1.35.0".to_string()],
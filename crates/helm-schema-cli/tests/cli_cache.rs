@@ -0,0 +1,224 @@
+//! `cache ls|du|prune|verify|prefetch` inspect and maintain the managed
+//! Kubernetes and CRD catalog schema caches.
+
+use std::path::PathBuf;
+use std::process::Output;
+use std::time::{Duration, SystemTime};
+
+use color_eyre::eyre::{self, WrapErr as _};
+use serde_json::{Value, json};
+use test_util::prelude::sim_assert_eq;
+
+#[path = "common/cli_bin.rs"]
+mod cli_bin;
+
+const MIRROR: &str = "https://mirror.example.com/crds";
+
+/// A K8s cache and a CRD cache with entries from the default source and
+/// from [`MIRROR`].
+struct Caches {
+    _work: tempfile::TempDir,
+    k8s: PathBuf,
+    crds: PathBuf,
+}
+
+impl Caches {
+    fn new() -> eyre::Result<Self> {
+        let work = tempfile::tempdir()?;
+        let k8s = work.path().join("k8s");
+        let crds = work.path().join("crds");
+        for root in [&k8s, &crds] {
+            cli_bin::write(&root.join("CACHE_LAYOUT_VERSION"), "1\n")?;
+        }
+        cli_bin::write(
+            &k8s.join("default/v1.35.0/configmap-v1.json"),
+            &json!({
+                "type": "object",
+                "properties": { "immutable": { "type": "boolean" } }
+            })
+            .to_string(),
+        )?;
+        cli_bin::write(
+            &k8s.join("default/v1.35.0/podsecuritypolicy-policy-v1beta1.json.not-found"),
+            "not-found\n",
+        )?;
+        cli_bin::write(
+            &crds.join(format!("{}/example.com/widget_v1.json", mirror_source_id())),
+            r#"{"type": "object"}"#,
+        )?;
+        Ok(Self {
+            _work: work,
+            k8s,
+            crds,
+        })
+    }
+
+    fn run(&self, args: &[&str]) -> eyre::Result<Output> {
+        cli_bin::helm_schema()
+            .arg("cache")
+            .args(args)
+            .arg("--k8s-schema-cache-dir")
+            .arg(&self.k8s)
+            .arg("--crd-catalog-cache-dir")
+            .arg(&self.crds)
+            .args(["--crd-catalog-mirror", MIRROR, "--offline"])
+            .output()
+            .wrap_err("run helm-schema cache")
+    }
+}
+
+/// Cache namespace the providers file [`MIRROR`] entries under.
+fn mirror_source_id() -> String {
+    helm_schema_k8s::source_id_for_url(MIRROR)
+}
+
+#[test]
+fn ls_attributes_entries_to_their_source() -> eyre::Result<()> {
+    let caches = Caches::new()?;
+    let listed = cli_bin::success_json(caches.run(&["ls", "--format", "json"])?)?;
+    let rows: Vec<_> = listed
+        .as_array()
+        .expect("ls reports an array")
+        .iter()
+        .map(|entry| {
+            (
+                entry["cache"].clone(),
+                entry["kind"].clone(),
+                entry["path"].clone(),
+                entry["origin"].clone(),
+            )
+        })
+        .collect();
+    let mirror_path = format!("{}/example.com/widget_v1.json", mirror_source_id());
+    sim_assert_eq!(
+        have: rows,
+        want: vec![
+            (
+                json!("kubernetes"),
+                json!("schema"),
+                json!("default/v1.35.0/configmap-v1.json"),
+                Value::Null,
+            ),
+            (
+                json!("kubernetes"),
+                json!("not-found"),
+                json!("default/v1.35.0/podsecuritypolicy-policy-v1beta1.json"),
+                Value::Null,
+            ),
+            (
+                json!("crd-catalog"),
+                json!("schema"),
+                json!(mirror_path),
+                json!(MIRROR),
+            ),
+        ]
+    );
+    Ok(())
+}
+
+#[test]
+fn du_sums_entries_per_source() -> eyre::Result<()> {
+    let caches = Caches::new()?;
+    let usage = cli_bin::success_json(caches.run(&["du", "--format", "json"])?)?;
+    let kubernetes = &usage[0]["sources"]["default"];
+    sim_assert_eq!(have: kubernetes["documents"].clone(), want: json!(1));
+    sim_assert_eq!(have: kubernetes["not_found"].clone(), want: json!(1));
+    sim_assert_eq!(
+        have: usage[1]["sources"][mirror_source_id()]["documents"].clone(),
+        want: json!(1)
+    );
+    Ok(())
+}
+
+#[test]
+fn verify_fails_on_damaged_entries() -> eyre::Result<()> {
+    let caches = Caches::new()?;
+    let intact = caches.run(&["verify"])?;
+    assert!(
+        intact.status.success(),
+        "intact caches verify: {}",
+        String::from_utf8_lossy(&intact.stderr)
+    );
+
+    cli_bin::write(
+        &caches.k8s.join("default/v1.35.0/secret-v1.json"),
+        "{ truncated",
+    )?;
+    let damaged = caches.run(&["verify"])?;
+    let stdout = String::from_utf8(damaged.stdout)?;
+    let stderr = String::from_utf8(damaged.stderr)?;
+    assert!(
+        !damaged.status.success(),
+        "a damaged entry must fail verify"
+    );
+    assert!(
+        stdout.contains("default/v1.35.0/secret-v1.json\tnot valid JSON"),
+        "verify names the damaged entry: {stdout}"
+    );
+    assert!(
+        stderr.contains("SchemaCacheDamaged"),
+        "stderr names the verify failure: {stderr}"
+    );
+    Ok(())
+}
+
+#[test]
+fn prune_removes_only_entries_older_than_the_cutoff() -> eyre::Result<()> {
+    let caches = Caches::new()?;
+    let stale = caches.k8s.join("default/v1.35.0/configmap-v1.json");
+    std::fs::File::options()
+        .write(true)
+        .open(&stale)?
+        .set_modified(SystemTime::now() - Duration::from_hours(3 * 24))?;
+
+    let dry_run = caches.run(&["prune", "--older-than", "2d", "--dry-run"])?;
+    assert!(dry_run.status.success());
+    assert!(stale.exists(), "--dry-run keeps every entry");
+
+    let pruned = caches.run(&["prune", "--older-than", "2d"])?;
+    let stdout = String::from_utf8(pruned.stdout)?;
+    assert!(pruned.status.success(), "prune succeeds: {stdout}");
+    assert!(
+        stdout.contains("kubernetes: removed 1 entry"),
+        "prune reports the removal: {stdout}"
+    );
+    assert!(!stale.exists(), "the stale document is removed");
+    assert!(
+        caches
+            .k8s
+            .join("default/v1.35.0/podsecuritypolicy-policy-v1beta1.json.not-found")
+            .exists(),
+        "fresh entries are kept"
+    );
+    assert!(caches.k8s.join("CACHE_LAYOUT_VERSION").exists());
+    Ok(())
+}
+
+#[test]
+fn prefetch_reports_unresolved_kinds() -> eyre::Result<()> {
+    let caches = Caches::new()?;
+    let output = caches.run(&["prefetch", "--kinds", "v1/ConfigMap,v1/Gizmo"])?;
+    let stdout = String::from_utf8(output.stdout)?;
+    assert!(
+        !output.status.success(),
+        "an unresolved kind fails prefetch"
+    );
+    assert!(
+        stdout.contains("cached v1/ConfigMap") && stdout.contains("unresolved v1/Gizmo"),
+        "prefetch reports each kind: {stdout}"
+    );
+    Ok(())
+}
+
+#[test]
+fn prefetch_rejects_malformed_kinds() -> eyre::Result<()> {
+    let caches = Caches::new()?;
+    let output = caches.run(&["prefetch", "--kinds", "Deployment"])?;
+    let stderr = String::from_utf8(output.stderr)?;
+    assert!(!output.status.success());
+    assert!(
+        stderr.contains("apiVersion/Kind"),
+        "stderr explains the expected form: {stderr}"
+    );
+    Ok(())
+}
@@ -0,0 +1,149 @@
+//! `--capabilities` decides `.Capabilities.APIVersions.Has` branches against
+//! the target cluster's profile instead of the vanilla Kubernetes release.
+
+use std::path::Path;
+use std::process::Output;
+
+use color_eyre::eyre;
+use indoc::indoc;
+use serde_json::{Value, json};
+
+#[path = "common/cli_bin.rs"]
+mod cli_bin;
+
+fn run(chart: &Path, profile: &Path) -> eyre::Result<Output> {
+    cli_bin::run_offline([
+        chart.as_os_str(),
+        std::ffi::OsStr::new("--capabilities"),
+        profile.as_os_str(),
+    ])
+}
+
+fn write_chart(dir: &Path) -> eyre::Result<()> {
+    cli_bin::write_chart(
+        dir,
+        "app",
+        "{}\n",
+        &[
+            (
+                "crds/widgets.example.com.yaml",
+                indoc! {"
+                    apiVersion: apiextensions.k8s.io/v1
+                    kind: CustomResourceDefinition
+                    metadata:
+                      name: widgets.example.com
+                    spec:
+                      group: example.com
+                      names:
+                        kind: Widget
+                        plural: widgets
+                      scope: Namespaced
+                      versions:
+                        - name: v2
+                          served: true
+                          storage: true
+                          schema:
+                            openAPIV3Schema:
+                              type: object
+                              properties:
+                                spec:
+                                  type: object
+                                  properties:
+                                    size:
+                                      type: integer
+                        - name: v1
+                          served: true
+                          storage: false
+                          schema:
+                            openAPIV3Schema:
+                              type: object
+                              properties:
+                                spec:
+                                  type: object
+                                  properties:
+                                    size:
+                                      type: boolean
+                "},
+            ),
+            (
+                "templates/_helpers.tpl",
+                indoc! {r#"
+                    {{- define "app.widget.apiVersion" -}}
+                    {{- if .Capabilities.APIVersions.Has "example.com/v2" -}}
+                    example.com/v2
+                    {{- else -}}
+                    example.com/v1
+                    {{- end -}}
+                    {{- end -}}
+                "#},
+            ),
+            (
+                "templates/widget.yaml",
+                indoc! {r#"
+                    apiVersion: {{ include "app.widget.apiVersion" . }}
+                    kind: Widget
+                    metadata:
+                      name: widget
+                    spec:
+                      size: {{ .Values.size }}
+                "#},
+            ),
+        ],
+    )
+}
+
+#[test]
+fn capability_profile_selects_the_served_api_version() -> eyre::Result<()> {
+    let chart = tempfile::tempdir()?;
+    write_chart(chart.path())?;
+    let profiles = tempfile::tempdir()?;
+
+    // `example.com/v2` types `size` as an integer, `example.com/v1` as a
+    // boolean.
+    for (profile, accepted, rejected) in [
+        (
+            "kubeVersion: v1.30.0\napiVersions:\n  - example.com/v2\n",
+            json!({ "size": 3 }),
+            json!({ "size": true }),
+        ),
+        (
+            "apiVersions: []\n",
+            json!({ "size": true }),
+            json!({ "size": 3 }),
+        ),
+    ] {
+        let path = profiles.path().join("capabilities.yaml");
+        std::fs::write(&path, profile)?;
+        let output = run(chart.path(), &path)?;
+        let stderr = String::from_utf8(output.stderr)?;
+        assert!(output.status.success(), "generation failed: {stderr}");
+        let schema: Value = serde_json::from_slice(&output.stdout)?;
+        let validator = jsonschema::validator_for(&schema)?;
+        assert!(
+            validator.is_valid(&accepted) && !validator.is_valid(&rejected),
+            "profile {profile:?}: schema={schema}"
+        );
+    }
+    Ok(())
+}
+
+#[test]
+fn invalid_capability_profile_fails_generation() -> eyre::Result<()> {
+    let chart = tempfile::tempdir()?;
+    write_chart(chart.path())?;
+    let profiles = tempfile::tempdir()?;
+    let path = profiles.path().join("capabilities.yaml");
+    std::fs::write(&path, "apiVersion: [monitoring.coreos.com/v1]\n")?;
+
+    let output = run(chart.path(), &path)?;
+    let stderr = String::from_utf8(output.stderr)?;
+    assert!(
+        !output.status.success(),
+        "a misspelled profile key must fail"
+    );
+    assert!(
+        stderr.contains("InvalidCapabilityProfile"),
+        "stderr names the profile error: {stderr}"
+    );
+    Ok(())
+}
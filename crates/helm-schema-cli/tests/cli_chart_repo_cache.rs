@@ -0,0 +1,54 @@
+//! `--chart-repo-cache` names the Helm repository cache non-vendored
+//! dependencies load from; a dependency missing there is reported.
+
+use color_eyre::eyre;
+use indoc::indoc;
+
+#[path = "common/cli_bin.rs"]
+mod cli_bin;
+
+#[test]
+fn missing_locked_dependency_is_reported_by_name() -> eyre::Result<()> {
+    let dir = tempfile::tempdir()?;
+    let repository_cache = tempfile::tempdir()?;
+    cli_bin::write(
+        &dir.path().join("Chart.yaml"),
+        indoc! {"
+            apiVersion: v2
+            name: app
+            version: 0.1.0
+            dependencies:
+              - name: redis
+                version: 18.x.x
+                repository: oci://registry.example.com/charts
+        "},
+    )?;
+    cli_bin::write(
+        &dir.path().join("Chart.lock"),
+        indoc! {"
+            dependencies:
+              - name: redis
+                repository: oci://registry.example.com/charts
+                version: 18.1.0
+        "},
+    )?;
+
+    let output = cli_bin::run_offline([
+        dir.path().as_os_str(),
+        std::ffi::OsStr::new("--chart-repo-cache"),
+        repository_cache.path().as_os_str(),
+    ])?;
+
+    let stderr = String::from_utf8(output.stderr)?;
+    assert!(output.status.success(), "generation failed: {stderr}");
+    let archive = repository_cache.path().join("redis-18.1.0.tgz");
+    assert!(
+        stderr.contains(&format!(
+            "warning: dependency redis 18.1.0 (oci://registry.example.com/charts) of chart app \
+             is not vendored under charts/ or cached locally; its values are omitted; tried: {}",
+            archive.display()
+        )),
+        "stderr must name the unresolved dependency: {stderr}"
+    );
+    Ok(())
+}
@@ -0,0 +1,174 @@
+//! `helm-schema --check` compares the regenerated schema with the committed
+//! `values.schema.json`, reports structural drift, and never writes output.
+
+use color_eyre::eyre;
+use indoc::indoc;
+use test_util::prelude::sim_assert_eq;
+
+#[path = "common/cli_bin.rs"]
+mod cli_bin;
+
+fn write_chart(dir: &std::path::Path, deployment: &str) -> eyre::Result<()> {
+    cli_bin::write_chart(
+        dir,
+        "checked",
+        "replicas: 1\nname: checked\n",
+        &[("templates/deployment.yaml", deployment)],
+    )
+}
+
+fn helm_schema(chart: &std::path::Path, args: &[&str]) -> eyre::Result<(bool, String)> {
+    let mut command_line = vec![chart.as_os_str()];
+    command_line.extend(args.iter().map(std::ffi::OsStr::new));
+    let output = cli_bin::run_offline(command_line)?;
+    Ok((output.status.success(), String::from_utf8(output.stdout)?))
+}
+
+const DEPLOYMENT: &str = indoc! {r"
+    apiVersion: apps/v1
+    kind: Deployment
+    metadata:
+      name: {{ .Values.name }}
+    spec:
+      replicas: {{ .Values.replicas }}
+"};
+
+#[test]
+fn check_passes_when_the_committed_schema_is_current() -> eyre::Result<()> {
+    let dir = tempfile::tempdir()?;
+    write_chart(dir.path(), DEPLOYMENT)?;
+    let schema_path = dir.path().join("values.schema.json");
+    let schema_arg = schema_path.to_string_lossy().into_owned();
+    let (generated, _) = helm_schema(dir.path(), &["-o", &schema_arg])?;
+    assert!(generated, "initial generation must succeed");
+
+    let (success, report) = helm_schema(dir.path(), &["--check"])?;
+
+    assert!(success, "an up-to-date schema must pass: {report}");
+    sim_assert_eq!(
+        have: report,
+        want: format!("{} is up to date\n", schema_path.display())
+    );
+    Ok(())
+}
+
+#[test]
+fn check_reports_template_drift_without_writing() -> eyre::Result<()> {
+    let dir = tempfile::tempdir()?;
+    write_chart(dir.path(), DEPLOYMENT)?;
+    let schema_path = dir.path().join("values.schema.json");
+    let schema_arg = schema_path.to_string_lossy().into_owned();
+    let (generated, _) = helm_schema(dir.path(), &["-o", &schema_arg])?;
+    assert!(generated, "initial generation must succeed");
+    let committed = std::fs::read_to_string(&schema_path)?;
+
+    write_chart(
+        dir.path(),
+        indoc! {r"
+            apiVersion: apps/v1
+            kind: Deployment
+            metadata:
+              name: {{ .Values.name }}
+            spec:
+              paused: {{ .Values.paused }}
+        "},
+    )?;
+    let (success, report) = helm_schema(dir.path(), &["--check"])?;
+
+    assert!(!success, "drift must fail the run");
+    let lines: Vec<&str> = report.lines().collect();
+    sim_assert_eq!(
+        have: lines.first().copied(),
+        want: Some(format!("{} is out of date", schema_path.display()).as_str())
+    );
+    assert!(
+        lines
+            .get(1)
+            .is_some_and(|line| line.starts_with("cause: template drift")),
+        "{report}"
+    );
+    assert!(
+        lines.contains(&"  + .Values.paused: property added"),
+        "{report}"
+    );
+    assert!(
+        lines.contains(&"  + .Values.replicas: type widened from integer to any"),
+        "{report}"
+    );
+    sim_assert_eq!(have: std::fs::read_to_string(&schema_path)?, want: committed);
+    Ok(())
+}
+
+#[test]
+fn check_reports_policy_drift_and_missing_schema() -> eyre::Result<()> {
+    let dir = tempfile::tempdir()?;
+    write_chart(dir.path(), DEPLOYMENT)?;
+    let schema_path = dir.path().join("values.schema.json");
+
+    let (success, report) = helm_schema(dir.path(), &["--check"])?;
+    assert!(!success, "a missing schema must fail the check");
+    assert!(report.contains("is missing"), "{report}");
+    assert!(!schema_path.exists(), "--check must not write the schema");
+
+    let schema_arg = schema_path.to_string_lossy().into_owned();
+    let (generated, _) = helm_schema(dir.path(), &["-o", &schema_arg])?;
+    assert!(generated, "initial generation must succeed");
+    let (success, report) = helm_schema(dir.path(), &["--check", "--keep-refs"])?;
+
+    assert!(!success, "a policy change must fail the check");
+    assert!(report.contains("cause: policy drift"), "{report}");
+    Ok(())
+}
+
+#[test]
+fn check_ignores_a_helm_schema_version_change_without_contract_drift() -> eyre::Result<()> {
+    let dir = tempfile::tempdir()?;
+    write_chart(dir.path(), DEPLOYMENT)?;
+    let schema_path = dir.path().join("values.schema.json");
+    let schema_arg = schema_path.to_string_lossy().into_owned();
+    let (generated, _) = helm_schema(dir.path(), &["-o", &schema_arg])?;
+    assert!(generated, "initial generation must succeed");
+    let mut committed: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(&schema_path)?)?;
+    committed["x-helm-schema-policy"]["generator-version"] = "0.0.0".into();
+    std::fs::write(&schema_path, serde_json::to_string_pretty(&committed)?)?;
+
+    let (success, report) = helm_schema(dir.path(), &["--check"])?;
+
+    assert!(
+        success,
+        "a version bump alone must not fail the check: {report}"
+    );
+    sim_assert_eq!(
+        have: report,
+        want: format!("{} is up to date\n", schema_path.display())
+    );
+
+    write_chart(
+        dir.path(),
+        indoc! {r"
+            apiVersion: apps/v1
+            kind: Deployment
+            metadata:
+              name: {{ .Values.name }}
+            spec:
+              replicas: {{ .Values.replicas }}
+              paused: {{ .Values.paused }}
+        "},
+    )?;
+    let (success, report) = helm_schema(dir.path(), &["--check"])?;
+
+    assert!(!success, "contract drift must fail the check");
+    sim_assert_eq!(
+        have: report.lines().skip(1).collect::<Vec<_>>(),
+        want: vec![
+            format!(
+                "cause: helm-schema version change (0.0.0 -> {})",
+                env!("CARGO_PKG_VERSION")
+            )
+            .as_str(),
+            "  + .Values.paused: property added",
+        ]
+    );
+    Ok(())
+}
@@ -0,0 +1,107 @@
+//! `--cluster-openapi` resolves resource schemas from a cluster's saved
+//! `/openapi/v3` discovery.
+
+use std::path::Path;
+use std::process::Output;
+
+use color_eyre::eyre;
+use indoc::indoc;
+use serde_json::{Value, json};
+
+#[path = "common/cli_bin.rs"]
+mod cli_bin;
+
+fn run(chart: &Path, dump: &Path) -> eyre::Result<Output> {
+    cli_bin::run_offline([
+        chart.as_os_str(),
+        std::ffi::OsStr::new("--cluster-openapi"),
+        dump.as_os_str(),
+    ])
+}
+
+fn write_chart(dir: &Path) -> eyre::Result<()> {
+    cli_bin::write_chart(
+        dir,
+        "app",
+        "{}\n",
+        &[(
+            "templates/monitor.yaml",
+            indoc! {"
+                apiVersion: monitoring.coreos.com/v1
+                kind: ServiceMonitor
+                metadata:
+                  name: app
+                spec:
+                  jobLabel: {{ .Values.jobLabel }}
+                  sampleLimit: {{ .Values.sampleLimit }}
+            "},
+        )],
+    )
+}
+
+#[test]
+fn dumped_crds_type_the_values_they_receive() -> eyre::Result<()> {
+    let chart = tempfile::tempdir()?;
+    write_chart(chart.path())?;
+    let dump = tempfile::tempdir()?;
+    let group_dir = dump.path().join("apis/monitoring.coreos.com");
+    std::fs::create_dir_all(&group_dir)?;
+    std::fs::write(
+        group_dir.join("v1.json"),
+        serde_json::to_vec(&json!({
+            "openapi": "3.0.0",
+            "paths": {
+                "/apis/monitoring.coreos.com/v1/namespaces/{namespace}/servicemonitors": {}
+            },
+            "components": { "schemas": {
+                "com.coreos.monitoring.v1.ServiceMonitor": {
+                    "type": "object",
+                    "properties": {
+                        "spec": {
+                            "type": "object",
+                            "properties": {
+                                "jobLabel": { "type": "string" },
+                                "sampleLimit": { "type": "integer", "format": "int64" }
+                            }
+                        }
+                    },
+                    "x-kubernetes-group-version-kind": [
+                        { "group": "monitoring.coreos.com", "kind": "ServiceMonitor", "version": "v1" }
+                    ]
+                }
+            } }
+        }))?,
+    )?;
+
+    let output = run(chart.path(), dump.path())?;
+    let stderr = String::from_utf8(output.stderr)?;
+    assert!(output.status.success(), "generation failed: {stderr}");
+    let schema: Value = serde_json::from_slice(&output.stdout)?;
+    let validator = jsonschema::validator_for(&schema)?;
+    assert!(
+        validator.is_valid(&json!({ "jobLabel": "app", "sampleLimit": 100 })),
+        "schema={schema}"
+    );
+    assert!(
+        !validator.is_valid(&json!({ "sampleLimit": true })),
+        "sampleLimit takes the dumped integer type: schema={schema}"
+    );
+    Ok(())
+}
+
+#[test]
+fn invalid_dump_document_fails_generation() -> eyre::Result<()> {
+    let chart = tempfile::tempdir()?;
+    write_chart(chart.path())?;
+    let dump = tempfile::tempdir()?;
+    std::fs::write(dump.path().join("index.json"), "{ \"paths\": ")?;
+
+    let output = run(chart.path(), dump.path())?;
+    let stderr = String::from_utf8(output.stderr)?;
+    assert!(!output.status.success(), "a truncated document must fail");
+    assert!(
+        stderr.contains("InvalidClusterOpenApi") && stderr.contains("index.json"),
+        "stderr names the broken document: {stderr}"
+    );
+    Ok(())
+}
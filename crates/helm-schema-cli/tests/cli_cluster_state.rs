@@ -0,0 +1,118 @@
+//! `--cluster-state` resolves `lookup` calls against a manifest directory, so
+//! guards on an existing object are decided instead of abstaining.
+
+use std::path::Path;
+
+use color_eyre::eyre;
+use indoc::indoc;
+use serde_json::{Value, json};
+
+#[path = "common/cli_bin.rs"]
+mod cli_bin;
+
+fn generate(chart: &Path, flags: &[&str]) -> eyre::Result<Value> {
+    let mut argv = vec![chart.as_os_str()];
+    argv.extend(flags.iter().map(std::ffi::OsStr::new));
+    cli_bin::success_json(cli_bin::run_offline(argv)?)
+}
+
+fn write_chart(dir: &Path) -> eyre::Result<()> {
+    cli_bin::write_chart(
+        dir,
+        "app",
+        "password: \"\"\n",
+        &[(
+            "templates/secret.yaml",
+            indoc! {r#"
+                {{- $existing := lookup "v1" "Secret" "prod" "db" }}
+                {{- if not $existing }}
+                {{- if not .Values.password }}
+                {{- fail "password is required until the secret exists" }}
+                {{- end }}
+                {{- end }}
+                apiVersion: v1
+                kind: ConfigMap
+                metadata:
+                  name: app
+            "#},
+        )],
+    )
+}
+
+#[test]
+fn cluster_state_decides_lookup_gates() -> eyre::Result<()> {
+    let chart = tempfile::tempdir()?;
+    write_chart(chart.path())?;
+    let empty_cluster = tempfile::tempdir()?;
+    let cluster = tempfile::tempdir()?;
+    // `kubectl get -o yaml` wraps several objects in a `List`.
+    std::fs::write(
+        cluster.path().join("secrets.yaml"),
+        indoc! {"
+            apiVersion: v1
+            kind: List
+            items:
+              - apiVersion: v1
+                kind: Secret
+                metadata:
+                  name: db
+                  namespace: prod
+                data:
+                  password: c2VjcmV0
+        "},
+    )?;
+
+    let empty_password = json!({ "password": "" });
+    for (flags, want, label) in [
+        (
+            Vec::new(),
+            true,
+            "without a cluster state the gate abstains",
+        ),
+        (
+            vec![
+                "--cluster-state",
+                cluster.path().to_str().unwrap_or_default(),
+            ],
+            true,
+            "an existing secret makes the fail dead",
+        ),
+        (
+            vec![
+                "--cluster-state",
+                empty_cluster.path().to_str().unwrap_or_default(),
+            ],
+            false,
+            "an absent secret binds the fail",
+        ),
+    ] {
+        let schema = generate(chart.path(), &flags)?;
+        let validator = jsonschema::validator_for(&schema)?;
+        assert!(
+            validator.is_valid(&empty_password) == want,
+            "{label}: schema={schema}"
+        );
+    }
+    Ok(())
+}
+
+#[test]
+fn unreadable_cluster_state_fails_generation() -> eyre::Result<()> {
+    let chart = tempfile::tempdir()?;
+    write_chart(chart.path())?;
+    let cluster = tempfile::tempdir()?;
+    std::fs::write(cluster.path().join("broken.yaml"), "kind: [unterminated\n")?;
+
+    let output = cli_bin::run_offline([
+        chart.path().as_os_str(),
+        std::ffi::OsStr::new("--cluster-state"),
+        cluster.path().as_os_str(),
+    ])?;
+    let stderr = String::from_utf8(output.stderr)?;
+    assert!(!output.status.success(), "a broken manifest must fail");
+    assert!(
+        stderr.contains("InvalidClusterState") && stderr.contains("broken.yaml"),
+        "stderr names the broken manifest: {stderr}"
+    );
+    Ok(())
+}
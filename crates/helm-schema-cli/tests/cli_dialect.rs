@@ -0,0 +1,73 @@
+//! `--dialect 2020-12` writes a schema that declares and validates as
+//! JSON Schema 2020-12, and records the dialect in the policy annotation.
+
+use color_eyre::eyre;
+use indoc::indoc;
+use serde_json::{Value, json};
+use test_util::prelude::sim_assert_eq;
+
+#[path = "common/cli_bin.rs"]
+mod cli_bin;
+
+fn generate(dialect: &str) -> eyre::Result<Value> {
+    let dir = tempfile::tempdir()?;
+    cli_bin::write_chart(
+        dir.path(),
+        "app",
+        "replicas: 1\nports: []\n",
+        &[(
+            "templates/configmap.yaml",
+            indoc! {r#"
+                apiVersion: v1
+                kind: ConfigMap
+                metadata:
+                  name: app
+                data:
+                  replicas: {{ .Values.replicas | quote }}
+                  {{- range .Values.ports }}
+                  port-{{ . }}: "open"
+                  {{- end }}
+            "#},
+        )],
+    )?;
+
+    let mut argv = vec![dir.path().as_os_str()];
+    argv.extend(["--dialect", dialect].map(std::ffi::OsStr::new));
+    cli_bin::success_json(cli_bin::run_offline(argv)?)
+}
+
+#[test]
+fn dialect_2020_12_output_is_a_valid_2020_12_schema() -> eyre::Result<()> {
+    let schema = generate("2020-12")?;
+    sim_assert_eq!(
+        have: schema.get("$schema"),
+        want: Some(&json!("https://json-schema.org/draft/2020-12/schema"))
+    );
+    sim_assert_eq!(
+        have: schema.pointer("/x-helm-schema-policy/modifiers/dialect"),
+        want: Some(&json!("2020-12"))
+    );
+    assert!(
+        jsonschema::draft202012::meta::is_valid(&schema),
+        "output must validate against the 2020-12 metaschema: {schema:#}"
+    );
+
+    let validator = jsonschema::validator_for(&schema)?;
+    assert!(validator.is_valid(&json!({"replicas": 2, "ports": [80]})));
+    assert!(!validator.is_valid(&json!({"replicas": 2, "unknown": true})));
+    Ok(())
+}
+
+#[test]
+fn default_dialect_keeps_the_draft_07_annotation() -> eyre::Result<()> {
+    let schema = generate("draft-07")?;
+    sim_assert_eq!(
+        have: schema.get("$schema"),
+        want: Some(&json!("http://json-schema.org/draft-07/schema#"))
+    );
+    sim_assert_eq!(
+        have: schema.pointer("/x-helm-schema-policy/modifiers/dialect"),
+        want: None
+    );
+    Ok(())
+}
@@ -0,0 +1,96 @@
+//! `helm-schema diff` compares the values contracts of two chart versions and
+//! classifies each change as breaking or compatible.
+
+use color_eyre::eyre;
+use indoc::indoc;
+use test_util::prelude::sim_assert_eq;
+
+#[path = "common/cli_bin.rs"]
+mod cli_bin;
+
+fn write_chart(dir: &std::path::Path, values: &str, deployment: &str) -> eyre::Result<()> {
+    cli_bin::write_chart(
+        dir,
+        "diffed",
+        values,
+        &[("templates/deployment.yaml", deployment)],
+    )
+}
+
+fn diff(old: &std::path::Path, new: &std::path::Path) -> eyre::Result<(bool, String)> {
+    let output = cli_bin::run_offline([
+        std::ffi::OsStr::new("diff"),
+        old.as_os_str(),
+        new.as_os_str(),
+    ])?;
+    Ok((output.status.success(), String::from_utf8(output.stdout)?))
+}
+
+const OLD_DEPLOYMENT: &str = indoc! {r"
+    apiVersion: apps/v1
+    kind: Deployment
+    metadata:
+      name: {{ .Values.name }}
+      annotations:
+        host: {{ .Values.host }}
+    spec:
+      replicas: {{ .Values.replicas }}
+"};
+
+#[test]
+fn classifies_breaking_and_compatible_changes() -> eyre::Result<()> {
+    let old = tempfile::tempdir()?;
+    let new = tempfile::tempdir()?;
+    write_chart(
+        old.path(),
+        "name: diffed\nreplicas: 1\nhost: example.com\nlegacy: true\n",
+        OLD_DEPLOYMENT,
+    )?;
+    write_chart(
+        new.path(),
+        "name: diffed\nreplicas: 1\nhost: example.com\nexpose: false\npaused: false\n",
+        indoc! {r"
+            apiVersion: apps/v1
+            kind: Deployment
+            metadata:
+              name: {{ .Values.name }}
+              {{- if .Values.expose }}
+              annotations:
+                host: {{ .Values.host }}
+              {{- end }}
+            spec:
+              replicas: {{ .Values.replicas }}
+              paused: {{ .Values.paused }}
+        "},
+    )?;
+
+    let (success, report) = diff(old.path(), new.path())?;
+
+    assert!(!success, "breaking changes must fail the run");
+    sim_assert_eq!(
+        have: report,
+        want: indoc! {"
+            breaking changes (3):
+              - .Values: conditional overlay added
+              - .Values.host: now read only under .Values.expose
+              - .Values.legacy: property removed
+            compatible changes (3):
+              + .Values.expose: property added
+              + .Values.host: type widened from string to any
+              + .Values.paused: property added
+        "}
+    );
+    Ok(())
+}
+
+#[test]
+fn identical_charts_have_no_changes() -> eyre::Result<()> {
+    let old = tempfile::tempdir()?;
+    write_chart(old.path(), "name: diffed\nreplicas: 1\n", OLD_DEPLOYMENT)?;
+
+    let (success, report) = diff(old.path(), old.path())?;
+
+    assert!(success, "an unchanged chart must pass: {report}");
+    sim_assert_eq!(have: report, want: "no values contract changes\n");
+    Ok(())
+}
@@ -0,0 +1,76 @@
+//! `helm-schema docs` prints a values reference table that also lists values
+//! the templates read but `values.yaml` never declares.
+
+use color_eyre::eyre;
+use indoc::indoc;
+use test_util::prelude::sim_assert_eq;
+
+#[path = "common/cli_bin.rs"]
+mod cli_bin;
+
+fn write_chart(dir: &std::path::Path) -> eyre::Result<()> {
+    cli_bin::write_chart(
+        dir,
+        "documented",
+        indoc! {r"
+            # -- Hostname served by the ingress.
+            host: example.com
+            expose: false
+        "},
+        &[(
+            "templates/deployment.yaml",
+            indoc! {r"
+                apiVersion: apps/v1
+                kind: Deployment
+                metadata:
+                  name: documented
+                  {{- if .Values.expose }}
+                  annotations:
+                    host: {{ .Values.host }}
+                  {{- end }}
+                spec:
+                  replicas: {{ .Values.replicas | default 1 }}
+            "},
+        )],
+    )
+}
+
+fn docs(chart: &std::path::Path, args: &[&str]) -> eyre::Result<String> {
+    let mut command_line = vec![std::ffi::OsStr::new("docs"), chart.as_os_str()];
+    command_line.extend(args.iter().map(std::ffi::OsStr::new));
+    cli_bin::success_stdout(cli_bin::run_offline(command_line)?)
+}
+
+#[test]
+fn markdown_reference_lists_undeclared_values_and_read_guards() -> eyre::Result<()> {
+    let dir = tempfile::tempdir()?;
+    write_chart(dir.path())?;
+
+    sim_assert_eq!(
+        have: docs(dir.path(), &[])?,
+        want: indoc! {r#"
+            | Key | Type | Default | Description | Required | Condition |
+            |---|---|---|---|---|---|
+            | `expose` | any | `false` |  | no |  |
+            | `host` | any | `"example.com"` | Hostname served by the ingress. | no | `.Values.expose` |
+            | `replicas` | integer | *(not set)* |  | no |  |
+        "#}
+    );
+    Ok(())
+}
+
+#[test]
+fn html_reference_escapes_cells() -> eyre::Result<()> {
+    let dir = tempfile::tempdir()?;
+    write_chart(dir.path())?;
+
+    let html = docs(dir.path(), &["--format", "html"])?;
+
+    assert!(html.starts_with("<table>\n"), "{html}");
+    assert!(
+        html.contains("<td><code>&quot;example.com&quot;</code></td>"),
+        "{html}"
+    );
+    assert!(html.contains("<td><em>not set</em></td>"), "{html}");
+    Ok(())
+}
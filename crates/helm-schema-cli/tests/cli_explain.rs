@@ -0,0 +1,110 @@
+//! `helm-schema explain` reports where templates read a values path, under
+//! which guards, and which manifest fields it renders into.
+
+use color_eyre::eyre;
+use indoc::indoc;
+use test_util::prelude::sim_assert_eq;
+
+#[path = "common/cli_bin.rs"]
+mod cli_bin;
+
+fn write_chart(dir: &std::path::Path) -> eyre::Result<()> {
+    cli_bin::write_chart(
+        dir,
+        "explained",
+        "replicas: 1\ningress:\n  enabled: false\n  host: example.com\n",
+        &[
+            (
+                "templates/_helpers.tpl",
+                indoc! {r#"
+                    {{- define "explained.host" -}}
+                    {{ .Values.ingress.host }}
+                    {{- end -}}
+                "#},
+            ),
+            (
+                "templates/ingress.yaml",
+                indoc! {r#"
+                    {{- if .Values.ingress.enabled }}
+                    apiVersion: networking.k8s.io/v1
+                    kind: Ingress
+                    metadata:
+                      name: explained
+                    spec:
+                      rules:
+                        - host: {{ include "explained.host" . }}
+                    {{- end }}
+                "#},
+            ),
+        ],
+    )
+}
+
+fn explain(chart: &std::path::Path, args: &[&str]) -> eyre::Result<String> {
+    let mut command_line = vec![std::ffi::OsStr::new("explain"), chart.as_os_str()];
+    command_line.extend(args.iter().map(std::ffi::OsStr::new));
+    cli_bin::success_stdout(cli_bin::run_offline(command_line)?)
+}
+
+#[test]
+fn text_report_names_guards_rendered_fields_and_helper_locations() -> eyre::Result<()> {
+    let dir = tempfile::tempdir()?;
+    write_chart(dir.path())?;
+
+    let report = explain(dir.path(), &[".Values.ingress.host"])?;
+
+    sim_assert_eq!(
+        have: report,
+        want: indoc! {"
+            .Values.ingress.host
+              schema type: any
+              facts: has_non_control_use, has_render_use, has_unlayered_non_control_use, used_as_serialized
+              uses:
+              - .Values.ingress.host
+                  renders into: networking.k8s.io/v1 Ingress spec.rules[*].host (Scalar)
+                  when: .Values.ingress.enabled
+                  at templates/ingress.yaml:8:13
+                  at templates/_helpers.tpl:2:1 via explained.host
+        "}
+    );
+    Ok(())
+}
+
+#[test]
+fn json_report_carries_structured_guards_and_emitted_types() -> eyre::Result<()> {
+    let dir = tempfile::tempdir()?;
+    write_chart(dir.path())?;
+
+    let report: serde_json::Value =
+        serde_json::from_str(&explain(dir.path(), &["ingress", "--format", "json"])?)?;
+
+    sim_assert_eq!(have: &report["path"], want: &serde_json::json!("ingress"));
+    sim_assert_eq!(have: &report["schema_types"], want: &serde_json::json!(["object"]));
+    let host_use = report["uses"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .find(|use_| use_["source_expr"] == "ingress.host")
+        .ok_or_else(|| eyre::eyre!("missing descendant use: {report:#}"))?;
+    sim_assert_eq!(
+        have: host_use,
+        want: &serde_json::json!({
+            "source_expr": "ingress.host",
+            "relation": "descendant",
+            "kind": "Scalar",
+            "resource": { "api_version": "networking.k8s.io/v1", "kind": "Ingress" },
+            "rendered_path": "spec.rules[*].host",
+            "conditions": [[{ "type": "truthy", "path": "ingress.enabled" }]],
+            "locations": [
+                { "file": "templates/ingress.yaml", "line": 8, "column": 13 },
+                {
+                    "file": "templates/_helpers.tpl",
+                    "line": 2,
+                    "column": 1,
+                    "helper_chain": ["explained.host"]
+                }
+            ]
+        })
+    );
+    Ok(())
+}
@@ -0,0 +1,129 @@
+//! `--field-schema-override` merges schemas onto resource fields addressed
+//! by `(group, kind, path)`, whichever provider resolves them.
+
+use std::path::Path;
+use std::process::Output;
+
+use color_eyre::eyre;
+use indoc::indoc;
+use serde_json::{Value, json};
+
+#[path = "common/cli_bin.rs"]
+mod cli_bin;
+
+fn run(chart: &Path, dump: &Path, overrides: &Path) -> eyre::Result<Output> {
+    cli_bin::run_offline([
+        chart.as_os_str(),
+        std::ffi::OsStr::new("--cluster-openapi"),
+        dump.as_os_str(),
+        std::ffi::OsStr::new("--field-schema-override"),
+        overrides.as_os_str(),
+    ])
+}
+
+fn write_chart(dir: &Path) -> eyre::Result<()> {
+    cli_bin::write_chart(
+        dir,
+        "app",
+        "{}\n",
+        &[(
+            "templates/monitor.yaml",
+            indoc! {"
+                apiVersion: monitoring.coreos.com/v1
+                kind: ServiceMonitor
+                metadata:
+                  name: app
+                spec:
+                  jobLabel: {{ .Values.jobLabel }}
+            "},
+        )],
+    )
+}
+
+/// A cluster discovery dump serving `ServiceMonitor` with a plain string
+/// `spec.jobLabel`.
+fn write_dump(dir: &Path) -> eyre::Result<()> {
+    cli_bin::write(
+        &dir.join("apis/monitoring.coreos.com/v1.json"),
+        &json!({
+            "openapi": "3.0.0",
+            "paths": {
+                "/apis/monitoring.coreos.com/v1/namespaces/{namespace}/servicemonitors": {}
+            },
+            "components": { "schemas": {
+                "com.coreos.monitoring.v1.ServiceMonitor": {
+                    "type": "object",
+                    "properties": {
+                        "spec": {
+                            "type": "object",
+                            "properties": {
+                                "jobLabel": { "type": "string" }
+                            }
+                        }
+                    },
+                    "x-kubernetes-group-version-kind": [
+                        { "group": "monitoring.coreos.com", "kind": "ServiceMonitor", "version": "v1" }
+                    ]
+                }
+            } }
+        })
+        .to_string(),
+    )
+}
+
+#[test]
+fn field_override_tightens_every_value_reaching_the_field() -> eyre::Result<()> {
+    let chart = tempfile::tempdir()?;
+    write_chart(chart.path())?;
+    let dump = tempfile::tempdir()?;
+    write_dump(dump.path())?;
+    let overrides = chart.path().join("field-overrides.yaml");
+    std::fs::write(
+        &overrides,
+        indoc! {r#"
+            fields:
+              - group: monitoring.coreos.com
+                kind: ServiceMonitor
+                path: spec.jobLabel
+                schema:
+                  pattern: "^team-"
+        "#},
+    )?;
+
+    let output = run(chart.path(), dump.path(), &overrides)?;
+    let stderr = String::from_utf8(output.stderr)?;
+    assert!(output.status.success(), "generation failed: {stderr}");
+    let schema: Value = serde_json::from_slice(&output.stdout)?;
+    let validator = jsonschema::validator_for(&schema)?;
+    assert!(
+        validator.is_valid(&json!({ "jobLabel": "team-app" })),
+        "schema={schema}"
+    );
+    assert!(
+        !validator.is_valid(&json!({ "jobLabel": "app" })),
+        "jobLabel takes the override's pattern: schema={schema}"
+    );
+    Ok(())
+}
+
+#[test]
+fn invalid_override_file_fails_generation() -> eyre::Result<()> {
+    let chart = tempfile::tempdir()?;
+    write_chart(chart.path())?;
+    let dump = tempfile::tempdir()?;
+    write_dump(dump.path())?;
+    let overrides = chart.path().join("field-overrides.yaml");
+    std::fs::write(&overrides, "fields:\n  - kind: ServiceMonitor\n")?;
+
+    let output = run(chart.path(), dump.path(), &overrides)?;
+    let stderr = String::from_utf8(output.stderr)?;
+    assert!(
+        !output.status.success(),
+        "an entry without a path must fail"
+    );
+    assert!(
+        stderr.contains("InvalidFieldSchemaOverrides"),
+        "stderr names the broken override file: {stderr}"
+    );
+    Ok(())
+}
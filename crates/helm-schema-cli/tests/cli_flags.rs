@@ -4,7 +4,10 @@
 use clap::{CommandFactory as _, Parser};
 use color_eyre::eyre;
 use helm_schema_cli::Cli;
-use helm_schema_cli::cli::{CrdVersionLookup, DiagFormat, K8sVersionFallback, SchemaProfile};
+use helm_schema_cli::cli::{
+    Command, CrdVersionLookup, DiagFormat, K8sVersionFallback, ReportFormat, SchemaDialect,
+    SchemaProfile,
+};
 use test_util::prelude::sim_assert_eq;
 
 fn parse(args: &[&str]) -> Result<Cli, String> {
@@ -29,6 +32,24 @@ fn cli_perf_flags_parse() {
     );
 }
 
+#[test]
+fn cli_analysis_cache_is_opt_in() {
+    let cli = parse(&[]).expect("parse");
+    assert!(cli.perf.analysis_cache().is_none());
+
+    let cli = parse(&["--analysis-cache-dir", "/tmp/analysis"]).expect("parse");
+    let cache = cli
+        .perf
+        .analysis_cache()
+        .expect("a cache directory enables the cache");
+    sim_assert_eq!(
+        have: cache.root(),
+        want: Some(std::path::Path::new("/tmp/analysis"))
+    );
+    let cli = parse(&["--analysis-cache"]).expect("parse");
+    assert!(cli.perf.analysis_cache().is_some());
+}
+
 #[test]
 fn cli_output_minimize_defaults_on_with_opt_out() {
     let cli = parse(&[]).expect("parse");
@@ -46,6 +67,17 @@ fn cli_output_strip_descriptions_flag_parses() {
     assert!(cli.output.strip_descriptions);
 }
 
+#[test]
+fn cli_output_dialect_defaults_to_draft_07() -> eyre::Result<()> {
+    let cli = parse(&[]).map_err(|error| eyre::eyre!(error))?;
+    sim_assert_eq!(have: cli.output.dialect, want: SchemaDialect::Draft07);
+
+    let cli = parse(&["--dialect", "2020-12"]).map_err(|error| eyre::eyre!(error))?;
+    sim_assert_eq!(have: cli.output.dialect, want: SchemaDialect::Draft2020_12);
+    assert!(parse(&["--dialect", "2019-09"]).is_err());
+    Ok(())
+}
+
 #[test]
 fn cli_schema_profile_defaults_to_full_and_accepts_lean() -> eyre::Result<()> {
     let cli = parse(&[]).map_err(|error| eyre::eyre!(error))?;
@@ -94,6 +126,17 @@ fn cli_output_ref_modes_conflict() {
     assert!(err.contains("--keep-refs") || err.contains("--inline-refs"));
 }
 
+#[test]
+fn cli_watch_requires_an_output_file_and_conflicts_with_check() {
+    let err = parse(&["--watch"]).expect_err("expected missing --output");
+    assert!(err.contains("--output"), "{err}");
+    let err = parse(&["--watch", "-o", "/tmp/schema.json", "--check"])
+        .expect_err("expected clap conflict");
+    assert!(err.contains("--check") || err.contains("--watch"), "{err}");
+    let cli = parse(&["--watch", "-o", "/tmp/schema.json"]).expect("parse");
+    assert!(cli.output.watch);
+}
+
 #[test]
 fn cli_values_files_flag_is_repeatable() {
     let cli = parse(&["-f", "/tmp/base.yaml", "--values", "/tmp/override.yaml"]).expect("parse");
@@ -242,3 +285,137 @@ fn k8s_version_fallback_zero_means_disabled() {
     let window = cli.k8s.resolved_fallback_window().expect("resolve");
     sim_assert_eq!(have: window, want: None);
 }
+
+#[test]
+fn explain_subcommand_shares_global_analysis_flags() -> eyre::Result<()> {
+    for args in [
+        &[
+            "helm-schema",
+            "--offline",
+            "explain",
+            "/tmp/chart",
+            "image.tag",
+        ][..],
+        &[
+            "helm-schema",
+            "explain",
+            "/tmp/chart",
+            "image.tag",
+            "--offline",
+        ][..],
+    ] {
+        let cli = Cli::try_parse_from(args)?;
+        assert!(cli.k8s.offline, "{args:?} must enable --offline");
+        sim_assert_eq!(have: cli.chart_dir, want: None);
+        let Some(Command::Explain(explain)) = cli.command else {
+            eyre::bail!("{args:?} must select the explain subcommand");
+        };
+        sim_assert_eq!(have: explain.chart_dir, want: std::path::PathBuf::from("/tmp/chart"));
+        sim_assert_eq!(have: explain.values_path, want: "image.tag");
+        sim_assert_eq!(have: explain.format, want: ReportFormat::Text);
+    }
+    let cli = Cli::try_parse_from(["helm-schema", "explain", "/tmp/c", "a", "--format", "json"])?;
+    let Some(Command::Explain(explain)) = cli.command else {
+        eyre::bail!("expected the explain subcommand");
+    };
+    sim_assert_eq!(have: explain.format, want: ReportFormat::Json);
+    Ok(())
+}
+
+#[test]
+fn subcommands_reject_schema_writing_flags() -> eyre::Result<()> {
+    let cli = Cli::try_parse_from([
+        "helm-schema",
+        "--watch",
+        "-o",
+        "x.json",
+        "explain",
+        "/tmp/chart",
+        "image.tag",
+    ])?;
+    let err = cli
+        .validate_subcommand_flags()
+        .expect_err("explain never writes a schema");
+    assert!(err.contains("--output, --watch"), "{err}");
+
+    let cli = Cli::try_parse_from(["helm-schema", "--keep-refs", "docs", "/tmp/chart"])?;
+    let err = cli
+        .validate_subcommand_flags()
+        .expect_err("docs ignores reference handling");
+    assert!(err.contains("--keep-refs"), "{err}");
+
+    let cli = Cli::try_parse_from([
+        "helm-schema",
+        "--keep-refs",
+        "bundle",
+        "export",
+        "--chart",
+        "/tmp/chart",
+        "--out",
+        "b.tar.zst",
+    ])?;
+    cli.validate_subcommand_flags()
+        .map_err(|error| eyre::eyre!(error))?;
+
+    let cli = parse(&["--watch", "-o", "x.json"]).map_err(|error| eyre::eyre!(error))?;
+    cli.validate_subcommand_flags()
+        .map_err(|error| eyre::eyre!(error))?;
+    Ok(())
+}
+
+#[test]
+fn subcommands_reject_a_top_level_chart_dir() -> eyre::Result<()> {
+    let cli = Cli::try_parse_from(["helm-schema", "/does/not/exist", "values", "/tmp/chart"])?;
+    let err = cli
+        .validate_subcommand_flags()
+        .expect_err("the top-level chart would be ignored");
+    assert!(err.contains("/does/not/exist"), "{err}");
+
+    let cli = Cli::try_parse_from(["helm-schema", "values", "/tmp/chart"])?;
+    cli.validate_subcommand_flags()
+        .map_err(|error| eyre::eyre!(error))?;
+    Ok(())
+}
+
+#[test]
+fn schema_generation_still_requires_a_chart_dir() {
+    let err = Cli::try_parse_from(["helm-schema", "--offline"]).expect_err("missing chart");
+    assert!(err.to_string().contains("<CHART_DIR>"), "{err}");
+}
+
+#[test]
+fn validate_subcommand_checks_positional_and_dash_f_values_files() -> eyre::Result<()> {
+    let cli = Cli::try_parse_from([
+        "helm-schema",
+        "validate",
+        "/tmp/chart",
+        "a.yaml",
+        "b.yaml",
+        "-f",
+        "docs.yaml",
+    ])?;
+    let Some(Command::Validate(validate)) = cli.command else {
+        eyre::bail!("expected the validate subcommand");
+    };
+    sim_assert_eq!(have: validate.chart_dir, want: std::path::PathBuf::from("/tmp/chart"));
+    sim_assert_eq!(
+        have: validate.values_files,
+        want: vec![std::path::PathBuf::from("a.yaml"), std::path::PathBuf::from("b.yaml")]
+    );
+    sim_assert_eq!(
+        have: cli.chart.values_files,
+        want: vec![std::path::PathBuf::from("docs.yaml")],
+        "-f files are checked after the positional ones"
+    );
+
+    let cli = Cli::try_parse_from(["helm-schema", "validate", "/tmp/chart", "-f", "my.yaml"])?;
+    cli.validate_subcommand_flags()
+        .map_err(|error| eyre::eyre!(error))?;
+
+    let cli = Cli::try_parse_from(["helm-schema", "validate", "/tmp/chart"])?;
+    let err = cli
+        .validate_subcommand_flags()
+        .expect_err("validate needs a values file");
+    assert!(err.contains("-f/--values"), "{err}");
+    Ok(())
+}
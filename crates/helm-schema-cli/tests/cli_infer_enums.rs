@@ -0,0 +1,97 @@
+//! `--infer-enums` publishes the literals a value is compared against as
+//! `examples`, and as `enum` in strict mode when an `else fail` closes the set.
+
+use color_eyre::eyre;
+use indoc::indoc;
+use serde_json::{Value, json};
+use test_util::prelude::sim_assert_eq;
+
+#[path = "common/cli_bin.rs"]
+mod cli_bin;
+
+fn generate(flags: &[&str]) -> eyre::Result<Value> {
+    let dir = tempfile::tempdir()?;
+    cli_bin::write_chart(
+        dir.path(),
+        "app",
+        "mode: standalone\nlogFormat: text\n",
+        &[(
+            "templates/configmap.yaml",
+            indoc! {r#"
+                apiVersion: v1
+                kind: ConfigMap
+                metadata:
+                  name: app
+                data:
+                  {{- if eq .Values.mode "standalone" }}
+                  replicas: "1"
+                  {{- else if eq .Values.mode "cluster" }}
+                  replicas: "3"
+                  {{- else }}
+                  {{- fail "mode must be standalone or cluster" }}
+                  {{- end }}
+                  {{- if eq .Values.logFormat "json" }}
+                  structured: "true"
+                  {{- end }}
+                  format: {{ .Values.logFormat | quote }}
+            "#},
+        )],
+    )?;
+
+    let mut argv = vec![dir.path().as_os_str()];
+    argv.extend(flags.iter().map(std::ffi::OsStr::new));
+    cli_bin::success_json(cli_bin::run_offline(argv)?)
+}
+
+#[test]
+fn bare_flag_annotates_examples_without_narrowing() -> eyre::Result<()> {
+    let schema = generate(&["--infer-enums"])?;
+    sim_assert_eq!(
+        have: schema.pointer("/properties/mode/examples"),
+        want: Some(&json!(["cluster", "standalone"]))
+    );
+    sim_assert_eq!(
+        have: schema.pointer("/properties/logFormat/examples"),
+        want: Some(&json!(["json"]))
+    );
+    sim_assert_eq!(have: schema.pointer("/properties/mode/enum"), want: None);
+    sim_assert_eq!(
+        have: schema.pointer("/x-helm-schema-policy/modifiers/infer-enums"),
+        want: Some(&json!("examples"))
+    );
+    sim_assert_eq!(
+        have: schema.pointer("/x-helm-schema-policy/narrowing"),
+        want: Some(&json!([]))
+    );
+    Ok(())
+}
+
+#[test]
+fn strict_mode_closes_values_guarded_by_an_else_fail() -> eyre::Result<()> {
+    let schema = generate(&["--infer-enums", "strict"])?;
+    sim_assert_eq!(
+        have: schema.pointer("/properties/mode/enum"),
+        want: Some(&json!(["cluster", "standalone"]))
+    );
+    sim_assert_eq!(have: schema.pointer("/properties/logFormat/enum"), want: None);
+    sim_assert_eq!(
+        have: schema.pointer("/x-helm-schema-policy/narrowing"),
+        want: Some(&json!(["infer-enums"]))
+    );
+
+    let validator = jsonschema::validator_for(&schema)?;
+    assert!(validator.is_valid(&json!({"mode": "cluster", "logFormat": "logfmt"})));
+    assert!(!validator.is_valid(&json!({"mode": "replicated"})));
+    Ok(())
+}
+
+#[test]
+fn default_output_carries_no_inferred_enums() -> eyre::Result<()> {
+    let schema = generate(&[])?;
+    sim_assert_eq!(have: schema.pointer("/properties/mode/examples"), want: None);
+    sim_assert_eq!(
+        have: schema.pointer("/x-helm-schema-policy/modifiers/infer-enums"),
+        want: None
+    );
+    Ok(())
+}
@@ -0,0 +1,249 @@
+//! `helm-schema lsp` serves values-file diagnostics, hovers, definitions, and
+//! completions from the chart analysis over stdio.
+
+use std::io::{Read as _, Write as _};
+use std::process::Stdio;
+
+use color_eyre::eyre::{self, OptionExt as _, WrapErr as _};
+use indoc::indoc;
+use serde_json::{Value, json};
+use test_util::prelude::sim_assert_eq;
+
+#[path = "common/cli_bin.rs"]
+mod cli_bin;
+
+fn frame(message: &Value) -> Vec<u8> {
+    let body = message.to_string();
+    format!("Content-Length: {}\r\n\r\n{body}", body.len()).into_bytes()
+}
+
+/// Splits the server's stdout into its JSON-RPC messages.
+fn unframe(mut output: &str) -> eyre::Result<Vec<Value>> {
+    let mut messages = Vec::new();
+    while let Some((header, rest)) = output.split_once("\r\n\r\n") {
+        let length: usize = header
+            .strip_prefix("Content-Length: ")
+            .ok_or_eyre("missing Content-Length")?
+            .parse()?;
+        let (body, rest) = rest.split_at(length);
+        messages.push(serde_json::from_str(body)?);
+        output = rest;
+    }
+    Ok(messages)
+}
+
+fn write_chart(chart: &std::path::Path) -> eyre::Result<()> {
+    cli_bin::write_chart(
+        chart,
+        "served",
+        indoc! {r#"
+            # -- Pod replica count.
+            replicas: 1
+            image:
+              tag: "1.0"
+        "#},
+        &[(
+            "templates/deployment.yaml",
+            indoc! {r"
+                apiVersion: apps/v1
+                kind: Deployment
+                metadata:
+                  name: served
+                spec:
+                  replicas: {{ .Values.replicas }}
+                  template:
+                    spec:
+                      containers:
+                        - image: app:{{ .Values.image.tag }}
+                          imagePullPolicy: {{ .Values.image.pullPolicy }}
+            "},
+        )],
+    )
+}
+
+/// Sends `messages` to a server for `chart` and returns everything it wrote
+/// before exiting.
+fn exchange(chart: &std::path::Path, messages: &[Value]) -> eyre::Result<Vec<Value>> {
+    let mut child = cli_bin::helm_schema()
+        .args(["lsp", "--offline", "--no-k8s-schemas"])
+        .arg(chart)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::null())
+        .spawn()
+        .wrap_err("spawn helm-schema lsp")?;
+    let mut stdin = child.stdin.take().ok_or_eyre("stdin")?;
+    for message in messages {
+        stdin.write_all(&frame(message))?;
+    }
+    drop(stdin);
+    let mut stdout = String::new();
+    child
+        .stdout
+        .take()
+        .ok_or_eyre("stdout")?
+        .read_to_string(&mut stdout)?;
+    assert!(child.wait()?.success(), "the server must exit cleanly");
+    unframe(&stdout)
+}
+
+fn response(messages: &[Value], id: u64) -> eyre::Result<&Value> {
+    messages
+        .iter()
+        .find(|message| message["id"] == id)
+        .map(|message| &message["result"])
+        .ok_or_eyre("missing response")
+}
+
+#[test]
+fn serves_values_file_features_from_the_chart_analysis() -> eyre::Result<()> {
+    let dir = tempfile::tempdir()?;
+    let chart = dir.path();
+    write_chart(chart)?;
+    let uri = format!("file://{}", chart.join("values.yaml").display());
+    let buffer = "replicas: many\nimage:\n  tag: \"1.0\"\n  \n";
+    let document = json!({ "uri": uri });
+
+    let messages = exchange(
+        chart,
+        &[
+            json!({ "jsonrpc": "2.0", "id": 1, "method": "initialize", "params": { "capabilities": {} } }),
+            json!({ "jsonrpc": "2.0", "method": "initialized", "params": {} }),
+            json!({
+                "jsonrpc": "2.0",
+                "method": "textDocument/didOpen",
+                "params": { "textDocument": {
+                    "uri": uri, "languageId": "yaml", "version": 1, "text": buffer,
+                } },
+            }),
+            json!({
+                "jsonrpc": "2.0", "id": 2, "method": "textDocument/hover",
+                "params": { "textDocument": document, "position": { "line": 0, "character": 2 } },
+            }),
+            json!({
+                "jsonrpc": "2.0", "id": 3, "method": "textDocument/definition",
+                "params": { "textDocument": document, "position": { "line": 2, "character": 3 } },
+            }),
+            json!({
+                "jsonrpc": "2.0", "id": 4, "method": "textDocument/completion",
+                "params": { "textDocument": document, "position": { "line": 3, "character": 2 } },
+            }),
+            json!({ "jsonrpc": "2.0", "id": 5, "method": "shutdown" }),
+            json!({ "jsonrpc": "2.0", "method": "exit" }),
+        ],
+    )?;
+
+    let published = messages
+        .iter()
+        .find(|message| message["method"] == "textDocument/publishDiagnostics")
+        .ok_or_eyre("missing diagnostics")?;
+    let diagnostics = published["params"]["diagnostics"]
+        .as_array()
+        .ok_or_eyre("diagnostics array")?;
+    sim_assert_eq!(have: diagnostics.len(), want: 1);
+    sim_assert_eq!(have: &diagnostics[0]["range"]["start"], want: &json!({ "line": 0, "character": 0 }));
+    sim_assert_eq!(have: &diagnostics[0]["code"], want: &json!("type"));
+
+    let hover = response(&messages, 2)?["contents"]["value"]
+        .as_str()
+        .ok_or_eyre("hover markdown")?;
+    sim_assert_eq!(
+        have: hover,
+        want: indoc! {"
+            `.Values.replicas`: `integer`
+
+            Pod replica count.
+
+            Read by:
+            - `templates/deployment.yaml:6:13`"
+        }
+    );
+
+    sim_assert_eq!(
+        have: response(&messages, 3)?,
+        want: &json!([{
+            "uri": format!("file://{}", chart.join("templates/deployment.yaml").display()),
+            "range": {
+                "start": { "line": 9, "character": 21 },
+                "end": { "line": 9, "character": 21 },
+            },
+        }])
+    );
+
+    let labels: Vec<&str> = response(&messages, 4)?
+        .as_array()
+        .ok_or_eyre("completion items")?
+        .iter()
+        .filter_map(|item| item["label"].as_str())
+        .collect();
+    sim_assert_eq!(have: labels, want: vec!["pullPolicy", "tag"]);
+    Ok(())
+}
+
+#[test]
+fn checks_values_files_keys_against_the_contract() -> eyre::Result<()> {
+    let dir = tempfile::tempdir()?;
+    let chart = dir.path();
+    write_chart(chart)?;
+    let open = |path: &str, language: &str| {
+        json!({
+            "jsonrpc": "2.0",
+            "method": "textDocument/didOpen",
+            "params": { "textDocument": {
+                "uri": format!("file://{}", chart.join(path).display()),
+                "languageId": language,
+                "version": 1,
+                "text": "replicaz: 2\nimage:\n  tga: \"1.0\"\n",
+            } },
+        })
+    };
+
+    let messages = exchange(
+        chart,
+        &[
+            json!({ "jsonrpc": "2.0", "id": 1, "method": "initialize", "params": { "capabilities": {} } }),
+            open("values-prod.yaml", "yaml"),
+            open("ci/config.yaml", "yaml"),
+            open("ci/overrides.yaml", "helm-values"),
+            json!({ "jsonrpc": "2.0", "id": 2, "method": "shutdown" }),
+            json!({ "jsonrpc": "2.0", "method": "exit" }),
+        ],
+    )?;
+
+    let published: Vec<(String, Vec<(u64, String)>)> = messages
+        .iter()
+        .filter(|message| message["method"] == "textDocument/publishDiagnostics")
+        .map(|message| {
+            let uri = message["params"]["uri"].as_str().unwrap_or_default();
+            let file = uri
+                .rsplit_once(&*chart.to_string_lossy())
+                .map_or(uri, |(_, file)| file);
+            let diagnostics = message["params"]["diagnostics"]
+                .as_array()
+                .into_iter()
+                .flatten()
+                .map(|diagnostic| {
+                    (
+                        diagnostic["range"]["start"]["line"]
+                            .as_u64()
+                            .unwrap_or_default(),
+                        diagnostic["code"].as_str().unwrap_or_default().to_string(),
+                    )
+                })
+                .collect();
+            (file.to_string(), diagnostics)
+        })
+        .collect();
+    let unknown = vec![
+        (0, "unknown-key".to_string()),
+        (2, "unknown-key".to_string()),
+    ];
+    sim_assert_eq!(
+        have: published,
+        want: vec![
+            ("/values-prod.yaml".to_string(), unknown.clone()),
+            ("/ci/overrides.yaml".to_string(), unknown),
+        ]
+    );
+    Ok(())
+}
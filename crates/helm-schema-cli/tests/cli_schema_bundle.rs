@@ -0,0 +1,219 @@
+//! `bundle export` captures exactly the cached schema documents a chart's
+//! lookups consult, and `--schema-bundle` serves the same lookups from the
+//! bundle alone.
+
+use std::path::Path;
+use std::process::Output;
+
+use color_eyre::eyre::{self, WrapErr as _};
+use indoc::indoc;
+use serde_json::{Value, json};
+use test_util::prelude::sim_assert_eq;
+
+#[path = "common/cli_bin.rs"]
+mod cli_bin;
+
+/// Runs offline with upstream Kubernetes schemas on, since the bundle is
+/// what serves them.
+fn run(args: &[&std::ffi::OsStr]) -> eyre::Result<Output> {
+    cli_bin::helm_schema()
+        .args(args)
+        .arg("--offline")
+        .output()
+        .wrap_err("run helm-schema")
+}
+
+/// Managed caches holding the documents the chart needs, a `$ref` target,
+/// and documents it never touches.
+fn write_caches(k8s: &Path, crds: &Path) -> eyre::Result<()> {
+    for root in [k8s, crds] {
+        cli_bin::write(&root.join("CACHE_LAYOUT_VERSION"), "1\n")?;
+    }
+    let version = k8s.join("default/v1.35.0");
+    cli_bin::write(
+        &version.join("configmap-v1.json"),
+        r#"{"$ref": "_definitions.json#/definitions/io.k8s.api.core.v1.ConfigMap"}"#,
+    )?;
+    cli_bin::write(
+        &version.join("_definitions.json"),
+        &json!({
+            "definitions": {
+                "io.k8s.api.core.v1.ConfigMap": {
+                    "type": "object",
+                    "properties": {
+                        "immutable": { "type": "boolean" },
+                        "data": {
+                            "type": "object",
+                            "additionalProperties": { "type": "string" }
+                        }
+                    }
+                }
+            }
+        })
+        .to_string(),
+    )?;
+    cli_bin::write(&version.join("secret-v1.json"), r#"{"type": "object"}"#)?;
+
+    let group = crds.join("default/example.com");
+    cli_bin::write(
+        &group.join("widget_v1.json"),
+        &json!({
+            "type": "object",
+            "properties": {
+                "spec": {
+                    "type": "object",
+                    "properties": { "size": { "type": "integer" } }
+                }
+            }
+        })
+        .to_string(),
+    )?;
+    cli_bin::write(&group.join("gadget_v1.json"), r#"{"type": "object"}"#)?;
+    Ok(())
+}
+
+fn write_chart(dir: &Path) -> eyre::Result<()> {
+    cli_bin::write_chart(
+        dir,
+        "app",
+        "{}\n",
+        &[
+            (
+                "templates/configmap.yaml",
+                indoc! {"
+                    apiVersion: v1
+                    kind: ConfigMap
+                    metadata:
+                      name: app
+                    immutable: {{ .Values.immutable }}
+                "},
+            ),
+            (
+                "templates/widget.yaml",
+                indoc! {"
+                    apiVersion: example.com/v1
+                    kind: Widget
+                    metadata:
+                      name: app
+                    spec:
+                      size: {{ .Values.size }}
+                "},
+            ),
+        ],
+    )
+}
+
+fn bundle_entries(bundle: &Path) -> eyre::Result<Vec<String>> {
+    let decoder = zstd::Decoder::new(std::fs::File::open(bundle)?)?;
+    let mut archive = tar::Archive::new(decoder);
+    let mut entries = Vec::new();
+    for entry in archive.entries()? {
+        entries.push(entry?.path()?.display().to_string());
+    }
+    entries.sort();
+    Ok(entries)
+}
+
+#[test]
+fn exported_bundle_reproduces_the_schema_without_caches() -> eyre::Result<()> {
+    let work = tempfile::tempdir()?;
+    let chart = work.path().join("chart");
+    let k8s = work.path().join("k8s-cache");
+    let crds = work.path().join("crd-cache");
+    let bundle = work.path().join("out/bundle.tar.zst");
+    write_chart(&chart)?;
+    write_caches(&k8s, &crds)?;
+
+    let cached = run(&[
+        chart.as_os_str(),
+        "--k8s-schema-cache-dir".as_ref(),
+        k8s.as_os_str(),
+        "--crd-catalog-cache-dir".as_ref(),
+        crds.as_os_str(),
+    ])?;
+    assert!(
+        cached.status.success(),
+        "generation failed: {}",
+        String::from_utf8_lossy(&cached.stderr)
+    );
+    let cached: Value = serde_json::from_slice(&cached.stdout)?;
+
+    let exported = run(&[
+        "bundle".as_ref(),
+        "export".as_ref(),
+        "--chart".as_ref(),
+        chart.as_os_str(),
+        "--out".as_ref(),
+        bundle.as_os_str(),
+        "--k8s-schema-cache-dir".as_ref(),
+        k8s.as_os_str(),
+        "--crd-catalog-cache-dir".as_ref(),
+        crds.as_os_str(),
+    ])?;
+    assert!(
+        exported.status.success(),
+        "export failed: {}",
+        String::from_utf8_lossy(&exported.stderr)
+    );
+    assert!(
+        String::from_utf8(exported.stdout)?.contains("bundled 3 schema documents"),
+        "export reports the bundled document count"
+    );
+    sim_assert_eq!(
+        have: bundle_entries(&bundle)?,
+        want: vec![
+            "crds/CACHE_LAYOUT_VERSION",
+            "crds/default/example.com/widget_v1.json",
+            "kubernetes/CACHE_LAYOUT_VERSION",
+            "kubernetes/default/v1.35.0/_definitions.json",
+            "kubernetes/default/v1.35.0/configmap-v1.json",
+        ],
+        "the bundle holds the touched documents and their $ref targets only"
+    );
+
+    std::fs::remove_dir_all(&k8s)?;
+    std::fs::remove_dir_all(&crds)?;
+    let bundled = run(&[
+        chart.as_os_str(),
+        "--schema-bundle".as_ref(),
+        bundle.as_os_str(),
+    ])?;
+    assert!(
+        bundled.status.success(),
+        "generation from the bundle failed: {}",
+        String::from_utf8_lossy(&bundled.stderr)
+    );
+    let bundled: Value = serde_json::from_slice(&bundled.stdout)?;
+    sim_assert_eq!(
+        have: bundled,
+        want: cached,
+        "the bundle reproduces the cached schema"
+    );
+
+    let validator = jsonschema::validator_for(&bundled)?;
+    assert!(validator.is_valid(&json!({ "immutable": true, "size": 3 })));
+    assert!(!validator.is_valid(&json!({ "size": "three" })));
+    Ok(())
+}
+
+#[test]
+fn unreadable_schema_bundle_fails_generation() -> eyre::Result<()> {
+    let work = tempfile::tempdir()?;
+    let chart = work.path().join("chart");
+    write_chart(&chart)?;
+    let bundle = work.path().join("bundle.tar.zst");
+    std::fs::write(&bundle, "not a bundle")?;
+
+    let output = run(&[
+        chart.as_os_str(),
+        "--schema-bundle".as_ref(),
+        bundle.as_os_str(),
+    ])?;
+    let stderr = String::from_utf8(output.stderr)?;
+    assert!(!output.status.success(), "a corrupt bundle must fail");
+    assert!(
+        stderr.contains("ReadSchemaBundle"),
+        "stderr names the bundle error: {stderr}"
+    );
+    Ok(())
+}
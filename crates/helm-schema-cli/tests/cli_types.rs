@@ -0,0 +1,127 @@
+//! `helm-schema types` prints language bindings of the values contract.
+
+use std::path::Path;
+
+use color_eyre::eyre;
+use indoc::indoc;
+use test_util::prelude::sim_assert_eq;
+
+#[path = "common/cli_bin.rs"]
+mod cli_bin;
+
+fn write_chart(dir: &Path) -> eyre::Result<()> {
+    cli_bin::write_chart(
+        dir,
+        "typed",
+        indoc! {r"
+            # -- Serve TLS on the listener.
+            tls:
+              enabled: false
+              cert: ''
+        "},
+        &[(
+            "templates/configmap.yaml",
+            indoc! {r"
+                apiVersion: v1
+                kind: ConfigMap
+                metadata:
+                  name: typed
+                data:
+                  {{- if .Values.tls.enabled }}
+                  cert: {{ .Values.tls.cert | quote }}
+                  {{- end }}
+            "},
+        )],
+    )
+}
+
+fn types(dir: &Path, args: &[&str]) -> eyre::Result<String> {
+    let mut command_line = vec![std::ffi::OsStr::new("types"), dir.as_os_str()];
+    command_line.extend(args.iter().map(std::ffi::OsStr::new));
+    cli_bin::success_stdout(cli_bin::run_offline(command_line)?)
+}
+
+#[test]
+fn typescript_is_the_default_language() -> eyre::Result<()> {
+    let dir = tempfile::tempdir()?;
+    write_chart(dir.path())?;
+
+    sim_assert_eq!(
+        have: types(dir.path(), &[])?,
+        want: indoc! {r"
+            // Code generated by helm-schema. DO NOT EDIT.
+
+            export interface Values {
+              /** Serve TLS on the listener. */
+              tls?: ValuesTls;
+            }
+
+            export type ValuesTls = (
+              | {
+                  cert?: string;
+                  enabled: true;
+                }
+              | {
+                  enabled?: false;
+                }
+            );
+        "}
+    );
+    Ok(())
+}
+
+#[test]
+fn go_package_and_root_name_are_configurable() -> eyre::Result<()> {
+    let dir = tempfile::tempdir()?;
+    write_chart(dir.path())?;
+
+    let bindings = types(
+        dir.path(),
+        &[
+            "--lang",
+            "go",
+            "--go-package",
+            "chart",
+            "--root-name",
+            "Config",
+        ],
+    )?;
+    assert!(
+        bindings.starts_with("// Code generated by helm-schema. DO NOT EDIT.\n\npackage chart\n"),
+        "{bindings}"
+    );
+    assert!(bindings.contains("type ConfigTls struct {"), "{bindings}");
+    assert!(
+        bindings.contains("\t// Only read when enabled is true.\n\tCert    *string"),
+        "{bindings}"
+    );
+    Ok(())
+}
+
+#[test]
+fn cue_definitions_close_over_the_guarded_cases() -> eyre::Result<()> {
+    let dir = tempfile::tempdir()?;
+    write_chart(dir.path())?;
+
+    sim_assert_eq!(
+        have: types(dir.path(), &["--lang", "cue"])?,
+        want: indoc! {r"
+            // Code generated by helm-schema. DO NOT EDIT.
+
+            #Values: {
+            	// Serve TLS on the listener.
+            	tls?: #ValuesTls
+            }
+
+            #ValuesTls: {
+            	{
+            		cert?: string
+            		enabled!: true
+            	} | {
+            		enabled?: false
+            	}
+            }
+        "}
+    );
+    Ok(())
+}
@@ -0,0 +1,95 @@
+//! `helm-schema validate` checks values files against the inferred contract
+//! and reports each violation at its values-file line and template reads.
+
+use color_eyre::eyre::{self, WrapErr as _};
+use indoc::indoc;
+use test_util::prelude::sim_assert_eq;
+
+#[path = "common/cli_bin.rs"]
+mod cli_bin;
+
+fn write_chart(dir: &std::path::Path) -> eyre::Result<()> {
+    cli_bin::write_chart(
+        dir,
+        "validated",
+        "replicas: 1\n",
+        &[(
+            "templates/deployment.yaml",
+            indoc! {r"
+                apiVersion: apps/v1
+                kind: Deployment
+                metadata:
+                  name: validated
+                spec:
+                  replicas: {{ .Values.replicas }}
+            "},
+        )],
+    )
+}
+
+fn validate(chart: &std::path::Path, values: &std::path::Path) -> eyre::Result<(bool, String)> {
+    let output = cli_bin::helm_schema()
+        .arg("validate")
+        .arg(chart)
+        .arg(values)
+        .args(["--offline", "--no-k8s-schemas"])
+        .current_dir(chart)
+        .output()
+        .wrap_err("run helm-schema validate")?;
+    Ok((output.status.success(), String::from_utf8(output.stdout)?))
+}
+
+#[test]
+fn reports_violations_at_values_file_lines_with_template_reads() -> eyre::Result<()> {
+    let dir = tempfile::tempdir()?;
+    write_chart(dir.path())?;
+    std::fs::write(dir.path().join("prod.yaml"), "# prod\nreplicas: two\n")?;
+
+    let (success, report) = validate(dir.path(), std::path::Path::new("prod.yaml"))?;
+
+    assert!(!success, "violations must fail the run");
+    sim_assert_eq!(
+        have: report,
+        want: indoc! {r#"
+            prod.yaml:2: .Values.replicas: "two" is not of type "integer" (type)
+                read at templates/deployment.yaml:6:13
+        "#}
+    );
+    Ok(())
+}
+
+#[test]
+fn conforming_values_pass() -> eyre::Result<()> {
+    let dir = tempfile::tempdir()?;
+    write_chart(dir.path())?;
+    std::fs::write(dir.path().join("prod.yaml"), "replicas: 3\n")?;
+
+    let (success, report) = validate(dir.path(), std::path::Path::new("prod.yaml"))?;
+
+    assert!(success, "{report}");
+    sim_assert_eq!(have: report, want: "values conform to the inferred contract\n");
+    Ok(())
+}
+
+#[test]
+fn dash_f_values_files_are_checked() -> eyre::Result<()> {
+    let dir = tempfile::tempdir()?;
+    write_chart(dir.path())?;
+    std::fs::write(dir.path().join("prod.yaml"), "replicas: two\n")?;
+
+    let output = cli_bin::helm_schema()
+        .arg("validate")
+        .arg(dir.path())
+        .args(["-f", "prod.yaml", "--offline", "--no-k8s-schemas"])
+        .current_dir(dir.path())
+        .output()
+        .wrap_err("run helm-schema validate")?;
+
+    assert!(!output.status.success(), "violations must fail the run");
+    let report = String::from_utf8(output.stdout)?;
+    assert!(
+        report.starts_with("prod.yaml:1: .Values.replicas:"),
+        "{report}"
+    );
+    Ok(())
+}
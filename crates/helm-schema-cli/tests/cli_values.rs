@@ -0,0 +1,47 @@
+//! `helm-schema values` prints an annotated `values.yaml` covering every
+//! values path the contract knows about.
+
+use color_eyre::eyre;
+use indoc::indoc;
+use test_util::prelude::sim_assert_eq;
+
+#[path = "common/cli_bin.rs"]
+mod cli_bin;
+
+#[test]
+fn skeleton_lists_defaults_and_placeholders_for_undeclared_reads() -> eyre::Result<()> {
+    let dir = tempfile::tempdir()?;
+    cli_bin::write_chart(
+        dir.path(),
+        "skeleton",
+        indoc! {r"
+            # -- Hostname served by the ingress.
+            host: example.com
+        "},
+        &[(
+            "templates/deployment.yaml",
+            indoc! {r"
+                apiVersion: apps/v1
+                kind: Deployment
+                metadata:
+                  name: skeleton
+                  annotations:
+                    host: {{ .Values.host }}
+                spec:
+                  replicas: {{ .Values.replicas | default 1 }}
+            "},
+        )],
+    )?;
+
+    let output = cli_bin::run_offline([std::ffi::OsStr::new("values"), dir.path().as_os_str()])?;
+
+    sim_assert_eq!(
+        have: cli_bin::success_stdout(output)?,
+        want: indoc! {"
+            # Hostname served by the ingress.
+            host: example.com
+            # replicas: 0
+        "}
+    );
+    Ok(())
+}
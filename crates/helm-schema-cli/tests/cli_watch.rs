@@ -0,0 +1,205 @@
+//! `helm-schema --watch` regenerates the output file when chart inputs change.
+
+use std::io::{BufRead as _, BufReader};
+use std::process::{Child, Stdio};
+use std::sync::mpsc;
+use std::time::Duration;
+
+use color_eyre::eyre::{self, OptionExt as _, WrapErr as _};
+use indoc::indoc;
+use serde_json::Value;
+use test_util::prelude::sim_assert_eq;
+
+#[path = "common/cli_bin.rs"]
+mod cli_bin;
+
+/// Upper bound for one regeneration of the tiny fixture chart.
+const RUN_TIMEOUT: Duration = Duration::from_mins(2);
+
+/// Kills the watcher when the test ends, whatever the outcome.
+struct Watcher(Child);
+
+impl Drop for Watcher {
+    fn drop(&mut self) {
+        let _ = self.0.kill();
+        let _ = self.0.wait();
+    }
+}
+
+fn properties(path: &std::path::Path) -> eyre::Result<Vec<String>> {
+    let schema: Value = serde_json::from_slice(&std::fs::read(path)?)?;
+    Ok(schema
+        .get("properties")
+        .and_then(Value::as_object)
+        .ok_or_eyre("schema properties")?
+        .keys()
+        .cloned()
+        .collect())
+}
+
+#[test]
+fn regenerates_the_output_file_when_a_template_changes() -> eyre::Result<()> {
+    let dir = tempfile::tempdir()?;
+    let chart = dir.path().join("chart");
+    cli_bin::write_chart(
+        &chart,
+        "watched",
+        "replicas: 1\n",
+        &[(
+            "templates/configmap.yaml",
+            indoc! {r"
+                apiVersion: v1
+                kind: ConfigMap
+                metadata:
+                  name: watched
+                data:
+                  replicas: {{ .Values.replicas | quote }}
+            "},
+        )],
+    )?;
+    let template = chart.join("templates/configmap.yaml");
+    let output = dir.path().join("values.schema.json");
+
+    let mut watcher = Watcher(
+        cli_bin::helm_schema()
+            .args(["--offline", "--no-k8s-schemas", "--watch", "-o"])
+            .arg(&output)
+            .arg(&chart)
+            .stdout(Stdio::piped())
+            .stderr(Stdio::null())
+            .spawn()
+            .wrap_err("spawn helm-schema --watch")?,
+    );
+    let stdout = watcher.0.stdout.take().ok_or_eyre("stdout")?;
+    let (lines_tx, lines) = mpsc::channel();
+    std::thread::spawn(move || {
+        for line in BufReader::new(stdout).lines().map_while(Result::ok) {
+            if lines_tx.send(line).is_err() {
+                break;
+            }
+        }
+    });
+
+    let output_display = output.display().to_string();
+    sim_assert_eq!(
+        have: lines.recv_timeout(RUN_TIMEOUT)?,
+        want: format!("{output_display}: wrote schema")
+    );
+    sim_assert_eq!(have: properties(&output)?, want: vec!["replicas".to_string()]);
+
+    std::fs::write(
+        &template,
+        indoc! {r"
+            apiVersion: v1
+            kind: ConfigMap
+            metadata:
+              name: watched
+            data:
+              replicas: {{ .Values.replicas | quote }}
+              mode: {{ .Values.mode | quote }}
+        "},
+    )?;
+    let summary = lines.recv_timeout(RUN_TIMEOUT)?;
+    assert!(
+        summary.starts_with(&format!("{output_display}: ")) && summary.contains("0 breaking"),
+        "{summary}"
+    );
+    sim_assert_eq!(
+        have: properties(&output)?,
+        want: vec!["mode".to_string(), "replicas".to_string()]
+    );
+    Ok(())
+}
+
+#[test]
+fn regenerates_when_a_provider_input_changes() -> eyre::Result<()> {
+    let dir = tempfile::tempdir()?;
+    let chart = dir.path().join("chart");
+    cli_bin::write_chart(
+        &chart,
+        "watched",
+        "replicas: 1\n",
+        &[(
+            "templates/configmap.yaml",
+            indoc! {r"
+                apiVersion: v1
+                kind: ConfigMap
+                metadata:
+                  name: watched
+                data:
+                  replicas: {{ .Values.replicas | quote }}
+            "},
+        )],
+    )?;
+    let capabilities = dir.path().join("capabilities.yaml");
+    let cluster_state = dir.path().join("cluster-state");
+    let field_overrides = dir.path().join("field-overrides.yaml");
+    std::fs::write(&capabilities, "apiVersions: []\n")?;
+    std::fs::create_dir(&cluster_state)?;
+    std::fs::write(&field_overrides, "fields: []\n")?;
+    let output = dir.path().join("values.schema.json");
+
+    let mut watcher = Watcher(
+        cli_bin::helm_schema()
+            .args(["--offline", "--no-k8s-schemas", "--watch", "-o"])
+            .arg(&output)
+            .arg("--capabilities")
+            .arg(&capabilities)
+            .arg("--cluster-state")
+            .arg(&cluster_state)
+            .arg("--field-schema-override")
+            .arg(&field_overrides)
+            .arg(&chart)
+            .stdout(Stdio::piped())
+            .stderr(Stdio::null())
+            .spawn()
+            .wrap_err("spawn helm-schema --watch")?,
+    );
+    let stdout = watcher.0.stdout.take().ok_or_eyre("stdout")?;
+    let (lines_tx, lines) = mpsc::channel();
+    std::thread::spawn(move || {
+        for line in BufReader::new(stdout).lines().map_while(Result::ok) {
+            if lines_tx.send(line).is_err() {
+                break;
+            }
+        }
+    });
+
+    let output_display = output.display().to_string();
+    sim_assert_eq!(
+        have: lines.recv_timeout(RUN_TIMEOUT)?,
+        want: format!("{output_display}: wrote schema")
+    );
+
+    for (path, contents) in [
+        (
+            capabilities.clone(),
+            "apiVersions:\n  - monitoring.coreos.com/v1\n",
+        ),
+        (
+            cluster_state.join("configmap.yaml"),
+            "apiVersion: v1\nkind: ConfigMap\nmetadata:\n  name: existing\n",
+        ),
+        (
+            field_overrides.clone(),
+            indoc! {"
+                fields:
+                  - kind: ConfigMap
+                    path: data
+                    schema:
+                      type: object
+            "},
+        ),
+    ] {
+        std::fs::write(&path, contents)?;
+        let summary = lines
+            .recv_timeout(RUN_TIMEOUT)
+            .wrap_err_with(|| format!("no regeneration after {} changed", path.display()))?;
+        assert!(
+            summary.starts_with(&format!("{output_display}: "))
+                && !summary.contains("regeneration failed"),
+            "{summary}"
+        );
+    }
+    Ok(())
+}
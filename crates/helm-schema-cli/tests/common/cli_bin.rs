@@ -0,0 +1,90 @@
+//! The built `helm-schema` binary and the throwaway charts CLI tests run it
+//! against.
+#![allow(
+    dead_code,
+    reason = "every CLI test crate includes this module but uses only some helpers"
+)]
+
+use std::ffi::OsStr;
+use std::path::Path;
+use std::process::{Command, Output};
+
+use color_eyre::eyre::{self, WrapErr as _};
+use serde_json::Value;
+
+/// Cargo builds the binary before running this test and points
+/// `CARGO_BIN_EXE_helm-schema` at it, with the platform's executable
+/// extension already applied. Resolving the path by hand instead would miss
+/// Windows' `.exe` and leave the tests looking at a file that never exists.
+pub const HELM_SCHEMA_BIN: &str = env!("CARGO_BIN_EXE_helm-schema");
+
+/// A `helm-schema` invocation, for tests that need stdin, a working
+/// directory, or a long-running child.
+pub fn helm_schema() -> Command {
+    Command::new(HELM_SCHEMA_BIN)
+}
+
+/// Runs `helm-schema` with `args`, offline and without upstream Kubernetes
+/// schemas, so the outcome depends only on the files the test wrote.
+pub fn run_offline<I, S>(args: I) -> eyre::Result<Output>
+where
+    I: IntoIterator<Item = S>,
+    S: AsRef<OsStr>,
+{
+    helm_schema()
+        .args(args)
+        .args(["--offline", "--no-k8s-schemas"])
+        .output()
+        .wrap_err("run helm-schema")
+}
+
+/// Standard output of a successful run.
+///
+/// # Errors
+///
+/// Fails with the run's standard error when it exited non-zero.
+pub fn success_stdout(output: Output) -> eyre::Result<String> {
+    eyre::ensure!(
+        output.status.success(),
+        "helm-schema failed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    Ok(String::from_utf8(output.stdout)?)
+}
+
+/// Standard output of a successful run, parsed as JSON.
+///
+/// # Errors
+///
+/// Fails when the run exited non-zero or printed something other than JSON.
+pub fn success_json(output: Output) -> eyre::Result<Value> {
+    Ok(serde_json::from_str(&success_stdout(output)?)?)
+}
+
+/// Writes `contents` to `path`, creating missing parent directories.
+pub fn write(path: &Path, contents: &str) -> eyre::Result<()> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    std::fs::write(path, contents)?;
+    Ok(())
+}
+
+/// Writes a `0.1.0` chart called `name` with the given `values.yaml` and
+/// further `files`, each relative to the chart root.
+pub fn write_chart(
+    dir: &Path,
+    name: &str,
+    values: &str,
+    files: &[(&str, &str)],
+) -> eyre::Result<()> {
+    write(
+        &dir.join("Chart.yaml"),
+        &format!("apiVersion: v2\nname: {name}\nversion: 0.1.0\n"),
+    )?;
+    write(&dir.join("values.yaml"), values)?;
+    for (path, contents) in files {
+        write(&dir.join(path), contents)?;
+    }
+    Ok(())
+}
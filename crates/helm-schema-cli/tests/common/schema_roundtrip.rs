@@ -2,6 +2,7 @@ use std::path::PathBuf;
 
 use color_eyre::eyre::{self, WrapErr};
 use helm_schema::AnalysisSession;
+use helm_schema::generation::EnumInference;
 use helm_schema_cli::{GenerateOptions, ProviderOptions, SchemaProfile};
 use serde_json::Value;
 use vfs::VfsPath;
@@ -35,6 +36,8 @@ pub fn generate_chart_schema_for_path(chart_relative_path: &str) -> eyre::Result
         include_subchart_values: true,
         values_files: Vec::new(),
         infer_required: false,
+        infer_enums: EnumInference::Off,
+        cluster_state: None,
         emission: SchemaProfile::default().into(),
         provider: ProviderOptions {
             k8s_versions: vec!["v1.29.0-standalone-strict".to_string()],
@@ -32,6 +32,7 @@
 
 use color_eyre::eyre::{self, WrapErr};
 use helm_schema::AnalysisSession;
+use helm_schema::generation::EnumInference;
 use helm_schema_cli::{GenerateOptions, ProviderOptions, SchemaProfile};
 use indoc::indoc;
 use test_util::prelude::sim_assert_eq;
@@ -123,6 +124,8 @@ fn duplicate_helper_name_losing_body_does_not_contaminate_type_hints() -> eyre::
         include_subchart_values: true,
         values_files: Vec::new(),
         infer_required: false,
+        infer_enums: EnumInference::Off,
+        cluster_state: None,
         emission: SchemaProfile::default().into(),
         provider: ProviderOptions {
             k8s_versions: vec!["v1.35.0".to_string()],
@@ -10,6 +10,7 @@
 
 use color_eyre::eyre::{self, WrapErr};
 use helm_schema::AnalysisSession;
+use helm_schema::generation::EnumInference;
 use helm_schema_cli::{GenerateOptions, ProviderOptions, SchemaProfile};
 use indoc::indoc;
 use vfs::VfsPath;
@@ -74,6 +75,8 @@ fn generated_schema() -> eyre::Result<serde_json::Value> {
         include_subchart_values: true,
         values_files: Vec::new(),
         infer_required: false,
+        infer_enums: EnumInference::Off,
+        cluster_state: None,
         emission: SchemaProfile::default().into(),
         provider: ProviderOptions {
             k8s_versions: vec!["v1.35.0".to_string()],
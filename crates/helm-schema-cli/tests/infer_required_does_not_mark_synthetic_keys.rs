@@ -11,6 +11,7 @@ use std::io::Read;
 
 use color_eyre::eyre::{self, WrapErr as _};
 use helm_schema::AnalysisSession;
+use helm_schema::generation::EnumInference;
 use helm_schema_cli::{GenerateOptions, ProviderOptions, SchemaProfile};
 use serde_json::Value;
 use vfs::VfsPath;
@@ -54,6 +55,8 @@ fn infer_required_skips_synthetic_top_level_value_keys() -> eyre::Result<()> {
         include_subchart_values: true,
         values_files: Vec::new(),
         infer_required: true,
+        infer_enums: EnumInference::Off,
+        cluster_state: None,
         emission: SchemaProfile::default().into(),
         provider: ProviderOptions {
             k8s_versions: vec!["v1.29.0-standalone-strict".to_string()],
@@ -22,6 +22,7 @@
 
 use color_eyre::eyre::{self, WrapErr};
 use helm_schema::AnalysisSession;
+use helm_schema::generation::EnumInference;
 use helm_schema_cli::{GenerateOptions, ProviderOptions, SchemaProfile};
 use indoc::indoc;
 use serde_json::Value;
@@ -95,6 +96,8 @@ fn library_helper_non_literal_default_suppresses_required() -> eyre::Result<()>
         include_subchart_values: true,
         values_files: Vec::new(),
         infer_required: true,
+        infer_enums: EnumInference::Off,
+        cluster_state: None,
         emission: SchemaProfile::default().into(),
         provider: ProviderOptions {
             k8s_versions: vec!["v1.35.0".to_string()],
@@ -7,6 +7,7 @@ use color_eyre::eyre::{self, WrapErr};
 use flate2::Compression;
 use flate2::write::GzEncoder;
 use helm_schema::AnalysisSession;
+use helm_schema::generation::EnumInference;
 use helm_schema_cli::{GenerateOptions, ProviderOptions, SchemaProfile};
 use vfs::VfsPath;
 
@@ -195,6 +196,8 @@ fn packaged_library_common_ingress_helper_propagates_schema() -> eyre::Result<()
         include_subchart_values: true,
         values_files: Vec::new(),
         infer_required: false,
+        infer_enums: EnumInference::Off,
+        cluster_state: None,
         emission: SchemaProfile::default().into(),
         provider: ProviderOptions {
             k8s_versions: vec!["v1.35.0".to_string()],
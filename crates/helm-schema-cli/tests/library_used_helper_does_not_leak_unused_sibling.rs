@@ -16,6 +16,7 @@
 
 use color_eyre::eyre::{self, WrapErr};
 use helm_schema::AnalysisSession;
+use helm_schema::generation::EnumInference;
 use helm_schema_cli::{GenerateOptions, ProviderOptions, SchemaProfile};
 use indoc::indoc;
 use serde_json::Value;
@@ -110,6 +111,8 @@ fn unused_helper_in_used_library_does_not_leak_type_hint() -> eyre::Result<()> {
         include_subchart_values: true,
         values_files: Vec::new(),
         infer_required: false,
+        infer_enums: EnumInference::Off,
+        cluster_state: None,
         emission: SchemaProfile::default().into(),
         provider: ProviderOptions {
             k8s_versions: vec!["v1.35.0".to_string()],
@@ -194,6 +197,8 @@ fn unused_helper_in_used_library_does_not_perturb_infer_required() -> eyre::Resu
         include_subchart_values: true,
         values_files: Vec::new(),
         infer_required: true,
+        infer_enums: EnumInference::Off,
+        cluster_state: None,
         emission: SchemaProfile::default().into(),
         provider: ProviderOptions {
             k8s_versions: vec!["v1.35.0".to_string()],
@@ -66,6 +66,7 @@ fn multiple_override_external_refs_use_distinct_bundled_definitions() -> eyre::R
         "x-helm-schema-generated": true,
         "x-helm-schema-policy": {
             "annotation-format-version": 1,
+            "generator-version": env!("CARGO_PKG_VERSION"),
             "modifiers": {
                 "overrides": {
                     "count": 2,
@@ -0,0 +1,140 @@
+use serde_json::{Map, Value};
+
+/// One `lookup apiVersion kind namespace name` call. The namespace and name
+/// are `None` when the template computes them from inputs the analysis
+/// cannot pin (`.Release.Namespace`, a fullname helper).
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ClusterLookupQuery {
+    /// Literal `apiVersion` argument (`v1`, `apps/v1`).
+    pub api_version: String,
+    /// Literal `kind` argument.
+    pub kind: String,
+    /// Namespace argument; `""` selects cluster-scoped objects for a get and
+    /// every namespace for a list.
+    pub namespace: Option<String>,
+    /// Name argument; `""` lists instead of getting one object.
+    pub name: Option<String>,
+}
+
+/// Authoritative answer to a `lookup` call against a known cluster state.
+pub trait ClusterStateOracle: Send + Sync + std::fmt::Debug {
+    /// Every map the call may return, or `None` when uncertain. A fully
+    /// literal call has exactly one result; a missing object answers the
+    /// empty map, as Helm does.
+    fn cluster_lookup(&self, query: &ClusterLookupQuery) -> Option<Vec<Value>>;
+}
+
+/// A cluster state given as the complete list of its objects, such as a
+/// directory of exported manifests. Anything not listed is absent.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ClusterStateSnapshot {
+    objects: Vec<Value>,
+}
+
+impl ClusterStateSnapshot {
+    /// Snapshot of the given objects. Entries without a string `apiVersion`,
+    /// `kind`, and `metadata.name` can never be looked up and are dropped.
+    #[must_use]
+    pub fn new(objects: impl IntoIterator<Item = Value>) -> Self {
+        Self {
+            objects: objects
+                .into_iter()
+                .filter(|object| {
+                    object_field(object, &["apiVersion"]).is_some()
+                        && object_field(object, &["kind"]).is_some()
+                        && object_field(object, &["metadata", "name"]).is_some()
+                })
+                .collect(),
+        }
+    }
+
+    /// Number of objects in the snapshot.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.objects.len()
+    }
+
+    /// Whether the snapshot holds no objects.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.objects.is_empty()
+    }
+
+    fn objects_of<'a>(&'a self, query: &'a ClusterLookupQuery) -> impl Iterator<Item = &'a Value> {
+        self.objects.iter().filter(|object| {
+            object_field(object, &["apiVersion"]) == Some(query.api_version.as_str())
+                && object_field(object, &["kind"]) == Some(query.kind.as_str())
+        })
+    }
+
+    fn list(&self, query: &ClusterLookupQuery, namespace: &str) -> Value {
+        let items = self
+            .objects_of(query)
+            .filter(|object| {
+                namespace.is_empty()
+                    || object_field(object, &["metadata", "namespace"]) == Some(namespace)
+            })
+            .cloned()
+            .collect();
+        let mut list = Map::new();
+        list.insert(
+            "apiVersion".to_string(),
+            Value::String(query.api_version.clone()),
+        );
+        list.insert(
+            "kind".to_string(),
+            Value::String(format!("{}List", query.kind)),
+        );
+        list.insert("metadata".to_string(), Value::Object(Map::new()));
+        list.insert("items".to_string(), Value::Array(items));
+        Value::Object(list)
+    }
+}
+
+impl ClusterStateOracle for ClusterStateSnapshot {
+    fn cluster_lookup(&self, query: &ClusterLookupQuery) -> Option<Vec<Value>> {
+        match (query.namespace.as_deref(), query.name.as_deref()) {
+            (Some(namespace), Some("")) => Some(vec![self.list(query, namespace)]),
+            (Some(namespace), Some(name)) => Some(vec![
+                self.objects_of(query)
+                    .find(|object| {
+                        object_field(object, &["metadata", "name"]) == Some(name)
+                            && object_field(object, &["metadata", "namespace"]).unwrap_or("")
+                                == namespace
+                    })
+                    .cloned()
+                    .unwrap_or_else(|| Value::Object(Map::new())),
+            ]),
+            // A computed namespace may name any namespace, including one
+            // without the object; computed names are gets, never the empty
+            // list selector.
+            (None, Some("")) => None,
+            (namespace, name) => {
+                let mut results: Vec<Value> = self
+                    .objects_of(query)
+                    .filter(|object| {
+                        name.is_none_or(|name| {
+                            object_field(object, &["metadata", "name"]) == Some(name)
+                        }) && namespace.is_none_or(|namespace| {
+                            object_field(object, &["metadata", "namespace"]).unwrap_or("")
+                                == namespace
+                        })
+                    })
+                    .cloned()
+                    .collect();
+                results.push(Value::Object(Map::new()));
+                Some(results)
+            }
+        }
+    }
+}
+
+fn object_field<'a>(object: &'a Value, path: &[&str]) -> Option<&'a str> {
+    path.iter()
+        .try_fold(object, |value, segment| value.get(segment))?
+        .as_str()
+}
+
+#[cfg(test)]
+#[path = "tests/cluster_state.rs"]
+mod tests;
@@ -1,10 +1,13 @@
 use std::collections::{BTreeMap, BTreeSet};
 
+use serde::{Deserialize, Serialize};
+
 use crate::{GuardValue, ProviderSchemaUse};
 
 /// Values-decidable guard expression that can be lowered into JSON Schema
 /// conditionals.
-#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize)]
+#[serde(rename_all = "snake_case")]
 pub enum ConditionalGuard {
     /// The value at `path` is Helm-truthy.
     Truthy {
@@ -125,6 +128,14 @@ pub enum ConditionalGuard {
         /// Inclusive minimum number of members.
         bound: i64,
     },
+    /// The value at `path` is a list with at least `bound` items. Exact:
+    /// both polarities encode.
+    MinItems {
+        /// Values path expected to hold the list.
+        path: String,
+        /// Inclusive minimum number of items.
+        bound: i64,
+    },
     /// Logical negation of a guard.
     Not(Box<ConditionalGuard>),
     /// Conjunction of every enclosed guard.
@@ -203,6 +214,10 @@ impl ConditionalGuard {
                 path: map(&path),
                 bound,
             },
+            Self::MinItems { path, bound } => Self::MinItems {
+                path: map(&path),
+                bound,
+            },
             Self::Not(inner) => Self::Not(Box::new(inner.map_value_paths(map))),
             Self::AllOf(guards) => Self::AllOf(
                 guards
@@ -235,7 +250,8 @@ impl ConditionalGuard {
             | Self::ContainsTruthyMember { path, .. }
             | Self::ContainsEquals { path, .. }
             | Self::AtMostOneMember { path }
-            | Self::MinMembers { path, .. } => {
+            | Self::MinMembers { path, .. }
+            | Self::MinItems { path, .. } => {
                 paths.insert(path.clone());
             }
             Self::Not(inner) => inner.collect_value_paths(paths),
@@ -305,7 +321,8 @@ impl ConditionalOverlayEvidence {
 ///
 /// The contract layer records the field category structurally from the
 /// rendered document path. JSON Schema lowering remains a generator policy.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize)]
+#[serde(rename_all = "snake_case")]
 pub enum MetadataFieldKind {
     /// `metadata.labels` and `metadata.annotations`.
     StringMap,
@@ -361,7 +378,7 @@ pub struct ContractPathSchemaEvidence {
 /// (empty for the whole values tree), any node may be a singleton
 /// `{key: PROGRAM}` map that the chart's engine replaces with the
 /// `tpl`-rendered, YAML-reparsed program result before consumers read it.
-#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
 pub struct ValuesProgramWrapper {
     /// Values subtree the engine rewrites; empty means the whole tree.
     pub scope_path: String,
@@ -379,7 +396,7 @@ pub struct ValuesProgramWrapper {
 /// The target remains user-overridable; the source supplies only keys absent
 /// from the target, matching `mustMergeOverwrite SOURCE TARGET` before the
 /// result replaces a root or nested `Values` object.
-#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
 pub struct ValuesDefaultSource {
     /// Effective values subtree receiving defaults, with an empty path denoting `.Values`.
     pub target_path: String,
@@ -397,6 +414,118 @@ pub struct ContractFailImplication {
     pub target: ContractRequirementTarget,
     /// Conjunction of requirements the affected value must satisfy.
     pub requirements: Vec<FailValueRequirement>,
+    /// Literal messages of the `fail`/`required` calls whose failing test
+    /// lowered into exactly this implication.
+    pub messages: FailMessages,
+}
+
+impl ContractFailImplication {
+    /// Whether both implications bind the same requirement, messages aside.
+    #[must_use]
+    pub fn same_requirement(&self, other: &Self) -> bool {
+        self.outer_guards == other.outer_guards
+            && self.target == other.target
+            && self.requirements == other.requirements
+    }
+
+    /// Records `implication` once: an already recorded implication of the
+    /// same requirement absorbs its messages instead.
+    pub fn push_unique(implications: &mut Vec<Self>, implication: Self) {
+        if let Some(existing) = implications
+            .iter_mut()
+            .find(|existing| existing.same_requirement(&implication))
+        {
+            existing.messages.extend(implication.messages);
+        } else {
+            implications.push(implication);
+        }
+    }
+
+    /// Sorts `implications` and collapses every run binding the same
+    /// requirement into one implication carrying all of the run's messages.
+    /// The sort is stable and ignores messages, so a run keeps them in the
+    /// order they were recorded.
+    pub fn sort_dedup(implications: &mut Vec<Self>) {
+        implications.sort_by(|left, right| {
+            (&left.outer_guards, &left.target, &left.requirements).cmp(&(
+                &right.outer_guards,
+                &right.target,
+                &right.requirements,
+            ))
+        });
+        implications.dedup_by(|later, earlier| {
+            if !earlier.same_requirement(later) {
+                return false;
+            }
+            earlier.messages.append(&mut later.messages);
+            true
+        });
+    }
+}
+
+/// Literal messages of `fail`/`required` calls, each kept once, in the
+/// order the templates raise them.
+#[derive(Debug, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct FailMessages(Vec<String>);
+
+impl FailMessages {
+    /// Records `message` unless it is already present.
+    pub fn insert(&mut self, message: String) {
+        if !self.0.contains(&message) {
+            self.0.push(message);
+        }
+    }
+
+    /// Moves the messages of `other` not already present to the end.
+    pub fn append(&mut self, other: &mut Self) {
+        self.extend(std::mem::take(&mut other.0));
+    }
+
+    /// Whether no message is recorded.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Messages in the order they were first recorded.
+    pub fn iter(&self) -> std::slice::Iter<'_, String> {
+        self.0.iter()
+    }
+}
+
+impl Extend<String> for FailMessages {
+    fn extend<I: IntoIterator<Item = String>>(&mut self, messages: I) {
+        for message in messages {
+            self.insert(message);
+        }
+    }
+}
+
+impl FromIterator<String> for FailMessages {
+    fn from_iter<I: IntoIterator<Item = String>>(messages: I) -> Self {
+        let mut out = Self::default();
+        out.extend(messages);
+        out
+    }
+}
+
+impl IntoIterator for FailMessages {
+    type Item = String;
+    type IntoIter = std::vec::IntoIter<String>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a FailMessages {
+    type Item = &'a String;
+    type IntoIter = std::slice::Iter<'a, String>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter()
+    }
 }
 
 /// Runtime value within a values-path contract that must satisfy a requirement.
@@ -468,7 +597,7 @@ pub enum ContractRequirementTarget {
 }
 
 /// The quoting style of a manually quoted YAML scalar hosting a raw splice.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
 pub enum QuotedScalarStyle {
     /// `"…"` — every `\` must begin a YAML escape and every `"` be escaped.
     Double,
@@ -611,6 +740,26 @@ pub enum FailValueRequirement {
         /// Inclusive maximum length, when one is known.
         max: Option<u64>,
     },
+    /// A string value must have at most this many characters; other kinds
+    /// are unconstrained. The negation of `gt (len x) N`, whose string
+    /// strengthening only ever fires on strings.
+    MaxStringLength(u64),
+    /// A string value must have at least this many characters; other kinds
+    /// are unconstrained. The negation of `lt (len x) N`, whose string
+    /// strengthening fires only on strings too short to reach `N` bytes.
+    MinStringLength(u64),
+    /// A list value must have at most this many items; other kinds are
+    /// unconstrained. The list half of negating `gt (len x) N`.
+    MaxItems(u64),
+    /// A mapping value must have at most this many members; other kinds are
+    /// unconstrained. The mapping half of negating `gt (len x) N`.
+    MaxMembers(u64),
+    /// A numeric value's `int` coercion must be at least this bound; other
+    /// kinds are unconstrained. The negation of `lt (int x) N`.
+    IntAtLeast(i64),
+    /// A numeric value's `int` coercion must be at most this bound; other
+    /// kinds are unconstrained. The negation of `gt (int x) N`.
+    IntAtMost(i64),
     /// The value HOSTS literal member reads: it must be an object — or one
     /// of the kinds the chart's own type dispatch provably handles before
     /// the reads run (nack converts the string image form with `set`).
@@ -715,6 +864,9 @@ pub struct ContractSchemaSignals {
     /// (`fail`/`required` under fully lowerable conditions). An empty clause
     /// is an unconditional termination.
     terminal_clauses: Vec<Vec<ConditionalGuard>>,
+    /// Literal `fail`/`required` messages of the calls each terminal clause
+    /// stems from, keyed by the clause itself.
+    terminal_clause_messages: BTreeMap<Vec<ConditionalGuard>, FailMessages>,
 }
 
 impl ContractSchemaSignals {
@@ -788,6 +940,7 @@ impl ContractSchemaSignals {
             values_program_wrappers: BTreeSet::new(),
             values_program_wrapper_exclusions: BTreeSet::new(),
             terminal_clauses,
+            terminal_clause_messages: BTreeMap::new(),
         }
     }
 
@@ -871,11 +1024,77 @@ impl ContractSchemaSignals {
                         ..ContractPathSchemaEvidence::default()
                     });
                 for implication in implications {
-                    if !entry.fail_implications.contains(&implication) {
-                        entry.fail_implications.push(implication);
+                    ContractFailImplication::push_unique(&mut entry.fail_implications, implication);
+                }
+            }
+        }
+        self
+    }
+
+    /// Scopes provider typing to the conditions under which the typed
+    /// resource's schema is defined. `definition_guards` returns the
+    /// conjoined guards of a resource's definition (empty when it is defined
+    /// unconditionally), or `None` when they cannot be decoded.
+    ///
+    /// A provider use of a guarded resource moves into an overlay under
+    /// those guards, joined with the guards of the overlay it came from, so
+    /// the resource schema types the path only where its definition renders.
+    /// A use whose definition guards do not decode types nothing.
+    #[must_use]
+    pub fn with_resource_definition_guards(
+        mut self,
+        definition_guards: impl Fn(&crate::ResourceRef) -> Option<Vec<ConditionalGuard>>,
+    ) -> Self {
+        let split = |uses: Vec<ProviderSchemaUse>| {
+            let mut unguarded = Vec::new();
+            let mut guarded = BTreeMap::<Vec<ConditionalGuard>, Vec<ProviderSchemaUse>>::new();
+            for provider_use in uses {
+                match definition_guards(&provider_use.resource) {
+                    Some(guards) if guards.is_empty() => unguarded.push(provider_use),
+                    Some(guards) => guarded.entry(guards).or_default().push(provider_use),
+                    None => {}
+                }
+            }
+            (unguarded, guarded)
+        };
+        for evidence in self.schema_evidence_by_value_path.values_mut() {
+            let mut scoped_overlays = Vec::new();
+            let (unguarded, guarded) = split(std::mem::take(&mut evidence.provider_schema_uses));
+            evidence.provider_schema_uses = unguarded;
+            for (guards, provider_schema_uses) in guarded {
+                scoped_overlays.push(ConditionalPathOverlay {
+                    guards,
+                    evidence: ConditionalOverlayEvidence {
+                        facts: evidence.facts,
+                        metadata_field_kinds: evidence.metadata_field_kinds.clone(),
+                        type_hints: evidence.type_hints.clone(),
+                        provider_schema_uses,
+                    },
+                    preserve_base_schema: true,
+                });
+            }
+            for overlay in &mut evidence.conditional_overlays {
+                let (unguarded, guarded) =
+                    split(std::mem::take(&mut overlay.evidence.provider_schema_uses));
+                overlay.evidence.provider_schema_uses = unguarded;
+                for (definition_guards, provider_schema_uses) in guarded {
+                    let mut guards = overlay.guards.clone();
+                    for guard in definition_guards {
+                        if !guards.contains(&guard) {
+                            guards.push(guard);
+                        }
                     }
+                    scoped_overlays.push(ConditionalPathOverlay {
+                        guards,
+                        evidence: ConditionalOverlayEvidence {
+                            provider_schema_uses,
+                            ..overlay.evidence.clone()
+                        },
+                        preserve_base_schema: overlay.preserve_base_schema,
+                    });
                 }
             }
+            evidence.conditional_overlays.extend(scoped_overlays);
         }
         self
     }
@@ -913,6 +1132,28 @@ impl ContractSchemaSignals {
         &self.values_program_wrapper_exclusions
     }
 
+    /// Attaches the messages of the `fail`/`required` calls behind each
+    /// terminal clause.
+    #[must_use]
+    pub fn with_terminal_clause_messages(
+        mut self,
+        messages: impl IntoIterator<Item = (Vec<ConditionalGuard>, FailMessages)>,
+    ) -> Self {
+        for (clause, clause_messages) in messages {
+            self.terminal_clause_messages
+                .entry(clause)
+                .or_default()
+                .extend(clause_messages);
+        }
+        self
+    }
+
+    /// Literal `fail`/`required` messages by the terminal clause they explain.
+    #[must_use]
+    pub fn terminal_clause_messages(&self) -> &BTreeMap<Vec<ConditionalGuard>, FailMessages> {
+        &self.terminal_clause_messages
+    }
+
     /// Paths the chart ranges DIRECTLY: their runtime iterable domain is
     /// wider than any declared shape, so ancestor subtree schemas must not
     /// shadow their own resolutions.
@@ -965,7 +1206,7 @@ impl ContractSchemaSignals {
 /// This bundles the contract-owned path state that schema lowering needs, so
 /// generator code does not have to reconstruct semantic facts from multiple
 /// lower-level projections.
-#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize)]
 pub struct ContractValuePathFacts {
     /// Whether analysis observed referenced paths below this path.
     pub has_referenced_descendants: bool,
@@ -296,6 +296,15 @@ pub enum Guard {
         /// Inclusive minimum number of mapping members.
         bound: i64,
     },
+    /// The value at `path` is a list with at least `bound` items. Emitted
+    /// only as a disjunct of a `gt (len X) N` strengthening, where a long
+    /// enough list certainly satisfies the comparison.
+    MinItems {
+        /// Values path expected to hold the list.
+        path: String,
+        /// Inclusive minimum number of list items.
+        bound: i64,
+    },
     /// The mapping at `path` contains `key` as a literal member — Sprig
     /// `hasKey`/`dig` observability, where a present nil member IS present
     /// (cilium's removed-option guards abort on the truthy `"<nil>"`
@@ -391,6 +400,7 @@ impl Guard {
             | Self::IntLt { .. }
             | Self::AtMostOneMember { .. }
             | Self::MinMembers { .. }
+            | Self::MinItems { .. }
             | Self::HasKey { .. }
             | Self::NotHasKey { .. }
             | Self::ContainsEquals { .. }
@@ -422,6 +432,7 @@ impl Guard {
             | Guard::IntLt { path, .. }
             | Guard::AtMostOneMember { path }
             | Guard::MinMembers { path, .. }
+            | Guard::MinItems { path, .. }
             | Guard::HasKey { path, .. }
             | Guard::NotHasKey { path, .. }
             | Guard::ContainsEquals { path, .. }
@@ -510,6 +521,10 @@ impl Guard {
                 path: map(&path),
                 bound,
             },
+            Guard::MinItems { path, bound } => Guard::MinItems {
+                path: map(&path),
+                bound,
+            },
             Guard::HasKey { path, key } => Guard::HasKey {
                 path: map(&path),
                 key,
@@ -2,6 +2,7 @@
 
 mod capability;
 mod capability_liveness;
+mod cluster_state;
 mod contract_signals;
 mod contract_use;
 mod guard;
@@ -22,11 +23,12 @@ mod value_path;
 
 pub use capability::{ApiPresenceQuery, CapabilityGuard, HelperBranch, HelperBranchBody};
 pub use capability_liveness::{CapabilityOracle, live_literals};
+pub use cluster_state::{ClusterLookupQuery, ClusterStateOracle, ClusterStateSnapshot};
 pub use contract_signals::{
     ConditionalGuard, ConditionalOverlayEvidence, ConditionalPathOverlay, ContractFailImplication,
     ContractPathSchemaEvidence, ContractRequirednessEvidence, ContractRequirementTarget,
-    ContractSchemaSignals, ContractValuePathFacts, FailValueRequirement, MetadataFieldKind,
-    QuotedScalarStyle, ValuesDefaultSource, ValuesProgramWrapper,
+    ContractSchemaSignals, ContractValuePathFacts, FailMessages, FailValueRequirement,
+    MetadataFieldKind, QuotedScalarStyle, ValuesDefaultSource, ValuesProgramWrapper,
 };
 pub use contract_use::{ContractUse, MergeLayerTransform, MergeLayersUse, SplitSegmentUse};
 pub use guard::{Guard, GuardValue};
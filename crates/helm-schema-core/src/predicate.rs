@@ -1,9 +1,13 @@
 use std::collections::BTreeSet;
 
+use serde::{Deserialize, Serialize};
+
 use crate::{Guard, GuardValue};
 
 /// How an inexact predicate participates in later semantic projection.
-#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[derive(
+    Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize,
+)]
 pub enum ApproximationRole {
     /// Ordinary control flow whose exact relation is unavailable.
     #[default]
@@ -14,7 +18,7 @@ pub enum ApproximationRole {
 }
 
 /// Typed Boolean formula recovered from template control flow.
-#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
 pub enum Predicate {
     /// Formula that holds for every input.
     True,
@@ -235,6 +239,7 @@ impl Predicate {
                 | Guard::IntLt { .. }
                 | Guard::AtMostOneMember { .. }
                 | Guard::MinMembers { .. }
+                | Guard::MinItems { .. }
                 | Guard::HasKey { .. }
                 | Guard::NotHasKey { .. }
                 | Guard::ContainsEquals { .. }
@@ -399,6 +404,7 @@ impl Predicate {
                 | Guard::IntLt { .. }
                 | Guard::AtMostOneMember { .. }
                 | Guard::MinMembers { .. }
+                | Guard::MinItems { .. }
                 | Guard::HasKey { .. }
                 | Guard::NotHasKey { .. }
                 | Guard::ContainsEquals { .. }
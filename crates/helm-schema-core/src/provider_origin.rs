@@ -5,6 +5,8 @@ use serde::{Deserialize, Serialize};
 pub enum ProviderOrigin {
     /// User-configured local schema file.
     LocalOverride,
+    /// Saved `/openapi/v3` discovery of the target cluster.
+    ClusterOpenApi,
     /// `CRD` schema declared by the analyzed chart.
     ChartLocalCrd,
     /// Default or mirrored CRD catalog.
@@ -0,0 +1,99 @@
+use serde_json::json;
+use test_util::prelude::sim_assert_eq;
+
+use super::{ClusterLookupQuery, ClusterStateOracle, ClusterStateSnapshot};
+
+fn snapshot() -> ClusterStateSnapshot {
+    ClusterStateSnapshot::new([
+        json!({
+            "apiVersion": "v1",
+            "kind": "Secret",
+            "metadata": { "name": "db", "namespace": "prod" },
+            "data": { "password": "c2VjcmV0" },
+        }),
+        json!({
+            "apiVersion": "v1",
+            "kind": "Secret",
+            "metadata": { "name": "db", "namespace": "staging" },
+            "data": { "password": "c3RhZ2luZw==" },
+        }),
+        json!({
+            "apiVersion": "v1",
+            "kind": "Namespace",
+            "metadata": { "name": "prod" },
+        }),
+        json!({ "kind": "Secret", "metadata": { "name": "unaddressable" } }),
+    ])
+}
+
+fn query(kind: &str, namespace: Option<&str>, name: Option<&str>) -> ClusterLookupQuery {
+    ClusterLookupQuery {
+        api_version: "v1".to_string(),
+        kind: kind.to_string(),
+        namespace: namespace.map(str::to_string),
+        name: name.map(str::to_string),
+    }
+}
+
+#[test]
+fn literal_gets_answer_the_object_or_the_empty_map() {
+    let snapshot = snapshot();
+    sim_assert_eq!(have: snapshot.len(), want: 3);
+    sim_assert_eq!(
+        have: snapshot.cluster_lookup(&query("Secret", Some("prod"), Some("db"))),
+        want: Some(vec![json!({
+            "apiVersion": "v1",
+            "kind": "Secret",
+            "metadata": { "name": "db", "namespace": "prod" },
+            "data": { "password": "c2VjcmV0" },
+        })])
+    );
+    sim_assert_eq!(
+        have: snapshot.cluster_lookup(&query("Secret", Some("dev"), Some("db"))),
+        want: Some(vec![json!({})])
+    );
+    sim_assert_eq!(
+        have: snapshot
+            .cluster_lookup(&query("Namespace", Some(""), Some("prod")))
+            .map(|results| results.len()),
+        want: Some(1)
+    );
+}
+
+#[test]
+fn empty_names_list_the_selected_namespace() {
+    let snapshot = snapshot();
+    let list = snapshot.cluster_lookup(&query("Secret", Some("staging"), Some("")));
+    sim_assert_eq!(
+        have: list.as_deref().and_then(|results| results[0]["items"].as_array()).map(Vec::len),
+        want: Some(1)
+    );
+    let all = snapshot.cluster_lookup(&query("Secret", Some(""), Some("")));
+    sim_assert_eq!(
+        have: all.as_deref().map(|results| results[0]["kind"].clone()),
+        want: Some(json!("SecretList"))
+    );
+    sim_assert_eq!(
+        have: all.as_deref().and_then(|results| results[0]["items"].as_array()).map(Vec::len),
+        want: Some(2)
+    );
+}
+
+#[test]
+fn computed_arguments_answer_every_candidate_and_absence() {
+    let snapshot = snapshot();
+    sim_assert_eq!(
+        have: snapshot
+            .cluster_lookup(&query("Secret", None, Some("db")))
+            .map(|results| results.len()),
+        want: Some(3)
+    );
+    sim_assert_eq!(
+        have: snapshot.cluster_lookup(&query("ConfigMap", None, None)),
+        want: Some(vec![json!({})])
+    );
+    sim_assert_eq!(
+        have: snapshot.cluster_lookup(&query("Secret", None, Some(""))),
+        want: None
+    );
+}
@@ -420,6 +420,22 @@ fn build_single_condition_fragment(
                 ),
             )
         }
+        // Exact: the guard claims lists of at least `bound` items and nothing
+        // else.
+        ConditionalGuard::MinItems { path, bound } => build_default_aware_leaf_condition_fragment(
+            path,
+            ancestor_segments,
+            SchemaNode::foreign(serde_json::json!({
+                "type": "array",
+                "minItems": bound.max(&0),
+            })),
+            matches!(
+                yaml_value_at_path(subchart_defaults_doc, path),
+                Some(YamlValue::Sequence(items))
+                    if *bound <= 0
+                        || usize::try_from(*bound).is_ok_and(|bound| items.len() >= bound)
+            ),
+        ),
         ConditionalGuard::Not(inner) => {
             let negated = SchemaNode::not(build_single_condition_fragment(
                 inner,
@@ -1068,6 +1084,12 @@ fn evaluate_guard_on_values(guard: &ConditionalGuard, values_yaml_doc: &YamlValu
                 if *bound <= 0
                     || usize::try_from(*bound).is_ok_and(|bound| mapping.len() >= bound)
         )),
+        ConditionalGuard::MinItems { path, bound } => Some(matches!(
+            yaml_value_at_path(values_yaml_doc, path),
+            Some(YamlValue::Sequence(items))
+                if *bound <= 0
+                    || usize::try_from(*bound).is_ok_and(|bound| items.len() >= bound)
+        )),
         ConditionalGuard::Not(inner) => {
             evaluate_guard_on_values(inner, values_yaml_doc).map(|v| !v)
         }
@@ -236,6 +236,7 @@ impl LoweredEmissionPlan {
             append_terminal_clauses(
                 &mut document,
                 &terminal_clauses,
+                self.contract_schema_signals.terminal_clause_messages(),
                 self.contract_schema_signals.values_default_sources(),
                 &self.documents.composed,
                 absence,
@@ -266,17 +267,9 @@ impl LoweredEmissionPlan {
         }
 
         let fill_span = tracing::info_span!("default_fill_and_finish").entered();
-        {
-            let _span = tracing::info_span!("merge_missing_defaults").entered();
-            emission_report
-                .canonicalization
-                .default_backfill_abstentions += document
-                .merge_missing_values_yaml_defaults_under_roots(
-                    &self.documents.input_defaults,
-                    &self.support.accepted_values_root_paths,
-                    &self.support.default_fill_skip_paths,
-                );
-        }
+        emission_report
+            .canonicalization
+            .default_backfill_abstentions += self.backfill_values_defaults(&mut document);
         if completion_pass == CompletionPass::ValuesDefaultBackfill {
             return finish_generated(document.into_value(), emission_report);
         }
@@ -356,6 +349,42 @@ impl LoweredEmissionPlan {
         finish_generated(schema, emission_report)
     }
 
+    /// The projected values tree with declared defaults backfilled and the
+    /// `global` namespace opened, before definitions and descriptions are
+    /// attached, together with the provider definitions its `$ref`s name.
+    pub(crate) fn values_tree(&self, projected: ProjectedTree) -> (Value, BTreeMap<String, Value>) {
+        let ProjectedTree {
+            mut document,
+            provider_definitions,
+            ..
+        } = projected;
+        self.backfill_values_defaults(&mut document);
+        document.open_helm_global_namespace();
+        (document.into_value(), provider_definitions)
+    }
+
+    /// Composed defaults with the paths Helm shadows removed.
+    pub(crate) fn input_defaults(&self) -> &YamlValue {
+        &self.documents.input_defaults
+    }
+
+    pub(crate) fn values_descriptions(&self) -> &BTreeMap<String, String> {
+        &self.values_descriptions
+    }
+
+    pub(crate) fn contract_schema_signals(&self) -> &ContractSchemaSignals {
+        &self.contract_schema_signals
+    }
+
+    fn backfill_values_defaults(&self, document: &mut SchemaDocument) -> usize {
+        let _span = tracing::info_span!("merge_missing_defaults").entered();
+        document.merge_missing_values_yaml_defaults_under_roots(
+            &self.documents.input_defaults,
+            &self.support.accepted_values_root_paths,
+            &self.support.default_fill_skip_paths,
+        )
+    }
+
     #[cfg(feature = "bench-support")]
     pub(crate) fn benchmark_retained_candidate_bytes(&self) -> usize {
         fn record_candidate(
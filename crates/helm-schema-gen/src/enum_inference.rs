@@ -0,0 +1,318 @@
+//! Opt-in enum inference for generated values schemas.
+//!
+//! Templates often compare a value against a fixed set of literals
+//! (`eq .Values.mode "a"`, `ne .Values.mode "b"`,
+//! `has .Values.mode (list "a" "b")`) without the chart declaring that set
+//! anywhere. This post-pass collects the string and numeric literals each
+//! values path is compared against, joins them with any `enum` or `const`
+//! the provider schema already carries at that path, and publishes them as
+//! `examples`.
+//!
+//! Comparisons alone never prove a closed set: a chart may branch on a few
+//! interesting values and render anything else verbatim. [`Strict`]
+//! therefore emits `enum` only for paths whose comparisons end in a terminal
+//! clause, i.e. an `else` branch that calls `fail`, so every value outside
+//! the compared literals already aborts rendering.
+//!
+//! [`Strict`]: EnumInference::Strict
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use helm_schema_core::{
+    ConditionalGuard, ContractSchemaSignals, ContractUse, Guard, GuardValue, Predicate,
+};
+use serde_json::{Map, Value};
+
+/// How guard literals are published in the generated schema.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum EnumInference {
+    /// Leave the schema unchanged.
+    #[default]
+    Off,
+    /// Annotate compared paths with `examples`; accepted values do not change.
+    Examples,
+    /// Like [`EnumInference::Examples`], and additionally constrain paths
+    /// whose comparisons are closed by an `else fail` with `enum`.
+    Strict,
+}
+
+impl EnumInference {
+    /// Stable name used in policy annotations.
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Off => "off",
+            Self::Examples => "examples",
+            Self::Strict => "strict",
+        }
+    }
+}
+
+/// Mutate `schema` in place to publish the literals each values path is
+/// compared against.
+///
+/// Literals come from the render conditions of the unmerged contract uses
+/// rather than from per-path schema evidence: once uses that share a render
+/// site merge, an unconditional use of the same path (such as the one a
+/// chart default seeds) absorbs their guards. Paths whose property is
+/// not part of the inferred object tree, such as list items or paths only
+/// read through a dynamic key, are skipped.
+pub fn apply_enum_inference(
+    schema: &mut Value,
+    uses: &[ContractUse],
+    signals: &ContractSchemaSignals,
+    mode: EnumInference,
+) {
+    if mode == EnumInference::Off {
+        return;
+    }
+    let mut compared = BTreeMap::<String, BTreeSet<GuardValue>>::new();
+    for predicate in uses
+        .iter()
+        .flat_map(|contract_use| contract_use.condition.disjuncts())
+        .flatten()
+    {
+        collect_predicate_literals(predicate, &mut compared);
+    }
+    for guard in signals.terminal_clauses().iter().flatten() {
+        collect_compared_literals(guard, &mut compared);
+    }
+    let closed = if mode == EnumInference::Strict {
+        closed_literal_sets(signals.terminal_clauses())
+    } else {
+        BTreeMap::new()
+    };
+
+    for (path, literals) in compared {
+        let parts = crate::split_value_path(&path);
+        let Some(provider_literals) =
+            navigate_to_property(schema, &parts).map(|node| enum_literals(schema, node))
+        else {
+            continue;
+        };
+        let Some(node) = navigate_to_property_mut(schema, &parts) else {
+            continue;
+        };
+        if let Some(allowed) = closed.get(&path) {
+            set_keyword(node, "enum", allowed.iter().map(literal_value).collect());
+            continue;
+        }
+        let mut examples: Vec<Value> = literals
+            .iter()
+            .filter(|literal| !matches!(literal, GuardValue::String(text) if text.is_empty()))
+            .map(literal_value)
+            .collect();
+        for literal in provider_literals {
+            if !examples.contains(&literal) {
+                examples.push(literal);
+            }
+        }
+        if !examples.is_empty() {
+            set_keyword(node, "examples", examples);
+        }
+    }
+}
+
+/// Record every string or numeric literal a render condition compares a
+/// path against.
+fn collect_predicate_literals(
+    predicate: &Predicate,
+    compared: &mut BTreeMap<String, BTreeSet<GuardValue>>,
+) {
+    match predicate {
+        Predicate::Guard(Guard::Eq { path, value } | Guard::NotEq { path, value })
+            if is_enum_literal(value) =>
+        {
+            compared
+                .entry(path.clone())
+                .or_default()
+                .insert(value.clone());
+        }
+        Predicate::Not(inner) => collect_predicate_literals(inner, compared),
+        Predicate::And(predicates) | Predicate::Or(predicates) => {
+            for predicate in predicates {
+                collect_predicate_literals(predicate, compared);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Record every string or numeric literal a terminal-clause guard compares
+/// a path against.
+fn collect_compared_literals(
+    guard: &ConditionalGuard,
+    compared: &mut BTreeMap<String, BTreeSet<GuardValue>>,
+) {
+    match guard {
+        ConditionalGuard::Eq { path, value } | ConditionalGuard::NotEq { path, value }
+            if is_enum_literal(value) =>
+        {
+            compared
+                .entry(path.clone())
+                .or_default()
+                .insert(value.clone());
+        }
+        ConditionalGuard::Not(inner) => collect_compared_literals(inner, compared),
+        ConditionalGuard::AllOf(guards) | ConditionalGuard::AnyOf(guards) => {
+            for guard in guards {
+                collect_compared_literals(guard, compared);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Paths a terminal clause restricts to a closed literal set: a clause made
+/// only of negated comparisons against one path aborts rendering for every
+/// value outside those literals.
+fn closed_literal_sets(
+    clauses: &[Vec<ConditionalGuard>],
+) -> BTreeMap<String, BTreeSet<GuardValue>> {
+    let mut closed = BTreeMap::<String, BTreeSet<GuardValue>>::new();
+    for clause in clauses {
+        let mut clause_path = None;
+        let mut allowed = BTreeSet::new();
+        let proves_closed = !clause.is_empty()
+            && clause.iter().all(|guard| {
+                excluded_literals(guard).is_some_and(|(path, literals)| {
+                    allowed.extend(literals);
+                    *clause_path.get_or_insert(path) == path
+                })
+            });
+        if let (true, Some(path)) = (proves_closed, clause_path) {
+            closed.entry(path.to_string()).or_default().extend(allowed);
+        }
+    }
+    closed
+}
+
+/// The path and literals a negated comparison excludes, for guards of the
+/// form `ne x a`, `not (eq x a)`, and `not (has x (list a b))`.
+fn excluded_literals(guard: &ConditionalGuard) -> Option<(&str, Vec<GuardValue>)> {
+    match guard {
+        ConditionalGuard::NotEq { path, value } if is_enum_literal(value) => {
+            Some((path, vec![value.clone()]))
+        }
+        ConditionalGuard::Not(inner) => included_literals(inner),
+        _ => None,
+    }
+}
+
+/// The path and literals a positive comparison accepts.
+fn included_literals(guard: &ConditionalGuard) -> Option<(&str, Vec<GuardValue>)> {
+    match guard {
+        ConditionalGuard::Eq { path, value } if is_enum_literal(value) => {
+            Some((path, vec![value.clone()]))
+        }
+        ConditionalGuard::AnyOf(guards) => {
+            let mut path = None;
+            let mut literals = Vec::new();
+            for guard in guards {
+                let (member_path, member_literals) = included_literals(guard)?;
+                if *path.get_or_insert(member_path) != member_path {
+                    return None;
+                }
+                literals.extend(member_literals);
+            }
+            Some((path?, literals))
+        }
+        _ => None,
+    }
+}
+
+const fn is_enum_literal(value: &GuardValue) -> bool {
+    matches!(
+        value,
+        GuardValue::String(_) | GuardValue::Int(_) | GuardValue::Float(_)
+    )
+}
+
+fn literal_value(literal: &GuardValue) -> Value {
+    serde_json::to_value(literal).unwrap_or(Value::Null)
+}
+
+/// Literals the schema at `node` already enumerates through `enum` or
+/// `const`, looking through composition keywords and local `$ref`s.
+fn enum_literals(root: &Value, node: &Value) -> Vec<Value> {
+    let mut literals = Vec::new();
+    let mut visited = BTreeSet::new();
+    collect_enum_literals(root, node, &mut visited, &mut literals);
+    literals
+}
+
+fn collect_enum_literals<'a>(
+    root: &'a Value,
+    node: &'a Value,
+    visited: &mut BTreeSet<&'a str>,
+    literals: &mut Vec<Value>,
+) {
+    let Some(object) = node.as_object() else {
+        return;
+    };
+    let declared = object
+        .get("enum")
+        .and_then(Value::as_array)
+        .into_iter()
+        .flatten()
+        .chain(object.get("const"));
+    for literal in declared {
+        if (literal.is_string() || literal.is_number()) && !literals.contains(literal) {
+            literals.push(literal.clone());
+        }
+    }
+    for keyword in ["anyOf", "oneOf", "allOf"] {
+        for member in object
+            .get(keyword)
+            .and_then(Value::as_array)
+            .into_iter()
+            .flatten()
+        {
+            collect_enum_literals(root, member, visited, literals);
+        }
+    }
+    if let Some(reference) = object.get("$ref").and_then(Value::as_str)
+        && let Some(pointer) = reference.strip_prefix('#')
+        && visited.insert(reference)
+        && let Some(target) = root.pointer(pointer)
+    {
+        collect_enum_literals(root, target, visited, literals);
+    }
+}
+
+fn navigate_to_property<'a>(schema: &'a Value, segments: &[String]) -> Option<&'a Value> {
+    let mut node = schema;
+    for seg in segments {
+        node = node.get("properties")?.get(seg.as_str())?;
+    }
+    Some(node)
+}
+
+fn navigate_to_property_mut<'a>(
+    schema: &'a mut Value,
+    segments: &[String],
+) -> Option<&'a mut Value> {
+    let mut node = schema;
+    for seg in segments {
+        node = node.get_mut("properties")?.get_mut(seg.as_str())?;
+    }
+    Some(node)
+}
+
+/// Set `keyword` on `node`. Draft-07 ignores siblings of `$ref`, so a
+/// referencing node is first wrapped in an `allOf`.
+fn set_keyword(node: &mut Value, keyword: &str, values: Vec<Value>) {
+    if node.get("$ref").is_some() {
+        let referenced = node.take();
+        let mut wrapper = Map::new();
+        wrapper.insert("allOf".to_string(), Value::Array(vec![referenced]));
+        *node = Value::Object(wrapper);
+    }
+    if let Some(object) = node.as_object_mut() {
+        object.insert(keyword.to_string(), Value::Array(values));
+    }
+}
+
+#[cfg(test)]
+#[path = "tests/enum_inference.rs"]
+mod tests;
@@ -7,6 +7,7 @@ mod condition_encoding;
 mod emission_plan;
 mod emission_policy;
 mod emission_report;
+pub mod enum_inference;
 mod foreign_schema;
 mod merge;
 mod overlay_lowering;
@@ -22,6 +23,7 @@ mod resolve_policy;
 mod schema_model;
 mod schema_node;
 mod schema_tree;
+mod type_bindings;
 mod values_yaml;
 
 use std::collections::{BTreeMap, BTreeSet};
@@ -40,6 +42,11 @@ pub use emission_report::{
     CanonicalizationCounts, CarrierCounts, EmissionReport, FactCounts, InsertionAbstentionCounts,
     MandatoryOutcomes,
 };
+pub use type_bindings::{
+    BindingLanguage, BindingOptions, FieldType, ObjectType, ValueType, ValuesTypeModel,
+    VariantCase, VariantSet, VariantTag, generate_values_type_model, render_type_bindings,
+};
+pub use values_yaml::render_values_skeleton;
 
 /// Inputs for JSON Schema generation from the current contract schema signals.
 ///
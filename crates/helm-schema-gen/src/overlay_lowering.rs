@@ -1,7 +1,7 @@
 use std::collections::{BTreeMap, BTreeSet};
 
 use helm_schema_core::{
-    ConditionalGuard, ConditionalPathOverlay, ContractSchemaSignals, GuardValue,
+    ConditionalGuard, ConditionalPathOverlay, ContractSchemaSignals, FailMessages, GuardValue,
     ProviderSchemaFragment, ProviderSchemaUse, ResourceSchemaOracle,
 };
 use serde_json::Value;
@@ -1279,7 +1279,8 @@ fn kind_selector_path(guards: &[ConditionalGuard], kinds: &BTreeSet<String>) ->
             | ConditionalGuard::ContainsTruthyMember { .. }
             | ConditionalGuard::ContainsEquals { .. }
             | ConditionalGuard::AtMostOneMember { .. }
-            | ConditionalGuard::MinMembers { .. } => {}
+            | ConditionalGuard::MinMembers { .. }
+            | ConditionalGuard::MinItems { .. } => {}
         }
     }
 
@@ -1438,7 +1439,15 @@ fn requirement_admits_runtime_type(
         // format as safe plain tokens, and every string kind has token-safe
         // inhabitants).
         | FailValueRequirement::QuotedSerializationSafe { .. }
-        | FailValueRequirement::PlainScalarSafe { .. } => true,
+        | FailValueRequirement::PlainScalarSafe { .. }
+        // Binds string lengths, collection sizes, and numeric bounds only,
+        // and every kind has inhabitants within any bound.
+        | FailValueRequirement::MaxStringLength(_)
+        | FailValueRequirement::MinStringLength(_)
+        | FailValueRequirement::MaxItems(_)
+        | FailValueRequirement::MaxMembers(_)
+        | FailValueRequirement::IntAtLeast(_)
+        | FailValueRequirement::IntAtMost(_) => true,
         FailValueRequirement::PrintfStringOperand => {
             matches!(runtime_type, "object" | "string")
         }
@@ -1721,7 +1730,8 @@ fn implication_guards_supported(
             | ConditionalGuard::ContainsTruthyMember { .. }
             | ConditionalGuard::ContainsEquals { .. }
             | ConditionalGuard::AtMostOneMember { .. }
-            | ConditionalGuard::MinMembers { .. } => true,
+            | ConditionalGuard::MinMembers { .. }
+            | ConditionalGuard::MinItems { .. } => true,
             ConditionalGuard::Not(inner) => implication_guards_supported(
                 std::slice::from_ref(inner),
                 target_value_path,
@@ -1763,7 +1773,8 @@ fn guards_supported_with_self_path(
             | ConditionalGuard::ContainsTruthyMember { .. }
             | ConditionalGuard::ContainsEquals { .. }
             | ConditionalGuard::AtMostOneMember { .. }
-            | ConditionalGuard::MinMembers { .. } => true,
+            | ConditionalGuard::MinMembers { .. }
+            | ConditionalGuard::MinItems { .. } => true,
             ConditionalGuard::Not(inner) => guards_supported_with_self_path(
                 std::slice::from_ref(inner),
                 self_path,
@@ -1790,11 +1801,17 @@ fn guards_supported_with_self_path(
 pub(crate) fn append_terminal_clauses(
     root_schema: &mut SchemaDocument,
     clauses: &[Vec<ConditionalGuard>],
+    clause_messages: &BTreeMap<Vec<ConditionalGuard>, FailMessages>,
     values_default_sources: &BTreeSet<helm_schema_core::ValuesDefaultSource>,
     values_yaml_doc: &YamlValue,
     absence: crate::condition_encoding::AbsenceDefaults<'_>,
 ) {
-    append_values_default_source_absence_clauses(root_schema, clauses, values_default_sources);
+    append_values_default_source_absence_clauses(
+        root_schema,
+        clauses,
+        clause_messages,
+        values_default_sources,
+    );
     // A deleted dependency values root is not the document minus a key:
     // helm recreates the table from the SUBCHART's own values, so a clause
     // whose guards all hold against that refill terminates every document
@@ -1813,9 +1830,10 @@ pub(crate) fn append_terminal_clauses(
         else {
             continue;
         };
-        root_schema.append_conditional(&[], condition, SchemaNode::foreign(Value::Bool(false)));
+        root_schema.append_terminal_conditional(&[], condition, None);
     }
     for guards in clauses {
+        let message = terminal_clause_error_message(clause_messages, guards);
         let shared_ancestor = shared_guard_ancestor_segments(guards);
         let all_vacuous = guards.iter().all(guard_holds_vacuously);
         // Keep present values attributed to their nearest shared object.
@@ -1845,11 +1863,7 @@ pub(crate) fn append_terminal_clauses(
             absence,
             crate::condition_encoding::ConditionPolarity::Narrow,
         ));
-        root_schema.append_conditional(
-            &ancestor_segments,
-            condition,
-            SchemaNode::foreign(Value::Bool(false)),
-        );
+        root_schema.append_terminal_conditional(&ancestor_segments, condition, message.as_ref());
         // `deeper_stage` is the effective subtree when the document supplies
         // no ancestor. A definitively false original formula makes the
         // missing-ancestor companion unreachable; uncertainty stays open.
@@ -1872,11 +1886,7 @@ pub(crate) fn append_terminal_clauses(
                     absence,
                     crate::condition_encoding::ConditionPolarity::Narrow,
                 ));
-                root_schema.append_conditional(
-                    &root,
-                    condition,
-                    SchemaNode::foreign(Value::Bool(false)),
-                );
+                root_schema.append_terminal_conditional(&root, condition, message.as_ref());
             }
         }
     }
@@ -1885,6 +1895,7 @@ pub(crate) fn append_terminal_clauses(
 fn append_values_default_source_absence_clauses(
     root_schema: &mut SchemaDocument,
     clauses: &[Vec<ConditionalGuard>],
+    clause_messages: &BTreeMap<Vec<ConditionalGuard>, FailMessages>,
     values_default_sources: &BTreeSet<helm_schema_core::ValuesDefaultSource>,
 ) {
     for guards in clauses {
@@ -1904,14 +1915,23 @@ fn append_values_default_source_absence_clauses(
         else {
             continue;
         };
-        root_schema.append_conditional(
+        root_schema.append_terminal_conditional(
             &[],
             SchemaNode::all_of(vec![target_absent, source_absent]),
-            SchemaNode::foreign(Value::Bool(false)),
+            terminal_clause_error_message(clause_messages, guards).as_ref(),
         );
     }
 }
 
+fn terminal_clause_error_message(
+    clause_messages: &BTreeMap<Vec<ConditionalGuard>, FailMessages>,
+    guards: &[ConditionalGuard],
+) -> Option<Value> {
+    clause_messages
+        .get(guards)
+        .and_then(crate::schema_tree::fail_error_message)
+}
+
 fn unique_values_default_source_path(
     effective_path: &str,
     sources: &BTreeSet<helm_schema_core::ValuesDefaultSource>,
@@ -1948,7 +1968,8 @@ fn guard_holds_vacuously(guard: &ConditionalGuard) -> bool {
         | ConditionalGuard::ContainsMemberEquals { .. }
         | ConditionalGuard::ContainsTruthyMember { .. }
         | ConditionalGuard::ContainsEquals { .. }
-        | ConditionalGuard::MinMembers { .. } => false,
+        | ConditionalGuard::MinMembers { .. }
+        | ConditionalGuard::MinItems { .. } => false,
         ConditionalGuard::Eq { value, .. } => matches!(value, GuardValue::Null),
         ConditionalGuard::NotEq { .. }
         | ConditionalGuard::Absent { .. }
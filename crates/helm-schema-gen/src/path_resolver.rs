@@ -407,7 +407,7 @@ pub(crate) fn fail_requirement_schema<'a>(
     let mut parts = Vec::new();
     let mut insertion_abstentions = 0;
     for implication in implications {
-        let requirement = fail_value_requirement_schema(
+        let mut requirement = fail_value_requirement_schema(
             &implication.requirements,
             !matches!(
                 &implication.target,
@@ -417,6 +417,11 @@ pub(crate) fn fail_requirement_schema<'a>(
         if is_empty_schema(&requirement) {
             continue;
         }
+        if let Value::Object(obj) = &mut requirement
+            && let Some(message) = crate::schema_tree::fail_error_message(&implication.messages)
+        {
+            obj.insert("errorMessage".to_string(), message);
+        }
         match &implication.target {
             helm_schema_core::ContractRequirementTarget::Value => parts.push(requirement),
             helm_schema_core::ContractRequirementTarget::Members { allow_integer } => {
@@ -615,6 +620,12 @@ pub(crate) fn fail_requirement_schema<'a>(
                             }
                             key_schemas.push(Value::Object(bounds));
                         }
+                        helm_schema_core::FailValueRequirement::MaxStringLength(max) => {
+                            key_schemas.push(serde_json::json!({ "maxLength": max }));
+                        }
+                        helm_schema_core::FailValueRequirement::MinStringLength(min) => {
+                            key_schemas.push(serde_json::json!({ "minLength": min }));
+                        }
                         // Keys are always strings, so the exclusions apply
                         // directly rather than through the non-string escape.
                         helm_schema_core::FailValueRequirement::PlainScalarSafe {
@@ -836,7 +847,15 @@ fn requirements_allow_runtime_kind(
         | FailValueRequirement::PlainScalarSafe { .. }
         // The field constraint applies only to objects carrying the field;
         // every other kind passes vacuously.
-        | FailValueRequirement::FieldHelmFalsy { .. } => true,
+        | FailValueRequirement::FieldHelmFalsy { .. }
+        // Binds string lengths, collection sizes, and numeric bounds only,
+        // and every kind has inhabitants within any bound.
+        | FailValueRequirement::MaxStringLength(_)
+        | FailValueRequirement::MinStringLength(_)
+        | FailValueRequirement::MaxItems(_)
+        | FailValueRequirement::MaxMembers(_)
+        | FailValueRequirement::IntAtLeast(_)
+        | FailValueRequirement::IntAtMost(_) => true,
         // Every runtime kind except null has truthy inhabitants.
         FailValueRequirement::HelmTruthy => schema_type != "null",
         FailValueRequirement::ComparableKind(required) => {
@@ -1004,6 +1023,28 @@ fn fail_value_requirement_schema(
                     }));
                 }
             }
+            FailValueRequirement::MaxStringLength(max) => {
+                parts.push(serde_json::json!({ "maxLength": max }));
+            }
+            FailValueRequirement::MinStringLength(min) => {
+                parts.push(serde_json::json!({ "minLength": min }));
+            }
+            FailValueRequirement::MaxItems(max) => {
+                parts.push(serde_json::json!({ "maxItems": max }));
+            }
+            FailValueRequirement::MaxMembers(max) => {
+                parts.push(serde_json::json!({ "maxProperties": max }));
+            }
+            FailValueRequirement::IntAtLeast(bound) => {
+                if let Some(bound) = truncated_int_bound(*bound, true) {
+                    parts.push(bound);
+                }
+            }
+            FailValueRequirement::IntAtMost(bound) => {
+                if let Some(bound) = truncated_int_bound(*bound, false) {
+                    parts.push(bound);
+                }
+            }
             FailValueRequirement::StringLengthBounds { min, max } => {
                 let mut bounds = serde_json::Map::new();
                 bounds.insert("type".to_string(), serde_json::json!("string"));
@@ -1195,6 +1236,20 @@ fn plain_scalar_safe_schema(token_initial: bool, templated: bool) -> Value {
     serde_json::json!({ "anyOf": arms })
 }
 
+/// The numbers whose `int` coercion is at least (or, when `at_least` is
+/// false, at most) `bound`. `int` truncates toward zero, so a fraction just
+/// past an integer bound rounds back onto it only on the side nearer zero:
+/// `int x >= 2` admits exactly `x >= 2`, while `int x >= -2` admits
+/// exactly `x > -3`.
+fn truncated_int_bound(bound: i64, at_least: bool) -> Option<Value> {
+    Some(match (at_least, bound) {
+        (true, 1..) => serde_json::json!({ "minimum": bound }),
+        (true, _) => serde_json::json!({ "exclusiveMinimum": bound.checked_sub(1)? }),
+        (false, ..0) => serde_json::json!({ "maximum": bound }),
+        (false, _) => serde_json::json!({ "exclusiveMaximum": bound.checked_add(1)? }),
+    })
+}
+
 fn type_hint_schema(schema_types: &BTreeSet<String>) -> Value {
     if schema_types.is_empty() {
         return empty_schema();
@@ -676,6 +676,7 @@ fn source_origin_label(origin: ProviderOrigin) -> &'static str {
         ProviderOrigin::DefaultCatalog => "crd_catalog",
         ProviderOrigin::ChartLocalCrd => "chart_crd",
         ProviderOrigin::LocalOverride => "override",
+        ProviderOrigin::ClusterOpenApi => "cluster",
     }
 }
 
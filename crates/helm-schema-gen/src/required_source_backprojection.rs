@@ -24,7 +24,7 @@
 use std::collections::{BTreeMap, HashMap};
 
 use helm_schema_core::{
-    ContractFailImplication, ContractRequirementTarget, ContractSchemaSignals,
+    ContractFailImplication, ContractRequirementTarget, ContractSchemaSignals, FailMessages,
     FailValueRequirement, ProviderSchemaUse, ResourceSchemaOracle, ValueKind,
 };
 use serde_json::Value;
@@ -77,6 +77,7 @@ pub(crate) fn synthesized_required_source_implications(
                     requirements: vec![FailValueRequirement::HasMemberEvenDefaulted(
                         leaf_segment.clone(),
                     )],
+                    messages: FailMessages::default(),
                 },
             );
             push_implication(
@@ -86,6 +87,7 @@ pub(crate) fn synthesized_required_source_implications(
                     outer_guards: Vec::new(),
                     target: ContractRequirementTarget::Value,
                     requirements: vec![FailValueRequirement::NotSchemaType("null".to_string())],
+                    messages: FailMessages::default(),
                 },
             );
         }
@@ -144,6 +146,7 @@ pub(crate) fn synthesized_required_source_implications(
                     requirements: vec![FailValueRequirement::HasMemberEvenDefaulted(
                         leaf_segment.clone(),
                     )],
+                    messages: FailMessages::default(),
                 },
             );
             push_implication(
@@ -153,6 +156,7 @@ pub(crate) fn synthesized_required_source_implications(
                     outer_guards: overlay.guards.clone(),
                     target: ContractRequirementTarget::Value,
                     requirements: vec![FailValueRequirement::NotSchemaType("null".to_string())],
+                    messages: FailMessages::default(),
                 },
             );
         }
@@ -333,6 +337,7 @@ pub(crate) fn synthesized_ranged_member_required_implications(
                         },
                     ),
                     requirements,
+                    messages: FailMessages::default(),
                 },
             );
         }
@@ -420,6 +425,7 @@ pub(crate) fn synthesized_split_segment_implications(
                         pattern,
                         templated: false,
                     }],
+                    messages: FailMessages::default(),
                 },
             );
         }
@@ -491,6 +497,7 @@ pub(crate) fn synthesized_range_key_implications(
                     outer_guards: branch_guards.to_vec(),
                     target: ContractRequirementTarget::Keys,
                     requirements,
+                    messages: FailMessages::default(),
                 },
             );
         }
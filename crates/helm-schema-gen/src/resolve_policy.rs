@@ -276,6 +276,7 @@ impl ResolvePolicy {
             | ConditionalGuard::HasKey { .. }
             | ConditionalGuard::AtMostOneMember { .. }
             | ConditionalGuard::MinMembers { .. }
+            | ConditionalGuard::MinItems { .. }
             | ConditionalGuard::Not(_)
             | ConditionalGuard::AllOf(_)
             | ConditionalGuard::AnyOf(_) => None,
@@ -1417,7 +1418,8 @@ fn collect_positive_self_types(
         | helm_schema_core::ConditionalGuard::TypeIs { .. }
         | helm_schema_core::ConditionalGuard::MatchesPattern { .. }
         | helm_schema_core::ConditionalGuard::AtMostOneMember { .. }
-        | helm_schema_core::ConditionalGuard::MinMembers { .. } => {}
+        | helm_schema_core::ConditionalGuard::MinMembers { .. }
+        | helm_schema_core::ConditionalGuard::MinItems { .. } => {}
     }
 }
 
@@ -280,7 +280,14 @@ pub(crate) fn is_empty_schema(value: &Value) -> bool {
 pub(crate) fn is_annotation_keyword(key: &str) -> bool {
     matches!(
         key,
-        "description" | "title" | "default" | "examples" | "deprecated" | "readOnly" | "writeOnly"
+        "description"
+            | "errorMessage"
+            | "title"
+            | "default"
+            | "examples"
+            | "deprecated"
+            | "readOnly"
+            | "writeOnly"
     )
 }
 
@@ -1,5 +1,6 @@
 use std::collections::{BTreeMap, BTreeSet};
 
+use helm_schema_core::FailMessages;
 use serde_json::{Map, Value};
 use serde_yaml::Value as YamlValue;
 
@@ -83,7 +84,31 @@ impl SchemaDocument {
         condition: SchemaNode,
         then_schema: SchemaNode,
     ) {
-        append_conditional_at_parts(&mut self.root, ancestor_segments, condition, then_schema);
+        append_conditional_at_parts(
+            &mut self.root,
+            ancestor_segments,
+            condition,
+            then_schema,
+            None,
+        );
+    }
+
+    /// Appends a terminal clause: no value satisfying `condition` renders.
+    /// The `fail`/`required` message behind it, if any, annotates the
+    /// clause's entry as `errorMessage`.
+    pub(crate) fn append_terminal_conditional(
+        &mut self,
+        ancestor_segments: &[String],
+        condition: SchemaNode,
+        error_message: Option<&Value>,
+    ) {
+        append_conditional_at_parts(
+            &mut self.root,
+            ancestor_segments,
+            condition,
+            SchemaNode::foreign(Value::Bool(false)),
+            error_message,
+        );
     }
 
     /// Drops the structural `type: object` from a host whose members are
@@ -594,13 +619,18 @@ fn schemas_equal_ignoring_noop_collection_keywords(left: &Value, right: &Value)
     left == right
 }
 
-fn conditional_entry(condition: Value, then_schema: SchemaNode) -> SchemaNode {
+fn conditional_entry(
+    condition: Value,
+    then_schema: SchemaNode,
+    error_message: Option<&Value>,
+) -> SchemaNode {
     SchemaNode::foreign(Value::Object(
         [
             ("if".to_string(), condition),
             ("then".to_string(), then_schema.into_value()),
         ]
         .into_iter()
+        .chain(error_message.map(|message| ("errorMessage".to_string(), message.clone())))
         .collect(),
     ))
 }
@@ -632,9 +662,10 @@ fn append_conditional_at_parts(
     path_segments: &[String],
     condition: SchemaNode,
     then_schema: SchemaNode,
+    error_message: Option<&Value>,
 ) {
     let Some((head, tail)) = path_segments.split_first() else {
-        push_conditional_entry(node, condition, then_schema, false);
+        push_conditional_entry(node, condition, then_schema, false, error_message);
         return;
     };
 
@@ -655,9 +686,15 @@ fn append_conditional_at_parts(
             let member = additional_properties.get_or_insert_with(|| Box::new(SchemaNode::empty()));
             if !member.is_false_schema() {
                 if tail.is_empty() {
-                    push_conditional_entry(member, condition, then_schema, true);
+                    push_conditional_entry(member, condition, then_schema, true, error_message);
                 } else {
-                    append_conditional_at_parts(member, tail, condition, then_schema);
+                    append_conditional_at_parts(
+                        member,
+                        tail,
+                        condition,
+                        then_schema,
+                        error_message,
+                    );
                 }
                 return;
             }
@@ -666,7 +703,13 @@ fn append_conditional_at_parts(
 
     if matches!(node, SchemaNode::Foreign(_)) && !node.is_empty_slot() {
         let mut fragment = SchemaNode::empty();
-        append_conditional_at_parts(&mut fragment, path_segments, condition, then_schema);
+        append_conditional_at_parts(
+            &mut fragment,
+            path_segments,
+            condition,
+            then_schema,
+            error_message,
+        );
         merge_conditional_fragment_into_foreign_slot(node, fragment);
         return;
     }
@@ -686,9 +729,9 @@ fn append_conditional_at_parts(
         }
     });
     if tail.is_empty() {
-        push_conditional_entry(child, condition, then_schema, true);
+        push_conditional_entry(child, condition, then_schema, true, error_message);
     } else {
-        append_conditional_at_parts(child, tail, condition, then_schema);
+        append_conditional_at_parts(child, tail, condition, then_schema, error_message);
     }
 }
 
@@ -706,6 +749,7 @@ fn push_conditional_entry(
     condition: SchemaNode,
     then_schema: SchemaNode,
     open_host: bool,
+    error_message: Option<&Value>,
 ) {
     if open_host {
         reconcile_node_host_with_branch_schema(node, &then_schema);
@@ -718,7 +762,7 @@ fn push_conditional_entry(
     let conditional = if crate::schema_model::is_empty_schema(&condition) {
         then_schema
     } else {
-        conditional_entry(condition, then_schema)
+        conditional_entry(condition, then_schema, error_message)
     };
 
     if !matches!(
@@ -857,6 +901,19 @@ pub(crate) fn apply_values_descriptions(root: &mut Value, descriptions: &BTreeMa
     }
 }
 
+/// The `errorMessage` annotation for a constraint lowered from `fail` or
+/// `required` calls: their trimmed literal messages in the order the
+/// templates raise them, joined with `; `.
+pub(crate) fn fail_error_message(messages: &FailMessages) -> Option<Value> {
+    let message = messages
+        .iter()
+        .map(|message| message.trim())
+        .filter(|message| !message.is_empty())
+        .collect::<Vec<_>>()
+        .join("; ");
+    (!message.is_empty()).then_some(Value::String(message))
+}
+
 fn visit_schema_values_at_path_mut(
     node: &mut Value,
     path_segments: &[String],
@@ -0,0 +1,155 @@
+use test_util::prelude::sim_assert_eq;
+
+use helm_schema_ast::DefineIndex;
+use helm_schema_ir::{ContractIr, SymbolicIrContext};
+use helm_schema_k8s::{Chain, KubernetesJsonSchemaProvider};
+use indoc::indoc;
+use serde_json::{Value, json};
+
+use super::{EnumInference, apply_enum_inference};
+use crate::{ValuesSchemaInput, generate_values_schema};
+
+const TEMPLATE: &str = indoc! {r#"
+    apiVersion: v1
+    kind: Service
+    metadata:
+      name: app
+    spec:
+      {{- if eq .Values.service.type "ClusterIP" }}
+      clusterIP: None
+      {{- else if eq .Values.service.type "NodePort" }}
+      externalTrafficPolicy: Local
+      {{- else }}
+      {{- fail "service.type must be ClusterIP or NodePort" }}
+      {{- end }}
+      {{- if has .Values.protocol (list "TCP" "UDP") }}
+      ports:
+        - port: {{ .Values.port }}
+          {{- if ne .Values.port 443 }}
+          name: plain
+          {{- end }}
+      {{- end }}
+      {{- if ne .Values.mode "" }}
+      sessionAffinity: ClientIP
+      {{- end }}
+"#};
+
+fn provider() -> Chain {
+    Chain::new(vec![Box::new(
+        KubernetesJsonSchemaProvider::new("v1.35.0")
+            .with_cache_dir(crate::tests::bundle_cache_dir())
+            .with_allow_download(false),
+    )])
+}
+
+fn parse_contract(src: &str) -> ContractIr {
+    let idx = DefineIndex::new();
+    SymbolicIrContext::new(&idx).generate_contract_ir(src)
+}
+
+fn generate_with_enums(mode: EnumInference) -> Value {
+    let contract = parse_contract(TEMPLATE);
+    let signals = contract.clone().finalize().into_schema_signals();
+    let mut schema = generate_values_schema(ValuesSchemaInput::new(&signals, &provider()));
+    apply_enum_inference(&mut schema, contract.uses(), &signals, mode);
+    schema
+}
+
+fn keyword<'a>(schema: &'a Value, path: &[&str], keyword: &str) -> Option<&'a Value> {
+    let mut node = schema;
+    for segment in path {
+        node = node.get("properties")?.get(*segment)?;
+    }
+    node.get(keyword)
+}
+
+#[test]
+fn off_leaves_the_schema_unchanged() {
+    let signals = parse_contract(TEMPLATE).finalize().into_schema_signals();
+    let schema = generate_values_schema(ValuesSchemaInput::new(&signals, &provider()));
+    sim_assert_eq!(have: generate_with_enums(EnumInference::Off), want: schema);
+}
+
+#[test]
+fn examples_mode_annotates_every_compared_path() {
+    let schema = generate_with_enums(EnumInference::Examples);
+
+    sim_assert_eq!(
+        have: keyword(&schema, &["service", "type"], "examples"),
+        want: Some(&json!(["ClusterIP", "NodePort"]))
+    );
+    sim_assert_eq!(
+        have: keyword(&schema, &["protocol"], "examples"),
+        want: Some(&json!(["TCP", "UDP"]))
+    );
+    sim_assert_eq!(have: keyword(&schema, &["port"], "examples"), want: Some(&json!([443])));
+    sim_assert_eq!(have: keyword(&schema, &["service", "type"], "enum"), want: None);
+}
+
+#[test]
+fn examples_mode_skips_empty_string_comparisons() {
+    let schema = generate_with_enums(EnumInference::Examples);
+
+    sim_assert_eq!(have: keyword(&schema, &["mode"], "examples"), want: None);
+}
+
+#[test]
+fn seeded_default_uses_do_not_hide_compared_literals() {
+    // Chart analysis seeds an unconditional use for every key in
+    // `values.yaml`; finalization then absorbs the guarded use of the same
+    // path into it.
+    let mut contract = parse_contract(TEMPLATE);
+    contract.push_pathless_scalar("protocol");
+    let signals = contract.clone().finalize().into_schema_signals();
+    let mut schema = generate_values_schema(ValuesSchemaInput::new(&signals, &provider()));
+    apply_enum_inference(
+        &mut schema,
+        contract.uses(),
+        &signals,
+        EnumInference::Examples,
+    );
+
+    sim_assert_eq!(
+        have: keyword(&schema, &["protocol"], "examples"),
+        want: Some(&json!(["TCP", "UDP"]))
+    );
+}
+
+#[test]
+fn strict_mode_closes_only_paths_guarded_by_an_else_fail() {
+    let schema = generate_with_enums(EnumInference::Strict);
+
+    sim_assert_eq!(
+        have: keyword(&schema, &["service", "type"], "enum"),
+        want: Some(&json!(["ClusterIP", "NodePort"]))
+    );
+    sim_assert_eq!(have: keyword(&schema, &["protocol"], "enum"), want: None);
+    sim_assert_eq!(
+        have: keyword(&schema, &["protocol"], "examples"),
+        want: Some(&json!(["TCP", "UDP"]))
+    );
+}
+
+#[test]
+fn examples_join_provider_enum_literals() {
+    let contract = parse_contract(TEMPLATE);
+    let signals = contract.clone().finalize().into_schema_signals();
+    let mut schema = generate_values_schema(ValuesSchemaInput::new(&signals, &provider()));
+    let protocol = schema
+        .pointer_mut("/properties/protocol")
+        .and_then(Value::as_object_mut)
+        .expect("protocol property");
+    protocol.insert("enum".to_string(), json!(["TCP", "UDP", "SCTP"]));
+
+    apply_enum_inference(
+        &mut schema,
+        contract.uses(),
+        &signals,
+        EnumInference::Examples,
+    );
+
+    sim_assert_eq!(
+        have: keyword(&schema, &["protocol"], "examples"),
+        want: Some(&json!(["TCP", "UDP", "SCTP"]))
+    );
+}
@@ -438,6 +438,464 @@ fn approximate_fail_guards_abstain() {
     );
 }
 
+/// A length check is approximated by a sound subset; its negation still
+/// lowers, to `maxLength`, because the string disjunct only ever matches
+/// strings: a long string fails to render.
+#[test]
+fn string_length_fail_guards_lower_to_max_length() {
+    let src = indoc! {r#"
+        {{- if gt (len .Values.release) 63 }}
+        {{- fail "release must be at most 63 characters" }}
+        {{- end }}
+        apiVersion: v1
+        kind: ConfigMap
+        metadata:
+          name: {{ .Values.release }}
+    "#};
+    let values_yaml = indoc! {"
+        release: app
+    "};
+    let schema = schema_for_values_yaml(parse_ir(src), Some(values_yaml));
+
+    for (release, want) in [
+        (serde_json::json!("a".repeat(63)), true),
+        (serde_json::json!("a".repeat(64)), false),
+    ] {
+        assert!(
+            schema_accepts_instance(&schema, &serde_json::json!({ "release": release })) == want,
+            "release={release}; schema={schema}"
+        );
+    }
+    assert!(
+        schema
+            .to_string()
+            .contains("\"errorMessage\":\"release must be at most 63 characters\""),
+        "the literal fail message annotates the lowered constraint: {schema}"
+    );
+}
+
+/// `len` counts list items and mapping members exactly, so a collection
+/// with more entries than the bound fails to render too: the negation adds
+/// `maxItems`/`maxProperties`, which leave every other kind alone.
+#[test]
+fn collection_length_fail_guards_lower_to_max_items_and_properties() {
+    let src = indoc! {r#"
+        {{- if gt (len .Values.hosts) 2 }}
+        {{- fail "at most two hosts" }}
+        {{- end }}
+        {{- if gt (len .Values.labels) 1 }}
+        {{- fail "at most one label" }}
+        {{- end }}
+        apiVersion: v1
+        kind: ConfigMap
+        metadata:
+          name: test
+        data:
+          hosts: {{ toYaml .Values.hosts | quote }}
+          labels: {{ toYaml .Values.labels | quote }}
+    "#};
+    let values_yaml = indoc! {"
+        hosts: []
+        labels: {}
+    "};
+    let schema = schema_for_values_yaml(parse_ir(src), Some(values_yaml));
+
+    let rendered = schema.to_string();
+    assert!(
+        rendered.contains("\"maxItems\":2") && rendered.contains("\"maxProperties\":1"),
+        "the negated strengthening bounds both collections: {schema}"
+    );
+    for (hosts, labels, want) in [
+        (
+            serde_json::json!(["a", "b"]),
+            serde_json::json!({ "a": "1" }),
+            true,
+        ),
+        (
+            serde_json::json!(["a", "b", "c"]),
+            serde_json::json!({}),
+            false,
+        ),
+        (
+            serde_json::json!([]),
+            serde_json::json!({ "a": "1", "b": "2" }),
+            false,
+        ),
+    ] {
+        let values = serde_json::json!({ "hosts": hosts, "labels": labels });
+        assert!(
+            schema_accepts_instance(&schema, &values) == want,
+            "values={values}; schema={schema}"
+        );
+    }
+}
+
+/// A below-bound length check lowers the same way from the other side.
+/// Go counts bytes and a character takes at most four, so `lt (len x) 9`
+/// only certainly fails for strings of at most two characters: the
+/// negation is `minLength: 3`, and short collections stay free.
+#[test]
+fn short_string_length_fail_guards_lower_to_min_length() {
+    let src = indoc! {r#"
+        {{- if lt (len .Values.token) 9 }}
+        {{- fail "token must be at least 9 bytes" }}
+        {{- end }}
+        apiVersion: v1
+        kind: Secret
+        metadata:
+          name: test
+        stringData:
+          token: {{ .Values.token }}
+    "#};
+    let values_yaml = indoc! {"
+        token: changeme-please
+    "};
+    let schema = schema_for_values_yaml(parse_ir(src), Some(values_yaml));
+
+    assert!(
+        schema.to_string().contains("\"minLength\":3"),
+        "the negated strengthening bounds the string: {schema}"
+    );
+    for (token, want) in [
+        (serde_json::json!("ab"), false),
+        (serde_json::json!("abc"), true),
+        (serde_json::json!("a".repeat(9)), true),
+    ] {
+        assert!(
+            schema_accepts_instance(&schema, &serde_json::json!({ "token": token })) == want,
+            "token={token}; schema={schema}"
+        );
+    }
+}
+
+/// An int-cast comparison keeps its exact terminal clause and also bounds
+/// the field numerically. `int` truncates toward zero, so floats inside the
+/// truncation window still render and the bound stays exclusive there.
+#[test]
+fn int_cast_fail_guards_bound_numbers() {
+    let src = indoc! {r#"
+        {{- if gt (int .Values.port) 65535 }}
+        {{- fail "port must be at most 65535" }}
+        {{- end }}
+        {{- if lt (int .Values.replicas) 1 }}
+        {{- fail "at least one replica is required" }}
+        {{- end }}
+        apiVersion: v1
+        kind: Service
+        metadata:
+          name: test
+        spec:
+          replicas: {{ .Values.replicas }}
+          port: {{ .Values.port }}
+    "#};
+    let values_yaml = indoc! {"
+        port: 8080
+        replicas: 1
+    "};
+    let schema = schema_for_values_yaml(parse_ir(src), Some(values_yaml));
+
+    let rendered = schema.to_string();
+    for keyword in ["\"exclusiveMaximum\":65536", "\"minimum\":1"] {
+        assert!(
+            rendered.contains(keyword),
+            "{keyword} bounds the field itself: {schema}"
+        );
+    }
+    for (instance, want) in [
+        (serde_json::json!({ "port": 65535, "replicas": 1 }), true),
+        (
+            serde_json::json!({ "port": 65535.9, "replicas": 1.5 }),
+            true,
+        ),
+        (serde_json::json!({ "port": 65536, "replicas": 1 }), false),
+        (serde_json::json!({ "port": 8080, "replicas": 0 }), false),
+        (serde_json::json!({ "port": 8080, "replicas": 0.9 }), false),
+        (serde_json::json!({ "port": "70000", "replicas": 1 }), false),
+    ] {
+        assert!(
+            schema_accepts_instance(&schema, &instance) == want,
+            "instance={instance}; schema={schema}"
+        );
+    }
+}
+
+/// A raw `gt`/`lt` over a values number never lowers to a bound: Helm
+/// decodes values numbers as float64, and Go templates reject comparing a
+/// float64 with an int literal ("incompatible types for comparison"), so
+/// the guard only renders for `--set` integers and bounds nothing a values
+/// file can supply.
+#[test]
+fn uncoerced_comparison_fail_guards_do_not_bound_numbers() {
+    let src = indoc! {r#"
+        {{- if gt .Values.port 65535 }}
+        {{- fail "port must be at most 65535" }}
+        {{- end }}
+        {{- if lt .Values.replicas 1 }}
+        {{- fail "at least one replica is required" }}
+        {{- end }}
+        apiVersion: v1
+        kind: Service
+        metadata:
+          name: test
+        spec:
+          replicas: {{ .Values.replicas }}
+          port: {{ .Values.port }}
+    "#};
+    let values_yaml = indoc! {"
+        port: 8080
+        replicas: 1
+    "};
+    let schema = schema_for_values_yaml(parse_ir(src), Some(values_yaml));
+
+    let rendered = schema.to_string();
+    for keyword in [
+        "\"maximum\"",
+        "\"exclusiveMaximum\"",
+        "\"minimum\"",
+        "\"exclusiveMinimum\"",
+    ] {
+        assert!(
+            !rendered.contains(keyword),
+            "{keyword} must not bound an uncoerced comparison: {schema}"
+        );
+    }
+    assert!(
+        schema_accepts_instance(
+            &schema,
+            &serde_json::json!({ "port": 70000, "replicas": 0 })
+        ),
+        "schema={schema}"
+    );
+}
+
+/// The inclusive comparators and the mirrored operand order normalize onto
+/// the same strict regions, so `ge`/`le` guards bound the field exactly as
+/// their strict spellings do.
+#[test]
+fn inclusive_int_cast_fail_guards_bound_numbers() {
+    let src = indoc! {r#"
+        {{- if ge (int .Values.port) 65536 }}
+        {{- fail "port must be at most 65535" }}
+        {{- end }}
+        {{- if le (int .Values.replicas) 0 }}
+        {{- fail "at least one replica is required" }}
+        {{- end }}
+        {{- if lt 10 (int .Values.workers) }}
+        {{- fail "at most ten workers are supported" }}
+        {{- end }}
+        apiVersion: v1
+        kind: Service
+        metadata:
+          name: test
+        spec:
+          replicas: {{ .Values.replicas }}
+          port: {{ .Values.port }}
+          workers: {{ .Values.workers }}
+    "#};
+    let values_yaml = indoc! {"
+        port: 8080
+        replicas: 1
+        workers: 2
+    "};
+    let schema = schema_for_values_yaml(parse_ir(src), Some(values_yaml));
+
+    let rendered = schema.to_string();
+    for keyword in [
+        "\"exclusiveMaximum\":65536",
+        "\"minimum\":1",
+        "\"exclusiveMaximum\":11",
+    ] {
+        assert!(
+            rendered.contains(keyword),
+            "{keyword} bounds the field itself: {schema}"
+        );
+    }
+    for (instance, want) in [
+        (
+            serde_json::json!({ "port": 65535, "replicas": 1, "workers": 10 }),
+            true,
+        ),
+        (
+            serde_json::json!({ "port": 65536, "replicas": 1, "workers": 10 }),
+            false,
+        ),
+        (
+            serde_json::json!({ "port": 8080, "replicas": 0, "workers": 10 }),
+            false,
+        ),
+        (
+            serde_json::json!({ "port": 8080, "replicas": 1, "workers": 11 }),
+            false,
+        ),
+    ] {
+        assert!(
+            schema_accepts_instance(&schema, &instance) == want,
+            "instance={instance}; schema={schema}"
+        );
+    }
+}
+
+/// A bound only holds where its `fail` can run: behind an enclosing `if`,
+/// it applies exactly when that guard holds.
+#[test]
+fn comparison_bounds_stay_conditional_on_enclosing_guards() {
+    let src = indoc! {r#"
+        {{- if .Values.strict }}
+        {{- if gt (int .Values.port) 1024 }}
+        {{- fail "strict mode requires a privileged port" }}
+        {{- end }}
+        {{- if gt (len .Values.name) 8 }}
+        {{- fail "strict mode requires a short name" }}
+        {{- end }}
+        {{- end }}
+        apiVersion: v1
+        kind: Service
+        metadata:
+          name: {{ .Values.name }}
+        spec:
+          port: {{ .Values.port }}
+    "#};
+    let values_yaml = indoc! {"
+        strict: false
+        name: web
+        port: 8080
+    "};
+    let schema = schema_for_values_yaml(parse_ir(src), Some(values_yaml));
+
+    for (instance, want) in [
+        (
+            serde_json::json!({ "strict": false, "name": "webserver-1", "port": 8080 }),
+            true,
+        ),
+        (
+            serde_json::json!({ "strict": true, "name": "web", "port": 443 }),
+            true,
+        ),
+        (
+            serde_json::json!({ "strict": true, "name": "web", "port": 8080 }),
+            false,
+        ),
+        (
+            serde_json::json!({ "strict": true, "name": "webserver-1", "port": 443 }),
+            false,
+        ),
+    ] {
+        assert!(
+            schema_accepts_instance(&schema, &instance) == want,
+            "instance={instance}; schema={schema}"
+        );
+    }
+}
+
+/// Literal `fail` and `required` messages annotate only the constraint
+/// their own failing test lowered into, as `errorMessage`; they never become
+/// a property description.
+#[test]
+fn fail_messages_annotate_lowered_constraints() {
+    let src = indoc! {r#"
+        {{- if not (regexMatch "^[a-z]+$" .Values.name) }}
+        {{- fail "name must be lowercase letters" }}
+        {{- end }}
+        {{- if gt (len .Values.name) 10 }}
+        {{- fail "name must be at most 10 characters" }}
+        {{- end }}
+        {{- if and .Values.a .Values.b }}
+        {{- fail "a and b are mutually exclusive" }}
+        {{- end }}
+        apiVersion: v1
+        kind: ConfigMap
+        metadata:
+          name: {{ .Values.name }}
+        data:
+          cluster: {{ required "a cluster name is required" .Values.clusterName }}
+          a: {{ .Values.a | quote }}
+          b: {{ .Values.b | quote }}
+    "#};
+    let values_yaml = indoc! {"
+        name: app
+        clusterName: prod
+        a: false
+        b: false
+    "};
+    let schema = schema_for_values_yaml(parse_ir(src), Some(values_yaml));
+
+    let mut error_messages = Vec::new();
+    collect_error_messages(&schema, &mut error_messages);
+    error_messages.sort();
+    error_messages.dedup();
+    sim_assert_eq!(
+        have: error_messages,
+        want: vec![
+            "a and b are mutually exclusive".to_string(),
+            "a cluster name is required".to_string(),
+            "name must be at most 10 characters".to_string(),
+            "name must be lowercase letters".to_string(),
+        ]
+    );
+    for path in [
+        "/properties/name/description",
+        "/properties/clusterName/description",
+    ] {
+        sim_assert_eq!(have: schema.pointer(path), want: None);
+    }
+    assert!(
+        !schema_accepts_instance(
+            &schema,
+            &serde_json::json!({ "name": "App", "clusterName": "prod" })
+        ),
+        "the message is an annotation on top of the lowered pattern: {schema}"
+    );
+}
+
+/// `fail` calls whose tests lower into the same constraint share one
+/// `errorMessage`, with their messages in template order.
+#[test]
+fn merged_fail_messages_keep_template_order() {
+    let src = indoc! {r#"
+        {{- if not .Values.tls }}
+        {{- fail "you cannot enable http3 without tls" }}
+        {{- end }}
+        {{- if not .Values.tls }}
+        {{- fail "TLS is required by the uplink" }}
+        {{- end }}
+        apiVersion: v1
+        kind: ConfigMap
+        metadata:
+          name: app
+        data:
+          tls: {{ .Values.tls | quote }}
+    "#};
+    let schema = schema_for_values_yaml(parse_ir(src), Some("tls: true\n"));
+
+    let mut error_messages = Vec::new();
+    collect_error_messages(&schema, &mut error_messages);
+    error_messages.dedup();
+    sim_assert_eq!(
+        have: error_messages,
+        want: vec!["you cannot enable http3 without tls; TLS is required by the uplink".to_string()]
+    );
+}
+
+fn collect_error_messages(node: &serde_json::Value, out: &mut Vec<String>) {
+    match node {
+        serde_json::Value::Object(obj) => {
+            for (key, value) in obj {
+                if key == "errorMessage" {
+                    out.extend(value.as_str().map(str::to_string));
+                } else {
+                    collect_error_messages(value, out);
+                }
+            }
+        }
+        serde_json::Value::Array(items) => {
+            for item in items {
+                collect_error_messages(item, out);
+            }
+        }
+        _ => {}
+    }
+}
+
 /// Helm's `required(message, subject)` terminates rendering when the
 /// subject is Helm-empty (absent, null, or ""): a direct subject binds a
 /// document-level requirement under the ambient guards, and a ranged
@@ -2681,6 +3139,56 @@ fn capabilities_defaulted_semver_gates_decode_against_the_policy_version() {
     }
 }
 
+/// A `lookup` against a configured cluster state decides its `if
+/// $existing` gate: an existing secret releases the chart's requirement on
+/// the password it would otherwise generate from, an absent one binds it,
+/// and without a cluster state the gate stays undecided and abstains.
+#[test]
+fn cluster_state_lookups_decide_existing_object_gates() {
+    let src = indoc! {r#"
+        {{- $existing := lookup "v1" "Secret" "prod" "db" }}
+        {{- if not $existing }}
+        {{- if not .Values.password }}
+        {{- fail "password is required until the secret exists" }}
+        {{- end }}
+        {{- end }}
+        kind: ConfigMap
+    "#};
+    let values_yaml = indoc! {r#"
+        password: ""
+    "#};
+    let secret = serde_json::json!({
+        "apiVersion": "v1",
+        "kind": "Secret",
+        "metadata": { "name": "db", "namespace": "prod" },
+        "data": { "password": "c2VjcmV0" },
+    });
+    for (contract, empty_password_renders, label) in [
+        (parse_ir(src), true, "no cluster state"),
+        (
+            parse_ir_with_cluster_state(src, vec![secret]),
+            true,
+            "the secret exists",
+        ),
+        (
+            parse_ir_with_cluster_state(src, Vec::new()),
+            false,
+            "the secret is absent",
+        ),
+    ] {
+        let schema = schema_for_values_yaml(contract, Some(values_yaml));
+        assert!(
+            schema_accepts_instance(&schema, &serde_json::json!({ "password": "" }))
+                == empty_password_renders,
+            "{label}: schema={schema}"
+        );
+        assert!(
+            schema_accepts_instance(&schema, &serde_json::json!({ "password": "x" })),
+            "{label}: a password always renders; schema={schema}"
+        );
+    }
+}
+
 /// Sprig `dig` splits its subject and intermediate-step contracts: the
 /// SUBJECT is type-asserted before any missing-key handling (an explicit
 /// null aborts; absence stays open to the caller's defaults), while an
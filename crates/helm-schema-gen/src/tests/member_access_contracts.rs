@@ -1864,6 +1864,7 @@ fn declared_shape_does_not_own_a_guarded_member_host_base() {
                 handled_kinds: Vec::new(),
                 complete_domain: true,
             }],
+            messages: helm_schema_core::FailMessages::default(),
         }],
         ..helm_schema_core::ContractPathSchemaEvidence::default()
     };
@@ -1937,6 +1938,7 @@ fn partial_member_host_domain_preserves_the_declared_base() {
                     handled_kinds: Vec::new(),
                     complete_domain: true,
                 }],
+                messages: helm_schema_core::FailMessages::default(),
             },
             helm_schema_core::ContractFailImplication {
                 outer_guards: vec![helm_schema_core::ConditionalGuard::Truthy {
@@ -1947,6 +1949,7 @@ fn partial_member_host_domain_preserves_the_declared_base() {
                     handled_kinds: Vec::new(),
                     complete_domain: false,
                 }],
+                messages: helm_schema_core::FailMessages::default(),
             },
         ],
         ..helm_schema_core::ContractPathSchemaEvidence::default()
@@ -53,7 +53,9 @@ mod required_source_backprojection;
 mod resolve_policy;
 mod shape_alternatives;
 mod string_transform_contracts;
+mod type_bindings;
 mod validator_reachability;
+mod values_skeleton;
 
 /// Provider chains resolve against the COMMITTED bundle with downloads off:
 /// provider availability is a test input, never ambient user-cache state.
@@ -119,6 +121,21 @@ fn parse_ir_with_kubernetes_version(src: &str, kubernetes_version: &str) -> Cont
     parse_ir_with_helpers_and_kubernetes_version(src, "", Some(kubernetes_version))
 }
 
+/// Like [`parse_ir`], with `lookup` calls resolving against a cluster state
+/// holding exactly `objects`.
+fn parse_ir_with_cluster_state(src: &str, objects: Vec<serde_json::Value>) -> ContractIr {
+    SymbolicIrContext::with_policy(
+        &DefineIndex::new(),
+        SymbolicPolicy {
+            cluster_state: Some(std::sync::Arc::new(
+                helm_schema_core::ClusterStateSnapshot::new(objects),
+            )),
+            ..SymbolicPolicy::default()
+        },
+    )
+    .generate_contract_ir(src)
+}
+
 fn with_type_hints(mut contract: ContractIr, hints: &[(&str, &str)]) -> ContractIr {
     for (path, schema_type) in hints {
         contract.add_type_hint(*path, *schema_type);
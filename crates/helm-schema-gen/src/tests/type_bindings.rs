@@ -0,0 +1,339 @@
+use test_util::prelude::sim_assert_eq;
+
+use super::*;
+use crate::{
+    BindingLanguage, BindingOptions, ValueType, ValuesTypeModel, VariantTag,
+    generate_values_type_model, render_type_bindings,
+};
+
+const TEMPLATE: &str = indoc! {r#"
+    apiVersion: apps/v1
+    kind: Deployment
+    metadata:
+      name: demo
+    spec:
+      replicas: {{ .Values.replicas }}
+      template:
+        spec:
+          containers:
+            - name: app
+              image: "{{ .Values.image.repository }}:{{ .Values.image.tag }}"
+              {{- if eq .Values.mode "advanced" }}
+              args:
+                - --level={{ .Values.level }}
+              {{- end }}
+              {{- if .Values.tls.enabled }}
+              env:
+                - name: CERT
+                  value: {{ .Values.tls.cert | quote }}
+              {{- end }}
+"#};
+
+const VALUES_YAML: &str = indoc! {"
+    replicas: 1
+    image:
+      repository: nginx
+      tag: latest
+    mode: basic
+    level: 3
+    tls:
+      enabled: false
+      cert: ''
+"};
+
+fn model() -> ValuesTypeModel {
+    let signals = schema_signals_for(parse_ir(TEMPLATE));
+    generate_values_type_model(
+        ValuesSchemaInput::new(&signals, &provider()).with_values_yaml(Some(VALUES_YAML)),
+    )
+}
+
+fn render(language: BindingLanguage) -> String {
+    render_type_bindings(
+        &model(),
+        &BindingOptions {
+            language,
+            ..BindingOptions::default()
+        },
+    )
+}
+
+#[test]
+fn guarded_reads_become_cases_of_their_sibling_discriminator() {
+    let model = model();
+
+    let mode = model.root.variants.as_ref().map(|variants| {
+        let cases: Vec<(&VariantTag, Vec<&String>)> = variants
+            .cases
+            .iter()
+            .map(|case| (&case.tag, case.fields.keys().collect()))
+            .collect();
+        (variants.discriminator.as_str(), cases)
+    });
+    sim_assert_eq!(
+        have: mode,
+        want: Some((
+            "mode",
+            vec![(&VariantTag::String("advanced".to_string()), vec![&"level".to_string()])],
+        ))
+    );
+    sim_assert_eq!(
+        have: model.root.fields.keys().collect::<Vec<_>>(),
+        want: vec!["image", "replicas", "tls"]
+    );
+
+    let Some(ValueType::Object(tls)) = model.root.fields.get("tls").map(|field| &field.ty) else {
+        panic!("tls should bind as an object");
+    };
+    let tls = tls.variants.as_ref().map(|variants| {
+        let tags: Vec<&VariantTag> = variants.cases.iter().map(|case| &case.tag).collect();
+        let fallback = variants
+            .fallback
+            .as_ref()
+            .map(|fallback| fallback.ty.clone());
+        (variants.discriminator.as_str(), tags, fallback)
+    });
+    sim_assert_eq!(
+        have: tls,
+        want: Some((
+            "enabled",
+            vec![&VariantTag::Bool(true)],
+            Some(ValueType::Literal(Value::Bool(false))),
+        ))
+    );
+}
+
+#[test]
+fn typescript_bindings_intersect_common_members_with_a_case_union() {
+    sim_assert_eq!(
+        have: render(BindingLanguage::TypeScript),
+        want: indoc! {r#"
+            // Code generated by helm-schema. DO NOT EDIT.
+
+            export type Values = {
+              image?: ValuesImage;
+              replicas: string | number;
+              tls?: ValuesTls;
+            } & (
+              | {
+                  level?: number;
+                  mode: "advanced";
+                }
+              | {
+                  mode?: string | null;
+                }
+            );
+
+            export interface ValuesImage {
+              repository?: string;
+              tag?: string;
+            }
+
+            export type ValuesTls = (
+              | {
+                  cert?: string;
+                  enabled: true;
+                }
+              | {
+                  enabled?: false;
+                }
+            );
+        "#}
+    );
+}
+
+#[test]
+fn go_bindings_document_case_fields_in_gofmt_aligned_columns() {
+    sim_assert_eq!(
+        have: render(BindingLanguage::Go),
+        want: indoc! {r#"
+            // Code generated by helm-schema. DO NOT EDIT.
+
+            package values
+
+            type Values struct {
+            	Image *ValuesImage `json:"image,omitempty" yaml:"image,omitempty"`
+            	// Only read when mode is "advanced".
+            	Level    *int64     `json:"level,omitempty" yaml:"level,omitempty"`
+            	Mode     *string    `json:"mode,omitempty" yaml:"mode,omitempty"`
+            	Replicas any        `json:"replicas" yaml:"replicas"`
+            	Tls      *ValuesTls `json:"tls,omitempty" yaml:"tls,omitempty"`
+            }
+
+            // Values of Values.Mode that select variant fields.
+            const (
+            	ValuesModeAdvanced = "advanced"
+            )
+
+            type ValuesImage struct {
+            	Repository *string `json:"repository,omitempty" yaml:"repository,omitempty"`
+            	Tag        *string `json:"tag,omitempty" yaml:"tag,omitempty"`
+            }
+
+            type ValuesTls struct {
+            	// Only read when enabled is true.
+            	Cert    *string `json:"cert,omitempty" yaml:"cert,omitempty"`
+            	Enabled *bool   `json:"enabled,omitempty" yaml:"enabled,omitempty"`
+            }
+        "#}
+    );
+}
+
+#[test]
+fn go_variant_constants_stay_unique_when_values_share_a_pascal_case_name() {
+    let signals = schema_signals_for(parse_ir(indoc! {r#"
+        apiVersion: v1
+        kind: ConfigMap
+        metadata:
+          name: demo
+        data:
+          {{- if eq .Values.mode "a-b" }}
+          dashed: {{ .Values.dashed | quote }}
+          {{- end }}
+          {{- if eq .Values.mode "a_b" }}
+          underscored: {{ .Values.underscored | quote }}
+          {{- end }}
+    "#}));
+    let model = generate_values_type_model(ValuesSchemaInput::new(&signals, &provider()));
+    let go = render_type_bindings(
+        &model,
+        &BindingOptions {
+            language: BindingLanguage::Go,
+            ..BindingOptions::default()
+        },
+    );
+
+    let constants = go
+        .lines()
+        .skip_while(|line| *line != "const (")
+        .skip(1)
+        .take_while(|line| *line != ")")
+        .collect::<Vec<_>>();
+    sim_assert_eq!(
+        have: constants,
+        want: vec!["\tValuesModeAB  = \"a-b\"", "\tValuesModeAB2 = \"a_b\""]
+    );
+}
+
+#[test]
+fn python_bindings_split_cases_into_typed_dicts() {
+    sim_assert_eq!(
+        have: render(BindingLanguage::Python),
+        want: indoc! {r#"
+            # Code generated by helm-schema. DO NOT EDIT.
+
+            from __future__ import annotations
+
+            from typing import Literal, Required, TypedDict, Union
+
+
+            class ValuesModeAdvanced(TypedDict, total=False):
+                image: ValuesImage
+                level: int
+                mode: Required[Literal["advanced"]]
+                replicas: Required[str | int]
+                tls: ValuesTls
+
+
+            class ValuesModeOther(TypedDict, total=False):
+                image: ValuesImage
+                mode: str | None
+                replicas: Required[str | int]
+                tls: ValuesTls
+
+
+            Values = Union[ValuesModeAdvanced, ValuesModeOther]
+
+
+            class ValuesImage(TypedDict, total=False):
+                repository: str
+                tag: str
+
+
+            class ValuesTlsEnabledEnabled(TypedDict, total=False):
+                cert: str
+                enabled: Required[Literal[True]]
+
+
+            class ValuesTlsEnabledOther(TypedDict, total=False):
+                enabled: Literal[False]
+
+
+            ValuesTls = Union[ValuesTlsEnabledEnabled, ValuesTlsEnabledOther]
+        "#}
+    );
+}
+
+#[test]
+fn cue_bindings_embed_case_disjunctions_in_closed_definitions() {
+    sim_assert_eq!(
+        have: render(BindingLanguage::Cue),
+        want: indoc! {r#"
+            // Code generated by helm-schema. DO NOT EDIT.
+
+            #Values: {
+            	image?: #ValuesImage
+            	replicas!: string | int
+            	tls?: #ValuesTls
+            	{
+            		level?: int
+            		mode!: "advanced"
+            	} | {
+            		mode?: (string & !="advanced") | null
+            	}
+            }
+
+            #ValuesImage: {
+            	repository?: string
+            	tag?: string
+            }
+
+            #ValuesTls: {
+            	{
+            		cert?: string
+            		enabled!: true
+            	} | {
+            		enabled?: false
+            	}
+            }
+        "#}
+    );
+}
+
+#[test]
+fn kcl_bindings_alias_the_union_of_case_schemas() {
+    sim_assert_eq!(
+        have: render(BindingLanguage::Kcl),
+        want: indoc! {r#"
+            # Code generated by helm-schema. DO NOT EDIT.
+
+            schema ValuesModeAdvanced:
+                image?: ValuesImage
+                level?: int
+                mode: "advanced"
+                replicas: str | int
+                tls?: ValuesTls
+
+            schema ValuesModeOther:
+                image?: ValuesImage
+                mode?: str
+                replicas: str | int
+                tls?: ValuesTls
+
+            type Values = ValuesModeAdvanced | ValuesModeOther
+
+            schema ValuesImage:
+                repository?: str
+                tag?: str
+
+            schema ValuesTlsEnabledEnabled:
+                cert?: str
+                enabled: True
+
+            schema ValuesTlsEnabledOther:
+                enabled?: False
+
+            type ValuesTls = ValuesTlsEnabledEnabled | ValuesTlsEnabledOther
+        "#}
+    );
+}
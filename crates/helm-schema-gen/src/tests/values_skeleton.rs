@@ -0,0 +1,71 @@
+use test_util::prelude::sim_assert_eq;
+
+use super::*;
+use crate::render_values_skeleton;
+
+#[test]
+fn skeleton_keeps_defaults_and_comments_out_undeclared_paths() -> Result<(), serde_yaml::Error> {
+    let defaults: serde_yaml::Value = serde_yaml::from_str(indoc! {"
+        image:
+          repository: nginx
+          tag: latest
+        ports:
+          - 80
+        annotations: {}
+    "})?;
+    let schema = serde_json::json!({
+        "type": "object",
+        "$defs": {
+            "resources": {
+                "type": "object",
+                "properties": { "limits": { "type": "object" } }
+            }
+        },
+        "properties": {
+            "image": {
+                "type": "object",
+                "properties": {
+                    "repository": { "type": "string", "description": "Image repository." },
+                    "tag": { "type": "string" },
+                    "digest": { "type": "string", "description": "Pinned digest." }
+                }
+            },
+            "ports": { "type": "array" },
+            "annotations": { "type": "object" },
+            "ingress": {
+                "type": "object",
+                "description": "Ingress settings.",
+                "properties": {
+                    "enabled": { "type": "boolean" },
+                    "host": { "type": ["string", "null"] }
+                }
+            },
+            "resources": { "$ref": "#/$defs/resources" }
+        }
+    });
+
+    let skeleton = render_values_skeleton(Some(&defaults), &schema)?;
+
+    sim_assert_eq!(
+        have: skeleton,
+        want: indoc! {r#"
+            image:
+              # Image repository.
+              repository: nginx
+              tag: latest
+              # Pinned digest.
+              # digest: ""
+            ports:
+              - 80
+            annotations: {}
+            # Ingress settings.
+            # ingress:
+            #   enabled: false
+            #   host: ""
+            # resources: {}
+        "#}
+    );
+    let reparsed: serde_yaml::Value = serde_yaml::from_str(&skeleton)?;
+    sim_assert_eq!(have: reparsed, want: defaults);
+    Ok(())
+}
@@ -0,0 +1,148 @@
+use std::collections::BTreeMap;
+use std::fmt::Write as _;
+
+use super::{
+    FieldType, GENERATED_NOTICE, ITEM_SEGMENT, MAP_VALUE_SEGMENT, ObjectType, TypeNames, ValueType,
+    VariantTag, case_members, child_path,
+};
+
+/// CUE keywords, which must be quoted to label a field.
+const KEYWORDS: [&str; 13] = [
+    "div", "false", "for", "if", "import", "in", "let", "mod", "null", "package", "quo", "rem",
+    "true",
+];
+
+/// Renders one closed definition per named object. Variants embed a
+/// disjunction of their cases, so a case's keys are only allowed while the
+/// discriminator holds that case's tag.
+pub(super) fn render(names: &TypeNames<'_>) -> String {
+    let mut out = format!("// {GENERATED_NOTICE}\n");
+    for (path, name, object) in &names.objects {
+        out.push('\n');
+        render_definition(&mut out, names, path, name, object);
+    }
+    out
+}
+
+fn render_definition(
+    out: &mut String,
+    names: &TypeNames<'_>,
+    path: &[String],
+    name: &str,
+    object: &ObjectType,
+) {
+    let _ = writeln!(out, "#{name}: {{");
+    render_members(out, names, path, &object.fields, &[], 1);
+    if let Some(variants) = &object.variants {
+        // The fallback case excludes the string tags of the other cases, so
+        // each value selects exactly one disjunct.
+        let tags: Vec<&str> = variants
+            .cases
+            .iter()
+            .filter_map(|case| match &case.tag {
+                VariantTag::String(tag) => Some(tag.as_str()),
+                VariantTag::Bool(_) => None,
+            })
+            .collect();
+        for (index, members) in case_members(variants).iter().enumerate() {
+            out.push_str(if index == 0 { "\t{\n" } else { "\t} | {\n" });
+            let excluded = if index < variants.cases.len() {
+                &[][..]
+            } else {
+                tags.as_slice()
+            };
+            render_members(out, names, path, members, excluded, 2);
+        }
+        out.push_str("\t}\n");
+    }
+    if object.additional.is_some() {
+        out.push_str("\t...\n");
+    }
+    out.push_str("}\n");
+}
+
+fn render_members(
+    out: &mut String,
+    names: &TypeNames<'_>,
+    path: &[String],
+    fields: &BTreeMap<String, FieldType>,
+    excluded: &[&str],
+    depth: usize,
+) {
+    let indent = "\t".repeat(depth);
+    for (key, field) in fields {
+        for line in field
+            .description
+            .iter()
+            .flat_map(|description| description.lines())
+        {
+            let _ = writeln!(out, "{indent}// {line}");
+        }
+        let marker = if field.required { "!" } else { "?" };
+        let ty = type_expression(names, &child_path(path, key), &field.ty, excluded);
+        let _ = writeln!(out, "{indent}{}{marker}: {ty}", label(key));
+    }
+}
+
+/// CUE expression of `ty`; `excluded` string literals are removed from a
+/// top-level `string`.
+fn type_expression(
+    names: &TypeNames<'_>,
+    path: &[String],
+    ty: &ValueType,
+    excluded: &[&str],
+) -> String {
+    match ty {
+        ValueType::Any => "_".to_string(),
+        ValueType::Null => "null".to_string(),
+        ValueType::Boolean => "bool".to_string(),
+        ValueType::Integer => "int".to_string(),
+        ValueType::Number => "number".to_string(),
+        ValueType::String if !excluded.is_empty() => {
+            let exclusions: Vec<String> = excluded
+                .iter()
+                .map(|tag| format!("!={}", serde_json::Value::String((*tag).to_string())))
+                .collect();
+            format!("(string & {})", exclusions.join(" & "))
+        }
+        ValueType::String => "string".to_string(),
+        ValueType::Literal(value) => value.to_string(),
+        ValueType::Array(items) => {
+            let items = type_expression(names, &child_path(path, ITEM_SEGMENT), items, &[]);
+            if items.contains(" | ") {
+                format!("[...({items})]")
+            } else {
+                format!("[...{items}]")
+            }
+        }
+        ValueType::Map(values) => format!(
+            "{{[string]: {}}}",
+            type_expression(names, &child_path(path, MAP_VALUE_SEGMENT), values, &[])
+        ),
+        ValueType::Object(_) => format!("#{}", names.name(path)),
+        ValueType::Union(members) => members
+            .iter()
+            .map(|member| type_expression(names, path, member, excluded))
+            .collect::<Vec<_>>()
+            .join(" | "),
+    }
+}
+
+/// Field label of `key`: bare when it is a regular identifier, otherwise a
+/// quoted string. Leading `_` and `#` would make a hidden field or a
+/// definition, so those keys are quoted too.
+fn label(key: &str) -> String {
+    let mut characters = key.chars();
+    let identifier = characters
+        .next()
+        .is_some_and(|first| first.is_ascii_alphabetic() || first == '$')
+        && characters.all(|character| {
+            character.is_ascii_alphanumeric() || character == '_' || character == '$'
+        })
+        && !KEYWORDS.contains(&key);
+    if identifier {
+        key.to_string()
+    } else {
+        serde_json::Value::String(key.to_string()).to_string()
+    }
+}
@@ -0,0 +1,223 @@
+use std::collections::BTreeSet;
+use std::fmt::Write as _;
+
+use serde_json::Value;
+
+use super::{
+    FieldType, GENERATED_NOTICE, ITEM_SEGMENT, MAP_VALUE_SEGMENT, ObjectType, TypeNames, ValueType,
+    VariantTag, child_path, pascal_case, unique_name,
+};
+
+/// Renders one struct per named object. Go has no sum types, so the keys
+/// of every variant case join the struct, documented with the
+/// discriminator value that selects them, and string discriminators gain
+/// one constant per case.
+pub(super) fn render(names: &TypeNames<'_>, package: &str) -> String {
+    let mut out = format!("// {GENERATED_NOTICE}\n\npackage {package}\n");
+    for (path, name, object) in &names.objects {
+        out.push('\n');
+        render_struct(&mut out, names, path, name, object);
+    }
+    out
+}
+
+fn render_struct(
+    out: &mut String,
+    names: &TypeNames<'_>,
+    path: &[String],
+    name: &str,
+    object: &ObjectType,
+) {
+    let mut members: Vec<(&String, FieldType, Option<String>)> = object
+        .fields
+        .iter()
+        .map(|(key, field)| (key, field.clone(), None))
+        .collect();
+    if let Some(variants) = &object.variants {
+        let tys = variants
+            .cases
+            .iter()
+            .map(|case| ValueType::Literal(case.tag.to_value()))
+            .chain(variants.fallback.iter().map(|fallback| fallback.ty.clone()))
+            .collect();
+        members.push((
+            &variants.discriminator,
+            FieldType {
+                ty: super::model::union(tys),
+                required: false,
+                description: variants.description.clone(),
+            },
+            None,
+        ));
+        for case in &variants.cases {
+            let selector = format!(
+                "Only read when {} is {}.",
+                variants.discriminator,
+                case.tag.to_value()
+            );
+            for (key, field) in &case.fields {
+                if !members.iter().any(|(member, ..)| *member == key) {
+                    members.push((key, field.clone(), Some(selector.clone())));
+                }
+            }
+        }
+        members.sort_by_key(|(key, ..)| *key);
+    }
+
+    let _ = writeln!(out, "type {name} struct {{");
+    let mut lines = Vec::new();
+    let mut used = BTreeSet::new();
+    for (key, field, selector) in &members {
+        let identifier = unique_name(&pascal_case(key), &mut used);
+        let comments: Vec<&str> = field
+            .description
+            .iter()
+            .flat_map(|description| description.lines())
+            .chain(selector.as_deref())
+            .collect();
+        lines.extend(
+            comments
+                .into_iter()
+                .map(|line| Line::Comment(line.to_string())),
+        );
+        let ty = type_expression(names, &child_path(path, key), &field.ty);
+        let (ty, tag) = if field.required {
+            (ty, (*key).clone())
+        } else {
+            (optional(ty), format!("{key},omitempty"))
+        };
+        lines.push(Line::Cells(vec![
+            identifier,
+            ty,
+            format!("`json:{tag:?} yaml:{tag:?}`"),
+        ]));
+    }
+    write_aligned(out, &lines);
+    out.push_str("}\n");
+
+    if let Some(variants) = &object.variants {
+        let constants: Vec<&String> = variants
+            .cases
+            .iter()
+            .filter_map(|case| match &case.tag {
+                VariantTag::String(value) => Some(value),
+                VariantTag::Bool(_) => None,
+            })
+            .collect();
+        if !constants.is_empty() {
+            let prefix = format!("{name}{}", pascal_case(&variants.discriminator));
+            let _ = writeln!(
+                out,
+                "\n// Values of {name}.{} that select variant fields.\nconst (",
+                pascal_case(&variants.discriminator)
+            );
+            let mut used = BTreeSet::new();
+            let lines: Vec<Line> = constants
+                .into_iter()
+                .map(|value| {
+                    Line::Cells(vec![
+                        unique_name(&format!("{prefix}{}", pascal_case(value)), &mut used),
+                        format!("= {}", Value::String(value.clone())),
+                    ])
+                })
+                .collect();
+            write_aligned(out, &lines);
+            out.push_str(")\n");
+        }
+    }
+}
+
+/// One indented line of a struct or `const` block.
+enum Line {
+    /// A full-line `//` comment.
+    Comment(String),
+    /// Space-separated cells, the last one unpadded.
+    Cells(Vec<String>),
+}
+
+/// Writes `lines` one tab deep with their cells aligned the way gofmt
+/// aligns them: every run of consecutive cell lines pads each column to its
+/// widest cell plus one space, and a comment line ends the run.
+fn write_aligned(out: &mut String, lines: &[Line]) {
+    for run in
+        lines.chunk_by(|left, right| matches!((left, right), (Line::Cells(_), Line::Cells(_))))
+    {
+        let mut widths: Vec<usize> = Vec::new();
+        for line in run {
+            if let Line::Cells(cells) = line {
+                for (column, cell) in cells.iter().enumerate() {
+                    let width = cell.chars().count();
+                    match widths.get_mut(column) {
+                        Some(widest) => *widest = (*widest).max(width),
+                        None => widths.push(width),
+                    }
+                }
+            }
+        }
+        for line in run {
+            match line {
+                Line::Comment(text) => {
+                    let _ = writeln!(out, "\t// {text}");
+                }
+                Line::Cells(cells) => {
+                    out.push('\t');
+                    if let Some((last, padded)) = cells.split_last() {
+                        for (cell, width) in padded.iter().zip(&widths) {
+                            let _ = write!(out, "{cell:<width$} ", width = *width);
+                        }
+                        out.push_str(last);
+                    }
+                    out.push('\n');
+                }
+            }
+        }
+    }
+}
+
+/// Pointer to scalars and structs, so an unset key stays distinguishable
+/// from its zero value; slices, maps, and `any` are already nilable.
+fn optional(ty: String) -> String {
+    if ty.starts_with("[]") || ty.starts_with("map[") || ty == "any" {
+        ty
+    } else {
+        format!("*{ty}")
+    }
+}
+
+fn type_expression(names: &TypeNames<'_>, path: &[String], ty: &ValueType) -> String {
+    match ty {
+        ValueType::Boolean | ValueType::Literal(Value::Bool(_)) => "bool".to_string(),
+        ValueType::Integer => "int64".to_string(),
+        ValueType::Literal(Value::Number(number)) if number.is_i64() || number.is_u64() => {
+            "int64".to_string()
+        }
+        ValueType::Number | ValueType::Literal(Value::Number(_)) => "float64".to_string(),
+        ValueType::String | ValueType::Literal(Value::String(_)) => "string".to_string(),
+        ValueType::Any | ValueType::Null | ValueType::Literal(_) => "any".to_string(),
+        ValueType::Array(items) => format!(
+            "[]{}",
+            type_expression(names, &child_path(path, ITEM_SEGMENT), items)
+        ),
+        ValueType::Map(values) => format!(
+            "map[string]{}",
+            type_expression(names, &child_path(path, MAP_VALUE_SEGMENT), values)
+        ),
+        ValueType::Object(_) => names.name(path).to_string(),
+        // A union binds as its one Go type once `null` is dropped, since
+        // an optional field already admits nil.
+        ValueType::Union(members) => {
+            let tys: BTreeSet<String> = members
+                .iter()
+                .filter(|member| !matches!(member, ValueType::Null))
+                .map(|member| type_expression(names, path, member))
+                .collect();
+            match tys.len() {
+                1 => tys.into_iter().next().unwrap_or_else(|| "any".to_string()),
+                _ if tys == BTreeSet::from(["float64".to_string(), "int64".to_string()]) => {
+                    "float64".to_string()
+                }
+                _ => "any".to_string(),
+            }
+        }
+    }
+}
@@ -0,0 +1,156 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::Write as _;
+
+use serde_json::Value;
+
+use super::{
+    FieldType, GENERATED_NOTICE, ITEM_SEGMENT, MAP_VALUE_SEGMENT, TypeNames, ValueType,
+    case_members, child_path,
+};
+
+/// KCL keywords, which must be quoted to name a schema attribute.
+const KEYWORDS: [&str; 27] = [
+    "False",
+    "None",
+    "True",
+    "Undefined",
+    "all",
+    "and",
+    "any",
+    "as",
+    "assert",
+    "check",
+    "elif",
+    "else",
+    "filter",
+    "for",
+    "if",
+    "import",
+    "in",
+    "is",
+    "lambda",
+    "map",
+    "mixin",
+    "not",
+    "or",
+    "protocol",
+    "rule",
+    "schema",
+    "type",
+];
+
+/// Renders one schema per named object. An object with variants becomes
+/// one schema per case, each repeating the common attributes, and a `type`
+/// alias for their union under the object's name.
+pub(super) fn render(names: &TypeNames<'_>) -> String {
+    let mut out = format!("# {GENERATED_NOTICE}\n");
+    for (path, name, object) in &names.objects {
+        let Some(variants) = &object.variants else {
+            render_schema(
+                &mut out,
+                names,
+                path,
+                name,
+                &object.fields,
+                object.additional.is_some(),
+            );
+            continue;
+        };
+        let case_names = names.case_names(path);
+        for (case_name, members) in case_names.iter().zip(case_members(variants)) {
+            let mut fields = object.fields.clone();
+            fields.extend(members);
+            render_schema(
+                &mut out,
+                names,
+                path,
+                case_name,
+                &fields,
+                object.additional.is_some(),
+            );
+        }
+        let _ = writeln!(out, "\ntype {name} = {}", case_names.join(" | "));
+    }
+    out
+}
+
+fn render_schema(
+    out: &mut String,
+    names: &TypeNames<'_>,
+    path: &[String],
+    name: &str,
+    fields: &BTreeMap<String, FieldType>,
+    open: bool,
+) {
+    let _ = writeln!(out, "\nschema {name}:");
+    for (key, field) in fields {
+        for line in field
+            .description
+            .iter()
+            .flat_map(|description| description.lines())
+        {
+            let _ = writeln!(out, "    # {line}");
+        }
+        let optional = if field.required { "" } else { "?" };
+        let ty = type_expression(names, &child_path(path, key), &field.ty);
+        let _ = writeln!(out, "    {}{optional}: {ty}", attribute(key));
+    }
+    // An index signature keeps undeclared keys valid and gives an otherwise
+    // empty schema a body.
+    if open || fields.is_empty() {
+        out.push_str("    [...str]: any\n");
+    }
+}
+
+fn type_expression(names: &TypeNames<'_>, path: &[String], ty: &ValueType) -> String {
+    match ty {
+        ValueType::Any | ValueType::Null => "any".to_string(),
+        ValueType::Boolean => "bool".to_string(),
+        ValueType::Integer => "int".to_string(),
+        ValueType::Number => "float".to_string(),
+        ValueType::String => "str".to_string(),
+        ValueType::Literal(Value::Bool(value)) => if *value { "True" } else { "False" }.to_string(),
+        ValueType::Literal(value) => value.to_string(),
+        ValueType::Array(items) => format!(
+            "[{}]",
+            type_expression(names, &child_path(path, ITEM_SEGMENT), items)
+        ),
+        ValueType::Map(values) => format!(
+            "{{str:{}}}",
+            type_expression(names, &child_path(path, MAP_VALUE_SEGMENT), values)
+        ),
+        ValueType::Object(_) => names.name(path).to_string(),
+        // KCL attributes admit `None` through `?`, so `null` members drop
+        // out of a union.
+        ValueType::Union(members) => {
+            let mut seen = BTreeSet::new();
+            let members: Vec<String> = members
+                .iter()
+                .filter(|member| !matches!(member, ValueType::Null))
+                .map(|member| type_expression(names, path, member))
+                .filter(|member| seen.insert(member.clone()))
+                .collect();
+            if members.is_empty() {
+                "any".to_string()
+            } else {
+                members.join(" | ")
+            }
+        }
+    }
+}
+
+/// Attribute name of `key`: bare when it is a public identifier, otherwise a
+/// quoted string, since a leading `_` would make the attribute private.
+fn attribute(key: &str) -> String {
+    let mut characters = key.chars();
+    let identifier = characters
+        .next()
+        .is_some_and(|first| first.is_ascii_alphabetic())
+        && characters.all(|character| character.is_ascii_alphanumeric() || character == '_')
+        && !KEYWORDS.contains(&key);
+    if identifier {
+        key.to_string()
+    } else {
+        Value::String(key.to_string()).to_string()
+    }
+}
@@ -0,0 +1,382 @@
+//! Typed language bindings (TypeScript, Go, Python, CUE, KCL) for a
+//! chart's values contract.
+//!
+//! The binding model is read from the projected values tree — the same
+//! document the JSON Schema is completed from — before conditional arms are
+//! lowered into `if`/`then` JSON. Fields that templates read only while a
+//! sibling boolean or string switch holds a literal become the cases of a
+//! discriminated union on that switch.
+
+mod cue;
+mod go;
+mod kcl;
+mod model;
+mod python;
+mod typescript;
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use serde_json::Value;
+
+use crate::ValuesSchemaInput;
+use crate::emission_plan::LoweredEmissionPlan;
+
+/// Language-neutral shape of a chart's values.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ValuesTypeModel {
+    /// The values root object.
+    pub root: ObjectType,
+}
+
+/// Type of one value.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ValueType {
+    /// Any YAML value.
+    Any,
+    /// `null`, which Helm reads as a deleted key.
+    Null,
+    /// `true` or `false`.
+    Boolean,
+    /// Whole number.
+    Integer,
+    /// Any number.
+    Number,
+    /// Text.
+    String,
+    /// One literal value.
+    Literal(Value),
+    /// List of items of the inner type.
+    Array(Box<ValueType>),
+    /// Mapping from arbitrary keys to the inner type.
+    Map(Box<ValueType>),
+    /// Mapping with known keys.
+    Object(ObjectType),
+    /// Any one of the listed types.
+    Union(Vec<ValueType>),
+}
+
+/// Mapping with known keys.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ObjectType {
+    /// Keys present in every variant.
+    pub fields: BTreeMap<String, FieldType>,
+    /// Type of keys beyond `fields`, when the schema constrains them.
+    pub additional: Option<Box<ValueType>>,
+    /// Keys that depend on a discriminator key of this object.
+    pub variants: Option<VariantSet>,
+}
+
+/// One key of an object.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FieldType {
+    /// Type of the value.
+    pub ty: ValueType,
+    /// Whether the key must be present.
+    pub required: bool,
+    /// Schema or values-file description.
+    pub description: Option<String>,
+}
+
+/// Discriminated union over one key of an object.
+#[derive(Debug, Clone, PartialEq)]
+pub struct VariantSet {
+    /// Key whose literal value selects the case.
+    pub discriminator: String,
+    /// Description of the discriminator key.
+    pub description: Option<String>,
+    /// Cases with the keys templates read under each discriminator value.
+    pub cases: Vec<VariantCase>,
+    /// Discriminator type of the remaining case, in which no case-specific
+    /// key is read; `None` when the cases cover every accepted value.
+    pub fallback: Option<Box<FieldType>>,
+}
+
+/// One case of a [`VariantSet`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct VariantCase {
+    /// Discriminator value selecting the case.
+    pub tag: VariantTag,
+    /// Keys read only under this case.
+    pub fields: BTreeMap<String, FieldType>,
+}
+
+/// Literal discriminator value.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub enum VariantTag {
+    /// A boolean switch, tested by truthiness or compared with a literal.
+    Bool(bool),
+    /// A string switch compared with `eq`.
+    String(String),
+}
+
+impl VariantTag {
+    /// The tag as a JSON literal.
+    #[must_use]
+    pub fn to_value(&self) -> Value {
+        match self {
+            Self::Bool(value) => Value::Bool(*value),
+            Self::String(value) => Value::String(value.clone()),
+        }
+    }
+}
+
+/// Target language of [`render_type_bindings`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum BindingLanguage {
+    /// TypeScript type aliases and interfaces.
+    #[default]
+    TypeScript,
+    /// Go structs with `json` and `yaml` tags.
+    Go,
+    /// Python `TypedDict`s.
+    Python,
+    /// CUE definitions, with variants as embedded disjunctions.
+    Cue,
+    /// KCL schemas, with variants as union type aliases.
+    Kcl,
+}
+
+/// Options of [`render_type_bindings`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BindingOptions {
+    /// Target language.
+    pub language: BindingLanguage,
+    /// Name of the root type; nested types are prefixed with it.
+    pub root_name: String,
+    /// Go package clause.
+    pub go_package: String,
+}
+
+impl Default for BindingOptions {
+    fn default() -> Self {
+        Self {
+            language: BindingLanguage::default(),
+            root_name: "Values".to_string(),
+            go_package: "values".to_string(),
+        }
+    }
+}
+
+/// Builds the binding model of a chart's values from the same inputs as
+/// [`generate_values_schema`](crate::generate_values_schema).
+#[tracing::instrument(skip_all)]
+#[expect(
+    clippy::large_types_passed_by_value,
+    reason = "the input is a Copy bundle of borrows built by chained `with_*` calls, and generation runs once per chart"
+)]
+pub fn generate_values_type_model(input: ValuesSchemaInput<'_>) -> ValuesTypeModel {
+    let plan = LoweredEmissionPlan::build(&input);
+    let (tree, definitions) = plan.values_tree(plan.project(input.emission_policy));
+    let defaults = serde_json::to_value(plan.input_defaults()).unwrap_or(Value::Null);
+    model::TypeModelBuilder {
+        definitions: &definitions,
+        defaults: &defaults,
+        descriptions: plan.values_descriptions(),
+        signals: plan.contract_schema_signals(),
+    }
+    .build(&tree)
+}
+
+/// Renders `model` as source code in the selected language.
+#[must_use]
+pub fn render_type_bindings(model: &ValuesTypeModel, options: &BindingOptions) -> String {
+    let names = TypeNames::assign(model, &options.root_name);
+    match options.language {
+        BindingLanguage::TypeScript => typescript::render(&names),
+        BindingLanguage::Go => go::render(&names, &options.go_package),
+        BindingLanguage::Python => python::render(&names),
+        BindingLanguage::Cue => cue::render(&names),
+        BindingLanguage::Kcl => kcl::render(&names),
+    }
+}
+
+/// Header line marking rendered bindings as generated.
+const GENERATED_NOTICE: &str = "Code generated by helm-schema. DO NOT EDIT.";
+
+/// Path segment of array items in [`TypeNames`] keys.
+const ITEM_SEGMENT: &str = "[]";
+/// Path segment of map values in [`TypeNames`] keys.
+const MAP_VALUE_SEGMENT: &str = "{}";
+
+/// Unique type names of every named object, keyed by its path from the
+/// root, in definition order.
+struct TypeNames<'a> {
+    objects: Vec<(Vec<String>, String, &'a ObjectType)>,
+    by_path: BTreeMap<Vec<String>, String>,
+    /// Case type names of objects with variants: one per case, then the
+    /// fallback's.
+    case_names: BTreeMap<Vec<String>, Vec<String>>,
+}
+
+impl<'a> TypeNames<'a> {
+    fn assign(model: &'a ValuesTypeModel, root_name: &str) -> Self {
+        let mut names = Self {
+            objects: Vec::new(),
+            by_path: BTreeMap::new(),
+            case_names: BTreeMap::new(),
+        };
+        let mut used = BTreeSet::new();
+        names.visit_object(&model.root, Vec::new(), root_name, &mut used);
+        names
+    }
+
+    fn visit_object(
+        &mut self,
+        object: &'a ObjectType,
+        path: Vec<String>,
+        root_name: &str,
+        used: &mut BTreeSet<String>,
+    ) {
+        let base = path
+            .iter()
+            .fold(root_name.to_string(), |mut name, segment| {
+                name.push_str(&match segment.as_str() {
+                    ITEM_SEGMENT => "Item".to_string(),
+                    MAP_VALUE_SEGMENT => "Value".to_string(),
+                    segment => pascal_case(segment),
+                });
+                name
+            });
+        let name = unique_name(&base, used);
+        if let Some(variants) = &object.variants {
+            let tags = variants
+                .cases
+                .iter()
+                .map(|case| Some(&case.tag))
+                .chain(variants.fallback.iter().map(|_| None));
+            let case_names = tags
+                .map(|tag| unique_name(&case_name(&name, variants, tag), used))
+                .collect();
+            self.case_names.insert(path.clone(), case_names);
+        }
+        self.by_path.insert(path.clone(), name.clone());
+        self.objects.push((path.clone(), name, object));
+
+        let variant_fields = object
+            .variants
+            .iter()
+            .flat_map(|variants| variants.cases.iter())
+            .flat_map(|case| case.fields.iter());
+        for (key, field) in object.fields.iter().chain(variant_fields) {
+            let mut child = path.clone();
+            child.push(key.clone());
+            self.visit_type(&field.ty, child, root_name, used);
+        }
+        if let Some(additional) = &object.additional {
+            let mut child = path;
+            child.push(MAP_VALUE_SEGMENT.to_string());
+            self.visit_type(additional, child, root_name, used);
+        }
+    }
+
+    fn visit_type(
+        &mut self,
+        ty: &'a ValueType,
+        path: Vec<String>,
+        root_name: &str,
+        used: &mut BTreeSet<String>,
+    ) {
+        match ty {
+            ValueType::Object(object) => {
+                if !self.by_path.contains_key(&path) {
+                    self.visit_object(object, path, root_name, used);
+                }
+            }
+            ValueType::Array(items) => {
+                let mut child = path;
+                child.push(ITEM_SEGMENT.to_string());
+                self.visit_type(items, child, root_name, used);
+            }
+            ValueType::Map(values) => {
+                let mut child = path;
+                child.push(MAP_VALUE_SEGMENT.to_string());
+                self.visit_type(values, child, root_name, used);
+            }
+            ValueType::Union(members) => {
+                for member in members {
+                    self.visit_type(member, path.clone(), root_name, used);
+                }
+            }
+            ValueType::Any
+            | ValueType::Null
+            | ValueType::Boolean
+            | ValueType::Integer
+            | ValueType::Number
+            | ValueType::String
+            | ValueType::Literal(_) => {}
+        }
+    }
+
+    fn name(&self, path: &[String]) -> &str {
+        self.by_path.get(path).map_or("", String::as_str)
+    }
+
+    fn case_names(&self, path: &[String]) -> &[String] {
+        self.case_names.get(path).map_or(&[], Vec::as_slice)
+    }
+}
+
+fn unique_name(base: &str, used: &mut BTreeSet<String>) -> String {
+    let mut name = base.to_string();
+    let mut suffix = 2;
+    while !used.insert(name.clone()) {
+        name = format!("{base}{suffix}");
+        suffix += 1;
+    }
+    name
+}
+
+/// `PascalCase` identifier of a values key or literal: alphanumeric runs
+/// are capitalized and joined, and a leading digit gains an `X` prefix.
+fn pascal_case(text: &str) -> String {
+    let mut out = String::new();
+    for word in text.split(|character: char| !character.is_ascii_alphanumeric()) {
+        let mut characters = word.chars();
+        if let Some(first) = characters.next() {
+            out.push(first.to_ascii_uppercase());
+            out.extend(characters);
+        }
+    }
+    if out.is_empty() || out.starts_with(|character: char| character.is_ascii_digit()) {
+        out.insert(0, 'X');
+    }
+    out
+}
+
+/// Name of a case type: the owning type, discriminator, and tag.
+fn case_name(owner: &str, variants: &VariantSet, tag: Option<&VariantTag>) -> String {
+    let tag = match tag {
+        Some(VariantTag::Bool(true)) => "Enabled".to_string(),
+        Some(VariantTag::Bool(false)) => "Disabled".to_string(),
+        Some(VariantTag::String(value)) => pascal_case(value),
+        None => "Other".to_string(),
+    };
+    format!("{owner}{}{tag}", pascal_case(&variants.discriminator))
+}
+
+/// Keys of each case of `variants`: the discriminator pinned to the case's
+/// tag with the keys read under it, then the fallback's discriminator alone.
+fn case_members(variants: &VariantSet) -> Vec<BTreeMap<String, FieldType>> {
+    let cases = variants.cases.iter().map(|case| {
+        let discriminator = FieldType {
+            ty: ValueType::Literal(case.tag.to_value()),
+            required: true,
+            description: variants.description.clone(),
+        };
+        let mut members = BTreeMap::from([(variants.discriminator.clone(), discriminator)]);
+        members.extend(case.fields.clone());
+        members
+    });
+    let fallback = variants
+        .fallback
+        .iter()
+        .map(|fallback| BTreeMap::from([(variants.discriminator.clone(), (**fallback).clone())]));
+    cases.chain(fallback).collect()
+}
+
+/// Child path of `key` under the object at `path`.
+fn child_path(path: &[String], key: &str) -> Vec<String> {
+    let mut child = path.to_vec();
+    child.push(key.to_string());
+    child
+}
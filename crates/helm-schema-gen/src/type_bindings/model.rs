@@ -0,0 +1,617 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use helm_schema_core::{
+    ConditionalGuard, ContractSchemaSignals, GuardValue, append_value_path, split_value_path,
+};
+use serde_json::{Map, Value};
+
+use super::{
+    FieldType, ObjectType, ValueType, ValuesTypeModel, VariantCase, VariantSet, VariantTag,
+};
+
+/// Maximum `$ref` hops followed while typing one schema node.
+const MAX_REF_DEPTH: usize = 16;
+
+/// Values-path segment of array items and map values.
+const MEMBER_SEGMENT: &str = "*";
+
+pub(super) struct TypeModelBuilder<'a> {
+    pub(super) definitions: &'a BTreeMap<String, Value>,
+    /// Composed chart defaults as JSON.
+    pub(super) defaults: &'a Value,
+    pub(super) descriptions: &'a BTreeMap<String, String>,
+    pub(super) signals: &'a ContractSchemaSignals,
+}
+
+impl TypeModelBuilder<'_> {
+    pub(super) fn build(&self, tree: &Value) -> ValuesTypeModel {
+        let mut root = match self.value_type(tree, "", 0) {
+            ValueType::Object(object) => object,
+            _ => ObjectType::default(),
+        };
+        self.split_object_variants(&mut root, "");
+        ValuesTypeModel { root }
+    }
+
+    /// Type of `schema` at `path`; an untyped schema takes the type of the
+    /// chart default, so `enabled: false` binds as a boolean even where
+    /// templates only test its truthiness.
+    fn value_type(&self, schema: &Value, path: &str, depth: usize) -> ValueType {
+        match self.schema_type(schema, path, depth) {
+            ValueType::Any => self.default_type(path),
+            ty => ty,
+        }
+    }
+
+    fn schema_type(&self, schema: &Value, path: &str, depth: usize) -> ValueType {
+        let Value::Object(object) = schema else {
+            return ValueType::Any;
+        };
+        if depth > MAX_REF_DEPTH {
+            return ValueType::Any;
+        }
+        if let Some(reference) = object.get("$ref").and_then(Value::as_str) {
+            return reference
+                .strip_prefix("#/$defs/")
+                .and_then(|name| self.definitions.get(name))
+                .map_or(ValueType::Any, |definition| {
+                    self.schema_type(definition, path, depth + 1)
+                });
+        }
+        if let Some(literal) = object.get("const") {
+            return literal_type(literal);
+        }
+        if let Some(Value::Array(literals)) = object.get("enum") {
+            return union(literals.iter().map(literal_type).collect());
+        }
+
+        let mut ty = match object.get("type") {
+            Some(Value::String(name)) => self.named_type(name, object, path, depth),
+            Some(Value::Array(names)) => union(
+                names
+                    .iter()
+                    .filter_map(Value::as_str)
+                    .map(|name| self.named_type(name, object, path, depth))
+                    .collect(),
+            ),
+            _ if object.contains_key("properties")
+                || object
+                    .get("additionalProperties")
+                    .is_some_and(|additional| !is_trivial(additional)) =>
+            {
+                self.object_type(object, path, depth)
+            }
+            _ if object.contains_key("items") => self.array_type(object, path, depth),
+            _ => ValueType::Any,
+        };
+        for keyword in ["anyOf", "oneOf"] {
+            if let Some(Value::Array(branches)) = object.get(keyword) {
+                let branches = branches
+                    .iter()
+                    .map(|branch| self.schema_type(branch, path, depth + 1))
+                    .collect();
+                ty = intersect(ty, union(branches));
+            }
+        }
+        // Conditional arms narrow only while their guard holds; the
+        // unconditional ones type every value.
+        if let Some(Value::Array(arms)) = object.get("allOf") {
+            for arm in arms.iter().filter(|arm| arm.get("if").is_none()) {
+                ty = intersect(ty, self.schema_type(arm, path, depth + 1));
+            }
+        }
+        ty
+    }
+
+    fn named_type(
+        &self,
+        name: &str,
+        object: &Map<String, Value>,
+        path: &str,
+        depth: usize,
+    ) -> ValueType {
+        match name {
+            "null" => ValueType::Null,
+            "boolean" => ValueType::Boolean,
+            "integer" => ValueType::Integer,
+            "number" => ValueType::Number,
+            "string" => ValueType::String,
+            "array" => self.array_type(object, path, depth),
+            "object" => self.object_type(object, path, depth),
+            _ => ValueType::Any,
+        }
+    }
+
+    fn array_type(&self, object: &Map<String, Value>, path: &str, depth: usize) -> ValueType {
+        let items_path = append_value_path(path, MEMBER_SEGMENT);
+        let items = object.get("items").map_or_else(
+            || self.default_type(&items_path),
+            |items| self.value_type(items, &items_path, depth + 1),
+        );
+        ValueType::Array(Box::new(items))
+    }
+
+    fn object_type(&self, object: &Map<String, Value>, path: &str, depth: usize) -> ValueType {
+        let additional = object
+            .get("additionalProperties")
+            .filter(|additional| !is_trivial(additional) && **additional != Value::Bool(false))
+            .map(|additional| {
+                self.value_type(
+                    additional,
+                    &append_value_path(path, MEMBER_SEGMENT),
+                    depth + 1,
+                )
+            });
+        let Some(Value::Object(properties)) = object.get("properties") else {
+            return ValueType::Map(Box::new(additional.unwrap_or(ValueType::Any)));
+        };
+        let required: BTreeSet<&str> = object
+            .get("required")
+            .and_then(Value::as_array)
+            .into_iter()
+            .flatten()
+            .filter_map(Value::as_str)
+            .collect();
+        let fields = properties
+            .iter()
+            .map(|(key, schema)| {
+                let field_path = append_value_path(path, key);
+                let field = FieldType {
+                    ty: self.value_type(schema, &field_path, depth + 1),
+                    required: required.contains(key.as_str()),
+                    description: schema
+                        .get("description")
+                        .and_then(Value::as_str)
+                        .map(str::to_string)
+                        .or_else(|| self.descriptions.get(&field_path).cloned()),
+                };
+                (key.clone(), field)
+            })
+            .collect();
+        ValueType::Object(ObjectType {
+            fields,
+            additional: additional.map(Box::new),
+            variants: None,
+        })
+    }
+
+    /// Type of the chart default at `path`; list items and map values read
+    /// the first member.
+    fn default_type(&self, path: &str) -> ValueType {
+        split_value_path(path)
+            .iter()
+            .try_fold(self.defaults, |value, segment| {
+                match (value, segment.as_str()) {
+                    (Value::Array(items), MEMBER_SEGMENT) => items.first(),
+                    (Value::Object(members), MEMBER_SEGMENT) => members.values().next(),
+                    (value, segment) => value.get(segment),
+                }
+            })
+            .map_or(ValueType::Any, value_type_of)
+    }
+
+    fn split_variants(&self, ty: &mut ValueType, path: &str) {
+        match ty {
+            ValueType::Object(object) => self.split_object_variants(object, path),
+            ValueType::Array(inner) | ValueType::Map(inner) => {
+                self.split_variants(inner, &append_value_path(path, MEMBER_SEGMENT));
+            }
+            ValueType::Union(members) => {
+                for member in members {
+                    self.split_variants(member, path);
+                }
+            }
+            ValueType::Any
+            | ValueType::Null
+            | ValueType::Boolean
+            | ValueType::Integer
+            | ValueType::Number
+            | ValueType::String
+            | ValueType::Literal(_) => {}
+        }
+    }
+
+    /// Moves the fields templates read only under a literal of one sibling
+    /// key into the cases of a [`VariantSet`] on that key.
+    ///
+    /// The key scoping the most fields wins; fields scoped by another key
+    /// stay common.
+    fn split_object_variants(&self, object: &mut ObjectType, path: &str) {
+        for (key, field) in &mut object.fields {
+            self.split_variants(&mut field.ty, &append_value_path(path, key));
+        }
+        if let Some(additional) = &mut object.additional {
+            self.split_variants(additional, &append_value_path(path, MEMBER_SEGMENT));
+        }
+
+        let mut scoped: BTreeMap<String, BTreeMap<String, BTreeSet<VariantTag>>> = BTreeMap::new();
+        for key in object.fields.keys() {
+            if let Some((discriminator, tags)) = self.field_tags(path, key)
+                && discriminator != *key
+                && object
+                    .fields
+                    .get(&discriminator)
+                    .is_some_and(|field| admits_tags(&field.ty, &tags))
+            {
+                scoped
+                    .entry(discriminator)
+                    .or_default()
+                    .insert(key.clone(), tags);
+            }
+        }
+        let Some((discriminator, fields)) =
+            scoped
+                .into_iter()
+                .max_by(|(left_key, left), (right_key, right)| {
+                    left.len()
+                        .cmp(&right.len())
+                        .then_with(|| right_key.cmp(left_key))
+                })
+        else {
+            return;
+        };
+        let Some(discriminator_field) = object.fields.remove(&discriminator) else {
+            return;
+        };
+
+        let tags: BTreeSet<VariantTag> = fields.values().flatten().cloned().collect();
+        let mut case_fields: BTreeMap<String, FieldType> = BTreeMap::new();
+        for key in fields.keys() {
+            if let Some(field) = object.fields.remove(key) {
+                case_fields.insert(key.clone(), field);
+            }
+        }
+        let cases = tags
+            .iter()
+            .map(|tag| VariantCase {
+                tag: tag.clone(),
+                fields: fields
+                    .iter()
+                    .filter(|(_, field_tags)| field_tags.contains(tag))
+                    .filter_map(|(key, _)| Some((key.clone(), case_fields.get(key)?.clone())))
+                    .collect(),
+            })
+            .collect();
+        let fallback = remaining_type(&discriminator_field.ty, &tags).map(|ty| {
+            Box::new(FieldType {
+                ty,
+                required: discriminator_field.required,
+                description: discriminator_field.description.clone(),
+            })
+        });
+        object.variants = Some(VariantSet {
+            discriminator,
+            description: discriminator_field.description,
+            cases,
+            fallback,
+        });
+    }
+
+    /// The sibling discriminator and its literals under which every
+    /// template read of `key` (or a descendant) happens, or `None` when any
+    /// read is unconditional or guarded otherwise.
+    fn field_tags(&self, object_path: &str, key: &str) -> Option<(String, BTreeSet<VariantTag>)> {
+        let field_path = append_value_path(object_path, key);
+        let object_segments = split_value_path(object_path);
+        let mut discriminator: Option<String> = None;
+        let mut tags = BTreeSet::new();
+        let evidence = self
+            .signals
+            .schema_evidence_by_value_path()
+            .range(field_path.clone()..)
+            .take_while(|(path, _)| path.starts_with(&field_path))
+            .filter(|(path, _)| {
+                path.len() == field_path.len()
+                    || path
+                        .get(field_path.len()..)
+                        .is_some_and(|rest| rest.starts_with('.'))
+            })
+            .map(|(_, evidence)| evidence)
+            .filter(|evidence| evidence.is_referenced_value_path);
+        let mut read = false;
+        for evidence in evidence {
+            read = true;
+            if evidence.conditional_overlays.is_empty()
+                || evidence
+                    .conditional_overlays
+                    .iter()
+                    .any(|overlay| overlay.preserve_base_schema)
+            {
+                return None;
+            }
+            for overlay in &evidence.conditional_overlays {
+                let (key, tag) = overlay
+                    .guards
+                    .iter()
+                    .find_map(|guard| sibling_tag(guard, &object_segments))?;
+                if *discriminator.get_or_insert_with(|| key.clone()) != key {
+                    return None;
+                }
+                tags.insert(tag);
+            }
+        }
+        Some((discriminator?, tags)).filter(|_| read)
+    }
+}
+
+/// The key and literal a guard on a direct child of the object at
+/// `object_segments` selects.
+fn sibling_tag(
+    guard: &ConditionalGuard,
+    object_segments: &[String],
+) -> Option<(String, VariantTag)> {
+    let (path, tag) = match guard {
+        ConditionalGuard::Truthy { path } => (path, VariantTag::Bool(true)),
+        ConditionalGuard::Not(inner) => match inner.as_ref() {
+            ConditionalGuard::Truthy { path } => (path, VariantTag::Bool(false)),
+            _ => return None,
+        },
+        ConditionalGuard::Eq {
+            path,
+            value: GuardValue::Bool(value),
+        } => (path, VariantTag::Bool(*value)),
+        ConditionalGuard::Eq {
+            path,
+            value: GuardValue::String(value),
+        } => (path, VariantTag::String(value.clone())),
+        _ => return None,
+    };
+    let mut segments = split_value_path(path);
+    let key = segments.pop()?;
+    (segments == object_segments).then_some((key, tag))
+}
+
+/// Whether a discriminator of type `ty` can hold every tag.
+fn admits_tags(ty: &ValueType, tags: &BTreeSet<VariantTag>) -> bool {
+    let members = members(ty);
+    tags.iter().all(|tag| {
+        let literal = tag.to_value();
+        members.iter().any(|member| match (member, tag) {
+            (ValueType::Boolean, VariantTag::Bool(_))
+            | (ValueType::String, VariantTag::String(_)) => true,
+            (ValueType::Literal(value), _) => *value == literal,
+            _ => false,
+        })
+    })
+}
+
+/// What `ty` accepts besides the tags, or `None` when nothing remains.
+fn remaining_type(ty: &ValueType, tags: &BTreeSet<VariantTag>) -> Option<ValueType> {
+    let mut remaining = Vec::new();
+    for member in members(ty) {
+        match member {
+            ValueType::Boolean => remaining.extend(
+                [false, true]
+                    .into_iter()
+                    .filter(|value| !tags.contains(&VariantTag::Bool(*value)))
+                    .map(|value| ValueType::Literal(Value::Bool(value))),
+            ),
+            ValueType::Literal(value) if tags.iter().any(|tag| tag.to_value() == *value) => {}
+            member => remaining.push(member.clone()),
+        }
+    }
+    (!remaining.is_empty()).then(|| union(remaining))
+}
+
+fn members(ty: &ValueType) -> Vec<&ValueType> {
+    match ty {
+        ValueType::Union(members) => members.iter().collect(),
+        ty => vec![ty],
+    }
+}
+
+fn is_trivial(schema: &Value) -> bool {
+    match schema {
+        Value::Bool(accepts) => *accepts,
+        Value::Object(object) => object.is_empty(),
+        _ => false,
+    }
+}
+
+fn literal_type(literal: &Value) -> ValueType {
+    match literal {
+        Value::Null => ValueType::Null,
+        literal => ValueType::Literal(literal.clone()),
+    }
+}
+
+fn value_type_of(value: &Value) -> ValueType {
+    match value {
+        Value::Null => ValueType::Any,
+        Value::Bool(_) => ValueType::Boolean,
+        Value::Number(number) if number.is_f64() => ValueType::Number,
+        Value::Number(_) => ValueType::Integer,
+        Value::String(_) => ValueType::String,
+        Value::Array(items) => ValueType::Array(Box::new(
+            items.first().map_or(ValueType::Any, value_type_of),
+        )),
+        Value::Object(_) => ValueType::Map(Box::new(ValueType::Any)),
+    }
+}
+
+/// Normalized union: nested unions flatten, literals fold into their
+/// base type, integers into numbers, objects, lists, and maps merge per
+/// kind, and a union of every kind is [`ValueType::Any`].
+pub(super) fn union(types: Vec<ValueType>) -> ValueType {
+    let mut flat = Vec::new();
+    for ty in types {
+        match ty {
+            ValueType::Any => return ValueType::Any,
+            ValueType::Union(members) => flat.extend(members),
+            ty => flat.push(ty),
+        }
+    }
+
+    let has = |flat: &[ValueType], probe: &ValueType| flat.contains(probe);
+    let mut members: Vec<ValueType> = Vec::new();
+    for ty in flat {
+        match ty {
+            ValueType::Object(object) => {
+                if let Some(ValueType::Object(existing)) = members
+                    .iter_mut()
+                    .find(|member| matches!(member, ValueType::Object(_)))
+                {
+                    merge_objects(existing, object, union);
+                } else {
+                    members.push(ValueType::Object(object));
+                }
+            }
+            ValueType::Array(items) => {
+                if let Some(ValueType::Array(existing)) = members
+                    .iter_mut()
+                    .find(|member| matches!(member, ValueType::Array(_)))
+                {
+                    let merged = union(vec![std::mem::replace(existing, ValueType::Any), *items]);
+                    **existing = merged;
+                } else {
+                    members.push(ValueType::Array(items));
+                }
+            }
+            ValueType::Map(values) => {
+                if let Some(ValueType::Map(existing)) = members
+                    .iter_mut()
+                    .find(|member| matches!(member, ValueType::Map(_)))
+                {
+                    let merged = union(vec![std::mem::replace(existing, ValueType::Any), *values]);
+                    **existing = merged;
+                } else {
+                    members.push(ValueType::Map(values));
+                }
+            }
+            ty if !has(&members, &ty) => members.push(ty),
+            _ => {}
+        }
+    }
+
+    let snapshot = members.clone();
+    members.retain(|member| match member {
+        ValueType::Integer => !has(&snapshot, &ValueType::Number),
+        ValueType::Literal(Value::Bool(_)) => !has(&snapshot, &ValueType::Boolean),
+        ValueType::Literal(Value::String(_)) => !has(&snapshot, &ValueType::String),
+        ValueType::Literal(Value::Number(number)) => {
+            let integral = number.is_i64() || number.is_u64();
+            let covered = has(&snapshot, &ValueType::Number)
+                || (integral && has(&snapshot, &ValueType::Integer));
+            !covered
+        }
+        _ => true,
+    });
+
+    let covers = |kind: fn(&ValueType) -> bool| members.iter().any(kind);
+    if covers(|member| matches!(member, ValueType::Boolean))
+        && covers(|member| matches!(member, ValueType::Number))
+        && covers(|member| matches!(member, ValueType::String))
+        && covers(|member| matches!(member, ValueType::Array(_)))
+        && covers(|member| matches!(member, ValueType::Object(_) | ValueType::Map(_)))
+    {
+        return ValueType::Any;
+    }
+    members.sort_by_key(kind_rank);
+    match members.len() {
+        0 => ValueType::Any,
+        1 => members.pop().unwrap_or(ValueType::Any),
+        _ => ValueType::Union(members),
+    }
+}
+
+fn kind_rank(ty: &ValueType) -> u8 {
+    match ty {
+        ValueType::Object(_) => 0,
+        ValueType::Map(_) => 1,
+        ValueType::Array(_) => 2,
+        ValueType::String => 3,
+        ValueType::Integer => 4,
+        ValueType::Number => 5,
+        ValueType::Boolean => 6,
+        ValueType::Literal(_) => 7,
+        ValueType::Any => 8,
+        ValueType::Null | ValueType::Union(_) => 9,
+    }
+}
+
+/// Values both types accept, approximated member by member; when no
+/// member pair is compatible, `left` is kept.
+fn intersect(left: ValueType, right: ValueType) -> ValueType {
+    match (left, right) {
+        (ValueType::Any, ty) | (ty, ValueType::Any) => ty,
+        (left, right) => {
+            let rights = members(&right);
+            let met: Vec<ValueType> = members(&left)
+                .into_iter()
+                .flat_map(|member| rights.iter().filter_map(move |other| meet(member, other)))
+                .collect();
+            if met.is_empty() { left } else { union(met) }
+        }
+    }
+}
+
+fn meet(left: &ValueType, right: &ValueType) -> Option<ValueType> {
+    match (left, right) {
+        (ValueType::Object(left), ValueType::Object(right)) => {
+            let mut merged = left.clone();
+            merge_objects(&mut merged, right.clone(), |types| {
+                types
+                    .into_iter()
+                    .reduce(intersect)
+                    .unwrap_or(ValueType::Any)
+            });
+            Some(ValueType::Object(merged))
+        }
+        (ValueType::Object(object), ValueType::Map(_))
+        | (ValueType::Map(_), ValueType::Object(object)) => Some(ValueType::Object(object.clone())),
+        (ValueType::Array(left), ValueType::Array(right)) => Some(ValueType::Array(Box::new(
+            intersect((**left).clone(), (**right).clone()),
+        ))),
+        (ValueType::Map(left), ValueType::Map(right)) => Some(ValueType::Map(Box::new(intersect(
+            (**left).clone(),
+            (**right).clone(),
+        )))),
+        (ValueType::Integer, ValueType::Number) | (ValueType::Number, ValueType::Integer) => {
+            Some(ValueType::Integer)
+        }
+        (ValueType::Literal(literal), ty) | (ty, ValueType::Literal(literal))
+            if admits_literal(ty, literal) =>
+        {
+            Some(ValueType::Literal(literal.clone()))
+        }
+        (left, right) => (left == right).then(|| left.clone()),
+    }
+}
+
+fn admits_literal(ty: &ValueType, literal: &Value) -> bool {
+    match (ty, literal) {
+        (ValueType::Boolean, Value::Bool(_))
+        | (ValueType::String, Value::String(_))
+        | (ValueType::Number, Value::Number(_)) => true,
+        (ValueType::Integer, Value::Number(number)) => number.is_i64() || number.is_u64(),
+        (ValueType::Literal(other), literal) => other == literal,
+        _ => false,
+    }
+}
+
+/// Merges `other` into `object`, combining the types of shared keys with
+/// `combine`.
+fn merge_objects(
+    object: &mut ObjectType,
+    other: ObjectType,
+    combine: fn(Vec<ValueType>) -> ValueType,
+) {
+    for (key, field) in other.fields {
+        match object.fields.get_mut(&key) {
+            Some(existing) => {
+                let ty = std::mem::replace(&mut existing.ty, ValueType::Any);
+                existing.ty = combine(vec![ty, field.ty]);
+                existing.required |= field.required;
+                if existing.description.is_none() {
+                    existing.description = field.description;
+                }
+            }
+            None => {
+                object.fields.insert(key, field);
+            }
+        }
+    }
+    if object.additional.is_none() {
+        object.additional = other.additional;
+    }
+}
@@ -0,0 +1,155 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::Write as _;
+
+use serde_json::Value;
+
+use super::{
+    FieldType, GENERATED_NOTICE, ITEM_SEGMENT, MAP_VALUE_SEGMENT, TypeNames, ValueType,
+    case_members, child_path,
+};
+
+/// Python keywords, which cannot name a `TypedDict` key in class syntax.
+const KEYWORDS: [&str; 35] = [
+    "False", "None", "True", "and", "as", "assert", "async", "await", "break", "class", "continue",
+    "def", "del", "elif", "else", "except", "finally", "for", "from", "global", "if", "import",
+    "in", "is", "lambda", "nonlocal", "not", "or", "pass", "raise", "return", "try", "while",
+    "with", "yield",
+];
+
+/// Renders one `TypedDict` per named object. An object with variants
+/// becomes one `TypedDict` per case, each repeating the common keys, and a
+/// `Union` alias under the object's name.
+pub(super) fn render(names: &TypeNames<'_>) -> String {
+    let mut imports = BTreeSet::from(["TypedDict"]);
+    let mut body = String::new();
+    for (path, name, object) in &names.objects {
+        let Some(variants) = &object.variants else {
+            render_typed_dict(&mut body, &mut imports, names, path, name, &object.fields);
+            continue;
+        };
+        let case_names = names.case_names(path);
+        for (case_name, members) in case_names.iter().zip(case_members(variants)) {
+            let mut fields = object.fields.clone();
+            fields.extend(members);
+            render_typed_dict(&mut body, &mut imports, names, path, case_name, &fields);
+        }
+        imports.insert("Union");
+        let _ = writeln!(body, "\n\n{name} = Union[{}]", case_names.join(", "));
+    }
+
+    let mut out = format!(
+        "# {GENERATED_NOTICE}\n\nfrom __future__ import annotations\n\nfrom typing import {}\n",
+        imports.into_iter().collect::<Vec<_>>().join(", ")
+    );
+    out.push_str(&body);
+    out
+}
+
+fn render_typed_dict(
+    out: &mut String,
+    imports: &mut BTreeSet<&'static str>,
+    names: &TypeNames<'_>,
+    path: &[String],
+    name: &str,
+    fields: &BTreeMap<String, FieldType>,
+) {
+    let members: Vec<(&String, &FieldType, String)> = fields
+        .iter()
+        .map(|(key, field)| {
+            let ty = type_expression(imports, names, &child_path(path, key), &field.ty);
+            let ty = if field.required {
+                imports.insert("Required");
+                format!("Required[{ty}]")
+            } else {
+                ty
+            };
+            (key, field, ty)
+        })
+        .collect();
+
+    // Keys that are not identifiers need the functional syntax, whose
+    // annotations evaluate eagerly and therefore stay quoted.
+    if !members.iter().all(|(key, ..)| is_identifier(key)) {
+        let _ = writeln!(out, "\n\n{name} = TypedDict(\n    {name:?},\n    {{");
+        for (key, field, ty) in members {
+            render_comment(out, "        ", field);
+            let _ = writeln!(
+                out,
+                "        {}: {},",
+                Value::String(key.clone()),
+                Value::String(ty)
+            );
+        }
+        out.push_str("    },\n    total=False,\n)\n");
+        return;
+    }
+
+    let _ = writeln!(out, "\n\nclass {name}(TypedDict, total=False):");
+    if members.is_empty() {
+        out.push_str("    pass\n");
+    }
+    for (key, field, ty) in members {
+        render_comment(out, "    ", field);
+        let _ = writeln!(out, "    {key}: {ty}");
+    }
+}
+
+fn render_comment(out: &mut String, indent: &str, field: &FieldType) {
+    for line in field
+        .description
+        .iter()
+        .flat_map(|description| description.lines())
+    {
+        let _ = writeln!(out, "{indent}# {line}");
+    }
+}
+
+fn type_expression(
+    imports: &mut BTreeSet<&'static str>,
+    names: &TypeNames<'_>,
+    path: &[String],
+    ty: &ValueType,
+) -> String {
+    match ty {
+        ValueType::Null => "None".to_string(),
+        ValueType::Boolean => "bool".to_string(),
+        ValueType::Integer => "int".to_string(),
+        ValueType::Number | ValueType::Literal(Value::Number(_)) => "float".to_string(),
+        ValueType::String => "str".to_string(),
+        ValueType::Literal(Value::Bool(value)) => {
+            imports.insert("Literal");
+            format!("Literal[{}]", if *value { "True" } else { "False" })
+        }
+        ValueType::Literal(Value::String(value)) => {
+            imports.insert("Literal");
+            format!("Literal[{}]", Value::String(value.clone()))
+        }
+        ValueType::Any | ValueType::Literal(_) => {
+            imports.insert("Any");
+            "Any".to_string()
+        }
+        ValueType::Array(items) => format!(
+            "list[{}]",
+            type_expression(imports, names, &child_path(path, ITEM_SEGMENT), items)
+        ),
+        ValueType::Map(values) => format!(
+            "dict[str, {}]",
+            type_expression(imports, names, &child_path(path, MAP_VALUE_SEGMENT), values)
+        ),
+        ValueType::Object(_) => names.name(path).to_string(),
+        ValueType::Union(members) => members
+            .iter()
+            .map(|member| type_expression(imports, names, path, member))
+            .collect::<Vec<_>>()
+            .join(" | "),
+    }
+}
+
+fn is_identifier(key: &str) -> bool {
+    let mut characters = key.chars();
+    characters
+        .next()
+        .is_some_and(|first| first.is_ascii_alphabetic() || first == '_')
+        && characters.all(|character| character.is_ascii_alphanumeric() || character == '_')
+        && !KEYWORDS.contains(&key)
+}
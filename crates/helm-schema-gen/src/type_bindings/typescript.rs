@@ -0,0 +1,142 @@
+use std::collections::BTreeMap;
+use std::fmt::Write as _;
+
+use super::{
+    FieldType, GENERATED_NOTICE, ITEM_SEGMENT, MAP_VALUE_SEGMENT, ObjectType, TypeNames, ValueType,
+    case_members, child_path,
+};
+
+/// Renders one exported declaration per named object. Objects with
+/// variants become an intersection of their common members with a union
+/// of cases keyed by the discriminator literal.
+pub(super) fn render(names: &TypeNames<'_>) -> String {
+    let mut out = format!("// {GENERATED_NOTICE}\n");
+    for (path, name, object) in &names.objects {
+        out.push('\n');
+        render_object(&mut out, names, path, name, object);
+    }
+    out
+}
+
+fn render_object(
+    out: &mut String,
+    names: &TypeNames<'_>,
+    path: &[String],
+    name: &str,
+    object: &ObjectType,
+) {
+    let Some(variants) = &object.variants else {
+        let _ = writeln!(out, "export interface {name} {{");
+        render_members(
+            out,
+            names,
+            path,
+            &object.fields,
+            object.additional.is_some(),
+            1,
+        );
+        out.push_str("}\n");
+        return;
+    };
+
+    if object.fields.is_empty() && object.additional.is_none() {
+        let _ = writeln!(out, "export type {name} = (");
+    } else {
+        let _ = writeln!(out, "export type {name} = {{");
+        render_members(
+            out,
+            names,
+            path,
+            &object.fields,
+            object.additional.is_some(),
+            1,
+        );
+        out.push_str("} & (\n");
+    }
+    for members in case_members(variants) {
+        out.push_str("  | {\n");
+        render_members(out, names, path, &members, false, 3);
+        out.push_str("    }\n");
+    }
+    out.push_str(");\n");
+}
+
+fn render_members(
+    out: &mut String,
+    names: &TypeNames<'_>,
+    path: &[String],
+    fields: &BTreeMap<String, FieldType>,
+    open: bool,
+    depth: usize,
+) {
+    let indent = "  ".repeat(depth);
+    for (key, field) in fields {
+        if let Some(description) = &field.description {
+            render_doc_comment(out, &indent, description);
+        }
+        let optional = if field.required { "" } else { "?" };
+        let ty = type_expression(names, &child_path(path, key), &field.ty);
+        let _ = writeln!(out, "{indent}{}{optional}: {ty};", property_key(key));
+    }
+    if open {
+        let _ = writeln!(out, "{indent}[key: string]: unknown;");
+    }
+}
+
+fn render_doc_comment(out: &mut String, indent: &str, description: &str) {
+    let description = description.replace("*/", "*\\/");
+    let lines: Vec<&str> = description.lines().collect();
+    if let [line] = lines.as_slice() {
+        let _ = writeln!(out, "{indent}/** {line} */");
+        return;
+    }
+    let _ = writeln!(out, "{indent}/**");
+    for line in lines {
+        let _ = writeln!(out, "{indent} * {line}");
+    }
+    let _ = writeln!(out, "{indent} */");
+}
+
+fn type_expression(names: &TypeNames<'_>, path: &[String], ty: &ValueType) -> String {
+    match ty {
+        ValueType::Any => "unknown".to_string(),
+        ValueType::Null => "null".to_string(),
+        ValueType::Boolean => "boolean".to_string(),
+        ValueType::Integer | ValueType::Number => "number".to_string(),
+        ValueType::String => "string".to_string(),
+        ValueType::Literal(value) => value.to_string(),
+        ValueType::Array(items) => {
+            let items = type_expression(names, &child_path(path, ITEM_SEGMENT), items);
+            if items.contains(' ') {
+                format!("({items})[]")
+            } else {
+                format!("{items}[]")
+            }
+        }
+        ValueType::Map(values) => format!(
+            "Record<string, {}>",
+            type_expression(names, &child_path(path, MAP_VALUE_SEGMENT), values)
+        ),
+        ValueType::Object(_) => names.name(path).to_string(),
+        ValueType::Union(members) => members
+            .iter()
+            .map(|member| type_expression(names, path, member))
+            .collect::<Vec<_>>()
+            .join(" | "),
+    }
+}
+
+fn property_key(key: &str) -> String {
+    let mut characters = key.chars();
+    let identifier = characters
+        .next()
+        .is_some_and(|first| first.is_ascii_alphabetic() || first == '_' || first == '$')
+        && characters.all(|character| {
+            character.is_ascii_alphanumeric() || character == '_' || character == '$'
+        });
+    if identifier {
+        key.to_string()
+    } else {
+        serde_json::Value::String(key.to_string()).to_string()
+    }
+}
@@ -470,3 +470,218 @@ pub(crate) fn child_value_path(parent: &[String], child: &str) -> Vec<String> {
     path.push(child.to_string());
     path
 }
+
+/// Maximum `$ref` hops followed while resolving one schema node.
+const MAX_SKELETON_REF_DEPTH: usize = 32;
+
+/// Renders an annotated `values.yaml` that lists every path of the values
+/// tree in `schema`.
+///
+/// Paths with a composed default keep it; paths that templates read without
+/// a default become commented placeholders, so the document still renders
+/// the same as an empty override. Schema descriptions become comments above
+/// their key. Provider-resolved `$ref`s are not expanded into their
+/// Kubernetes members.
+///
+/// # Errors
+///
+/// Returns an error when a default value cannot be serialized as YAML.
+pub fn render_values_skeleton(
+    defaults: Option<&YamlValue>,
+    schema: &Value,
+) -> Result<String, serde_yaml::Error> {
+    let mut writer = SkeletonWriter {
+        root: schema,
+        out: String::new(),
+    };
+    let defaults = match defaults {
+        Some(YamlValue::Mapping(mapping)) => Some(mapping),
+        _ => None,
+    };
+    writer.write_mapping(defaults, Some(schema), "", false, 0)?;
+    Ok(writer.out)
+}
+
+struct SkeletonWriter<'a> {
+    root: &'a Value,
+    out: String,
+}
+
+impl SkeletonWriter<'_> {
+    fn write_mapping(
+        &mut self,
+        defaults: Option<&serde_yaml::Mapping>,
+        schema: Option<&Value>,
+        prefix: &str,
+        commented: bool,
+        depth: usize,
+    ) -> Result<(), serde_yaml::Error> {
+        if depth > MAX_SKELETON_REF_DEPTH {
+            return Ok(());
+        }
+        let properties = schema
+            .map(|schema| resolve_skeleton_ref(self.root, schema))
+            .and_then(|schema| schema.get("properties"))
+            .and_then(Value::as_object);
+        let mut keys: Vec<YamlValue> = defaults
+            .map(|defaults| defaults.keys().cloned().collect())
+            .unwrap_or_default();
+        for name in properties.into_iter().flat_map(serde_json::Map::keys) {
+            let key = YamlValue::String(name.clone());
+            if !defaults.is_some_and(|defaults| defaults.contains_key(&key)) {
+                keys.push(key);
+            }
+        }
+
+        for key in keys {
+            let child_default = defaults.and_then(|defaults| defaults.get(&key));
+            let child_schema = key
+                .as_str()
+                .and_then(|name| properties.and_then(|properties| properties.get(name)));
+            let key_text = serde_yaml::to_string(&key)?.trim_end().to_string();
+            if let Some(description) = child_schema.and_then(|schema| {
+                schema
+                    .get("description")
+                    .or_else(|| resolve_skeleton_ref(self.root, schema).get("description"))
+                    .and_then(Value::as_str)
+            }) {
+                for line in description.trim().lines() {
+                    self.out.push_str(prefix);
+                    self.out.push_str("# ");
+                    self.out.push_str(line.trim_end());
+                    self.out.push('\n');
+                }
+            }
+            // Members of provider `$ref`s are Kubernetes fields, not chart
+            // values; only the chart-built tree is expanded.
+            let expandable = child_schema.filter(|schema| schema.get("$ref").is_none());
+
+            match child_default {
+                Some(YamlValue::Mapping(mapping)) if !mapping.is_empty() => {
+                    self.push_line(prefix, &format!("{key_text}:"));
+                    self.write_mapping(
+                        Some(mapping),
+                        expandable,
+                        &format!("{prefix}  "),
+                        commented,
+                        depth + 1,
+                    )?;
+                }
+                Some(value) => {
+                    let rendered = serde_yaml::to_string(value)?;
+                    let mut lines = rendered.trim_end().lines();
+                    if matches!(value, YamlValue::Sequence(items) if !items.is_empty()) {
+                        self.push_line(prefix, &format!("{key_text}:"));
+                    } else {
+                        let first = lines.next().unwrap_or_default();
+                        self.push_line(prefix, &format!("{key_text}: {first}"));
+                    }
+                    for line in lines {
+                        self.push_line(&format!("{prefix}  "), line);
+                    }
+                }
+                None => {
+                    let key_prefix = if commented {
+                        prefix.to_string()
+                    } else {
+                        format!("{prefix}# ")
+                    };
+                    let has_members = expandable
+                        .and_then(|schema| schema.get("properties"))
+                        .and_then(Value::as_object)
+                        .is_some_and(|properties| !properties.is_empty());
+                    if has_members {
+                        self.push_line(&key_prefix, &format!("{key_text}:"));
+                        self.write_mapping(
+                            None,
+                            expandable,
+                            &format!("{key_prefix}  "),
+                            true,
+                            depth + 1,
+                        )?;
+                    } else {
+                        let placeholder = child_schema
+                            .and_then(|schema| skeleton_placeholder(self.root, schema))
+                            .unwrap_or_default();
+                        let line = if placeholder.is_empty() {
+                            format!("{key_text}:")
+                        } else {
+                            format!("{key_text}: {placeholder}")
+                        };
+                        self.push_line(&key_prefix, &line);
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn push_line(&mut self, prefix: &str, line: &str) {
+        self.out.push_str(prefix);
+        self.out.push_str(line);
+        self.out.push('\n');
+    }
+}
+
+fn resolve_skeleton_ref<'a>(root: &'a Value, mut node: &'a Value) -> &'a Value {
+    for _ in 0..MAX_SKELETON_REF_DEPTH {
+        let Some(target) = node
+            .get("$ref")
+            .and_then(Value::as_str)
+            .and_then(|reference| reference.strip_prefix('#'))
+            .and_then(|pointer| root.pointer(pointer))
+        else {
+            break;
+        };
+        node = target;
+    }
+    node
+}
+
+/// Zero value of the single non-null type a schema node declares, looking
+/// through `anyOf`/`oneOf` branches (a `default`-guarded read adds an
+/// untyped falsy branch beside the typed one).
+fn skeleton_placeholder(root: &Value, schema: &Value) -> Option<&'static str> {
+    let mut types = BTreeSet::new();
+    collect_skeleton_types(root, schema, 0, &mut types);
+    types.remove("null");
+    let [ty] = <[&str; 1]>::try_from(types.into_iter().collect::<Vec<_>>()).ok()?;
+    Some(match ty {
+        "string" => "\"\"",
+        "integer" | "number" => "0",
+        "boolean" => "false",
+        "array" => "[]",
+        "object" => "{}",
+        _ => return None,
+    })
+}
+
+fn collect_skeleton_types<'a>(
+    root: &'a Value,
+    schema: &'a Value,
+    depth: usize,
+    types: &mut BTreeSet<&'a str>,
+) {
+    if depth > MAX_SKELETON_REF_DEPTH {
+        return;
+    }
+    let schema = resolve_skeleton_ref(root, schema);
+    match schema.get("type") {
+        Some(Value::String(ty)) => {
+            types.insert(ty);
+        }
+        Some(Value::Array(declared)) => types.extend(declared.iter().filter_map(Value::as_str)),
+        _ => {
+            for keyword in ["anyOf", "oneOf"] {
+                for branch in schema
+                    .get(keyword)
+                    .and_then(Value::as_array)
+                    .into_iter()
+                    .flatten()
+                {
+                    collect_skeleton_types(root, branch, depth + 1, types);
+                }
+            }
+        }
+    }
+}
@@ -1,8 +1,10 @@
 use std::cell::RefCell;
 use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
 use std::rc::Rc;
+use std::sync::Arc;
 
 use helm_schema_ast::{DefineIndex, TemplateExpr};
+use helm_schema_core::{ClusterLookupQuery, ClusterStateOracle};
 
 use crate::abstract_value::AbstractValue;
 use crate::eval_effect::Effects;
@@ -48,6 +50,7 @@ pub(crate) struct IrAnalysisDb {
     nil_scrub_helpers: RefCell<HashMap<String, bool>>,
     /// Exact immutable Helm root fields, represented separately from values.
     static_root_fields: HashMap<String, AbstractValue>,
+    cluster_state: Option<Arc<dyn ClusterStateOracle>>,
 }
 
 pub(crate) struct BoundHelperCallSummary {
@@ -63,6 +66,27 @@ fn static_root_fields(strings: BTreeMap<Vec<String>, String>) -> HashMap<String,
     roots.into_iter().collect()
 }
 
+/// Cluster objects are immutable render inputs like `.Chart`: strings stay
+/// exact so truthiness and equality decide, while other scalars only keep
+/// their position.
+fn cluster_object_value(value: &serde_json::Value) -> AbstractValue {
+    match value {
+        serde_json::Value::Object(fields) => AbstractValue::Dict(
+            fields
+                .iter()
+                .map(|(key, value)| (key.clone(), cluster_object_value(value)))
+                .collect(),
+        ),
+        serde_json::Value::Array(items) => {
+            AbstractValue::List(items.iter().map(cluster_object_value).collect())
+        }
+        serde_json::Value::String(value) => {
+            AbstractValue::StringSet(BTreeSet::from([value.clone()]))
+        }
+        _ => AbstractValue::Unknown,
+    }
+}
+
 fn insert_kubernetes_version_fields(strings: &mut BTreeMap<Vec<String>, String>, version: &str) {
     let version = version.trim_start_matches('v');
     let helm_version = format!("v{version}");
@@ -144,6 +168,7 @@ impl IrAnalysisDb {
             chart_default_strings,
             kubernetes_version,
             mut static_root_strings,
+            cluster_state,
         } = policy;
         if let Some(version) = kubernetes_version.as_deref() {
             insert_kubernetes_version_fields(&mut static_root_strings, version);
@@ -187,6 +212,7 @@ impl IrAnalysisDb {
             custom_merge_helpers: RefCell::new(HashMap::new()),
             nil_scrub_helpers: RefCell::new(HashMap::new()),
             static_root_fields: static_root_fields(static_root_strings),
+            cluster_state,
         }
     }
 
@@ -194,6 +220,19 @@ impl IrAnalysisDb {
         &self.static_root_fields
     }
 
+    /// Every value a `lookup` call may return under the configured cluster
+    /// state, or `None` without one.
+    pub(crate) fn cluster_lookup(&self, query: &ClusterLookupQuery) -> Option<AbstractValue> {
+        let results = self.cluster_state.as_ref()?.cluster_lookup(query)?;
+        let mut choices: BTreeSet<AbstractValue> =
+            results.iter().map(cluster_object_value).collect();
+        if choices.len() == 1 {
+            choices.pop_first()
+        } else {
+            Some(AbstractValue::Choice(choices))
+        }
+    }
+
     pub(crate) fn has_helper(&self, name: &str) -> bool {
         self.define_bodies.contains_key(name)
     }
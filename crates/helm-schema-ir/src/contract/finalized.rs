@@ -2,7 +2,7 @@ use std::collections::{BTreeMap, BTreeSet};
 
 use super::{ContractDocument, ContractUse};
 use crate::contract_signal_builder::derive_schema_signals_from_contract_parts;
-use helm_schema_core::ContractSchemaSignals;
+use helm_schema_core::{ConditionalGuard, ContractSchemaSignals};
 
 /// Finalized contract artifact derived from one canonical normalized contract.
 ///
@@ -77,6 +77,19 @@ impl FinalizedContract {
         ContractDocument::from_contract_uses(self.uses.clone())
     }
 
+    /// Scopes provider typing to the guards of each resource's definition;
+    /// see [`ContractSchemaSignals::with_resource_definition_guards`].
+    #[must_use]
+    pub fn with_resource_definition_guards(
+        mut self,
+        definition_guards: impl Fn(&helm_schema_core::ResourceRef) -> Option<Vec<ConditionalGuard>>,
+    ) -> Self {
+        self.schema_signals = self
+            .schema_signals
+            .with_resource_definition_guards(definition_guards);
+        self
+    }
+
     /// Consumes the contract and returns its schema-lowering signals.
     #[must_use]
     pub fn into_schema_signals(self) -> ContractSchemaSignals {
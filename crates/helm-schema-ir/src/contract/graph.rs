@@ -1,5 +1,8 @@
 use std::collections::{BTreeMap, BTreeSet};
 
+use helm_schema_core::FailMessages;
+use serde::{Deserialize, Serialize};
+
 use crate::contract::FinalizedContract;
 use crate::contract_normalization::{
     canonicalize_contract_uses, drop_default_guard_subsumed_duplicates,
@@ -11,7 +14,7 @@ use crate::{ContractUse, Guard, ValueKind, YamlPath};
 ///
 /// Accumulation, path rebasing, and normalization live behind this
 /// contract-layer artifact instead of a raw vector owned by callers.
-#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
 pub struct ContractIr {
     uses: Vec<ContractUse>,
     dependency_uses: Vec<ContractUse>,
@@ -70,6 +73,13 @@ impl ContractIr {
         }
     }
 
+    /// Claims recorded so far, before finalization merges claims that share
+    /// a render site and absorbs their conditions.
+    #[must_use]
+    pub fn uses(&self) -> &[ContractUse] {
+        &self.uses
+    }
+
     pub(crate) fn push(&mut self, contract_use: ContractUse) {
         self.uses.push(contract_use);
     }
@@ -142,9 +152,7 @@ impl ContractIr {
         self.values_program_wrapper_exclusions
             .append(&mut other.values_program_wrapper_exclusions);
         for condition in std::mem::take(&mut other.fail_conditions) {
-            if !self.fail_conditions.contains(&condition) {
-                self.fail_conditions.push(condition);
-            }
+            crate::eval_effect::push_fail_capture(&mut self.fail_conditions, condition);
         }
     }
 
@@ -162,10 +170,9 @@ impl ContractIr {
             conjunction: vec![condition],
             ranged: crate::range_modes::RangeModes::default(),
             kind: crate::eval_effect::CaptureKind::Fail,
+            messages: FailMessages::default(),
         };
-        if !self.fail_conditions.contains(&capture) {
-            self.fail_conditions.push(capture);
-        }
+        crate::eval_effect::push_fail_capture(&mut self.fail_conditions, capture);
     }
 
     /// Conjoins activation guards onto every use and terminating failure.
@@ -334,6 +341,73 @@ impl ContractIr {
             .collect();
     }
 
+    /// Copies of the uses and input-type evidence under the values subtree
+    /// `from`, rebased onto `to`.
+    ///
+    /// Chart `import-values` copy a dependency's values table into its
+    /// parent, so the parent path inherits the evidence the dependency's
+    /// templates give the source table. Paths outside `from` (guards on
+    /// other values) are kept as they are.
+    #[must_use]
+    pub fn project_value_subtree(&self, from: &[String], to: &[String]) -> Self {
+        let rebase = |path: &str| {
+            let segments = helm_schema_core::split_value_path(path);
+            let relative = segments.strip_prefix(from)?;
+            (!to.is_empty() || !relative.is_empty())
+                .then(|| helm_schema_core::join_value_path(to.iter().chain(relative)))
+        };
+        let mut rebase_or_keep = |path: &str| rebase(path).unwrap_or_else(|| path.to_string());
+
+        let mut projected = Self::default();
+        for contract_use in &self.uses {
+            if rebase(&contract_use.source_expr).is_none() {
+                continue;
+            }
+            let mut contract_use = contract_use.clone();
+            contract_use.map_value_paths(&mut rebase_or_keep);
+            projected.uses.push(contract_use);
+        }
+        for (hints, projected_hints) in [
+            (&self.type_hints, &mut projected.type_hints),
+            (&self.guarded_type_hints, &mut projected.guarded_type_hints),
+            (
+                &self.fallback_type_hints,
+                &mut projected.fallback_type_hints,
+            ),
+            (
+                &self.guarded_fallback_type_hints,
+                &mut projected.guarded_fallback_type_hints,
+            ),
+        ] {
+            for (path, schema_types) in hints {
+                if let Some(path) = rebase(path) {
+                    projected_hints
+                        .entry(path)
+                        .or_default()
+                        .extend(schema_types.iter().cloned());
+                }
+            }
+        }
+        for (paths, projected_paths) in [
+            (
+                &self.shape_erased_value_paths,
+                &mut projected.shape_erased_value_paths,
+            ),
+            (
+                &self.string_contract_value_paths,
+                &mut projected.string_contract_value_paths,
+            ),
+        ] {
+            projected_paths.extend(paths.iter().filter_map(|path| rebase(path)));
+        }
+        for (path, mode) in self.range_modes.iter() {
+            if let Some(path) = rebase(path) {
+                projected.range_modes.merge_mode(path, mode);
+            }
+        }
+        projected
+    }
+
     /// Projects dependency `global.*` contracts through Helm's parent-first
     /// coalesce while preserving the dependency-local fallback.
     pub fn project_dependency_global_contracts(&mut self, prefix: &[String]) {
@@ -569,9 +643,7 @@ impl ContractIr {
         conditions: impl IntoIterator<Item = crate::eval_effect::FailCapture>,
     ) {
         for conjunction in conditions {
-            if !self.fail_conditions.contains(&conjunction) {
-                self.fail_conditions.push(conjunction);
-            }
+            crate::eval_effect::push_fail_capture(&mut self.fail_conditions, conjunction);
         }
     }
 
@@ -614,8 +686,7 @@ impl ContractIr {
         drop_default_guard_subsumed_duplicates(&mut uses);
         drop_self_truthy_subsumed_duplicates(&mut uses);
         canonicalize_contract_uses(&mut uses);
-        fail_conditions.sort();
-        fail_conditions.dedup();
+        crate::eval_effect::sort_dedup_fail_captures(&mut fail_conditions);
         FinalizedContract::new(
             uses,
             &type_hints,
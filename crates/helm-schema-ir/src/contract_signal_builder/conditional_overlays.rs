@@ -1,6 +1,6 @@
 use super::{
     BTreeMap, BTreeSet, ConditionalGuard, ContractFailImplication, ContractPathAccumulator,
-    ContractRequirementTarget, ContractUse, FailValueRequirement, Guard, Predicate,
+    ContractRequirementTarget, ContractUse, FailMessages, FailValueRequirement, Guard, Predicate,
     ProviderSchemaUse, ValueKind, path_accumulator,
 };
 
@@ -9,10 +9,10 @@ use super::{
 /// positive reading keeps it out of the dormancy class.
 pub(super) fn hard_negation_paths(predicate: &Predicate, out: &mut BTreeSet<String>) {
     match predicate {
-        Predicate::Not(inner) => {
-            if !matches!(inner.as_ref(), Predicate::Guard(Guard::Absent { .. })) {
-                out.extend(inner.value_paths());
-            }
+        Predicate::Not(inner)
+            if !matches!(inner.as_ref(), Predicate::Guard(Guard::Absent { .. })) =>
+        {
+            out.extend(inner.value_paths());
         }
         Predicate::And(items) | Predicate::Or(items) => {
             for item in items {
@@ -620,6 +620,22 @@ pub(super) fn guard_to_conditional_guard(
                 bound: *bound,
             })
         }
+        Guard::MinItems {
+            path: value_path,
+            bound,
+        } => {
+            // Load-bearing on the target for the same reason as
+            // `MinMembers`: the arm fires only for lists that long.
+            let path = if target_value_path == Some(value_path.as_str()) {
+                (!path_contains_wildcard(value_path)).then(|| value_path.clone())?
+            } else {
+                path(value_path)?
+            };
+            Some(ConditionalGuard::MinItems {
+                path,
+                bound: *bound,
+            })
+        }
         Guard::TypeIs {
             path: value_path,
             schema_type,
@@ -773,12 +789,11 @@ pub(super) fn record_member_range_requirement(
         requirements: vec![FailValueRequirement::Iterable {
             allow_integer: inner_allows_integer,
         }],
+        messages: FailMessages::default(),
     };
     let acc = path_accumulator(paths, parent);
     acc.referenced = true;
-    if !acc.fail_implications.contains(&implication) {
-        acc.fail_implications.push(implication);
-    }
+    ContractFailImplication::push_unique(&mut acc.fail_implications, implication);
 }
 
 /// Whether a conjunct tests the TYPE of `source_expr`, positively or under
@@ -1,8 +1,8 @@
 use super::{
     ApproximationRole, BTreeMap, BTreeSet, ConditionalGuard, ConditionalOverlayEvidence,
     ContractFailImplication, ContractRequirednessEvidence, ContractRequirementTarget, ContractUse,
-    ContractValuePathFacts, FailValueRequirement, Guard, GuardDnf, MetadataFieldKind, Predicate,
-    ProviderSchemaUse, SourceUseFactSplit, ValueKind, collapse_layered_truthy_gates,
+    ContractValuePathFacts, FailMessages, FailValueRequirement, Guard, GuardDnf, MetadataFieldKind,
+    Predicate, ProviderSchemaUse, SourceUseFactSplit, ValueKind, collapse_layered_truthy_gates,
     extend_lowerable_predicate, hard_negation_paths, lowerable_conditional_guard_set,
     lowerable_conditional_guard_subset, path_accumulator, path_contains_wildcard,
     predicate_is_structural_ancestor_guard, predicate_skips_falsy_source,
@@ -1096,12 +1096,11 @@ pub(super) fn record_range_input_capture(
             requirements: vec![FailValueRequirement::Iterable {
                 allow_integer: !destructured && !json_decoded,
             }],
+            messages: FailMessages::default(),
         };
         let acc = path_accumulator(paths, path);
         acc.referenced = true;
-        if !acc.fail_implications.contains(&implication) {
-            acc.fail_implications.push(implication);
-        }
+        ContractFailImplication::push_unique(&mut acc.fail_implications, implication);
     }
 }
 
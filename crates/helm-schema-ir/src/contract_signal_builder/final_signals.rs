@@ -1,8 +1,8 @@
 use super::{
-    BTreeMap, BTreeSet, ConditionalGuard, ConditionalPathOverlay, ContractPathAccumulator,
-    ContractPathSchemaEvidence, ContractSchemaSignals, ContractValuePathFacts, MetadataFieldKind,
-    PathSchemaFactsAccumulator, ProviderSchemaUse, collect_paths_with_descendants,
-    record_member_access_implications,
+    BTreeMap, BTreeSet, ConditionalGuard, ConditionalPathOverlay, ContractFailImplication,
+    ContractPathAccumulator, ContractPathSchemaEvidence, ContractSchemaSignals,
+    ContractValuePathFacts, FailMessages, MetadataFieldKind, PathSchemaFactsAccumulator,
+    ProviderSchemaUse, collect_paths_with_descendants, record_member_access_implications,
 };
 
 pub(super) fn finish_schema_signals(
@@ -320,18 +320,38 @@ impl ContractPathAccumulator {
         // the encoding could not represent decide when those branches run,
         // so binding their typing path-wide would narrow states the branch
         // never reaches.
-        fail_implications.sort();
-        fail_implications.dedup();
-        let unconditional_requirements = fail_implications
+        ContractFailImplication::sort_dedup(&mut fail_implications);
+        // A guarded implication the same unconditional one subsumes is
+        // dropped; its messages move to the unconditional implication.
+        let mut unconditional_messages = fail_implications
             .iter()
             .filter(|implication| implication.outer_guards.is_empty())
-            .map(|implication| (implication.target.clone(), implication.requirements.clone()))
-            .collect::<BTreeSet<_>>();
+            .map(|implication| {
+                (
+                    (implication.target.clone(), implication.requirements.clone()),
+                    FailMessages::default(),
+                )
+            })
+            .collect::<BTreeMap<_, FailMessages>>();
         fail_implications.retain(|implication| {
-            implication.outer_guards.is_empty()
-                || !unconditional_requirements
-                    .contains(&(implication.target.clone(), implication.requirements.clone()))
+            if implication.outer_guards.is_empty() {
+                return true;
+            }
+            let Some(messages) = unconditional_messages
+                .get_mut(&(implication.target.clone(), implication.requirements.clone()))
+            else {
+                return true;
+            };
+            messages.extend(implication.messages.iter().cloned());
+            false
         });
+        for implication in &mut fail_implications {
+            if let Some(messages) = unconditional_messages
+                .remove(&(implication.target.clone(), implication.requirements.clone()))
+            {
+                implication.messages.extend(messages);
+            }
+        }
         let mut guarded_type_hints = guarded_type_hints;
         guarded_type_hints.extend(guarded_fallback_type_hints);
         ContractPathSchemaEvidence {
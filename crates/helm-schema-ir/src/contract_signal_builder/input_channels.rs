@@ -1,7 +1,8 @@
 use super::{
-    BTreeMap, BTreeSet, ContractFailImplication, ContractRequirementTarget, ContractSchemaSignals,
-    ContractUse, ContractValuePathFacts, FailValueRequirement, finish_schema_signals,
-    path_accumulator, record_contract_use, record_fail_conjunction,
+    BTreeMap, BTreeSet, ContractFailImplication, ContractPathAccumulator,
+    ContractRequirementTarget, ContractSchemaSignals, ContractUse, ContractValuePathFacts,
+    FailMessages, FailValueRequirement, finish_schema_signals, path_accumulator,
+    record_contract_use, record_fail_conjunction,
 };
 
 #[tracing::instrument(skip_all)]
@@ -23,39 +24,20 @@ pub(crate) fn derive_schema_signals_from_contract_parts(
 ) -> ContractSchemaSignals {
     let mut paths = BTreeMap::new();
     let mut terminal_clauses = Vec::new();
+    let mut terminal_clause_messages = BTreeMap::new();
     for contract_use in uses {
         record_contract_use(&mut paths, contract_use, range_modes);
     }
     for capture in fail_conditions {
-        record_fail_conjunction(&mut paths, &mut terminal_clauses, capture, range_modes);
-    }
-    for value_path in dependency_values_root_fragments {
-        if !value_path.trim().is_empty() {
-            let acc = path_accumulator(&mut paths, value_path);
-            acc.referenced = true;
-            acc.facts.record_facts(ContractValuePathFacts {
-                accepted_values_root_fragment: true,
-                accepted_dependency_values_root_fragment: true,
-                ..ContractValuePathFacts::default()
-            });
-            // Helm's dependency coalescing type-asserts every loaded
-            // dependency's values root BEFORE any rendering and regardless
-            // of the dependency's own activation: a present non-table
-            // aborts with "type mismatch on <name>" (verified against a
-            // condition-disabled dependency). The chart declares the key
-            // as a mapping, so a user null is deleted by the values
-            // coalesce that runs first and reaches the check as absent —
-            // hence the null-tolerant form.
-            let requires_table = ContractFailImplication {
-                outer_guards: Vec::new(),
-                target: ContractRequirementTarget::Value,
-                requirements: vec![FailValueRequirement::SchemaType("object".to_string())],
-            };
-            if !acc.fail_implications.contains(&requires_table) {
-                acc.fail_implications.push(requires_table);
-            }
-        }
+        record_fail_conjunction(
+            &mut paths,
+            &mut terminal_clauses,
+            &mut terminal_clause_messages,
+            capture,
+            range_modes,
+        );
     }
+    record_dependency_values_roots(&mut paths, dependency_values_root_fragments);
     // A path the chart consumes through a total stringification tolerates
     // any input type, even when the flow is too indirect for a placed row
     // (vault's `set . "csiEnabled" (eq (.Values.csi.enabled | toString)
@@ -84,60 +66,77 @@ pub(crate) fn derive_schema_signals_from_contract_parts(
         acc.facts.facts.has_non_self_guarded_string_contract = true;
         acc.type_hints.insert("string".to_string());
     }
-    for (value_path, schema_types) in type_hints {
-        let schema_types = schema_types
-            .iter()
-            .filter(|schema_type| !schema_type.trim().is_empty())
-            .cloned()
-            .collect::<BTreeSet<_>>();
-        if !value_path.trim().is_empty() && !schema_types.is_empty() {
-            let acc = path_accumulator(&mut paths, value_path);
-            acc.referenced = true;
-            acc.type_hints.extend(schema_types);
-        }
-    }
+    record_type_hint_channel(&mut paths, type_hints, |acc| &mut acc.type_hints);
     // Guarded hints hold only where their branches render: they type the
     // path's conditional overlays but never the unconditional base.
-    for (value_path, schema_types) in guarded_type_hints {
-        let schema_types = schema_types
-            .iter()
-            .filter(|schema_type| !schema_type.trim().is_empty())
-            .cloned()
-            .collect::<BTreeSet<_>>();
-        if !value_path.trim().is_empty() && !schema_types.is_empty() {
-            let acc = path_accumulator(&mut paths, value_path);
-            acc.referenced = true;
-            acc.guarded_type_hints.extend(schema_types);
-        }
-    }
+    record_type_hint_channel(&mut paths, guarded_type_hints, |acc| {
+        &mut acc.guarded_type_hints
+    });
     // Fallback hints type only the truthy arm of their path: the base
     // lowering keeps the Helm-falsy set open beside them.
-    for (value_path, schema_types) in fallback_type_hints {
-        let schema_types = schema_types
-            .iter()
-            .filter(|schema_type| !schema_type.trim().is_empty())
-            .cloned()
-            .collect::<BTreeSet<_>>();
-        if !value_path.trim().is_empty() && !schema_types.is_empty() {
-            let acc = path_accumulator(&mut paths, value_path);
+    record_type_hint_channel(&mut paths, fallback_type_hints, |acc| {
+        &mut acc.fallback_type_hints
+    });
+    // Branch-scoped fallback hints stay fallback-grade: they may type a
+    // conditional overlay, but never one whose renders all totally format.
+    record_type_hint_channel(&mut paths, guarded_fallback_type_hints, |acc| {
+        &mut acc.guarded_fallback_type_hints
+    });
+    finish_schema_signals(paths, terminal_clauses)
+        .with_terminal_clause_messages(terminal_clause_messages)
+}
+
+/// Marks each dependency values root as an accepted fragment that Helm's
+/// dependency coalescing requires to be a table.
+fn record_dependency_values_roots(
+    paths: &mut BTreeMap<String, ContractPathAccumulator>,
+    dependency_values_root_fragments: &BTreeSet<String>,
+) {
+    for value_path in dependency_values_root_fragments {
+        if !value_path.trim().is_empty() {
+            let acc = path_accumulator(paths, value_path);
             acc.referenced = true;
-            acc.fallback_type_hints.extend(schema_types);
+            acc.facts.record_facts(ContractValuePathFacts {
+                accepted_values_root_fragment: true,
+                accepted_dependency_values_root_fragment: true,
+                ..ContractValuePathFacts::default()
+            });
+            // Helm's dependency coalescing type-asserts every loaded
+            // dependency's values root BEFORE any rendering and regardless
+            // of the dependency's own activation: a present non-table
+            // aborts with "type mismatch on <name>" (verified against a
+            // condition-disabled dependency). The chart declares the key
+            // as a mapping, so a user null is deleted by the values
+            // coalesce that runs first and reaches the check as absent —
+            // hence the null-tolerant form.
+            let requires_table = ContractFailImplication {
+                outer_guards: Vec::new(),
+                target: ContractRequirementTarget::Value,
+                requirements: vec![FailValueRequirement::SchemaType("object".to_string())],
+                messages: FailMessages::default(),
+            };
+            ContractFailImplication::push_unique(&mut acc.fail_implications, requires_table);
         }
     }
-    // Branch-scoped fallback hints stay fallback-grade: they may type a
-    // conditional overlay, but never one whose renders all totally format
-    //.
-    for (value_path, schema_types) in guarded_fallback_type_hints {
+}
+
+/// Records one channel of interpreter type hints into the accumulator set
+/// `select` picks, skipping blank paths and types.
+fn record_type_hint_channel(
+    paths: &mut BTreeMap<String, ContractPathAccumulator>,
+    hints: &BTreeMap<String, BTreeSet<String>>,
+    select: fn(&mut ContractPathAccumulator) -> &mut BTreeSet<String>,
+) {
+    for (value_path, schema_types) in hints {
         let schema_types = schema_types
             .iter()
             .filter(|schema_type| !schema_type.trim().is_empty())
             .cloned()
             .collect::<BTreeSet<_>>();
         if !value_path.trim().is_empty() && !schema_types.is_empty() {
-            let acc = path_accumulator(&mut paths, value_path);
+            let acc = path_accumulator(paths, value_path);
             acc.referenced = true;
-            acc.guarded_fallback_type_hints.extend(schema_types);
+            select(acc).extend(schema_types);
         }
     }
-    finish_schema_signals(paths, terminal_clauses)
 }
@@ -5,8 +5,8 @@ use crate::{Guard, ProviderSchemaUse, ValueKind, contract::ContractUse};
 use helm_schema_core::{
     ApproximationRole, ConditionalGuard, ConditionalOverlayEvidence, ConditionalPathOverlay,
     ContractFailImplication, ContractPathSchemaEvidence, ContractRequirednessEvidence,
-    ContractRequirementTarget, ContractSchemaSignals, ContractValuePathFacts, FailValueRequirement,
-    GuardDnf, GuardValue, MetadataFieldKind, Predicate,
+    ContractRequirementTarget, ContractSchemaSignals, ContractValuePathFacts, FailMessages,
+    FailValueRequirement, GuardDnf, GuardValue, MetadataFieldKind, Predicate,
 };
 
 mod conditional_overlays;
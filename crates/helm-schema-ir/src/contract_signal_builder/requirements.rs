@@ -1,6 +1,6 @@
 use super::{
     BTreeMap, BTreeSet, ConditionalGuard, ContractFailImplication, ContractPathAccumulator,
-    ContractRequirementTarget, FailValueRequirement, Guard, GuardDnf, GuardValue,
+    ContractRequirementTarget, FailMessages, FailValueRequirement, Guard, GuardDnf, GuardValue,
     MemberAccessConditions, Predicate, TruthCondition, has_selection_chain_marker_stamp,
     lowerable_range_outer_guards, member_local_truthy_selector, path_accumulator,
     path_contains_wildcard, predicate_is_truthy_disjunction_over, predicate_skips_falsy_source,
@@ -20,6 +20,7 @@ use super::{
 pub(super) fn record_fail_conjunction(
     paths: &mut BTreeMap<String, ContractPathAccumulator>,
     terminal_clauses: &mut Vec<Vec<ConditionalGuard>>,
+    terminal_clause_messages: &mut BTreeMap<Vec<ConditionalGuard>, FailMessages>,
     capture: &crate::eval_effect::FailCapture,
     range_modes: &crate::range_modes::RangeModes,
 ) {
@@ -319,10 +320,11 @@ pub(super) fn record_fail_conjunction(
         return;
     }
     // An approximate enclosing condition abstains unless it admits a sound
-    // positive strengthening (it can only ever be an OUTER guard — the
-    // requirement extraction below never negates one), and a `$local` name
-    // leaking into predicate paths means the condition lowering lost the
-    // real subject: both make lowering unsound for the whole capture.
+    // positive strengthening: the requirement extraction below treats it as
+    // an OUTER guard, and only `negated_bound_requirements` ever negates
+    // one (length and int-cast bounds). A `$local` name leaking into
+    // predicate paths means the condition lowering lost the real subject.
+    // Either failure makes lowering unsound for the whole capture.
     let conjunction = remove_redundant_approximate_conditions(&capture.conjunction);
     if conjunction.iter().any(|predicate| {
         predicate.contains_approximation() && fail_outer_guard(predicate).is_none()
@@ -561,10 +563,19 @@ pub(super) fn record_fail_conjunction(
             if let Some(mut clause) = clause {
                 clause.sort();
                 clause.dedup();
-                if !clause.is_empty() && !terminal_clauses.contains(&clause) {
-                    terminal_clauses.push(clause);
+                if !clause.is_empty() {
+                    if !capture.messages.is_empty() {
+                        terminal_clause_messages
+                            .entry(clause.clone())
+                            .or_default()
+                            .extend(capture.messages.iter().cloned());
+                    }
+                    if !terminal_clauses.contains(&clause) {
+                        terminal_clauses.push(clause);
+                    }
                 }
             }
+            record_int_cast_bound_implication(paths, conjunction, &capture.messages);
         }
         return;
     }
@@ -608,12 +619,11 @@ pub(super) fn record_fail_conjunction(
             }
         }),
         requirements,
+        messages: capture.messages.clone(),
     };
     let acc = path_accumulator(paths, &target);
     acc.referenced = true;
-    if !acc.fail_implications.contains(&implication) {
-        acc.fail_implications.push(implication);
-    }
+    ContractFailImplication::push_unique(&mut acc.fail_implications, implication);
 }
 
 pub(super) fn record_range_key_prefix_requirement(
@@ -696,12 +706,11 @@ pub(super) fn record_range_key_prefix_requirement(
             prefix: (*prefix).to_string(),
         },
         requirements,
+        messages: FailMessages::default(),
     };
     let acc = path_accumulator(paths, collection_path);
     acc.referenced = true;
-    if !acc.fail_implications.contains(&implication) {
-        acc.fail_implications.push(implication);
-    }
+    ContractFailImplication::push_unique(&mut acc.fail_implications, implication);
     true
 }
 
@@ -789,12 +798,11 @@ pub(super) fn record_range_key_matches_requirement(
         outer_guards,
         target: ContractRequirementTarget::Keys,
         requirements: vec![requirement],
+        messages: FailMessages::default(),
     };
     let acc = path_accumulator(paths, collection_path);
     acc.referenced = true;
-    if !acc.fail_implications.contains(&implication) {
-        acc.fail_implications.push(implication);
-    }
+    ContractFailImplication::push_unique(&mut acc.fail_implications, implication);
     true
 }
 
@@ -1047,12 +1055,11 @@ pub(super) fn record_value_requirement_capture(
                     allow_integer: false,
                 },
                 requirements: vec![requirement],
+                messages: FailMessages::default(),
             };
             let acc = path_accumulator(paths, &collection_path);
             acc.referenced = true;
-            if !acc.fail_implications.contains(&implication) {
-                acc.fail_implications.push(implication);
-            }
+            ContractFailImplication::push_unique(&mut acc.fail_implications, implication);
             return;
         }
     }
@@ -1146,11 +1153,10 @@ pub(super) fn record_value_requirement_capture(
                 },
             },
             requirements: vec![requirement],
+            messages: FailMessages::default(),
         };
         let acc = path_accumulator(paths, collection_path);
-        if !acc.fail_implications.contains(&implication) {
-            acc.fail_implications.push(implication);
-        }
+        ContractFailImplication::push_unique(&mut acc.fail_implications, implication);
         return;
     }
     let (target_path, target, outer_guards) = if let Some(collection_path) = path.strip_suffix(".*")
@@ -1254,12 +1260,11 @@ pub(super) fn record_value_requirement_capture(
         outer_guards,
         target,
         requirements: vec![requirement],
+        messages: FailMessages::default(),
     };
     let acc = path_accumulator(paths, target_path);
     acc.referenced = true;
-    if !acc.fail_implications.contains(&implication) {
-        acc.fail_implications.push(implication);
-    }
+    ContractFailImplication::push_unique(&mut acc.fail_implications, implication);
 }
 
 pub(super) fn record_collection_item_requirements(
@@ -1289,12 +1294,11 @@ pub(super) fn record_collection_item_requirements(
                 allow_integer: false,
             },
             requirements,
+            messages: FailMessages::default(),
         };
         let acc = path_accumulator(paths, path);
         acc.referenced = true;
-        if !acc.fail_implications.contains(&implication) {
-            acc.fail_implications.push(implication);
-        }
+        ContractFailImplication::push_unique(&mut acc.fail_implications, implication);
     }
 }
 
@@ -1374,12 +1378,11 @@ pub(super) fn record_index_access_requirement(
         outer_guards,
         target: ContractRequirementTarget::Value,
         requirements: vec![FailValueRequirement::IndexableAt(index)],
+        messages: FailMessages::default(),
     };
     let acc = path_accumulator(paths, path);
     acc.referenced = true;
-    if !acc.fail_implications.contains(&implication) {
-        acc.fail_implications.push(implication);
-    }
+    ContractFailImplication::push_unique(&mut acc.fail_implications, implication);
 }
 
 pub(super) fn record_split_index_access_requirement(
@@ -1420,12 +1423,11 @@ pub(super) fn record_split_index_access_requirement(
                 segments: index + 1,
                 allow_non_string,
             }],
+            messages: FailMessages::default(),
         };
         let acc = path_accumulator(paths, path);
         acc.referenced = true;
-        if !acc.fail_implications.contains(&implication) {
-            acc.fail_implications.push(implication);
-        }
+        ContractFailImplication::push_unique(&mut acc.fail_implications, implication);
     }
 }
 
@@ -1504,12 +1506,11 @@ pub(super) fn record_member_relative_split_requirement(
             segments: index + 1,
             allow_non_string,
         }],
+        messages: FailMessages::default(),
     };
     let acc = path_accumulator(paths, &collection_path);
     acc.referenced = true;
-    if !acc.fail_implications.contains(&implication) {
-        acc.fail_implications.push(implication);
-    }
+    ContractFailImplication::push_unique(&mut acc.fail_implications, implication);
 }
 
 pub(super) fn record_range_key_string_requirements(
@@ -1536,12 +1537,11 @@ pub(super) fn record_range_key_string_requirements(
             outer_guards,
             target: ContractRequirementTarget::Keys,
             requirements: vec![FailValueRequirement::SchemaType("string".to_string())],
+            messages: FailMessages::default(),
         };
         let acc = path_accumulator(paths, path);
         acc.referenced = true;
-        if !acc.fail_implications.contains(&implication) {
-            acc.fail_implications.push(implication);
-        }
+        ContractFailImplication::push_unique(&mut acc.fail_implications, implication);
     }
 }
 
@@ -1576,13 +1576,73 @@ pub(super) fn record_range_key_plain_slot_requirements(
                 token_initial: true,
                 templated: false,
             }],
+            messages: FailMessages::default(),
         };
         let acc = path_accumulator(paths, path);
         acc.referenced = true;
-        if !acc.fail_implications.contains(&implication) {
-            acc.fail_implications.push(implication);
+        ContractFailImplication::push_unique(&mut acc.fail_implications, implication);
+    }
+}
+
+/// Restate a terminal int-cast comparison as a numeric bound on its path.
+///
+/// The terminal clause already rejects the exact failing region, string
+/// spellings and defaults included, but only as a document-level `not`.
+/// When the conjunction holds exactly one `gt (int x) N` / `lt (int x) N`
+/// test and everything else lowers to outer guards, the negated bound is
+/// also recorded as an implication, so the field itself carries
+/// `minimum`/`maximum`. The bound constrains numbers only and rejects
+/// nothing the clause admits.
+fn record_int_cast_bound_implication(
+    paths: &mut BTreeMap<String, ContractPathAccumulator>,
+    conjunction: &[Predicate],
+    messages: &FailMessages,
+) {
+    let mut bound = None;
+    let mut outer_guards = Vec::new();
+    for predicate in conjunction {
+        if let Predicate::Approximate {
+            sound_subset: Some(subset),
+            ..
+        } = predicate
+            && let Some((path, requirements)) = negated_bound_requirements(subset)
+            && let [
+                requirement @ (FailValueRequirement::IntAtLeast(_)
+                | FailValueRequirement::IntAtMost(_)),
+            ] = requirements.as_slice()
+        {
+            if bound.replace((path, requirement.clone())).is_some() {
+                return;
+            }
+            continue;
         }
+        let Some(guard) = fail_outer_guard(predicate) else {
+            return;
+        };
+        outer_guards.push(guard);
     }
+    let Some((path, requirement)) = bound else {
+        return;
+    };
+    if path_contains_wildcard(path)
+        || outer_guards
+            .iter()
+            .flat_map(ConditionalGuard::value_paths)
+            .any(|guard_path| path_contains_wildcard(&guard_path))
+    {
+        return;
+    }
+    outer_guards.sort();
+    outer_guards.dedup();
+    let implication = ContractFailImplication {
+        outer_guards,
+        target: ContractRequirementTarget::Value,
+        requirements: vec![requirement],
+        messages: messages.clone(),
+    };
+    let acc = path_accumulator(paths, path);
+    acc.referenced = true;
+    ContractFailImplication::push_unique(&mut acc.fail_implications, implication);
 }
 
 /// Whether a conjunct is a structurally negatable failing test. Positive
@@ -1603,10 +1663,102 @@ pub(super) fn predicate_is_negatable_test(predicate: &Predicate) -> bool {
         // member decode: the fallback truthy stand-ins for undecodable
         // conditions ride absolute paths, never wildcard member scopes.
         Predicate::Or(items) => items.iter().all(predicate_is_negatable_test),
+        // Length comparisons keep only their strengthening; see
+        // `negated_bound_requirements`. Int-cast comparisons keep the exact
+        // terminal clause and add their bound beside it
+        // (`record_int_cast_bound_implication`).
+        Predicate::Approximate {
+            sound_subset: Some(subset),
+            ..
+        } => negated_bound_requirements(subset).is_some_and(|(_, requirements)| {
+            requirements.iter().all(|requirement| {
+                matches!(
+                    requirement,
+                    FailValueRequirement::MaxStringLength(_)
+                        | FailValueRequirement::MinStringLength(_)
+                        | FailValueRequirement::MaxItems(_)
+                        | FailValueRequirement::MaxMembers(_)
+                )
+            })
+        }),
         _ => false,
     }
 }
 
+/// The bound requirements a failing comparison's strengthening negates to,
+/// with the path they constrain. A disjunctive strengthening negates to
+/// every disjunct's bound at once.
+///
+/// - `gt (len x) N` strengthens to "`x` matches `^[\s\S]{N+1,}$`, or is a
+///   mapping or list with at least `N+1` entries". The string disjunct
+///   holds only for strings of `N+1` or more characters, all of which fail:
+///   Go counts bytes, never fewer than characters. `len` counts mapping
+///   members and list items exactly, so the collection disjuncts fail too.
+///   The negation is `maxLength`, `maxProperties`, and `maxItems` of `N`.
+/// - `lt (len x) N` strengthens to "`x` matches `^[\s\S]{0,K}$`" with
+///   `4K < N`: no character takes more than four UTF-8 bytes, so all of
+///   those strings fail too. Short collections are left unbounded.
+/// - `gt (int x) N` / `lt (int x) N`, and the inclusive `ge`/`le` forms
+///   that normalize onto them, strengthen to the raw-integer region
+///   [`Guard::IntGt`] / [`Guard::IntLt`]. Numbers reach the comparison
+///   through `int`, which truncates, so the negation bounds the truncated
+///   value; strings and booleans coerce too, but stay unconstrained.
+///
+/// Each requirement thus rejects only inputs that fail to render.
+fn negated_bound_requirements(subset: &Predicate) -> Option<(&str, Vec<FailValueRequirement>)> {
+    let disjuncts = match subset {
+        Predicate::Or(items) => items.as_slice(),
+        subset => std::slice::from_ref(subset),
+    };
+    let mut scope = None;
+    let mut requirements = Vec::new();
+    for disjunct in disjuncts {
+        let Predicate::Guard(guard) = disjunct else {
+            return None;
+        };
+        let (path, requirement) = negated_guard_bound(guard)?;
+        if scope.replace(path).is_some_and(|scope| scope != path) {
+            return None;
+        }
+        requirements.push(requirement);
+    }
+    Some((scope?, requirements))
+}
+
+/// The bound a single strengthening guard negates to.
+fn negated_guard_bound(guard: &Guard) -> Option<(&str, FailValueRequirement)> {
+    match guard {
+        Guard::MatchesPattern {
+            path,
+            pattern,
+            templated: false,
+        } => {
+            let repetition = pattern.strip_prefix("^[\\s\\S]{")?.strip_suffix("}$")?;
+            let requirement = match repetition.split_once(',')? {
+                (minimum, "") => FailValueRequirement::MaxStringLength(
+                    minimum.parse::<u64>().ok()?.checked_sub(1)?,
+                ),
+                ("0", maximum) => FailValueRequirement::MinStringLength(
+                    maximum.parse::<u64>().ok()?.checked_add(1)?,
+                ),
+                _ => return None,
+            };
+            Some((path, requirement))
+        }
+        Guard::MinMembers { path, bound } => Some((
+            path,
+            FailValueRequirement::MaxMembers(u64::try_from(*bound).ok()?.checked_sub(1)?),
+        )),
+        Guard::MinItems { path, bound } => Some((
+            path,
+            FailValueRequirement::MaxItems(u64::try_from(*bound).ok()?.checked_sub(1)?),
+        )),
+        Guard::IntGt { path, bound } => Some((path, FailValueRequirement::IntAtMost(*bound))),
+        Guard::IntLt { path, bound } => Some((path, FailValueRequirement::IntAtLeast(*bound))),
+        _ => None,
+    }
+}
+
 /// Requirements implied by the NEGATION of a failing test: the negation
 /// must hold for the value at `scope` (a member scope `p.*` or the path
 /// itself).
@@ -1629,6 +1781,13 @@ pub(super) fn requirements_from_negation(
                 schema_type.clone(),
             )])
         }
+        Predicate::Approximate {
+            sound_subset: Some(subset),
+            ..
+        } => {
+            let (path, requirements) = negated_bound_requirements(subset)?;
+            (path == scope).then_some(requirements)
+        }
         Predicate::Guard(Guard::Absent { path }) => {
             let member = path.strip_prefix(&format!("{scope}."))?;
             (!member.contains('.'))
@@ -1888,12 +2047,11 @@ pub(super) fn record_member_access_capture(
                 },
             },
             requirements: vec![FailValueRequirement::SchemaType("object".to_string())],
+            messages: FailMessages::default(),
         };
         let acc = path_accumulator(paths, parent);
         acc.referenced = true;
-        if !acc.fail_implications.contains(&implication) {
-            acc.fail_implications.push(implication);
-        }
+        ContractFailImplication::push_unique(&mut acc.fail_implications, implication);
         return;
     }
     if path_contains_wildcard(&target) {
@@ -2167,11 +2325,10 @@ pub(super) fn record_member_access_implications(
                         handled_kinds: handled_kinds.clone(),
                         complete_domain,
                     }],
+                    messages: FailMessages::default(),
                 };
                 let acc = path_accumulator(paths, &path);
-                if !acc.fail_implications.contains(&implication) {
-                    acc.fail_implications.push(implication);
-                }
+                ContractFailImplication::push_unique(&mut acc.fail_implications, implication);
             }
         }
 
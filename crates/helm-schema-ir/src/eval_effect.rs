@@ -1,5 +1,8 @@
 use std::collections::{BTreeMap, BTreeSet};
 
+use helm_schema_core::FailMessages;
+use serde::{Deserialize, Serialize};
+
 use crate::abstract_value::AbstractValue;
 use crate::fragment_eval::ValueRead;
 use crate::helper_meta::{HelperOutputMeta, RenderedRow, insert_type_hint};
@@ -175,7 +178,7 @@ pub(crate) struct MemberHostConversion {
 /// conjunction as [`helm_schema_core::Predicate::Approximate`] conjuncts,
 /// so the negation can abstain instead of manufacturing requirements the
 /// chart never stated.
-#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
 pub(crate) struct FailCapture {
     pub(crate) conjunction: Vec<helm_schema_core::Predicate>,
     /// The range facts active at the capture site. Input identity says which
@@ -183,12 +186,17 @@ pub(crate) struct FailCapture {
     /// supplies the values-backed members of a possibly derived iterable.
     pub(crate) ranged: crate::range_modes::RangeModes,
     pub(crate) kind: CaptureKind,
+    /// The literal messages of the `fail` or `required` calls this capture
+    /// stems from. Captures differing only here are one failure, so
+    /// [`push_fail_capture`] merges them instead of keeping both.
+    #[serde(default)]
+    pub(crate) messages: FailMessages,
 }
 
 /// How a [`FailCapture`]'s conjunction lowers into schema requirements.
 /// The variants select mutually exclusive lowering paths in the signal
 /// builder; the payloads exist only for their variant's lane.
-#[derive(Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
 pub(crate) enum CaptureKind {
     /// A direct `fail`-style capture: the failing TEST conjunct is negated
     /// wherever the outer guards hold.
@@ -316,6 +324,46 @@ impl FailCapture {
             .iter()
             .any(helm_schema_core::Predicate::contains_approximation)
     }
+
+    /// Whether both captures describe the same failure, messages aside.
+    fn same_failure(&self, other: &Self) -> bool {
+        self.conjunction == other.conjunction
+            && self.ranged == other.ranged
+            && self.kind == other.kind
+    }
+}
+
+/// Record `capture` once: an already recorded capture of the same failure
+/// absorbs its messages instead.
+pub(crate) fn push_fail_capture(captures: &mut Vec<FailCapture>, capture: FailCapture) {
+    if let Some(existing) = captures
+        .iter_mut()
+        .find(|existing| existing.same_failure(&capture))
+    {
+        existing.messages.extend(capture.messages);
+    } else {
+        captures.push(capture);
+    }
+}
+
+/// Sort `captures` and collapse every run describing the same failure into
+/// one capture carrying all of the run's messages. The sort is stable and
+/// ignores messages, so a run keeps them in the order they were raised.
+pub(crate) fn sort_dedup_fail_captures(captures: &mut Vec<FailCapture>) {
+    captures.sort_by(|left, right| {
+        (&left.conjunction, &left.ranged, &left.kind).cmp(&(
+            &right.conjunction,
+            &right.ranged,
+            &right.kind,
+        ))
+    });
+    captures.dedup_by(|later, earlier| {
+        if !earlier.same_failure(later) {
+            return false;
+        }
+        earlier.messages.append(&mut later.messages);
+        true
+    });
 }
 
 impl CaptureKind {
@@ -517,14 +565,10 @@ impl Effects {
         );
         self.helper_suppressed_paths.extend(helper_suppressed_paths);
         for condition in helper_fails {
-            if !self.helper_fails.contains(&condition) {
-                self.helper_fails.push(condition);
-            }
+            push_fail_capture(&mut self.helper_fails, condition);
         }
         for condition in helper_text_fails {
-            if !self.helper_text_fails.contains(&condition) {
-                self.helper_text_fails.push(condition);
-            }
+            push_fail_capture(&mut self.helper_text_fails, condition);
         }
         self.member_host_conversions.extend(member_host_conversions);
         for (path, hints) in type_hints {
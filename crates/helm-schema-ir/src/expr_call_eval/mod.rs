@@ -806,9 +806,12 @@ fn eval_direct_invocation(
             record_string_call_consumers("lookup", args, env, resolver, &mut effects);
             // `lookup` returns cluster state selected by its arguments, not
             // any argument's runtime value. Keep argument evaluation and
-            // strict string contracts as dependencies while leaving the
-            // external map's contents unknown to downstream sinks.
-            EvalResult::with_effects(Some(AbstractValue::Unknown), effects)
+            // strict string contracts as dependencies; the external map is
+            // known only under a configured cluster state.
+            let value = cluster_lookup_query(args, env, resolver)
+                .and_then(|query| resolver.resolve_cluster_lookup(&query))
+                .unwrap_or(AbstractValue::Unknown);
+            EvalResult::with_effects(Some(value), effects)
         }
         "cat" => eval_cat(args, env, resolver),
         "index" => eval_index(args, false, env, resolver),
@@ -1732,6 +1735,31 @@ fn merge_arg_effects(
     }
 }
 
+/// The `lookup` selector, when its `apiVersion` and `kind` are exact strings.
+/// A namespace or name that is not one exact string stays open.
+fn cluster_lookup_query(
+    args: &[TemplateExpr],
+    env: &EvalEnv,
+    resolver: &mut impl HelperCallValueResolver,
+) -> Option<helm_schema_core::ClusterLookupQuery> {
+    let mut exact =
+        args.iter().map(
+            |arg| match eval_expr_with_helper_calls(arg, env, resolver).value {
+                Some(AbstractValue::StringSet(mut strings)) if strings.len() == 1 => {
+                    strings.pop_first()
+                }
+                _ => None,
+            },
+        );
+    let [api_version, kind, namespace, name] = std::array::from_fn(|_| exact.next().flatten());
+    Some(helm_schema_core::ClusterLookupQuery {
+        api_version: api_version?,
+        kind: kind?,
+        namespace,
+        name,
+    })
+}
+
 /// A call without a transfer function widens: the value is unknown, but every
 /// path that flowed into the call (including a piped value's effects) still
 /// influences the result.
@@ -7,7 +7,7 @@ use crate::eval_effect::{Effects, EvalResult};
 use crate::eval_env::EvalEnv;
 use crate::expr_eval::{HelperCallValueResolver, eval_expr_with_helper_calls};
 use crate::scalar_value::ScalarValue;
-use helm_schema_core::Predicate;
+use helm_schema_core::{FailMessages, Predicate};
 
 use super::serialization::record_total_conversion_effects;
 use super::value_facts::{identity_range_key_paths, identity_value_paths};
@@ -471,10 +471,9 @@ pub(super) fn record_raw_range_key_string_consumer_paths(
             kind: crate::eval_effect::CaptureKind::RangeKeyStrings {
                 paths: raw_paths.clone(),
             },
+            messages: FailMessages::default(),
         };
-        if !effects.helper_fails.contains(&capture) {
-            effects.helper_fails.push(capture);
-        }
+        crate::eval_effect::push_fail_capture(&mut effects.helper_fails, capture);
     }
     effects
         .derived_range_key_paths
@@ -562,10 +561,9 @@ pub(super) fn record_operand_presence_result(operand: &EvalResult, effects: &mut
             conjunction,
             ranged: crate::range_modes::RangeModes::default(),
             kind: crate::eval_effect::CaptureKind::AbsenceAborts { path: path.clone() },
+            messages: FailMessages::default(),
         };
-        if !effects.helper_fails.contains(&capture) {
-            effects.helper_fails.push(capture);
-        }
+        crate::eval_effect::push_fail_capture(&mut effects.helper_fails, capture);
     }
 }
 
@@ -595,10 +593,9 @@ pub(super) fn record_comparable_kind_result(
                         path: path.clone(),
                         schema_type: schema_type.to_string(),
                     },
+                    messages: FailMessages::default(),
                 };
-                if !effects.helper_fails.contains(&capture) {
-                    effects.helper_fails.push(capture);
-                }
+                crate::eval_effect::push_fail_capture(&mut effects.helper_fails, capture);
             }
         }
         return;
@@ -613,10 +610,9 @@ pub(super) fn record_comparable_kind_result(
                     path: path.clone(),
                     schema_type: schema_type.to_string(),
                 },
+                messages: FailMessages::default(),
             };
-            if !effects.helper_fails.contains(&capture) {
-                effects.helper_fails.push(capture);
-            }
+            crate::eval_effect::push_fail_capture(&mut effects.helper_fails, capture);
         }
     }
 }
@@ -719,10 +715,9 @@ pub(super) fn record_collection_item_kind_result(
                     schema_type: schema_type.to_string(),
                     pattern: pattern.map(str::to_string),
                 },
+                messages: FailMessages::default(),
             };
-            if !effects.helper_fails.contains(&capture) {
-                effects.helper_fails.push(capture);
-            }
+            crate::eval_effect::push_fail_capture(&mut effects.helper_fails, capture);
         }
     }
     for path in individual_paths {
@@ -765,10 +760,9 @@ pub(super) fn push_fail_capture(conjunction: Vec<Predicate>, effects: &mut Effec
         conjunction,
         ranged: crate::range_modes::RangeModes::default(),
         kind: crate::eval_effect::CaptureKind::Fail,
+        messages: FailMessages::default(),
     };
-    if !effects.helper_fails.contains(&capture) {
-        effects.helper_fails.push(capture);
-    }
+    crate::eval_effect::push_fail_capture(&mut effects.helper_fails, capture);
 }
 
 pub(super) fn push_value_type_capture(
@@ -786,10 +780,9 @@ pub(super) fn push_value_type_capture(
             schema_type,
             null_aborts,
         },
+        messages: FailMessages::default(),
     };
-    if !effects.helper_fails.contains(&capture) {
-        effects.helper_fails.push(capture);
-    }
+    crate::eval_effect::push_fail_capture(&mut effects.helper_fails, capture);
 }
 
 fn push_value_pattern_capture(
@@ -807,10 +800,9 @@ fn push_value_pattern_capture(
             pattern,
             templated,
         },
+        messages: FailMessages::default(),
     };
-    if !effects.helper_fails.contains(&capture) {
-        effects.helper_fails.push(capture);
-    }
+    crate::eval_effect::push_fail_capture(&mut effects.helper_fails, capture);
 }
 
 fn strict_operand_path_is_clean(path: &str, effects: &Effects) -> bool {
@@ -7,7 +7,7 @@ use crate::eval_effect::{Effects, EvalResult};
 use crate::eval_env::EvalEnv;
 use crate::expr_eval::{HelperCallValueResolver, eval_expr_with_helper_calls};
 use crate::helper_meta::HelperOutputMeta;
-use helm_schema_core::Predicate;
+use helm_schema_core::{FailMessages, Predicate};
 
 use super::eval_all_args;
 use super::strict_operands::{
@@ -105,12 +105,11 @@ pub(super) fn eval_dig(
                     ],
                     ranged: crate::range_modes::RangeModes::default(),
                     kind: crate::eval_effect::CaptureKind::Fail,
+                    messages: FailMessages::default(),
                 }]
             };
             for capture in captures {
-                if !effects.helper_fails.contains(&capture) {
-                    effects.helper_fails.push(capture);
-                }
+                crate::eval_effect::push_fail_capture(&mut effects.helper_fails, capture);
             }
         }
     }
@@ -147,6 +146,7 @@ fn raw_subject_captures(path: &str) -> Vec<crate::eval_effect::FailCapture> {
             kind: crate::eval_effect::CaptureKind::RequiredPresence {
                 path: path.to_string(),
             },
+            messages: FailMessages::default(),
         },
         crate::eval_effect::FailCapture {
             conjunction: vec![Predicate::from(crate::Guard::HasKey {
@@ -157,6 +157,7 @@ fn raw_subject_captures(path: &str) -> Vec<crate::eval_effect::FailCapture> {
             kind: crate::eval_effect::CaptureKind::DigSubject {
                 path: path.to_string(),
             },
+            messages: FailMessages::default(),
         },
     ]
 }
@@ -177,6 +178,7 @@ fn chain_subject_capture(path: &str) -> crate::eval_effect::FailCapture {
         kind: crate::eval_effect::CaptureKind::DigSubject {
             path: path.to_string(),
         },
+        messages: FailMessages::default(),
     }
 }
 
@@ -266,10 +268,9 @@ pub(super) fn eval_index(
                                 index,
                                 total_text_preimage: *total_text_preimage,
                             },
+                            messages: FailMessages::default(),
                         };
-                        if !effects.helper_fails.contains(&capture) {
-                            effects.helper_fails.push(capture);
-                        }
+                        crate::eval_effect::push_fail_capture(&mut effects.helper_fails, capture);
                     }
                     for path in identity_value_paths(Some(value)) {
                         for conjunction in
@@ -282,10 +283,12 @@ pub(super) fn eval_index(
                                     path: path.clone(),
                                     index,
                                 },
+                                messages: FailMessages::default(),
                             };
-                            if !effects.helper_fails.contains(&capture) {
-                                effects.helper_fails.push(capture);
-                            }
+                            crate::eval_effect::push_fail_capture(
+                                &mut effects.helper_fails,
+                                capture,
+                            );
                         }
                     }
                 }
@@ -340,10 +343,9 @@ pub(super) fn record_member_host_access(operand: &EvalResult, effects: &mut Effe
                 kind: crate::eval_effect::CaptureKind::MemberAccess {
                     handled_kinds: BTreeSet::new(),
                 },
+                messages: FailMessages::default(),
             };
-            if !effects.helper_fails.contains(&capture) {
-                effects.helper_fails.push(capture);
-            }
+            crate::eval_effect::push_fail_capture(&mut effects.helper_fails, capture);
         }
     }
 }
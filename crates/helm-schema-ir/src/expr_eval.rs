@@ -8,7 +8,7 @@ use crate::eval_env::EvalEnv;
 use crate::expr_call_eval::{eval_call_with_helper_calls, eval_pipeline_with_helper_calls};
 use crate::function_semantics::{CollectionShape, function_semantics};
 use crate::scalar_value::{ScalarValueDispatch, TruthCondition};
-use helm_schema_core::Predicate;
+use helm_schema_core::{FailMessages, Predicate};
 
 pub(crate) trait HelperCallValueResolver {
     fn resolve_helper_call(&mut self, name: &str, arg: Option<&TemplateExpr>)
@@ -21,6 +21,14 @@ pub(crate) trait HelperCallValueResolver {
     ) -> Option<EvalResult> {
         None
     }
+
+    /// The value a `lookup` call returns under the configured cluster state.
+    fn resolve_cluster_lookup(
+        &mut self,
+        _query: &helm_schema_core::ClusterLookupQuery,
+    ) -> Option<AbstractValue> {
+        None
+    }
 }
 
 struct NoHelperCallResolver;
@@ -163,10 +171,9 @@ fn record_member_host_capture(
         conjunction,
         ranged: crate::range_modes::RangeModes::default(),
         kind: crate::eval_effect::CaptureKind::MemberAccess { handled_kinds },
+        messages: FailMessages::default(),
     };
-    if !effects.helper_fails.contains(&capture) {
-        effects.helper_fails.push(capture);
-    }
+    crate::eval_effect::push_fail_capture(&mut effects.helper_fails, capture);
 }
 
 #[expect(
@@ -15,7 +15,7 @@ use crate::eval_effect::{SelectionTruthReachability, SelectionTruthSource};
 use crate::scalar_value::{ScalarValue, ScalarValueDispatch, TruthCondition, any_predicates};
 use crate::value_path_context::{guard_value_is_truthy, predicate_any};
 use crate::{Guard, ValueKind};
-use helm_schema_core::{GuardValue, Predicate};
+use helm_schema_core::{FailMessages, GuardValue, Predicate};
 
 use super::domain::{AbstractFragment, Guarded, PathCondition, Splice, SpliceMeta, and_conditions};
 use super::eval::{Adopted, ArmSpec, Contributions, Interpreter, NodeView};
@@ -637,6 +637,7 @@ impl Interpreter<'_> {
                         schema_type: "string".to_string(),
                         null_aborts: false,
                     },
+                    messages: FailMessages::default(),
                 }
             })
             .collect();
@@ -662,6 +663,7 @@ impl Interpreter<'_> {
                 conjunction: Vec::new(),
                 ranged: crate::range_modes::RangeModes::default(),
                 kind: crate::eval_effect::CaptureKind::AbsenceAborts { path: path.clone() },
+                messages: FailMessages::default(),
             })
             .collect();
         self.absorb_helper_fails(&captures);
@@ -1083,14 +1085,14 @@ impl Interpreter<'_> {
                     destructured,
                     json_decoded: identity.json_decoded,
                 },
+                messages: FailMessages::default(),
             };
             if !capture
                 .conjunction
                 .iter()
                 .any(|predicate| matches!(predicate, Predicate::False))
-                && !self.fail_conditions.contains(&capture)
             {
-                self.fail_conditions.push(capture);
+                crate::eval_effect::push_fail_capture(&mut self.fail_conditions, capture);
             }
         }
         self.record_selection_range_captures(iterable_value.as_ref(), destructured);
@@ -179,6 +179,7 @@ fn fmt_guard(guard: &Guard) -> String {
         }
         Guard::AtMostOneMember { path } => format!("atMostOneMember({path})"),
         Guard::MinMembers { path, bound } => format!("minMembers({path} >= {bound})"),
+        Guard::MinItems { path, bound } => format!("minItems({path} >= {bound})"),
         Guard::HasKey { path, key } => format!("hasKey({path}: {key})"),
         Guard::NotHasKey { path, key } => format!("notHasKey({path}: {key})"),
         Guard::ContainsEquals { path, value } => format!("containsEquals({path} ∋ {value})"),
@@ -60,7 +60,7 @@ use crate::scalar_value::{ScalarValueDispatch, TruthCondition};
 use crate::symbolic_local_state::SymbolicLocalState;
 use crate::value_path_context::ValuePathContext;
 use crate::{ContractProvenance, Guard, ResourceRef, SourceSpan};
-use helm_schema_core::{GuardDnf, Predicate};
+use helm_schema_core::{FailMessages, GuardDnf, Predicate};
 
 use super::domain::{
     AbstractFragment, AbstractString, EntryKey, Guarded, Mapping, MappingEntry, Opaque,
@@ -1011,11 +1011,12 @@ impl<'a> Interpreter<'a> {
     /// satisfy them. The RAW predicates are kept — the guard-DNF
     /// conversion drops conjuncts it cannot represent, which negation
     /// cannot tolerate.
-    pub(super) fn record_fail_condition(&mut self) {
+    pub(super) fn record_fail_condition(&mut self, message: Option<String>) {
         let capture = FailCapture {
             conjunction: self.fail_capture_conjunction(Vec::new()),
             ranged: self.capture_ranged_modes(),
             kind: CaptureKind::Fail,
+            messages: message.into_iter().collect(),
         };
         if capture
             .conjunction
@@ -1024,15 +1025,17 @@ impl<'a> Interpreter<'a> {
         {
             return;
         }
-        if !self.fail_conditions.contains(&capture) {
-            self.fail_conditions.push(capture);
-        }
+        crate::eval_effect::push_fail_capture(&mut self.fail_conditions, capture);
     }
 
     /// Record a `required(message, subject)` guardrail: rendering fails
     /// under the ambient predicates whenever the subject is Helm-empty
     /// (absent, null, or the empty string).
-    pub(super) fn record_required_condition(&mut self, subject_path: &str) {
+    pub(super) fn record_required_condition(
+        &mut self,
+        subject_path: &str,
+        message: Option<String>,
+    ) {
         let empty = Predicate::Or(vec![
             Predicate::from(Guard::Absent {
                 path: subject_path.to_string(),
@@ -1050,6 +1053,7 @@ impl<'a> Interpreter<'a> {
             conjunction: self.fail_capture_conjunction(vec![empty]),
             ranged: self.capture_ranged_modes(),
             kind: CaptureKind::Fail,
+            messages: message.into_iter().collect(),
         };
         if capture
             .conjunction
@@ -1058,9 +1062,7 @@ impl<'a> Interpreter<'a> {
         {
             return;
         }
-        if !self.fail_conditions.contains(&capture) {
-            self.fail_conditions.push(capture);
-        }
+        crate::eval_effect::push_fail_capture(&mut self.fail_conditions, capture);
     }
 
     /// The ambient predicates plus `tail`.
@@ -1238,10 +1240,9 @@ impl<'a> Interpreter<'a> {
                 kind: crate::eval_effect::CaptureKind::RangeKeyPlainSlot {
                     paths: [splice.values_path.clone()].into_iter().collect(),
                 },
+                messages: FailMessages::default(),
             };
-            if !self.fail_conditions.contains(&capture) {
-                self.fail_conditions.push(capture);
-            }
+            crate::eval_effect::push_fail_capture(&mut self.fail_conditions, capture);
         }
         for part in &string.parts {
             match part {
@@ -1395,9 +1396,7 @@ impl<'a> Interpreter<'a> {
 
     pub(super) fn absorb_helper_fails(&mut self, fails: &[FailCapture]) {
         for capture in self.scope_helper_fails(fails) {
-            if !self.fail_conditions.contains(&capture) {
-                self.fail_conditions.push(capture);
-            }
+            crate::eval_effect::push_fail_capture(&mut self.fail_conditions, capture);
         }
     }
 
@@ -1411,9 +1410,7 @@ impl<'a> Interpreter<'a> {
             return;
         }
         for capture in self.scope_helper_fails(fails) {
-            if !self.text_fails.contains(&capture) {
-                self.text_fails.push(capture);
-            }
+            crate::eval_effect::push_fail_capture(&mut self.text_fails, capture);
         }
     }
 
@@ -1455,6 +1452,7 @@ impl<'a> Interpreter<'a> {
                 conjunction,
                 ranged,
                 kind,
+                messages: body_capture.messages.clone(),
             };
             if capture
                 .conjunction
@@ -210,9 +210,7 @@ impl Interpreter<'_> {
             .extend(nested.string_contract_paths);
         self.range_modes.merge(&nested.range_modes);
         for capture in nested.fail_conditions {
-            if !self.fail_conditions.contains(&capture) {
-                self.fail_conditions.push(capture);
-            }
+            crate::eval_effect::push_fail_capture(&mut self.fail_conditions, capture);
         }
         self.absorb_member_host_conversions(&nested.member_host_conversions);
         self.apply_root_set_mutations(
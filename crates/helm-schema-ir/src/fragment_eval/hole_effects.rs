@@ -40,13 +40,22 @@ pub(super) enum RenderedDemotion {
 
 /// The subject expressions of every `required(message, subject)` call in
 /// an expression, including the piped form (`subject | required "msg"`,
-/// where the piped value arrives as the trailing argument).
-pub(super) fn required_call_subjects(expr: &TemplateExpr) -> Vec<&TemplateExpr> {
+/// where the piped value arrives as the trailing argument), each with the
+/// call's message when it is a string literal.
+pub(super) fn required_call_subjects(expr: &TemplateExpr) -> Vec<(&TemplateExpr, Option<&str>)> {
     let mut subjects = Vec::new();
     collect_required_subjects(expr, &mut subjects);
     subjects
 }
 
+/// The message of a `fail` or `required` call when it is a string literal.
+pub(super) fn literal_call_message(message: &TemplateExpr) -> Option<&str> {
+    match message.deparen() {
+        TemplateExpr::Literal(literal) => literal.as_string(),
+        _ => None,
+    }
+}
+
 fn guard_gates_hint(guard: &Guard, path: &str) -> bool {
     let foreign = |guard_path: &str| {
         guard_path != path
@@ -80,6 +89,9 @@ fn guard_gates_hint(guard: &Guard, path: &str) -> bool {
         | Guard::MinMembers {
             path: guard_path, ..
         }
+        | Guard::MinItems {
+            path: guard_path, ..
+        }
         | Guard::HasKey {
             path: guard_path, ..
         }
@@ -155,14 +167,14 @@ pub(super) fn subject_is_statically_helm_empty(expr: &TemplateExpr) -> bool {
 
 pub(super) fn collect_required_subjects<'e>(
     expr: &'e TemplateExpr,
-    out: &mut Vec<&'e TemplateExpr>,
+    out: &mut Vec<(&'e TemplateExpr, Option<&'e str>)>,
 ) {
     match expr {
         TemplateExpr::Call { function, args } => {
             if function == "required"
-                && let [_, subject] = args.as_slice()
+                && let [message, subject] = args.as_slice()
             {
-                out.push(subject);
+                out.push((subject, literal_call_message(message)));
             }
             for arg in args {
                 collect_required_subjects(arg, out);
@@ -172,10 +184,10 @@ pub(super) fn collect_required_subjects<'e>(
             for (index, stage) in stages.iter().enumerate() {
                 if let TemplateExpr::Call { function, args } = stage
                     && function == "required"
-                    && args.len() == 1
+                    && let [message] = args.as_slice()
                     && let Some(previous) = index.checked_sub(1).and_then(|index| stages.get(index))
                 {
-                    out.push(previous);
+                    out.push((previous, literal_call_message(message)));
                 }
                 collect_required_subjects(stage, out);
             }
@@ -308,31 +320,32 @@ impl Interpreter<'_> {
     /// subjects attach per-member requirements.
     pub(super) fn record_required_subjects(&mut self, exprs: &[TemplateExpr]) {
         let mut subject_paths = Vec::new();
-        let mut statically_empty = false;
+        let mut statically_empty = None;
         {
             let context = self.value_path_context();
             for expr in exprs {
-                for subject in required_call_subjects(expr) {
+                for (subject, message) in required_call_subjects(expr) {
+                    let message = message.map(str::to_string);
                     // `required "msg" nil` (and its `index (dict) …`
                     // spellings) is a pure validator: whenever control
                     // reaches it, rendering terminates, so the ambient
                     // predicates form a terminal clause.
                     if subject_is_statically_helm_empty(subject) {
-                        statically_empty = true;
+                        statically_empty.get_or_insert(message);
                         continue;
                     }
                     let paths = context.paths_for_expr(subject);
                     if paths.len() == 1 {
-                        subject_paths.extend(paths);
+                        subject_paths.extend(paths.into_iter().map(|path| (path, message.clone())));
                     }
                 }
             }
         }
-        if statically_empty {
-            self.record_fail_condition();
+        if let Some(message) = statically_empty {
+            self.record_fail_condition(message);
         }
-        for path in subject_paths {
-            self.record_required_condition(&path);
+        for (path, message) in subject_paths {
+            self.record_required_condition(&path, message);
         }
     }
 
@@ -17,7 +17,7 @@ use crate::fragment_expr_eval::FragmentEvalContext;
 use crate::helper_meta::merge_rendered_row_meta;
 use crate::scalar_value::{ScalarValueDispatch, TruthCondition};
 use crate::{Guard, ValueKind};
-use helm_schema_core::Predicate;
+use helm_schema_core::{FailMessages, Predicate};
 
 use super::domain::{
     AbstractFragment, AbstractString, Guarded, PathCondition, StringPart, and_conditions,
@@ -54,6 +54,25 @@ pub(super) fn expr_contains_fail_call(expr: &TemplateExpr) -> bool {
     found
 }
 
+/// The literal message of the first `fail` call in the expressions.
+pub(super) fn fail_call_message(exprs: &[TemplateExpr]) -> Option<String> {
+    let mut message = None;
+    for expr in exprs {
+        expr.walk(|inner| {
+            if let TemplateExpr::Call { function, args } = inner
+                && function == "fail"
+                && message.is_none()
+            {
+                message = args
+                    .first()
+                    .and_then(super::hole_effects::literal_call_message)
+                    .map(str::to_string);
+            }
+        });
+    }
+    message
+}
+
 /// One layout segment of a scalar run: literal text, a template hole, or a
 /// whole inline control region (grouping the region's holes and texts).
 enum Segment {
@@ -287,7 +306,7 @@ impl Interpreter<'_> {
         // A `fail` hole terminates rendering: no valid values document may
         // satisfy the guards active here, and the action renders nothing.
         if exprs.iter().any(expr_contains_fail_call) {
-            self.record_fail_condition();
+            self.record_fail_condition(fail_call_message(&exprs));
             self.restore_site(previous_site);
             return (Guarded::empty(), None);
         }
@@ -452,10 +471,9 @@ impl Interpreter<'_> {
                 path: path.to_string(),
                 schema_type: "object".to_string(),
             },
+            messages: FailMessages::default(),
         };
-        if !self.fail_conditions.contains(&capture) {
-            self.fail_conditions.push(capture);
-        }
+        crate::eval_effect::push_fail_capture(&mut self.fail_conditions, capture);
     }
 
     /// Claim the unquoted-slot lexical language for the text an UNQUOTED
@@ -521,66 +539,10 @@ impl Interpreter<'_> {
                 conjunction: Vec::new(),
                 ranged: crate::range_modes::RangeModes::default(),
                 kind,
+                messages: FailMessages::default(),
             })
             .collect();
-        let mut formatted_paths = effects.plain_slot_string_format_paths.clone();
-        let mut formatted_meta = value
-            .map(AbstractValue::plain_slot_string_format_meta)
-            .unwrap_or_default();
-        for (path, meta) in &effects.local_output_meta {
-            if meta.plain_slot_string_format {
-                formatted_meta.entry(path.clone()).or_default().merge(meta);
-            }
-        }
-        for (path, meta) in &formatted_meta {
-            if meta.plain_slot_string_format && !meta.partial_text {
-                formatted_paths.insert(path.clone());
-            }
-        }
-        // `printf` itself erases the operand's structural shape, but a token-
-        // opening `%s` still renders that operand's characters. Later encoders
-        // and YAML serializers clear or block the formatter channel.
-        formatted_paths.retain(|path| {
-            !effects.encoded_paths.contains(path) && !effects.yaml_serialized_paths.contains(path)
-        });
-        for path in formatted_paths {
-            let mut shared = BTreeSet::new();
-            if formatted_meta
-                .get(&path)
-                .is_none_or(|meta| meta.predicates.is_empty())
-                && (effects.defaults.contains(&path) || effects.local_default_paths.contains(&path))
-            {
-                shared.insert(Predicate::truthy_path(path.clone()));
-            }
-            let branches = formatted_meta
-                .get(&path)
-                .filter(|meta| !meta.predicates.is_empty())
-                .map_or_else(
-                    || vec![shared.clone()],
-                    |meta| {
-                        meta.predicates
-                            .iter()
-                            .map(|branch| {
-                                let mut conjunction = shared.clone();
-                                conjunction.extend(branch.iter().cloned());
-                                conjunction
-                            })
-                            .collect()
-                    },
-                );
-            for conjunction in branches {
-                for kind in [
-                    crate::eval_effect::CaptureKind::PrintfStringOperand { path: path.clone() },
-                    crate::eval_effect::CaptureKind::AbsenceAborts { path: path.clone() },
-                ] {
-                    captures.push(crate::eval_effect::FailCapture {
-                        conjunction: conjunction.iter().cloned().collect(),
-                        ranged: crate::range_modes::RangeModes::default(),
-                        kind,
-                    });
-                }
-            }
-        }
+        captures.extend(plain_slot_format_captures(value, effects));
         if !captures.is_empty() {
             self.record_yaml_text_fails(&captures);
         }
@@ -753,7 +715,7 @@ impl Interpreter<'_> {
             return Vec::new();
         }
         if exprs.iter().any(expr_contains_fail_call) {
-            self.record_fail_condition();
+            self.record_fail_condition(fail_call_message(&exprs));
             self.restore_site(previous_site);
             return Vec::new();
         }
@@ -1130,6 +1092,7 @@ impl Interpreter<'_> {
                 })],
                 ranged: crate::range_modes::RangeModes::default(),
                 kind: crate::eval_effect::CaptureKind::Fail,
+                messages: FailMessages::default(),
             });
         }
         for (paths, style, templated) in [
@@ -1163,6 +1126,7 @@ impl Interpreter<'_> {
                         style,
                         templated,
                     },
+                    messages: FailMessages::default(),
                 });
             }
         }
@@ -1182,6 +1146,7 @@ impl Interpreter<'_> {
                     token_initial: false,
                     templated: true,
                 },
+                messages: FailMessages::default(),
             });
         }
         if !captures.is_empty() {
@@ -1347,7 +1312,7 @@ impl Interpreter<'_> {
         }
         let previous_site = self.enter_hole_site(span);
         if exprs.iter().any(expr_contains_fail_call) {
-            self.record_fail_condition();
+            self.record_fail_condition(fail_call_message(&exprs));
             self.restore_site(previous_site);
             return;
         }
@@ -1367,3 +1332,72 @@ impl Interpreter<'_> {
         self.restore_site(previous_site);
     }
 }
+
+/// Captures for `printf` operands a plain slot renders through a
+/// token-opening `%s`: the operand must be a string, and present.
+fn plain_slot_format_captures(
+    value: Option<&AbstractValue>,
+    effects: &Effects,
+) -> Vec<crate::eval_effect::FailCapture> {
+    let mut captures = Vec::new();
+    let mut formatted_paths = effects.plain_slot_string_format_paths.clone();
+    let mut formatted_meta = value
+        .map(AbstractValue::plain_slot_string_format_meta)
+        .unwrap_or_default();
+    for (path, meta) in &effects.local_output_meta {
+        if meta.plain_slot_string_format {
+            formatted_meta.entry(path.clone()).or_default().merge(meta);
+        }
+    }
+    for (path, meta) in &formatted_meta {
+        if meta.plain_slot_string_format && !meta.partial_text {
+            formatted_paths.insert(path.clone());
+        }
+    }
+    // `printf` itself erases the operand's structural shape, but a token-
+    // opening `%s` still renders that operand's characters. Later encoders
+    // and YAML serializers clear or block the formatter channel.
+    formatted_paths.retain(|path| {
+        !effects.encoded_paths.contains(path) && !effects.yaml_serialized_paths.contains(path)
+    });
+    for path in formatted_paths {
+        let mut shared = BTreeSet::new();
+        if formatted_meta
+            .get(&path)
+            .is_none_or(|meta| meta.predicates.is_empty())
+            && (effects.defaults.contains(&path) || effects.local_default_paths.contains(&path))
+        {
+            shared.insert(Predicate::truthy_path(path.clone()));
+        }
+        let branches = formatted_meta
+            .get(&path)
+            .filter(|meta| !meta.predicates.is_empty())
+            .map_or_else(
+                || vec![shared.clone()],
+                |meta| {
+                    meta.predicates
+                        .iter()
+                        .map(|branch| {
+                            let mut conjunction = shared.clone();
+                            conjunction.extend(branch.iter().cloned());
+                            conjunction
+                        })
+                        .collect()
+                },
+            );
+        for conjunction in branches {
+            for kind in [
+                crate::eval_effect::CaptureKind::PrintfStringOperand { path: path.clone() },
+                crate::eval_effect::CaptureKind::AbsenceAborts { path: path.clone() },
+            ] {
+                captures.push(crate::eval_effect::FailCapture {
+                    conjunction: conjunction.iter().cloned().collect(),
+                    ranged: crate::range_modes::RangeModes::default(),
+                    kind,
+                    messages: FailMessages::default(),
+                });
+            }
+        }
+    }
+    captures
+}
@@ -24,7 +24,7 @@ use helm_schema_core::Predicate;
 use super::domain::{PathCondition, StringPart, TaintPart, and_conditions, stamp_part_sites};
 use super::eval::Interpreter;
 use super::hole_effects::RenderedDemotion;
-use super::holes::expr_contains_fail_call;
+use super::holes::{expr_contains_fail_call, fail_call_message};
 use super::lower::{
     LowerScope, MAX_SCALAR_ARM_FANOUT, lower_scalar_dispatch_arms, lower_value_scalar_arms,
 };
@@ -379,14 +379,14 @@ impl Interpreter<'_> {
                     destructured,
                     json_decoded: identity.json_decoded,
                 },
+                messages: helm_schema_core::FailMessages::default(),
             };
             if !capture
                 .conjunction
                 .iter()
                 .any(|predicate| matches!(predicate, Predicate::False))
-                && !self.fail_conditions.contains(&capture)
             {
-                self.fail_conditions.push(capture);
+                crate::eval_effect::push_fail_capture(&mut self.fail_conditions, capture);
             }
         }
     }
@@ -419,14 +419,14 @@ impl Interpreter<'_> {
                     chain: chain.clone(),
                     allow_integer: !destructured,
                 },
+                messages: helm_schema_core::FailMessages::default(),
             };
             if !capture
                 .conjunction
                 .iter()
                 .any(|predicate| matches!(predicate, Predicate::False))
-                && !self.fail_conditions.contains(&capture)
             {
-                self.fail_conditions.push(capture);
+                crate::eval_effect::push_fail_capture(&mut self.fail_conditions, capture);
             }
             prior_falsy.push(Predicate::truthy_path(path.clone()).negated());
         }
@@ -640,7 +640,7 @@ impl Interpreter<'_> {
                 // document may satisfy the guards active here, and the
                 // action renders nothing.
                 if exprs.iter().any(expr_contains_fail_call) {
-                    self.record_fail_condition();
+                    self.record_fail_condition(fail_call_message(&exprs));
                     return Vec::new();
                 }
                 self.record_required_subjects(&exprs);
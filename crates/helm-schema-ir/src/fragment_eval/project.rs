@@ -422,6 +422,7 @@ fn walk_node(
                             kind: crate::eval_effect::CaptureKind::AbsenceAborts {
                                 path: row.source_expr.clone(),
                             },
+                            messages: helm_schema_core::FailMessages::default(),
                         }
                     }));
                 }
@@ -142,6 +142,13 @@ impl HelperCallValueResolver for BoundHelperValueResolver<'_, '_, '_, '_> {
             .to_string();
         self.resolve_helper_call(&name, arg)
     }
+
+    fn resolve_cluster_lookup(
+        &mut self,
+        query: &helm_schema_core::ClusterLookupQuery,
+    ) -> Option<AbstractValue> {
+        self.params.context.analysis_db.cluster_lookup(query)
+    }
 }
 
 impl BoundHelperValueResolver<'_, '_, '_, '_> {
@@ -1,11 +1,13 @@
 use std::collections::BTreeMap;
 
+use serde::{Deserialize, Serialize};
+
 /// How `range` iterates one values path.
 ///
 /// A default (all-false) mode means the path carries no range facts; absent
 /// map entries and default modes are interchangeable, so queries never need
 /// to distinguish "no entry" from "entry with no flags".
-#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
 pub(crate) struct RangeMode {
     /// The iterable itself is this values path (`range .Values.x`).
     pub(crate) input_identity: bool,
@@ -27,7 +29,7 @@ pub(crate) struct RangeMode {
 /// summary, a fail capture, a contract graph): one map instead of three
 /// parallel path sets, so merging, remapping, and copying cannot leave one
 /// flavor behind.
-#[derive(Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
 pub(crate) struct RangeModes {
     modes: BTreeMap<String, RangeMode>,
 }
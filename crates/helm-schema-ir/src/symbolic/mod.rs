@@ -1,7 +1,9 @@
 use std::collections::BTreeMap;
 use std::rc::Rc;
+use std::sync::Arc;
 
 use helm_schema_ast::DefineIndex;
+use helm_schema_core::ClusterStateOracle;
 
 use crate::analysis_db::IrAnalysisDb;
 use crate::contract::ContractIr;
@@ -19,6 +21,9 @@ pub struct SymbolicPolicy {
     pub kubernetes_version: Option<String>,
     /// Exact scalar strings beneath immutable roots such as `.Chart`.
     pub static_root_strings: BTreeMap<Vec<String>, String>,
+    /// Cluster state `lookup` calls resolve against. Without one, `lookup`
+    /// returns an opaque map.
+    pub cluster_state: Option<Arc<dyn ClusterStateOracle>>,
 }
 
 /// Reusable state for generating symbolic IR across many templates that
@@ -596,6 +596,7 @@ fn contract_ir_activation_guards_gate_fail_captures() {
         ],
         ranged: crate::range_modes::RangeModes::default(),
         kind: crate::eval_effect::CaptureKind::Fail,
+        messages: helm_schema_core::FailMessages::default(),
     }]);
 
     contract.append_guards_to_all_uses(&[Guard::Truthy {
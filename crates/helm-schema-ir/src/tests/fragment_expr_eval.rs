@@ -1760,6 +1760,7 @@ fn partial_helper_conditions_keep_typed_subsets_in_both_control_lanes() {
                         handled_kinds: Vec::new(),
                         complete_domain: false,
                     }],
+                    messages: helm_schema_core::FailMessages::default(),
                 },
             ))
         })
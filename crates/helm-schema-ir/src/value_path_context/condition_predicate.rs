@@ -1845,43 +1845,52 @@ impl ValuePathContext<'_> {
         self.negated_membership_sound_subset(expr)
     }
 
-    /// `gt (len .Values.x) N` admits a bounded string strengthening: a
-    /// string of more than N CHARACTERS has more than N bytes, so it always
-    /// satisfies Go's byte-length comparison (cilium's 32-character
-    /// `cluster.name` bound). Long collections also satisfy the guard but
-    /// have no pattern encoding, so they stay outside the subset. The
-    /// inclusive comparators normalize by shifting the bound (traefik's
-    /// `ge (len .Values.hub.token) 65` license gates).
+    /// `gt (len .Values.x) N` admits a bounded strengthening: a string of
+    /// more than N CHARACTERS has more than N bytes, so it always satisfies
+    /// Go's byte-length comparison (cilium's 32-character `cluster.name`
+    /// bound), and `len` counts list items and mapping members exactly, so
+    /// lists and maps with more than N entries satisfy it too. Each kind is
+    /// its own disjunct. The inclusive comparators normalize by shifting
+    /// the bound (traefik's `ge (len .Values.hub.token) 65` license gates).
+    ///
+    /// The below-bound forms (`lt (len x) N`, `le`, and the mirrored
+    /// operand orders) strengthen the same way from the other side: a
+    /// character takes at most four UTF-8 bytes, so a string of K
+    /// characters with `4K < N` is always shorter than N bytes. Short
+    /// collections satisfy those forms too but stay outside the subset, so
+    /// a lower bound only ever constrains strings.
     fn len_comparison_sound_subset(&self, expr: &TemplateExpr) -> Vec<Guard> {
         let TemplateExpr::Call { function, args } = expr.deparen() else {
             return Vec::new();
         };
-        let form = match (function.as_str(), args.as_slice()) {
-            ("gt" | "ge", [left, right]) => match right.deparen() {
-                TemplateExpr::Literal(Literal::Int(bound)) => {
-                    Some((function, left.deparen(), *bound))
-                }
-                _ => None,
-            },
-            ("lt" | "le", [left, right]) => match left.deparen() {
-                TemplateExpr::Literal(Literal::Int(bound)) => {
-                    Some((function, right.deparen(), *bound))
-                }
-                _ => None,
-            },
+        let [left, right] = args.as_slice() else {
+            return Vec::new();
+        };
+        // `(len_expr, B, above)`: the length is strictly above (or, when
+        // `above` is false, strictly below) `B`. The inclusive forms shift
+        // the bound; overflow abstains.
+        let form = match (function.as_str(), left.deparen(), right.deparen()) {
+            ("gt", len_expr, TemplateExpr::Literal(Literal::Int(bound)))
+            | ("lt", TemplateExpr::Literal(Literal::Int(bound)), len_expr) => {
+                Some((len_expr, *bound, true))
+            }
+            ("ge", len_expr, TemplateExpr::Literal(Literal::Int(bound)))
+            | ("le", TemplateExpr::Literal(Literal::Int(bound)), len_expr) => {
+                bound.checked_sub(1).map(|bound| (len_expr, bound, true))
+            }
+            ("lt", len_expr, TemplateExpr::Literal(Literal::Int(bound)))
+            | ("gt", TemplateExpr::Literal(Literal::Int(bound)), len_expr) => {
+                Some((len_expr, *bound, false))
+            }
+            ("le", len_expr, TemplateExpr::Literal(Literal::Int(bound)))
+            | ("ge", TemplateExpr::Literal(Literal::Int(bound)), len_expr) => {
+                bound.checked_add(1).map(|bound| (len_expr, bound, false))
+            }
             _ => None,
         };
-        let Some((function, len_expr, bound)) = form else {
+        let Some((len_expr, bound, above)) = form else {
             return Vec::new();
         };
-        let bound = match function.as_str() {
-            "gt" | "lt" => bound,
-            // `ge (len x) N` ⇔ `gt (len x) (N-1)`; overflow abstains.
-            _ => match bound.checked_sub(1) {
-                Some(bound) => bound,
-                None => return Vec::new(),
-            },
-        };
         let TemplateExpr::Call { function, args } = len_expr else {
             return Vec::new();
         };
@@ -1895,9 +1904,31 @@ impl ValuePathContext<'_> {
         ) else {
             return Vec::new();
         };
+        if above {
+            let Ok(count) = i64::try_from(bound + 1) else {
+                return Vec::new();
+            };
+            let string = Guard::MatchesPattern {
+                path: path.clone(),
+                pattern: format!("^[\\s\\S]{{{},}}$", bound + 1),
+                templated: false,
+            };
+            let members = Guard::MinMembers {
+                path: path.clone(),
+                bound: count,
+            };
+            let items = Guard::MinItems { path, bound: count };
+            return vec![Guard::AnyOf {
+                alternatives: vec![vec![string], vec![members], vec![items]],
+            }];
+        }
+        // No length is below zero.
+        let Some(longest) = bound.checked_sub(1) else {
+            return Vec::new();
+        };
         vec![Guard::MatchesPattern {
             path,
-            pattern: format!("^[\\s\\S]{{{},}}$", bound + 1),
+            pattern: format!("^[\\s\\S]{{0,{}}}$", longest / 4),
             templated: false,
         }]
     }
@@ -2251,6 +2282,11 @@ impl ValuePathContext<'_> {
     /// shifted bound — `ge (int x) N` ⇔ `gt (int x) (N-1)` over int64 —
     /// abstaining when the shift overflows (cilium's `ge (int
     /// .Values.cluster.id) 128` ENI window).
+    ///
+    /// Only cast operands lower. Helm decodes values numbers as float64 and
+    /// Go templates reject comparing a float64 with an int literal, so a raw
+    /// `gt .Values.replicas 0` is a render error for every number a values
+    /// file supplies and bounds nothing.
     fn int_cast_comparison_sound_subset(&self, expr: &TemplateExpr) -> Vec<Guard> {
         self.int_cast_comparison_region_subset(expr, false)
     }
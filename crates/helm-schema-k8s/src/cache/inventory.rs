@@ -0,0 +1,235 @@
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+use serde::Serialize;
+use serde_json::Value;
+
+use super::layout::{LAYOUT_MARKER_FILENAME, not_found_marker_path};
+use super::source_meta::{SourceMeta, content_sha256, meta_sidecar_path, read_meta_sidecar};
+
+const NOT_FOUND_SUFFIX: &str = ".not-found";
+
+/// What a managed cache entry records.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum CacheEntryKind {
+    /// A fetched schema document.
+    Schema,
+    /// An authoritative upstream 404 for the schema at this path.
+    NotFound,
+}
+
+/// One entry of a managed cache root: a schema document or a not-found
+/// marker, with its `.meta` sidecar when one was written.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct CacheEntry {
+    /// Schema path relative to the root, `/`-separated. A not-found
+    /// marker reports the path of the schema it stands for.
+    pub path: String,
+    /// Source namespace: [`super::default_source_id`] or a mirror's id.
+    pub source_id: String,
+    /// Whether the entry holds a document or an absence.
+    pub kind: CacheEntryKind,
+    /// On-disk size of the entry and its sidecar.
+    pub bytes: u64,
+    /// When the entry was last written, i.e. fetched.
+    #[serde(skip)]
+    pub modified: Option<SystemTime>,
+    /// Provenance recorded by `--crd-cache-record-source`.
+    pub meta: Option<SourceMeta>,
+}
+
+impl CacheEntry {
+    fn file(&self, root: &Path) -> PathBuf {
+        let schema = root.join(&self.path);
+        match self.kind {
+            CacheEntryKind::Schema => schema,
+            CacheEntryKind::NotFound => not_found_marker_path(&schema),
+        }
+    }
+}
+
+/// Result of re-checking one schema entry against its sidecar.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+#[serde(tag = "status", rename_all = "kebab-case")]
+pub enum CacheVerification {
+    /// The content hashes to the digest the sidecar recorded.
+    Verified,
+    /// The content no longer matches the recorded digest.
+    Mismatch {
+        /// Digest recorded at fetch time.
+        recorded: String,
+        /// Digest of the content now on disk.
+        actual: String,
+    },
+    /// No sidecar digest to compare against; the content parses as JSON.
+    Unrecorded,
+    /// The content is not valid JSON, so lookups would treat it as a miss.
+    Unparseable,
+    /// The entry could not be read.
+    Unreadable,
+}
+
+impl CacheVerification {
+    /// Whether the entry is known to be damaged.
+    #[must_use]
+    pub fn is_failure(&self) -> bool {
+        !matches!(self, Self::Verified | Self::Unrecorded)
+    }
+}
+
+/// Every entry beneath a managed cache root, ordered by path. A missing
+/// root holds no entries.
+///
+/// # Errors
+///
+/// Returns an error when an existing directory cannot be read.
+pub fn cache_entries(root: &Path) -> io::Result<Vec<CacheEntry>> {
+    let mut entries = Vec::new();
+    if root.is_dir() {
+        collect_entries(root, root, &mut entries)?;
+    }
+    entries.sort_by(|a, b| a.path.cmp(&b.path).then(a.kind.cmp(&b.kind)));
+    Ok(entries)
+}
+
+fn collect_entries(root: &Path, dir: &Path, entries: &mut Vec<CacheEntry>) -> io::Result<()> {
+    for entry in fs::read_dir(dir)? {
+        let entry = entry?;
+        let path = entry.path();
+        if entry.file_type()?.is_dir() {
+            collect_entries(root, &path, entries)?;
+            continue;
+        }
+        let Some(name) = entry.file_name().to_str().map(str::to_string) else {
+            continue;
+        };
+        let (kind, schema_name) = if let Some(schema) = name.strip_suffix(NOT_FOUND_SUFFIX) {
+            (CacheEntryKind::NotFound, schema.to_string())
+        } else if Path::new(&name)
+            .extension()
+            .is_some_and(|extension| extension == "json")
+        {
+            (CacheEntryKind::Schema, name)
+        } else {
+            // Layout markers, `.meta` sidecars, and interrupted writes.
+            continue;
+        };
+        if dir == root && schema_name == LAYOUT_MARKER_FILENAME {
+            continue;
+        }
+        let Ok(relative) = dir
+            .join(&schema_name)
+            .strip_prefix(root)
+            .map(Path::to_path_buf)
+        else {
+            continue;
+        };
+        let relative: Vec<_> = relative
+            .components()
+            .map(|component| component.as_os_str().to_string_lossy().to_string())
+            .collect();
+        let metadata = entry.metadata()?;
+        let schema_path = dir.join(&schema_name);
+        let meta = match kind {
+            CacheEntryKind::Schema => read_meta_sidecar(&schema_path),
+            CacheEntryKind::NotFound => None,
+        };
+        let sidecar_bytes = match kind {
+            CacheEntryKind::Schema => fs::metadata(meta_sidecar_path(&schema_path))
+                .map(|metadata| metadata.len())
+                .unwrap_or(0),
+            CacheEntryKind::NotFound => 0,
+        };
+        entries.push(CacheEntry {
+            source_id: relative.first().cloned().unwrap_or_default(),
+            path: relative.join("/"),
+            kind,
+            bytes: metadata.len() + sidecar_bytes,
+            modified: metadata.modified().ok(),
+            meta,
+        });
+    }
+    Ok(())
+}
+
+/// Remove every entry last written before `cutoff`, with its sidecar, and
+/// the directories left empty. Returns the removed entries. The layout
+/// marker is kept, so the pruned root stays valid.
+///
+/// # Errors
+///
+/// Returns an error when the root cannot be walked or an entry cannot be
+/// removed.
+pub fn prune_cache(root: &Path, cutoff: SystemTime) -> io::Result<Vec<CacheEntry>> {
+    let mut removed = Vec::new();
+    for entry in cache_entries(root)? {
+        if entry.modified.is_none_or(|modified| modified >= cutoff) {
+            continue;
+        }
+        let file = entry.file(root);
+        fs::remove_file(&file)?;
+        if entry.kind == CacheEntryKind::Schema {
+            remove_if_present(&meta_sidecar_path(&file))?;
+        }
+        removed.push(entry);
+    }
+    if root.is_dir() {
+        remove_empty_dirs(root, root)?;
+    }
+    Ok(removed)
+}
+
+/// Re-hash a schema entry against the digest its sidecar recorded.
+#[must_use]
+pub fn verify_cache_entry(root: &Path, entry: &CacheEntry) -> CacheVerification {
+    if entry.kind == CacheEntryKind::NotFound {
+        return CacheVerification::Verified;
+    }
+    let Ok(bytes) = fs::read(root.join(&entry.path)) else {
+        return CacheVerification::Unreadable;
+    };
+    let actual = content_sha256(&bytes);
+    match entry.meta.as_ref().and_then(|meta| meta.sha256.clone()) {
+        Some(recorded) if recorded != actual => CacheVerification::Mismatch { recorded, actual },
+        recorded => {
+            if serde_json::from_slice::<Value>(&bytes).is_err() {
+                CacheVerification::Unparseable
+            } else if recorded.is_some() {
+                CacheVerification::Verified
+            } else {
+                CacheVerification::Unrecorded
+            }
+        }
+    }
+}
+
+fn remove_if_present(path: &Path) -> io::Result<()> {
+    match fs::remove_file(path) {
+        Err(err) if err.kind() != io::ErrorKind::NotFound => Err(err),
+        _ => Ok(()),
+    }
+}
+
+/// Removes the empty directories beneath `dir`, keeping the root itself.
+fn remove_empty_dirs(root: &Path, dir: &Path) -> io::Result<bool> {
+    let mut empty = true;
+    for entry in fs::read_dir(dir)? {
+        let entry = entry?;
+        if entry.file_type()?.is_dir() && remove_empty_dirs(root, &entry.path())? {
+            continue;
+        }
+        empty = false;
+    }
+    if empty && dir != root {
+        fs::remove_dir(dir)?;
+        return Ok(true);
+    }
+    Ok(false)
+}
+
+#[cfg(test)]
+#[path = "tests/inventory.rs"]
+mod tests;
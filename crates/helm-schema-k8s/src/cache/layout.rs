@@ -78,7 +78,8 @@ pub(crate) fn layout_marker_path(root: &Path) -> PathBuf {
 /// launched, so two runs over the same chart from different directories
 /// would consult two different caches — and concurrent runs sharing a
 /// directory would race on the same files.
-pub(crate) fn default_cache_dir(env_var: &str, leaf: &str) -> PathBuf {
+#[must_use]
+pub fn default_cache_dir(env_var: &str, leaf: &str) -> PathBuf {
     if let Some(path) = env_path(env_var) {
         return path;
     }
@@ -1,3 +1,4 @@
+mod inventory;
 mod layout;
 mod layout_check;
 mod negative_cache;
@@ -5,16 +6,21 @@ mod source_doc_cache;
 mod source_id;
 mod source_meta;
 
+pub use inventory::{
+    CacheEntry, CacheEntryKind, CacheVerification, cache_entries, prune_cache, verify_cache_entry,
+};
 pub use layout::{
-    CACHE_LAYOUT_VERSION, LAYOUT_MARKER_FILENAME, k8s_cache_path, not_found_marker_exists,
+    CACHE_LAYOUT_VERSION, LAYOUT_MARKER_FILENAME, default_cache_dir, k8s_cache_path,
+    not_found_marker_exists,
 };
 pub(crate) use layout::{
-    cache_root_has_legacy_layout, crd_cache_path, default_cache_dir, json_files,
-    not_found_marker_path, subdirs, write_not_found_marker,
+    cache_root_has_legacy_layout, crd_cache_path, json_files, not_found_marker_path, subdirs,
+    write_not_found_marker,
 };
 pub(crate) use layout_check::LayoutCheckOutcome;
 pub use layout_check::LayoutChecker;
 pub use negative_cache::NegativeCache;
 pub(crate) use source_doc_cache::{SourceDocCache, read_cached_json_doc};
 pub use source_id::{default_source_id, source_id_for_url};
+pub use source_meta::SourceMeta;
 pub(crate) use source_meta::write_meta_sidecar;
@@ -1,25 +1,77 @@
+use std::fmt::Write as _;
 use std::fs;
 use std::io::Write;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::time::{SystemTime, UNIX_EPOCH};
 
-/// Write a `<path>.meta` sidecar recording the source URL and a
-/// fetch timestamp. Best-effort: silently swallows errors so a sidecar
-/// failure never blocks the actual cache write.
-pub(crate) fn write_meta_sidecar(file_path: &Path, source_url: &str) {
-    let meta_path = file_path.with_extension(
-        file_path
-            .extension()
-            .and_then(|e| e.to_str())
-            .map(|ext| format!("{ext}.meta"))
-            .unwrap_or_else(|| "meta".to_string()),
-    );
+use serde::Serialize;
+use sha2::{Digest, Sha256};
+
+/// Provenance a `<path>.meta` sidecar recorded for one cache entry.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize)]
+pub struct SourceMeta {
+    /// URL the entry was fetched from.
+    pub source_url: Option<String>,
+    /// Fetch time in seconds since the Unix epoch.
+    pub fetched_at: Option<u64>,
+    /// Lowercase hex SHA-256 of the fetched bytes. Sidecars written before
+    /// digests were recorded have none.
+    pub sha256: Option<String>,
+}
+
+/// Write a `<path>.meta` sidecar recording the source URL, a fetch
+/// timestamp, and the SHA-256 of `bytes`. Best-effort: silently swallows
+/// errors so a sidecar failure never blocks the actual cache write.
+pub(crate) fn write_meta_sidecar(file_path: &Path, source_url: &str, bytes: &[u8]) {
     let timestamp = SystemTime::now()
         .duration_since(UNIX_EPOCH)
         .map(|d| d.as_secs())
         .unwrap_or(0);
-    let body = format!("source_url: {source_url}\nfetched_at: {timestamp}\n");
-    if let Ok(mut f) = fs::File::create(&meta_path) {
+    let body = format!(
+        "source_url: {source_url}\nfetched_at: {timestamp}\nsha256: {}\n",
+        content_sha256(bytes)
+    );
+    if let Ok(mut f) = fs::File::create(meta_sidecar_path(file_path)) {
         let _ = f.write_all(body.as_bytes());
     }
 }
+
+/// The `<path>.meta` sidecar path of a cache entry.
+pub(crate) fn meta_sidecar_path(file_path: &Path) -> PathBuf {
+    file_path.with_extension(
+        file_path
+            .extension()
+            .and_then(|e| e.to_str())
+            .map(|ext| format!("{ext}.meta"))
+            .unwrap_or_else(|| "meta".to_string()),
+    )
+}
+
+/// Read the sidecar of `file_path`. `None` when there is no sidecar;
+/// unknown or malformed lines are skipped.
+pub(crate) fn read_meta_sidecar(file_path: &Path) -> Option<SourceMeta> {
+    let body = fs::read_to_string(meta_sidecar_path(file_path)).ok()?;
+    let mut meta = SourceMeta::default();
+    for line in body.lines() {
+        let Some((key, value)) = line.split_once(": ") else {
+            continue;
+        };
+        let value = value.trim();
+        match key {
+            "source_url" => meta.source_url = Some(value.to_string()),
+            "fetched_at" => meta.fetched_at = value.parse().ok(),
+            "sha256" => meta.sha256 = Some(value.to_string()),
+            _ => {}
+        }
+    }
+    Some(meta)
+}
+
+/// Lowercase hex SHA-256 of `bytes`, as sidecars record it.
+pub(crate) fn content_sha256(bytes: &[u8]) -> String {
+    let mut digest = String::with_capacity(64);
+    for byte in Sha256::digest(bytes) {
+        let _ = write!(digest, "{byte:02x}");
+    }
+    digest
+}
@@ -0,0 +1,129 @@
+use std::fs;
+use std::path::Path;
+use std::time::{Duration, SystemTime};
+
+use test_util::prelude::sim_assert_eq;
+
+use super::super::source_meta::write_meta_sidecar;
+use super::*;
+
+fn write(path: &Path, contents: &str) {
+    fs::create_dir_all(path.parent().expect("entry has a parent")).expect("create dirs");
+    fs::write(path, contents).expect("write entry");
+}
+
+fn set_modified(path: &Path, modified: SystemTime) {
+    fs::File::options()
+        .write(true)
+        .open(path)
+        .and_then(|file| file.set_modified(modified))
+        .expect("set mtime");
+}
+
+#[test]
+fn entries_list_documents_and_absences_per_source() {
+    let root = tempfile::tempdir().expect("tempdir");
+    write(&root.path().join(LAYOUT_MARKER_FILENAME), "1\n");
+    let document = root.path().join("default/v1.35.0/configmap-v1.json");
+    write(&document, "{}");
+    write_meta_sidecar(&document, "https://example.com/configmap-v1.json", b"{}");
+    write(
+        &root
+            .path()
+            .join("0c65ce4011ae/v1.35.0/secret-v1.json.not-found"),
+        "not-found\n",
+    );
+    write(
+        &root.path().join("default/v1.35.0/pod-v1.json.tmp.1.2"),
+        "{",
+    );
+
+    let entries = cache_entries(root.path()).expect("list entries");
+    let listed: Vec<_> = entries
+        .iter()
+        .map(|entry| (entry.source_id.as_str(), entry.path.as_str(), entry.kind))
+        .collect();
+    sim_assert_eq!(
+        have: listed,
+        want: vec![
+            (
+                "0c65ce4011ae",
+                "0c65ce4011ae/v1.35.0/secret-v1.json",
+                CacheEntryKind::NotFound,
+            ),
+            (
+                "default",
+                "default/v1.35.0/configmap-v1.json",
+                CacheEntryKind::Schema,
+            ),
+        ]
+    );
+    let meta = entries[1].meta.as_ref().expect("sidecar is read");
+    sim_assert_eq!(
+        have: meta.source_url.as_deref(),
+        want: Some("https://example.com/configmap-v1.json")
+    );
+}
+
+#[test]
+fn missing_root_has_no_entries() {
+    let root = tempfile::tempdir().expect("tempdir");
+    let entries = cache_entries(&root.path().join("absent")).expect("list entries");
+    assert!(entries.is_empty());
+}
+
+#[test]
+fn verify_rehashes_against_the_sidecar_digest() {
+    let root = tempfile::tempdir().expect("tempdir");
+    let intact = root.path().join("default/v1.35.0/configmap-v1.json");
+    write(&intact, r#"{"type":"object"}"#);
+    write_meta_sidecar(&intact, "https://example.com/a", br#"{"type":"object"}"#);
+    let tampered = root.path().join("default/v1.35.0/secret-v1.json");
+    write(&tampered, r#"{"type":"string"}"#);
+    write_meta_sidecar(&tampered, "https://example.com/b", br#"{"type":"object"}"#);
+    write(&root.path().join("default/v1.35.0/pod-v1.json"), "{");
+    write(&root.path().join("default/v1.35.0/service-v1.json"), "{}");
+
+    let results: Vec<_> = cache_entries(root.path())
+        .expect("list entries")
+        .iter()
+        .map(|entry| {
+            (
+                entry.path.clone(),
+                verify_cache_entry(root.path(), entry).is_failure(),
+            )
+        })
+        .collect();
+    sim_assert_eq!(
+        have: results,
+        want: vec![
+            ("default/v1.35.0/configmap-v1.json".to_string(), false),
+            ("default/v1.35.0/pod-v1.json".to_string(), true),
+            ("default/v1.35.0/secret-v1.json".to_string(), true),
+            ("default/v1.35.0/service-v1.json".to_string(), false),
+        ]
+    );
+}
+
+#[test]
+fn prune_removes_stale_entries_with_sidecars_and_empty_dirs() {
+    let root = tempfile::tempdir().expect("tempdir");
+    write(&root.path().join(LAYOUT_MARKER_FILENAME), "1\n");
+    let stale = root.path().join("default/v1.30.0/configmap-v1.json");
+    write(&stale, "{}");
+    write_meta_sidecar(&stale, "https://example.com/a", b"{}");
+    let fresh = root.path().join("default/v1.35.0/configmap-v1.json");
+    write(&fresh, "{}");
+
+    let now = SystemTime::now();
+    set_modified(&stale, now - Duration::from_hours(1));
+    let removed = prune_cache(root.path(), now - Duration::from_mins(1)).expect("prune");
+
+    sim_assert_eq!(
+        have: removed.into_iter().map(|entry| entry.path).collect::<Vec<_>>(),
+        want: vec!["default/v1.30.0/configmap-v1.json".to_string()]
+    );
+    assert!(!root.path().join("default/v1.30.0").exists());
+    assert!(fresh.exists());
+    assert!(root.path().join(LAYOUT_MARKER_FILENAME).exists());
+}
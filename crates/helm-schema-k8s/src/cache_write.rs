@@ -41,7 +41,7 @@ pub(crate) fn write_fetched_schema_doc(
 ) -> Option<SchemaDoc> {
     write_atomic_file(local, bytes).ok()?;
     if record_source {
-        write_meta_sidecar(local, url);
+        write_meta_sidecar(local, url, bytes);
     }
     serde_json::from_slice::<Value>(bytes)
         .ok()
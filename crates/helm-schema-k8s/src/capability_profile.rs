@@ -0,0 +1,77 @@
+use std::collections::BTreeSet;
+
+use helm_schema_core::ApiPresenceQuery;
+use serde::Deserialize;
+
+use crate::is_k8s_builtin_group;
+
+/// The `.Capabilities` of a target cluster: the API group/versions it serves
+/// on top of the vanilla Kubernetes release, and the release itself. Mirrors
+/// `helm template --api-versions ... --kube-version ...`.
+///
+/// ```yaml
+/// kubeVersion: v1.29.3
+/// apiVersions:
+///   - monitoring.coreos.com/v1
+///   - gateway.networking.k8s.io/v1/HTTPRoute
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct CapabilityProfile {
+    #[serde(default)]
+    kube_version: Option<String>,
+    #[serde(default)]
+    api_versions: BTreeSet<String>,
+}
+
+impl CapabilityProfile {
+    /// Profile serving `api_versions` (`group/version` or
+    /// `group/version/Kind`) on the given Kubernetes release.
+    #[must_use]
+    pub fn new(
+        kube_version: Option<String>,
+        api_versions: impl IntoIterator<Item = String>,
+    ) -> Self {
+        Self {
+            kube_version,
+            api_versions: api_versions.into_iter().collect(),
+        }
+    }
+
+    /// Kubernetes release of the target cluster, as written in the profile.
+    #[must_use]
+    pub fn kube_version(&self) -> Option<&str> {
+        self.kube_version
+            .as_deref()
+            .map(str::trim)
+            .filter(|version| !version.is_empty())
+    }
+
+    /// Listed API group/versions and resources.
+    pub fn api_versions(&self) -> impl Iterator<Item = &str> {
+        self.api_versions.iter().map(String::as_str)
+    }
+
+    /// Answer a `.Capabilities.APIVersions.Has` query from the profile.
+    ///
+    /// Like `helm template --api-versions`, a query is present when its
+    /// literal is listed and absent otherwise, except that built-in groups
+    /// the profile does not list keep the vanilla release's answer from the
+    /// versioned schema providers (`None`).
+    #[must_use]
+    pub fn has_query(&self, query: &ApiPresenceQuery) -> Option<bool> {
+        if self.api_versions.contains(&query.canonical_helm_literal()) {
+            return Some(true);
+        }
+        let api_version = match query {
+            ApiPresenceQuery::Resource { api_version, .. }
+            | ApiPresenceQuery::GroupVersion { api_version } => api_version,
+        };
+        let group = api_version.rsplit_once('/').map_or("", |(group, _)| group);
+        (!is_k8s_builtin_group(group)).then_some(false)
+    }
+}
+
+#[cfg(test)]
+#[path = "tests/capability_profile.rs"]
+mod tests;
@@ -0,0 +1,212 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::sync::Arc;
+
+use helm_schema_core::ApiPresenceQuery;
+use serde_json::{Map, Value};
+
+use crate::local_schema_universe::ResourceDocKey;
+
+/// A live cluster's `/openapi/v3` discovery, as saved by `kubectl get --raw`:
+/// the index of served group/versions plus one `OpenAPI` v3 document per
+/// group/version. Built-in kinds, aggregated APIs, and installed CRDs all
+/// appear in it, so it answers for exactly the cluster it was taken from.
+#[derive(Clone, Debug, Default)]
+pub struct ClusterOpenApiDump {
+    source_id: String,
+    documents: Vec<DumpDocument>,
+    resources: BTreeMap<ResourceDocKey, DumpResource>,
+    group_versions: BTreeSet<String>,
+    indexed_group_versions: Option<BTreeSet<String>>,
+}
+
+#[derive(Clone, Debug)]
+pub(crate) struct DumpDocument {
+    pub(crate) filename: String,
+    schemas: Arc<Map<String, Value>>,
+}
+
+#[derive(Clone, Debug)]
+pub(crate) struct DumpResource {
+    document: usize,
+    pub(crate) definition: String,
+}
+
+impl ClusterOpenApiDump {
+    /// Empty dump whose schemas cite `source_id`, typically the dump
+    /// directory, as their provenance.
+    #[must_use]
+    pub fn new(source_id: impl Into<String>) -> Self {
+        Self {
+            source_id: source_id.into(),
+            ..Self::default()
+        }
+    }
+
+    /// Adds one saved discovery document.
+    ///
+    /// A group/version document contributes every schema in
+    /// `components.schemas` tagged with an `x-kubernetes-group-version-kind`
+    /// of its own group/version; the index (`/openapi/v3`) records which
+    /// group/versions the cluster serves. Other documents are ignored. The
+    /// first document to define a resource wins.
+    pub fn insert_document(&mut self, filename: impl Into<String>, document: &Value) {
+        let paths = document.get("paths").and_then(Value::as_object);
+        let Some(schemas) = document
+            .pointer("/components/schemas")
+            .and_then(Value::as_object)
+        else {
+            if let Some(paths) = paths {
+                self.indexed_group_versions
+                    .get_or_insert_with(BTreeSet::new)
+                    .extend(paths.keys().filter_map(|path| group_version_of_path(path)));
+            }
+            return;
+        };
+
+        // Every REST path of a group/version document lives under that
+        // group/version; shared types such as `DeleteOptions` carry the
+        // GVKs of other groups and must not claim them.
+        let document_group_version =
+            paths.and_then(|paths| paths.keys().find_map(|path| group_version_of_path(path)));
+        let index = self.documents.len();
+        for (definition, schema) in schemas {
+            for api_version_kind in schema_group_version_kinds(schema) {
+                if document_group_version
+                    .as_ref()
+                    .is_some_and(|group_version| *group_version != api_version_kind.api_version)
+                {
+                    continue;
+                }
+                self.group_versions
+                    .insert(api_version_kind.api_version.clone());
+                self.resources
+                    .entry(api_version_kind)
+                    .or_insert_with(|| DumpResource {
+                        document: index,
+                        definition: definition.clone(),
+                    });
+            }
+        }
+        if let Some(group_version) = document_group_version {
+            self.group_versions.insert(group_version);
+        }
+        self.documents.push(DumpDocument {
+            filename: filename.into(),
+            schemas: Arc::new(schemas.clone()),
+        });
+    }
+
+    /// Provenance identity of the dump.
+    #[must_use]
+    pub fn source_id(&self) -> &str {
+        &self.source_id
+    }
+
+    /// Number of resource kinds the dump defines.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.resources.len()
+    }
+
+    /// Whether the dump defines no resource kinds.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.resources.is_empty()
+    }
+
+    /// Answer a `.Capabilities.APIVersions.Has` query from the dump.
+    ///
+    /// A dumped group/version document lists all of its kinds, and the
+    /// index lists every served group/version, so both can deny. Without
+    /// the index, a group/version the dump lacks stays undecided.
+    #[must_use]
+    pub fn has_query(&self, query: &ApiPresenceQuery) -> Option<bool> {
+        match query {
+            ApiPresenceQuery::Resource { api_version, kind } => {
+                let key = ResourceDocKey {
+                    api_version: api_version.clone(),
+                    kind: kind.clone(),
+                };
+                let defined = self.resources.contains_key(&key);
+                if defined || self.group_versions.contains(api_version) {
+                    return Some(defined);
+                }
+                // A served group/version whose document was not dumped may
+                // hold any kind.
+                let indexed = self.is_indexed(api_version)?;
+                (!indexed).then_some(false)
+            }
+            ApiPresenceQuery::GroupVersion { api_version } => {
+                if self.group_versions.contains(api_version) {
+                    return Some(true);
+                }
+                self.is_indexed(api_version)
+            }
+        }
+    }
+
+    fn is_indexed(&self, api_version: &str) -> Option<bool> {
+        self.indexed_group_versions
+            .as_ref()
+            .map(|served| served.contains(api_version))
+    }
+
+    pub(crate) fn resource(&self, key: &ResourceDocKey) -> Option<(&DumpDocument, &DumpResource)> {
+        let resource = self.resources.get(key)?;
+        Some((self.documents.get(resource.document)?, resource))
+    }
+
+    /// The resource's definition as a standalone schema document: the
+    /// definition at the root, with its document's `components` alongside so
+    /// `#/components/schemas/...` references still resolve.
+    pub(crate) fn resource_schema_root(&self, key: &ResourceDocKey) -> Option<Value> {
+        let (document, resource) = self.resource(key)?;
+        let mut root = document.schemas.get(&resource.definition)?.clone();
+        let object = root.as_object_mut()?;
+        let mut components = Map::new();
+        components.insert(
+            "schemas".to_string(),
+            Value::Object(document.schemas.as_ref().clone()),
+        );
+        object.insert("components".to_string(), Value::Object(components));
+        Some(root)
+    }
+}
+
+/// `apiVersion` of a discovery path: `api/v1` and `/api/v1/pods` are the
+/// core `v1`; `apis/apps/v1` and `/apis/apps/v1/deployments` are `apps/v1`.
+fn group_version_of_path(path: &str) -> Option<String> {
+    let mut segments = path.trim_start_matches('/').split('/');
+    match (segments.next()?, segments.next(), segments.next()) {
+        ("api", Some(version), _) if !version.is_empty() => Some(version.to_string()),
+        ("apis", Some(group), Some(version)) if !group.is_empty() && !version.is_empty() => {
+            Some(format!("{group}/{version}"))
+        }
+        _ => None,
+    }
+}
+
+fn schema_group_version_kinds(schema: &Value) -> impl Iterator<Item = ResourceDocKey> + '_ {
+    schema
+        .get("x-kubernetes-group-version-kind")
+        .and_then(Value::as_array)
+        .into_iter()
+        .flatten()
+        .filter_map(|entry| {
+            let group = entry.get("group").and_then(Value::as_str).unwrap_or("");
+            let version = entry.get("version").and_then(Value::as_str)?;
+            let kind = entry.get("kind").and_then(Value::as_str)?;
+            Some(ResourceDocKey {
+                api_version: if group.is_empty() {
+                    version.to_string()
+                } else {
+                    format!("{group}/{version}")
+                },
+                kind: kind.to_string(),
+            })
+        })
+}
+
+#[cfg(test)]
+#[path = "tests/dump.rs"]
+mod tests;
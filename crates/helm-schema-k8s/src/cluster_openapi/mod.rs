@@ -0,0 +1,5 @@
+mod dump;
+mod provider;
+
+pub use dump::ClusterOpenApiDump;
+pub use provider::ClusterOpenApiSchemaProvider;
@@ -0,0 +1,98 @@
+use std::sync::Arc;
+
+use helm_schema_core::{ApiPresenceQuery, ResourceRef, YamlPath};
+use helm_schema_json_schema_walk::escape_json_pointer_segment;
+
+use crate::cache::SourceDocCache;
+use crate::doc_backed_schema::{LocalSchemaLeaf, lookup_root_metadata_path};
+use crate::local_schema_universe::ResourceDocKey;
+use crate::lookup::{
+    K8sSchemaProvider, ProviderLookupResult, ProviderOrigin, ProviderSchemaSource,
+};
+use crate::schema_doc::SchemaDoc;
+
+use super::ClusterOpenApiDump;
+
+/// Provider backed by a cluster's saved `/openapi/v3` discovery.
+#[derive(Debug)]
+pub struct ClusterOpenApiSchemaProvider {
+    dump: Arc<ClusterOpenApiDump>,
+    docs: SourceDocCache<ResourceDocKey>,
+}
+
+impl ClusterOpenApiSchemaProvider {
+    /// Creates a provider over a loaded discovery dump.
+    #[must_use]
+    pub fn new(dump: Arc<ClusterOpenApiDump>) -> Self {
+        Self {
+            dump,
+            docs: SourceDocCache::new(),
+        }
+    }
+
+    fn schema_doc(&self, key: &ResourceDocKey) -> Option<SchemaDoc> {
+        if let Some(doc) = self.docs.read(key) {
+            return Some(doc);
+        }
+        let doc = SchemaDoc::new(self.dump.resource_schema_root(key)?);
+        self.docs.write(key.clone(), doc.clone());
+        Some(doc)
+    }
+
+    /// Cites the leaf at its place in the dumped document: the definition
+    /// sits under `components/schemas`, not at the root it is served from.
+    fn source_for_leaf(
+        &self,
+        key: &ResourceDocKey,
+        leaf: &LocalSchemaLeaf,
+    ) -> Option<ProviderSchemaSource> {
+        let pointer = leaf.pointer()?;
+        let (document, resource) = self.dump.resource(key)?;
+        let pointer = if pointer.starts_with("/components/") {
+            pointer.to_string()
+        } else {
+            format!(
+                "/components/schemas/{}{pointer}",
+                escape_json_pointer_segment(&resource.definition)
+            )
+        };
+        Some(ProviderSchemaSource::new(
+            ProviderOrigin::ClusterOpenApi,
+            self.dump.source_id(),
+            None,
+            document.filename.clone(),
+            pointer,
+        ))
+    }
+}
+
+impl K8sSchemaProvider for ClusterOpenApiSchemaProvider {
+    fn origin(&self) -> ProviderOrigin {
+        ProviderOrigin::ClusterOpenApi
+    }
+
+    #[tracing::instrument(skip_all, fields(kind = resource.kind.as_str(), api_version = resource.api_version.as_str(), path_len = path.0.len()))]
+    fn lookup(&self, resource: &ResourceRef, path: &YamlPath) -> ProviderLookupResult {
+        let key = ResourceDocKey::from_resource(resource);
+        let Some(root) = self.schema_doc(&key) else {
+            return ProviderLookupResult::NotOwned;
+        };
+        lookup_root_metadata_path(&root, path, |leaf| self.source_for_leaf(&key, leaf))
+    }
+
+    fn has_resource(&self, resource: &ResourceRef) -> bool {
+        self.dump
+            .resource(&ResourceDocKey::from_resource(resource))
+            .is_some()
+    }
+
+    /// The dump is the cluster itself, so it answers for whatever it
+    /// covers regardless of the configured K8s version.
+    fn capability_has_query_at_primary_version(&self, query: &ApiPresenceQuery) -> Option<bool> {
+        self.dump.has_query(query)
+    }
+}
+
+#[cfg(test)]
+#[path = "tests/provider.rs"]
+mod tests;
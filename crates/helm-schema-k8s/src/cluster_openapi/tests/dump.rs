@@ -0,0 +1,76 @@
+use helm_schema_core::ApiPresenceQuery;
+use serde_json::json;
+use test_util::prelude::sim_assert_eq;
+
+use super::ClusterOpenApiDump;
+
+fn has(dump: &ClusterOpenApiDump, api: &str) -> Option<bool> {
+    let query = ApiPresenceQuery::parse_helm_literal(api).expect("parse api presence query");
+    dump.has_query(&query)
+}
+
+fn apps_v1_document() -> serde_json::Value {
+    json!({
+        "openapi": "3.0.0",
+        "paths": { "/apis/apps/v1/namespaces/{namespace}/deployments": {} },
+        "components": { "schemas": {
+            "io.k8s.api.apps.v1.Deployment": {
+                "type": "object",
+                "x-kubernetes-group-version-kind": [
+                    { "group": "apps", "kind": "Deployment", "version": "v1" }
+                ]
+            },
+            "io.k8s.apimachinery.pkg.apis.meta.v1.DeleteOptions": {
+                "type": "object",
+                "x-kubernetes-group-version-kind": [
+                    { "group": "apps", "kind": "DeleteOptions", "version": "v1" },
+                    { "group": "batch", "kind": "DeleteOptions", "version": "v1" }
+                ]
+            }
+        } }
+    })
+}
+
+#[test]
+fn group_version_documents_define_their_own_kinds() {
+    let mut dump = ClusterOpenApiDump::new("dump");
+    dump.insert_document("apis/apps/v1.json", &apps_v1_document());
+
+    sim_assert_eq!(have: dump.len(), want: 2);
+    sim_assert_eq!(have: has(&dump, "apps/v1/Deployment"), want: Some(true));
+    sim_assert_eq!(have: has(&dump, "apps/v1/StatefulSet"), want: Some(false));
+    sim_assert_eq!(have: has(&dump, "apps/v1"), want: Some(true));
+    // `DeleteOptions` names `batch/v1`, but only its own document speaks
+    // for a group/version.
+    sim_assert_eq!(have: has(&dump, "batch/v1"), want: None);
+}
+
+#[test]
+fn the_index_decides_undumped_group_versions() {
+    let mut dump = ClusterOpenApiDump::new("dump");
+    dump.insert_document("apis/apps/v1.json", &apps_v1_document());
+    dump.insert_document(
+        "index.json",
+        &json!({ "paths": {
+            "api/v1": { "serverRelativeURL": "/openapi/v3/api/v1?hash=A" },
+            "apis/apps/v1": { "serverRelativeURL": "/openapi/v3/apis/apps/v1?hash=B" },
+            "apis/monitoring.coreos.com/v1": {
+                "serverRelativeURL": "/openapi/v3/apis/monitoring.coreos.com/v1?hash=C"
+            },
+            ".well-known/openid-configuration": {}
+        } }),
+    );
+
+    sim_assert_eq!(have: has(&dump, "monitoring.coreos.com/v1"), want: Some(true));
+    sim_assert_eq!(have: has(&dump, "v1"), want: Some(true));
+    sim_assert_eq!(have: has(&dump, "networking.istio.io/v1beta1"), want: Some(false));
+    sim_assert_eq!(
+        have: has(&dump, "networking.istio.io/v1beta1/VirtualService"),
+        want: Some(false)
+    );
+    // Listed in the index but not dumped: its kinds stay unknown.
+    sim_assert_eq!(
+        have: has(&dump, "monitoring.coreos.com/v1/ServiceMonitor"),
+        want: None
+    );
+}
@@ -0,0 +1,101 @@
+use std::sync::Arc;
+
+use serde_json::json;
+use test_util::prelude::sim_assert_eq;
+
+use super::*;
+
+fn deployment() -> ResourceRef {
+    ResourceRef::concrete("apps/v1".to_string(), "Deployment".to_string())
+}
+
+fn path(segments: &[&str]) -> YamlPath {
+    YamlPath(segments.iter().map(ToString::to_string).collect())
+}
+
+/// The shape `kubectl get --raw /openapi/v3/apis/apps/v1` returns: kinds
+/// reach shared definitions through `allOf`-wrapped references.
+fn provider() -> ClusterOpenApiSchemaProvider {
+    let mut dump = ClusterOpenApiDump::new("cluster-dump");
+    dump.insert_document(
+        "apis/apps/v1.json",
+        &json!({
+            "openapi": "3.0.0",
+            "paths": { "/apis/apps/v1/namespaces/{namespace}/deployments": {} },
+            "components": { "schemas": {
+                "io.k8s.api.apps.v1.Deployment": {
+                    "type": "object",
+                    "properties": {
+                        "kind": { "type": "string" },
+                        "spec": {
+                            "allOf": [
+                                { "$ref": "#/components/schemas/io.k8s.api.apps.v1.DeploymentSpec" }
+                            ],
+                            "default": {}
+                        }
+                    },
+                    "x-kubernetes-group-version-kind": [
+                        { "group": "apps", "kind": "Deployment", "version": "v1" }
+                    ]
+                },
+                "io.k8s.api.apps.v1.DeploymentSpec": {
+                    "type": "object",
+                    "properties": {
+                        "replicas": { "type": "integer", "format": "int32" }
+                    }
+                }
+            } }
+        }),
+    );
+    ClusterOpenApiSchemaProvider::new(Arc::new(dump))
+}
+
+#[test]
+fn resolves_paths_through_component_references() {
+    let provider = provider();
+    assert!(provider.has_resource(&deployment()));
+
+    let ProviderLookupResult::Found { schema, .. } =
+        provider.lookup(&deployment(), &path(&["spec", "replicas"]))
+    else {
+        panic!("dumped Deployment should resolve spec.replicas");
+    };
+    let source = schema
+        .source()
+        .cloned()
+        .expect("cluster source should attach");
+    sim_assert_eq!(
+        have: schema.into_schema(),
+        want: json!({ "type": "integer", "format": "int32" })
+    );
+    sim_assert_eq!(have: source.origin(), want: ProviderOrigin::ClusterOpenApi);
+    sim_assert_eq!(have: source.source_id(), want: "cluster-dump");
+    sim_assert_eq!(have: source.filename(), want: "apis/apps/v1.json");
+    sim_assert_eq!(
+        have: source.pointer(),
+        want: "/components/schemas/io.k8s.api.apps.v1.DeploymentSpec/properties/replicas"
+    );
+}
+
+#[test]
+fn root_properties_cite_the_definition_in_the_document() {
+    let ProviderLookupResult::Found { schema, .. } =
+        provider().lookup(&deployment(), &path(&["kind"]))
+    else {
+        panic!("dumped Deployment should resolve kind");
+    };
+    sim_assert_eq!(
+        have: schema.source().map(|source| source.pointer().to_string()),
+        want: Some("/components/schemas/io.k8s.api.apps.v1.Deployment/properties/kind".to_string())
+    );
+}
+
+#[test]
+fn undumped_kinds_are_not_owned() {
+    let statefulset = ResourceRef::concrete("apps/v1".to_string(), "StatefulSet".to_string());
+    assert!(!provider().has_resource(&statefulset));
+    assert!(matches!(
+        provider().lookup(&statefulset, &path(&["spec"])),
+        ProviderLookupResult::NotOwned
+    ));
+}
@@ -2,5 +2,5 @@ mod cross_scan;
 mod provider;
 mod relative_path;
 
-pub use provider::CrdsCatalogSchemaProvider;
+pub use provider::{CrdsCatalogSchemaProvider, default_crd_schema_cache_dir};
 pub use relative_path::relative_path_for_resource;
@@ -15,6 +15,7 @@ use crate::inference::{ApiVersionCandidate, InferenceSource};
 use crate::lookup::{
     K8sSchemaProvider, ProviderLookupResult, ProviderOrigin, ProviderSchemaSource,
 };
+use crate::schema_bundle::{SCHEMA_BUNDLE_CRD_DIR, SchemaBundleRecorder, SchemaBundleSlot};
 use crate::schema_doc::SchemaDoc;
 use crate::source_cache::{
     CachedSchemaDocRequest, allow_download_from_env, load_source_schema_doc, source_url,
@@ -52,6 +53,7 @@ pub struct CrdsCatalogSchemaProvider {
     negative_cache: Arc<NegativeCache>,
     layout_checker: Arc<LayoutChecker>,
     diagnostic_sink: Option<DiagnosticSink>,
+    bundle_recorder: Option<Arc<SchemaBundleRecorder>>,
 
     mem: SourceDocCache<MemKey>,
 }
@@ -71,6 +73,7 @@ impl CrdsCatalogSchemaProvider {
             negative_cache: Arc::new(NegativeCache::new()),
             layout_checker: Arc::new(LayoutChecker::new()),
             diagnostic_sink: None,
+            bundle_recorder: None,
             mem: SourceDocCache::new(),
         }
     }
@@ -145,6 +148,13 @@ impl CrdsCatalogSchemaProvider {
         self
     }
 
+    /// Records every cache slot this provider consults into `recorder`.
+    #[must_use]
+    pub fn with_schema_bundle_recorder(mut self, recorder: Arc<SchemaBundleRecorder>) -> Self {
+        self.bundle_recorder = Some(recorder);
+        self
+    }
+
     fn run_layout_check(&self) -> LayoutCheckOutcome {
         self.layout_checker.check_and_prepare(
             &self.cache_dir,
@@ -201,6 +211,15 @@ impl CrdsCatalogSchemaProvider {
                 use_not_found_marker: false,
                 fetcher: self.fetcher.as_ref(),
                 negative_cache: &self.negative_cache,
+                bundle_slot: self.bundle_recorder.as_deref().map(|recorder| {
+                    SchemaBundleSlot::new(
+                        recorder,
+                        format!(
+                            "{SCHEMA_BUNDLE_CRD_DIR}/{}/{relative_path}",
+                            source.source_id
+                        ),
+                    )
+                }),
             },
             &self.mem,
             mem_key(&source.source_id, relative_path),
@@ -335,7 +354,11 @@ fn crd_root_has_legacy_layout(root: &Path) -> bool {
     cache_root_has_legacy_layout(root, |name| name.contains('.'))
 }
 
-fn default_crd_schema_cache_dir() -> PathBuf {
+/// Managed CRD catalog cache root used when no cache directory is set:
+/// `$HELM_SCHEMA_CRD_SCHEMA_CACHE`, else `helm-schema/crds-catalog` in the
+/// per-user cache directory.
+#[must_use]
+pub fn default_crd_schema_cache_dir() -> PathBuf {
     default_cache_dir("HELM_SCHEMA_CRD_SCHEMA_CACHE", "crds-catalog")
 }
 
@@ -88,6 +88,18 @@ pub enum DiagnosticKey {
     },
     /// Discovered chart config weakens emission relative to this invocation without it.
     DiscoveredConfigWeakensEmission,
+    /// A declared chart dependency is neither vendored nor cached locally.
+    UnresolvedChartDependency {
+        /// Name of the chart declaring the dependency.
+        chart: String,
+        /// Dependency chart name.
+        dependency: String,
+    },
+    /// A chart's dependency lock file does not parse.
+    UnreadableChartLock {
+        /// Path of the lock file.
+        path: String,
+    },
 }
 
 /// User-facing diagnostic. Every event helm-schema emits at runtime is
@@ -207,6 +219,31 @@ pub enum Diagnostic {
         /// Whether the config path came from an explicit `--config` argument.
         explicit: bool,
     },
+    /// A `Chart.yaml` dependency is not vendored under `charts/` and its
+    /// pinned archive is absent from the local Helm repository cache, so
+    /// the values it consumes are missing from the schema.
+    UnresolvedChartDependency {
+        /// Name of the chart declaring the dependency.
+        chart: String,
+        /// Dependency chart name.
+        dependency: String,
+        /// Version pinned by `Chart.lock`, or an exact `Chart.yaml` version.
+        version: Option<String>,
+        /// Repository the dependency is fetched from.
+        repository: Option<String>,
+        /// Archive paths consulted.
+        locations_tried: Vec<String>,
+    },
+    /// A chart's `Chart.lock` (or `requirements.lock`) does not parse, so
+    /// its dependencies resolve without pinned versions.
+    UnreadableChartLock {
+        /// Name of the chart the lock file belongs to.
+        chart: String,
+        /// Path of the lock file.
+        path: String,
+        /// Human-readable parse failure.
+        error: String,
+    },
 }
 
 impl Diagnostic {
@@ -297,6 +334,15 @@ impl Diagnostic {
             Diagnostic::DiscoveredConfigWeakensEmission { .. } => {
                 DiagnosticKey::DiscoveredConfigWeakensEmission
             }
+            Diagnostic::UnresolvedChartDependency {
+                chart, dependency, ..
+            } => DiagnosticKey::UnresolvedChartDependency {
+                chart: chart.clone(),
+                dependency: dependency.clone(),
+            },
+            Diagnostic::UnreadableChartLock { path, .. } => {
+                DiagnosticKey::UnreadableChartLock { path: path.clone() }
+            }
         }
     }
 
@@ -320,6 +366,9 @@ impl Diagnostic {
             }
             Diagnostic::CrdVersionNotFound {
                 locations_tried, ..
+            }
+            | Diagnostic::UnresolvedChartDependency {
+                locations_tried, ..
             } => canonicalise_strings(locations_tried),
             Diagnostic::CrdVersionAvailableAtOtherVersions {
                 available_versions, ..
@@ -332,7 +381,8 @@ impl Diagnostic {
             | Diagnostic::LocalOverrideUnreadable { .. }
             | Diagnostic::CacheLayoutInvalidated { .. }
             | Diagnostic::CacheLayoutForwardIncompatible { .. }
-            | Diagnostic::InputChannelNumericRangeAmbiguity { .. } => {}
+            | Diagnostic::InputChannelNumericRangeAmbiguity { .. }
+            | Diagnostic::UnreadableChartLock { .. } => {}
         }
     }
 }
@@ -89,6 +89,9 @@ pub fn format_diagnostic_text(diagnostic: &Diagnostic) -> String {
             }
             out
         }
+        Diagnostic::UnreadableChartLock { chart, path, error } => format!(
+            "warning: lock file {path} of chart {chart} is unreadable ({error}); its dependencies resolve without pinned versions"
+        ),
         Diagnostic::CrdVersionAvailableAtOtherVersions {
             group,
             kind,
@@ -138,5 +141,28 @@ pub fn format_diagnostic_text(diagnostic: &Diagnostic) -> String {
                 disabled_knobs.join(", ")
             )
         }
+        Diagnostic::UnresolvedChartDependency {
+            chart,
+            dependency,
+            version,
+            repository,
+            locations_tried,
+        } => {
+            let mut out = format!("warning: dependency {dependency}");
+            if let Some(version) = version {
+                let _ = write!(out, " {version}");
+            }
+            if let Some(repository) = repository {
+                let _ = write!(out, " ({repository})");
+            }
+            let _ = write!(
+                out,
+                " of chart {chart} is not vendored under charts/ or cached locally; its values are omitted"
+            );
+            if !locations_tried.is_empty() {
+                let _ = write!(out, "; tried: {}", locations_tried.join(", "));
+            }
+            out
+        }
     }
 }
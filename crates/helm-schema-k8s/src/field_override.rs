@@ -0,0 +1,197 @@
+use helm_schema_core::{DYNAMIC_MAPPING_VALUE_SEGMENT, ResourceRef, YamlPath};
+use serde::{Deserialize, Deserializer};
+use serde_json::Value;
+
+use crate::lookup::ProviderSchemaFragment;
+
+/// Schemas merged onto provider fragments of individual resource fields,
+/// addressed by `(group, kind, path)` rather than by values path. Every
+/// values path that reaches an overridden field, in any chart, sees the
+/// merged schema.
+///
+/// ```yaml
+/// fields:
+///   - group: apps
+///     kind: Deployment
+///     path: spec.template.spec.containers[*].resources
+///     schema:
+///       required: [limits]
+///   - kind: Pod
+///     path: spec.containers[*].image
+///     schema:
+///       pattern: "^registry\\.example\\.com/"
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct FieldSchemaOverrides {
+    #[serde(default)]
+    fields: Vec<FieldSchemaOverride>,
+}
+
+/// One field override.
+#[derive(Clone, Debug, PartialEq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct FieldSchemaOverride {
+    /// API group of the resource; empty for the core group.
+    #[serde(default)]
+    pub group: String,
+    /// Kubernetes kind of the resource.
+    pub kind: String,
+    /// Field path in the rendered resource, written dotted with `[*]` for
+    /// sequence items: `spec.template.spec.containers[*].resources`. An
+    /// empty path addresses the whole resource.
+    #[serde(deserialize_with = "deserialize_field_path")]
+    pub path: YamlPath,
+    /// JSON Schema merged onto the provider's schema for the field.
+    pub schema: Value,
+}
+
+impl FieldSchemaOverrides {
+    /// Overrides applied in the given order.
+    #[must_use]
+    pub fn new(fields: impl IntoIterator<Item = FieldSchemaOverride>) -> Self {
+        Self {
+            fields: fields.into_iter().collect(),
+        }
+    }
+
+    /// Appends `other`'s overrides, which then merge after these.
+    pub fn extend(&mut self, other: Self) {
+        self.fields.extend(other.fields);
+    }
+
+    /// Reports whether no field is overridden.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.fields.is_empty()
+    }
+
+    /// Merge every override reaching `path` of `resource` onto `fragment`.
+    ///
+    /// An override addressing an ancestor of `path` contributes the part of
+    /// its schema below that ancestor (through `properties`, `items`, and
+    /// `additionalProperties`), and one whose `required` lists the final
+    /// segment makes the field required in its parent. A changed schema no
+    /// longer maps to a provider document leaf, so it drops the fragment's
+    /// source ownership.
+    #[must_use]
+    pub fn apply(
+        &self,
+        resource: &ResourceRef,
+        path: &YamlPath,
+        fragment: ProviderSchemaFragment,
+    ) -> ProviderSchemaFragment {
+        let group = resource
+            .api_version
+            .rsplit_once('/')
+            .map_or("", |(group, _)| group);
+        let mut fragment = fragment;
+        for field in &self.fields {
+            if field.group != group || field.kind != resource.kind {
+                continue;
+            }
+            let Some(rest) = path.0.strip_prefix(field.path.0.as_slice()) else {
+                continue;
+            };
+            if let Some((leaf, parents)) = rest.split_last()
+                && subschema_at(&field.schema, parents)
+                    .is_some_and(|parent| lists_required(parent, leaf))
+            {
+                fragment = fragment.with_required_in_parent(true);
+            }
+            let Some(overlay) = subschema_at(&field.schema, rest) else {
+                continue;
+            };
+            if let Some(merged) = fragment
+                .clone()
+                .try_map_schema(|schema| Some(merge_field_schema(schema, overlay)))
+            {
+                fragment = merged;
+            }
+        }
+        fragment
+    }
+}
+
+/// The part of `schema` describing the value at `segments` below it.
+fn subschema_at<'schema>(schema: &'schema Value, segments: &[String]) -> Option<&'schema Value> {
+    let mut schema = schema;
+    for segment in segments {
+        if segment == DYNAMIC_MAPPING_VALUE_SEGMENT {
+            schema = schema.get("additionalProperties")?;
+            continue;
+        }
+        let (key, is_item) = segment
+            .strip_suffix("[*]")
+            .map_or((segment.as_str(), false), |key| (key, true));
+        if !key.is_empty() {
+            schema = schema.get("properties")?.get(key)?;
+        }
+        if is_item {
+            schema = schema.get("items")?;
+        }
+    }
+    Some(schema)
+}
+
+/// Whether `schema` requires the plain property `segment`.
+fn lists_required(schema: &Value, segment: &str) -> bool {
+    schema
+        .get("required")
+        .and_then(Value::as_array)
+        .is_some_and(|required| {
+            required
+                .iter()
+                .any(|member| member.as_str() == Some(segment))
+        })
+}
+
+/// Schema-aware merge: objects merge key by key, `required` lists union,
+/// and any other overlay value replaces the base. A base holding `$ref` is
+/// combined as `allOf: [base, overlay]` instead, because Draft-07 ignores
+/// every keyword beside `$ref`.
+fn merge_field_schema(base: &Value, overlay: &Value) -> Value {
+    let (Value::Object(base), Value::Object(overlay)) = (base, overlay) else {
+        return overlay.clone();
+    };
+    if base.contains_key("$ref") {
+        return serde_json::json!({
+            "allOf": [Value::Object(base.clone()), Value::Object(overlay.clone())]
+        });
+    }
+    let mut merged = base.clone();
+    for (key, value) in overlay {
+        let next = match (key.as_str(), merged.get(key), value) {
+            ("required", Some(Value::Array(existing)), Value::Array(added)) => {
+                let mut required = existing.clone();
+                for member in added {
+                    if !required.contains(member) {
+                        required.push(member.clone());
+                    }
+                }
+                Value::Array(required)
+            }
+            (_, Some(existing), value) => merge_field_schema(existing, value),
+            (_, None, value) => value.clone(),
+        };
+        merged.insert(key.clone(), next);
+    }
+    Value::Object(merged)
+}
+
+fn deserialize_field_path<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<YamlPath, D::Error> {
+    let path = String::deserialize(deserializer)?;
+    Ok(YamlPath(
+        path.split('.')
+            .map(str::trim)
+            .filter(|segment| !segment.is_empty())
+            .map(str::to_string)
+            .collect(),
+    ))
+}
+
+#[cfg(test)]
+#[path = "tests/field_override.rs"]
+mod tests;
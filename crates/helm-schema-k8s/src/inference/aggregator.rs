@@ -118,9 +118,10 @@ fn source_rank(source: InferenceSource) -> u8 {
 fn origin_rank(origin: ProviderOrigin) -> u8 {
     match origin {
         ProviderOrigin::LocalOverride => 0,
-        ProviderOrigin::ChartLocalCrd => 1,
-        ProviderOrigin::DefaultCatalog => 2,
-        ProviderOrigin::KubernetesOpenApi => 3,
+        ProviderOrigin::ClusterOpenApi => 1,
+        ProviderOrigin::ChartLocalCrd => 2,
+        ProviderOrigin::DefaultCatalog => 3,
+        ProviderOrigin::KubernetesOpenApi => 4,
     }
 }
 
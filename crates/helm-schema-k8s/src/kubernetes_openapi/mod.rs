@@ -3,5 +3,5 @@ mod provider;
 pub(crate) mod resolve_ctx;
 mod version_chain;
 
-pub use provider::KubernetesJsonSchemaProvider;
+pub use provider::{KubernetesJsonSchemaProvider, default_k8s_schema_cache_dir};
 pub use version_chain::K8sVersionChain;
@@ -19,6 +19,7 @@ use crate::lookup::{
     K8sSchemaProvider, LookupTrace, ProviderLookupResult, ProviderOrigin, ProviderSchemaFragment,
     ProviderSchemaSource, SourceProbeTraceOutcome, TracedApiPresenceOutcome,
 };
+use crate::schema_bundle::{SCHEMA_BUNDLE_K8S_DIR, SchemaBundleRecorder, SchemaBundleSlot};
 use crate::schema_doc::SchemaDoc;
 use crate::source_cache::{
     CachedSchemaDocRequest, SourceDocOutcome, allow_download_from_env, load_source_schema_doc,
@@ -69,6 +70,7 @@ pub struct KubernetesJsonSchemaProvider {
     negative_cache: Arc<NegativeCache>,
     layout_checker: Arc<LayoutChecker>,
     diagnostic_sink: Option<DiagnosticSink>,
+    bundle_recorder: Option<Arc<SchemaBundleRecorder>>,
 
     mem: SourceDocCache<MemKey>,
 }
@@ -102,6 +104,7 @@ impl KubernetesJsonSchemaProvider {
             negative_cache: Arc::new(NegativeCache::new()),
             layout_checker: Arc::new(LayoutChecker::new()),
             diagnostic_sink: None,
+            bundle_recorder: None,
             mem: SourceDocCache::new(),
         }
     }
@@ -176,6 +179,13 @@ impl KubernetesJsonSchemaProvider {
         self
     }
 
+    /// Records every cache slot this provider consults into `recorder`.
+    #[must_use]
+    pub fn with_schema_bundle_recorder(mut self, recorder: Arc<SchemaBundleRecorder>) -> Self {
+        self.bundle_recorder = Some(recorder);
+        self
+    }
+
     /// Provider-facing entry point: walk `(version, mirror)` and
     /// return the first source that owns the resource.
     #[tracing::instrument(skip_all, fields(kind = resource.kind.as_str(), api_version = resource.api_version.as_str()))]
@@ -228,6 +238,15 @@ impl KubernetesJsonSchemaProvider {
             use_not_found_marker: true,
             fetcher: self.fetcher.as_ref(),
             negative_cache: &self.negative_cache,
+            bundle_slot: self.bundle_recorder.as_deref().map(|recorder| {
+                SchemaBundleSlot::new(
+                    recorder,
+                    format!(
+                        "{SCHEMA_BUNDLE_K8S_DIR}/{}/{version}/{filename}",
+                        source.source_id
+                    ),
+                )
+            }),
         }
     }
 
@@ -590,6 +609,10 @@ fn looks_like_k8s_version_dir(name: &str) -> bool {
     major.chars().all(|c| c.is_ascii_digit()) && minor.chars().all(|c| c.is_ascii_digit())
 }
 
-fn default_k8s_schema_cache_dir() -> PathBuf {
+/// Managed `Kubernetes OpenAPI` cache root used when no cache directory is
+/// set: `$HELM_SCHEMA_K8S_SCHEMA_CACHE`, else
+/// `helm-schema/kubernetes-json-schema` in the per-user cache directory.
+#[must_use]
+pub fn default_k8s_schema_cache_dir() -> PathBuf {
     default_cache_dir("HELM_SCHEMA_K8S_SCHEMA_CACHE", "kubernetes-json-schema")
 }
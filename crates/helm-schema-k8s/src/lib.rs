@@ -10,13 +10,18 @@
 //!   - [`inference`]: Feature D apiVersion guessing.
 //!
 //! The per-provider modules ([`kubernetes_openapi`], [`crds_catalog`],
-//! [`local_override`], [`local_schema_universe`]) are slim composers of the above.
+//! [`local_override`], [`local_schema_universe`], [`cluster_openapi`]) are
+//! slim composers of the above.
 
 /// Classification of built-in Kubernetes API groups.
 pub mod builtin_groups;
 /// Persistent cache layout and negative-cache primitives.
 pub mod cache;
 mod cache_write;
+/// Target-cluster `.Capabilities` profiles.
+pub mod capability_profile;
+/// Provider backed by a cluster's saved `/openapi/v3` discovery.
+pub mod cluster_openapi;
 /// Provider for default and mirrored CRD catalogs.
 pub mod crds_catalog;
 /// Typed user-facing diagnostics and sinks.
@@ -24,6 +29,8 @@ pub mod diagnostic;
 mod doc_backed_schema;
 /// HTTP transport abstraction used by fetch-on-miss providers.
 pub mod fetch;
+/// Schema overrides addressed by resource field rather than values path.
+pub mod field_override;
 mod filename;
 /// Bounded `apiVersion` inference and its evidence types.
 pub mod inference;
@@ -37,6 +44,8 @@ pub mod local_schema_universe;
 pub mod lookup;
 mod metadata_enrichment;
 mod mirror_chain;
+/// Recording of the cache-backed schema documents a run consulted.
+pub mod schema_bundle;
 mod schema_doc;
 mod source_cache;
 
@@ -45,14 +54,19 @@ pub use cache::{
     CACHE_LAYOUT_VERSION, LAYOUT_MARKER_FILENAME, LayoutChecker, NegativeCache, default_source_id,
     source_id_for_url,
 };
-pub use crds_catalog::CrdsCatalogSchemaProvider;
+pub use capability_profile::CapabilityProfile;
+pub use cluster_openapi::{ClusterOpenApiDump, ClusterOpenApiSchemaProvider};
+pub use crds_catalog::{CrdsCatalogSchemaProvider, default_crd_schema_cache_dir};
 pub use diagnostic::{
     Diagnostic, DiagnosticKey, DiagnosticSink, format_diagnostic_json, format_diagnostic_text,
 };
 pub use fetch::{FetchError, HttpFetcher};
+pub use field_override::{FieldSchemaOverride, FieldSchemaOverrides};
 pub use filename::ordered_api_versions_for_resource;
 pub use inference::{ApiVersionCandidate, ApiVersionInferenceOutcome, InferenceSource};
-pub use kubernetes_openapi::{K8sVersionChain, KubernetesJsonSchemaProvider};
+pub use kubernetes_openapi::{
+    K8sVersionChain, KubernetesJsonSchemaProvider, default_k8s_schema_cache_dir,
+};
 pub use local_override::LocalSchemaProvider;
 pub use local_schema_universe::{
     ChartLocalCrdSchemaProvider, LocalResourceSchema, LocalSchemaUniverse,
@@ -63,6 +77,9 @@ pub use lookup::{
     ProviderLookupResult, ProviderOrigin, ProviderSchemaFragment, ProviderSchemaSource,
     ProviderSourceFragment, SourceProbeTraceOutcome, TracedApiPresenceOutcome, TracedLookupOutcome,
 };
+pub use schema_bundle::{
+    SCHEMA_BUNDLE_CRD_DIR, SCHEMA_BUNDLE_K8S_DIR, SchemaBundleEntry, SchemaBundleRecorder,
+};
 
 #[cfg(test)]
 #[path = "tests/lib.rs"]
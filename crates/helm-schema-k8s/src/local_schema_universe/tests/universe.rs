@@ -196,6 +196,7 @@ fn inserts_direct_resource_schema_without_crd_envelope() {
         }),
         source_id: "chart-local".to_string(),
         filename: "example.com_v1_Widget.schema.json".to_string(),
+        guards: Vec::new(),
     });
 
     let schema = universe
@@ -208,3 +209,30 @@ fn inserts_direct_resource_schema_without_crd_envelope() {
 
     sim_assert_eq!(have: schema, want: Some(&json!({"type": "boolean"})));
 }
+
+#[test]
+fn unconditional_definition_replaces_guarded_one() {
+    let widget = |filename: &str, guards: &[&str]| LocalResourceSchema {
+        api_version: "example.com/v1".to_string(),
+        kind: "Widget".to_string(),
+        schema: json!({"type": "object"}),
+        source_id: "chart-template-crd".to_string(),
+        filename: filename.to_string(),
+        guards: guards.iter().map(ToString::to_string).collect(),
+    };
+    let mut universe = LocalSchemaUniverse::default();
+    universe.insert_resource_schema(widget("guarded.yaml", &[".Values.crds.install"]));
+    universe.insert_resource_schema(widget("other-guard.yaml", &[".Values.legacy"]));
+    sim_assert_eq!(
+        have: universe.guards_for_resource(&resource("example.com/v1")),
+        want: Some([".Values.crds.install".to_string()].as_slice())
+    );
+
+    universe.insert_resource_schema(widget("unconditional.yaml", &[]));
+    universe.insert_resource_schema(widget("late-guarded.yaml", &[".Values.late"]));
+    let document = universe
+        .schema_document_for_resource(&resource("example.com/v1"))
+        .expect("widget is defined");
+    sim_assert_eq!(have: document.filename.as_str(), want: "unconditional.yaml");
+    assert!(document.guards.is_empty());
+}
@@ -2,6 +2,7 @@ use std::collections::BTreeMap;
 use std::sync::Arc;
 
 use helm_schema_core::ResourceRef;
+use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
 use crate::schema_doc::SchemaDoc;
@@ -23,10 +24,9 @@ impl ResourceDocKey {
 
 /// A schema document for one concrete Kubernetes resource coordinate.
 ///
-/// Static CRDs are one producer of this type today. Later, fully-literal
-/// rendered document projection can produce the same type without adding a
-/// second chart-local provider path.
-#[derive(Clone, Debug, PartialEq)]
+/// Static CRDs and CRDs projected from templates both produce this type, so
+/// chart-local resolution has a single provider path.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct LocalResourceSchema {
     /// Concrete API version declared by the CRD.
     pub api_version: String,
@@ -38,13 +38,16 @@ pub struct LocalResourceSchema {
     pub source_id: String,
     /// Logical filename used in provider provenance.
     pub filename: String,
+    /// Template conditions that must all hold for Helm to render the CRD,
+    /// e.g. `.Values.crds.install`. Empty when it renders unconditionally.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub guards: Vec<String>,
 }
 
 /// Chart-local schemas keyed by Kubernetes resource coordinate.
 ///
-/// The universe is source-agnostic: static `crds/` files populate it today,
-/// and later A3 document projection can add fully-literal rendered CRDs
-/// without changing provider resolution semantics.
+/// The universe is source-agnostic: static `crds/` files and CRDs projected
+/// from templates populate it through the same insertion path.
 #[derive(Clone, Debug, Default)]
 pub struct LocalSchemaUniverse {
     docs: BTreeMap<ResourceDocKey, LocalSchemaDocument>,
@@ -55,20 +58,43 @@ pub(crate) struct LocalSchemaDocument {
     pub(crate) doc: Arc<SchemaDoc>,
     pub(crate) source_id: String,
     pub(crate) filename: String,
+    pub(crate) guards: Vec<String>,
 }
 
 impl LocalSchemaUniverse {
     /// Inserts a resource schema unless that coordinate already has a document.
+    ///
+    /// The one exception is a guarded document: an unconditional definition
+    /// of the same coordinate replaces it, since it renders whenever the
+    /// guarded one does.
     pub fn insert_resource_schema(&mut self, resource_schema: LocalResourceSchema) {
         let key = ResourceDocKey {
             api_version: resource_schema.api_version,
             kind: resource_schema.kind,
         };
-        self.docs.entry(key).or_insert_with(|| LocalSchemaDocument {
-            doc: Arc::new(SchemaDoc::new(resource_schema.schema)),
-            source_id: resource_schema.source_id,
-            filename: resource_schema.filename,
-        });
+        if self.docs.get(&key).is_some_and(|existing| {
+            existing.guards.is_empty() || !resource_schema.guards.is_empty()
+        }) {
+            return;
+        }
+        self.docs.insert(
+            key,
+            LocalSchemaDocument {
+                doc: Arc::new(SchemaDoc::new(resource_schema.schema)),
+                source_id: resource_schema.source_id,
+                filename: resource_schema.filename,
+                guards: resource_schema.guards,
+            },
+        );
+    }
+
+    /// Template conditions guarding the chart-local definition of
+    /// `resource`; empty when the chart defines it unconditionally.
+    /// `None` when the chart does not define `resource`.
+    #[must_use]
+    pub fn guards_for_resource(&self, resource: &ResourceRef) -> Option<&[String]> {
+        self.schema_document_for_resource(resource)
+            .map(|document| document.guards.as_slice())
     }
 
     /// Reports whether the universe contains no resource schemas.
@@ -266,6 +292,7 @@ fn resource_schema_for_version(
         schema,
         source_id: source_id.to_string(),
         filename,
+        guards: Vec::new(),
     }
 }
 
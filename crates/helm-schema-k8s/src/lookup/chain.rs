@@ -1,9 +1,13 @@
+use std::sync::Arc;
+
 use helm_schema_core::{
     ApiPresenceQuery, CapabilityOracle, ProviderOrigin, ProviderSchemaUse, ResourceRef,
     ResourceSchemaOracle, YamlPath,
 };
 
+use crate::capability_profile::CapabilityProfile;
 use crate::diagnostic::{Diagnostic, DiagnosticSink};
+use crate::field_override::FieldSchemaOverrides;
 use crate::inference::{ApiVersionInferenceOutcome, infer_api_version};
 
 use super::chain_outcome::ChainLookupOutcome;
@@ -16,11 +20,15 @@ use super::trace::{LookupTrace, TracedApiPresenceOutcome, TracedLookupOutcome};
 use super::trait_def::K8sSchemaProvider;
 
 /// Composed provider chain with precedence
-/// `LocalOverride > DefaultCatalog > KubernetesOpenApi`.
+/// `LocalOverride > ClusterOpenApi > ChartLocalCrd > DefaultCatalog >
+/// KubernetesOpenApi`. Field schema overrides merge onto whichever
+/// provider's fragment wins.
 #[derive(Debug)]
 pub struct Chain {
     providers: Vec<Box<dyn K8sSchemaProvider>>,
     sink: Option<DiagnosticSink>,
+    capability_profile: Option<Arc<CapabilityProfile>>,
+    field_overrides: Option<Arc<FieldSchemaOverrides>>,
     inference_enabled: bool,
     inference_cache: MemoCache<String, ApiVersionInferenceOutcome>,
     provider_lookup_cache: MemoCache<ProviderLookupCacheKey, ProviderLookupResult>,
@@ -33,6 +41,8 @@ impl Chain {
         Self {
             providers,
             sink: None,
+            capability_profile: None,
+            field_overrides: None,
             inference_enabled: false,
             inference_cache: MemoCache::default(),
             provider_lookup_cache: MemoCache::default(),
@@ -46,6 +56,22 @@ impl Chain {
         self
     }
 
+    /// Answers `.Capabilities.APIVersions.Has` from the target cluster's
+    /// profile before asking any provider.
+    #[must_use]
+    pub fn with_capability_profile(mut self, profile: Arc<CapabilityProfile>) -> Self {
+        self.capability_profile = Some(profile);
+        self
+    }
+
+    /// Merges `(group, kind, path)`-addressed schemas onto the resolved
+    /// fragments of the fields they address.
+    #[must_use]
+    pub fn with_field_schema_overrides(mut self, overrides: Arc<FieldSchemaOverrides>) -> Self {
+        self.field_overrides = Some(overrides);
+        self
+    }
+
     /// Enable apiVersion inference (Feature D). Off by default.
     #[must_use]
     pub fn with_inference_enabled(mut self, enabled: bool) -> Self {
@@ -97,13 +123,25 @@ impl Chain {
     }
 
     /// Answer a typed `.Capabilities.APIVersions.Has` query and retain the
-    /// executed provider/source probes. The first provider that can answer wins,
-    /// matching [`K8sSchemaProvider::capability_has_query_at_primary_version`].
+    /// executed provider/source probes. A capability profile answers first;
+    /// otherwise the first provider that can answer wins, matching
+    /// [`K8sSchemaProvider::capability_has_query_at_primary_version`].
     pub fn capability_has_query_at_primary_version_traced(
         &self,
         query: &ApiPresenceQuery,
     ) -> TracedApiPresenceOutcome {
         let mut trace = LookupTrace::default();
+        if let Some(answer) = self
+            .capability_profile
+            .as_ref()
+            .and_then(|profile| profile.has_query(query))
+        {
+            trace.record_api_presence_profile(answer);
+            return TracedApiPresenceOutcome {
+                answer: Some(answer),
+                trace,
+            };
+        }
         for provider in &self.providers {
             let provider_outcome = provider.capability_has_query_at_primary_version_traced(query);
             let answer = provider_outcome.answer;
@@ -204,6 +242,10 @@ impl Chain {
                     resolved_k8s_version,
                 } => {
                     self.maybe_emit_fallback_version(resource, resolved_k8s_version.as_deref());
+                    let schema = match &self.field_overrides {
+                        Some(overrides) => overrides.apply(resource, path, schema),
+                        None => schema,
+                    };
                     Some(ChainLookupOutcome::Resolved(Some(schema)))
                 }
                 ProviderLookupResult::PathUnresolved => Some(ChainLookupOutcome::Resolved(None)),
@@ -95,3 +95,36 @@ fn repeated_inference_uses_cached_outcome() {
 
     sim_assert_eq!(have: calls.load(Ordering::SeqCst), want: 1);
 }
+
+#[test]
+fn field_schema_overrides_merge_onto_the_winning_fragment() {
+    let overrides = crate::FieldSchemaOverrides::new([crate::FieldSchemaOverride {
+        group: String::new(),
+        kind: "ConfigMap".to_string(),
+        path: YamlPath(vec!["metadata".to_string(), "name".to_string()]),
+        schema: json!({"maxLength": 63}),
+    }]);
+    let chain = Chain::new(vec![Box::new(CountingProvider {
+        calls: Arc::new(AtomicUsize::new(0)),
+    })])
+    .with_field_schema_overrides(Arc::new(overrides));
+    let resource = ResourceRef::concrete("v1".to_string(), "ConfigMap".to_string());
+
+    let name = chain.schema_fragment_for_resource_path(
+        &resource,
+        &YamlPath(vec!["metadata".to_string(), "name".to_string()]),
+    );
+    let namespace = chain.schema_fragment_for_resource_path(
+        &resource,
+        &YamlPath(vec!["metadata".to_string(), "namespace".to_string()]),
+    );
+
+    sim_assert_eq!(
+        have: name.map(ProviderSchemaFragment::into_schema),
+        want: Some(json!({"type": "string", "maxLength": 63}))
+    );
+    sim_assert_eq!(
+        have: namespace.map(ProviderSchemaFragment::into_schema),
+        want: Some(json!({"type": "string"}))
+    );
+}
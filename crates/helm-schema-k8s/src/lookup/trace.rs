@@ -32,6 +32,11 @@ impl LookupTrace {
             .push(LookupTraceEntry::ApiPresenceProvider { provider, answer });
     }
 
+    pub(crate) fn record_api_presence_profile(&mut self, answer: bool) {
+        self.entries
+            .push(LookupTraceEntry::ApiPresenceProfile { answer });
+    }
+
     pub(crate) fn record_api_presence_source_probe(
         &mut self,
         provider: ProviderOrigin,
@@ -83,6 +88,12 @@ pub enum LookupTraceEntry {
         /// Authoritative answer, or `None` when the provider cannot decide.
         answer: Option<bool>,
     },
+    /// The target cluster's capability profile answered whether an API
+    /// version is present, ahead of every provider.
+    ApiPresenceProfile {
+        /// Answer read from the profile.
+        answer: bool,
+    },
     /// A concrete upstream or cache source was probed for API presence.
     ApiPresenceSourceProbe {
         /// Provider responsible for the source.
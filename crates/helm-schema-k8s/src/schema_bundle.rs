@@ -0,0 +1,90 @@
+use std::collections::BTreeMap;
+use std::sync::{Arc, Mutex};
+
+use serde_json::Value;
+
+use crate::schema_doc::SchemaDoc;
+
+/// Directory holding the `Kubernetes OpenAPI` cache tree inside a schema
+/// bundle.
+pub const SCHEMA_BUNDLE_K8S_DIR: &str = "kubernetes";
+/// Directory holding the CRD catalog cache tree inside a schema bundle.
+pub const SCHEMA_BUNDLE_CRD_DIR: &str = "crds";
+
+/// One cache slot a provider consulted.
+#[derive(Clone, Debug, PartialEq)]
+pub enum SchemaBundleEntry {
+    /// The schema document the slot served.
+    Document(Arc<Value>),
+    /// An authoritative upstream 404, persisted as a `.not-found` marker.
+    NotFound,
+}
+
+/// Collects the cache-backed schema documents the fetch-on-miss providers
+/// consult during a run: resource documents, the documents their `$ref`s
+/// reach, and authoritative absences answering capability probes.
+///
+/// Entries are keyed by their path in the bundle, i.e. the provider's cache
+/// layout beneath [`SCHEMA_BUNDLE_K8S_DIR`] or [`SCHEMA_BUNDLE_CRD_DIR`], so
+/// the unpacked bundle can stand in for both caches.
+#[derive(Debug, Default)]
+pub struct SchemaBundleRecorder {
+    entries: Mutex<BTreeMap<String, SchemaBundleEntry>>,
+}
+
+impl SchemaBundleRecorder {
+    /// Creates an empty recorder.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Snapshot of the recorded entries, ordered by bundle path.
+    #[must_use]
+    pub fn entries(&self) -> BTreeMap<String, SchemaBundleEntry> {
+        self.entries
+            .lock()
+            .map(|entries| entries.clone())
+            .unwrap_or_default()
+    }
+
+    /// A document wins over an absence recorded for the same slot earlier,
+    /// e.g. by another mirror's probe.
+    fn record(&self, path: String, entry: SchemaBundleEntry) {
+        let Ok(mut entries) = self.entries.lock() else {
+            return;
+        };
+        match entry {
+            SchemaBundleEntry::Document(_) => {
+                entries.insert(path, entry);
+            }
+            SchemaBundleEntry::NotFound => {
+                entries.entry(path).or_insert(entry);
+            }
+        }
+    }
+}
+
+/// The bundle path one cache-backed document probe records under.
+pub(crate) struct SchemaBundleSlot<'a> {
+    recorder: &'a SchemaBundleRecorder,
+    path: String,
+}
+
+impl<'a> SchemaBundleSlot<'a> {
+    pub(crate) fn new(recorder: &'a SchemaBundleRecorder, path: String) -> Self {
+        Self { recorder, path }
+    }
+
+    pub(crate) fn record_document(&self, doc: &SchemaDoc) {
+        self.recorder.record(
+            self.path.clone(),
+            SchemaBundleEntry::Document(doc.shared_root()),
+        );
+    }
+
+    pub(crate) fn record_not_found(&self) {
+        self.recorder
+            .record(self.path.clone(), SchemaBundleEntry::NotFound);
+    }
+}
@@ -23,6 +23,10 @@ impl SchemaDoc {
     pub(crate) fn root(&self) -> &Value {
         self.root.as_ref()
     }
+
+    pub(crate) fn shared_root(&self) -> Arc<Value> {
+        Arc::clone(&self.root)
+    }
 }
 
 /// Clone `schema` without its `$ref` key, keeping sibling keys.
@@ -8,6 +8,7 @@ use crate::cache::{
 };
 use crate::cache_write::write_fetched_schema_doc;
 use crate::fetch::HttpFetcher;
+use crate::schema_bundle::SchemaBundleSlot;
 use crate::schema_doc::SchemaDoc;
 
 pub(crate) struct CachedSchemaDocRequest<'a> {
@@ -25,6 +26,8 @@ pub(crate) struct CachedSchemaDocRequest<'a> {
     pub(crate) use_not_found_marker: bool,
     pub(crate) fetcher: &'a dyn HttpFetcher,
     pub(crate) negative_cache: &'a NegativeCache,
+    /// When set, the probe's outcome is recorded for a schema bundle.
+    pub(crate) bundle_slot: Option<SchemaBundleSlot<'a>>,
 }
 
 /// Tri-state outcome of one `(source, cache slot)` schema-doc probe.
@@ -53,6 +56,28 @@ pub(crate) fn probe_source_schema_doc<K>(
     mem: &SourceDocCache<K>,
     mem_key: K,
 ) -> SourceDocOutcome
+where
+    K: Eq + Hash,
+{
+    let outcome = probe_cached_or_fetched_schema_doc(request, mem, mem_key);
+    if let Some(slot) = &request.bundle_slot {
+        match &outcome {
+            SourceDocOutcome::Found(doc) => slot.record_document(doc),
+            // Only absences the cache persists carry over to a bundle.
+            SourceDocOutcome::AuthoritativelyAbsent if request.use_not_found_marker => {
+                slot.record_not_found();
+            }
+            SourceDocOutcome::AuthoritativelyAbsent | SourceDocOutcome::Uncertain => {}
+        }
+    }
+    outcome
+}
+
+fn probe_cached_or_fetched_schema_doc<K>(
+    request: &CachedSchemaDocRequest<'_>,
+    mem: &SourceDocCache<K>,
+    mem_key: K,
+) -> SourceDocOutcome
 where
     K: Eq + Hash,
 {
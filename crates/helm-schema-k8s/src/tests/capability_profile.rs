@@ -0,0 +1,70 @@
+use helm_schema_core::ApiPresenceQuery;
+use test_util::prelude::sim_assert_eq;
+
+use super::CapabilityProfile;
+
+fn has(profile: &CapabilityProfile, api: &str) -> Option<bool> {
+    let query = ApiPresenceQuery::parse_helm_literal(api).expect("parse api presence query");
+    profile.has_query(&query)
+}
+
+fn profile() -> CapabilityProfile {
+    CapabilityProfile::new(
+        Some("v1.29.3".to_string()),
+        [
+            "monitoring.coreos.com/v1".to_string(),
+            "gateway.networking.k8s.io/v1/HTTPRoute".to_string(),
+            "policy/v1beta1".to_string(),
+        ],
+    )
+}
+
+#[test]
+fn listed_literals_are_present() {
+    let profile = profile();
+    sim_assert_eq!(have: has(&profile, "monitoring.coreos.com/v1"), want: Some(true));
+    sim_assert_eq!(
+        have: has(&profile, "gateway.networking.k8s.io/v1/HTTPRoute"),
+        want: Some(true)
+    );
+    sim_assert_eq!(have: has(&profile, "policy/v1beta1"), want: Some(true));
+}
+
+#[test]
+fn membership_is_exact_like_helm_api_versions() {
+    let profile = profile();
+    // A listed group/version says nothing about its kinds, and a listed
+    // kind says nothing about its group/version or sibling kinds.
+    sim_assert_eq!(
+        have: has(&profile, "monitoring.coreos.com/v1/ServiceMonitor"),
+        want: Some(false)
+    );
+    sim_assert_eq!(have: has(&profile, "gateway.networking.k8s.io/v1"), want: Some(false));
+    sim_assert_eq!(
+        have: has(&profile, "gateway.networking.k8s.io/v1/GRPCRoute"),
+        want: Some(false)
+    );
+}
+
+#[test]
+fn unlisted_groups_are_absent_unless_built_in() {
+    let profile = profile();
+    sim_assert_eq!(have: has(&profile, "networking.istio.io/v1beta1"), want: Some(false));
+    sim_assert_eq!(have: has(&profile, "autoscaling/v2"), want: None);
+    sim_assert_eq!(have: has(&profile, "v1/Pod"), want: None);
+    sim_assert_eq!(have: profile.kube_version(), want: Some("v1.29.3"));
+}
+
+#[test]
+fn profiles_deserialize_from_camel_case_fields() {
+    let profile: CapabilityProfile = serde_json::from_value(serde_json::json!({
+        "kubeVersion": "1.30",
+        "apiVersions": ["monitoring.coreos.com/v1"],
+    }))
+    .expect("deserialize profile");
+    sim_assert_eq!(have: profile.kube_version(), want: Some("1.30"));
+    sim_assert_eq!(
+        have: profile.api_versions().collect::<Vec<_>>(),
+        want: vec!["monitoring.coreos.com/v1"]
+    );
+}
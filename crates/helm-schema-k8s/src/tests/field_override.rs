@@ -0,0 +1,177 @@
+use serde_json::json;
+use test_util::prelude::sim_assert_eq;
+
+use super::*;
+use crate::lookup::ProviderSchemaSource;
+
+fn overrides(document: serde_json::Value) -> FieldSchemaOverrides {
+    serde_json::from_value(document).expect("valid overrides")
+}
+
+fn deployment() -> ResourceRef {
+    ResourceRef::concrete("apps/v1".to_string(), "Deployment".to_string())
+}
+
+fn path(dotted: &str) -> YamlPath {
+    YamlPath(dotted.split('.').map(str::to_string).collect())
+}
+
+fn resources_fragment() -> ProviderSchemaFragment {
+    ProviderSchemaFragment::new(json!({
+        "type": "object",
+        "properties": {
+            "limits": {"type": "object"},
+            "requests": {"type": "object"}
+        }
+    }))
+    .with_source(ProviderSchemaSource::kubernetes_openapi(
+        "default",
+        "v1.35.0",
+        "deployment-apps-v1.json",
+        "/properties/spec",
+    ))
+}
+
+#[test]
+fn override_merges_onto_the_addressed_field() {
+    let overrides = overrides(json!({
+        "fields": [{
+            "group": "apps",
+            "kind": "Deployment",
+            "path": "spec.template.spec.containers[*].resources",
+            "schema": {
+                "required": ["limits"],
+                "properties": {"limits": {"required": ["memory"]}}
+            }
+        }]
+    }));
+    let fragment = overrides.apply(
+        &deployment(),
+        &path("spec.template.spec.containers[*].resources"),
+        resources_fragment(),
+    );
+
+    sim_assert_eq!(
+        have: fragment.schema(),
+        want: &json!({
+            "type": "object",
+            "required": ["limits"],
+            "properties": {
+                "limits": {"type": "object", "required": ["memory"]},
+                "requests": {"type": "object"}
+            }
+        })
+    );
+    assert!(
+        fragment.source().is_none(),
+        "a changed schema no longer maps to the provider leaf"
+    );
+}
+
+#[test]
+fn ancestor_override_reaches_descendant_fields() {
+    let overrides = overrides(json!({
+        "fields": [{
+            "group": "apps",
+            "kind": "Deployment",
+            "path": "spec.template.spec",
+            "schema": {
+                "properties": {
+                    "containers": {
+                        "items": {
+                            "required": ["resources"],
+                            "properties": {
+                                "image": {"pattern": "^registry\\.example\\.com/"}
+                            }
+                        }
+                    }
+                }
+            }
+        }]
+    }));
+    let image = overrides.apply(
+        &deployment(),
+        &path("spec.template.spec.containers[*].image"),
+        ProviderSchemaFragment::new(json!({"type": "string"})),
+    );
+    sim_assert_eq!(
+        have: image.schema(),
+        want: &json!({"type": "string", "pattern": "^registry\\.example\\.com/"})
+    );
+    assert!(!image.required_in_parent());
+
+    let resources = overrides.apply(
+        &deployment(),
+        &path("spec.template.spec.containers[*].resources"),
+        resources_fragment(),
+    );
+    assert!(
+        resources.required_in_parent(),
+        "the ancestor's `required` reaches the field"
+    );
+    assert!(
+        resources.source().is_some(),
+        "an unchanged schema keeps provider ownership"
+    );
+}
+
+#[test]
+fn overrides_only_match_their_group_and_kind() {
+    let overrides = FieldSchemaOverrides::new([
+        FieldSchemaOverride {
+            group: String::new(),
+            kind: "Deployment".to_string(),
+            path: path("spec"),
+            schema: json!({"type": "null"}),
+        },
+        FieldSchemaOverride {
+            group: "apps".to_string(),
+            kind: "StatefulSet".to_string(),
+            path: path("spec"),
+            schema: json!({"type": "null"}),
+        },
+    ]);
+    let fragment = overrides.apply(
+        &deployment(),
+        &path("spec"),
+        ProviderSchemaFragment::new(json!({"type": "object"})),
+    );
+    sim_assert_eq!(have: fragment.schema(), want: &json!({"type": "object"}));
+}
+
+#[test]
+fn override_of_a_ref_field_is_enforced_under_draft_07() {
+    let overrides = overrides(json!({
+        "fields": [{
+            "group": "apps",
+            "kind": "Deployment",
+            "path": "spec.template.spec.containers[*].resources",
+            "schema": {"required": ["limits"]}
+        }]
+    }));
+    let fragment = overrides.apply(
+        &deployment(),
+        &path("spec.template.spec.containers[*].resources"),
+        ProviderSchemaFragment::new(json!({"$ref": "#/definitions/resources"})),
+    );
+    sim_assert_eq!(
+        have: fragment.schema(),
+        want: &json!({
+            "allOf": [{"$ref": "#/definitions/resources"}, {"required": ["limits"]}]
+        })
+    );
+
+    let mut document = fragment.schema().clone();
+    if let Some(document) = document.as_object_mut() {
+        document.insert(
+            "definitions".to_string(),
+            json!({"resources": {"type": "object"}}),
+        );
+    }
+    let validator = jsonschema::draft7::new(&document).expect("valid draft-07 schema");
+    assert!(validator.is_valid(&json!({"limits": {}})));
+    assert!(
+        !validator.is_valid(&json!({"requests": {}})),
+        "the override's `required` applies beside the `$ref`"
+    );
+}
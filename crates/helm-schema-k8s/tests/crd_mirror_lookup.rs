@@ -369,6 +369,10 @@ fn crd_cache_record_source_writes_meta_sidecar() -> eyre::Result<()> {
         content.contains(default_url),
         "sidecar carries the actual URL"
     );
+    assert!(
+        content.contains("sha256:"),
+        "sidecar records the content digest"
+    );
     Ok(())
 }
 
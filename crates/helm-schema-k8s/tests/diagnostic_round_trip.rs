@@ -72,6 +72,18 @@ fn sample_variants() -> Vec<Diagnostic> {
             disabled_knobs: vec!["terminal-clauses".to_string()],
             explicit: false,
         },
+        Diagnostic::UnresolvedChartDependency {
+            chart: "app".to_string(),
+            dependency: "redis".to_string(),
+            version: Some("18.1.0".to_string()),
+            repository: Some("https://charts.example.com".to_string()),
+            locations_tried: vec!["/cache/redis-18.1.0.tgz".to_string()],
+        },
+        Diagnostic::UnreadableChartLock {
+            chart: "app".to_string(),
+            path: "charts/app/Chart.lock".to_string(),
+            error: "dependencies: invalid type: string \"redis\", expected a sequence".to_string(),
+        },
     ]
 }
 
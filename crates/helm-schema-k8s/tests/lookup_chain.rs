@@ -2,12 +2,14 @@
 //! `PathUnresolved` silence, and the "`MissingSchema` only from chain"
 //! invariant.
 
+use std::sync::Arc;
+
 use helm_schema_core::{
     ApiPresenceQuery, ProviderSchemaUse, ResourceRef, ResourceSchemaOracle, ValueKind, YamlPath,
 };
 use helm_schema_k8s::{
-    Chain, Diagnostic, DiagnosticSink, K8sSchemaProvider, LookupTraceEntry, LookupTraceOutcome,
-    ProviderLookupResult, ProviderOrigin, ProviderSchemaFragment,
+    CapabilityProfile, Chain, Diagnostic, DiagnosticSink, K8sSchemaProvider, LookupTraceEntry,
+    LookupTraceOutcome, ProviderLookupResult, ProviderOrigin, ProviderSchemaFragment,
 };
 use serde_json::Value;
 use test_util::prelude::sim_assert_eq;
@@ -405,6 +407,44 @@ fn traced_capability_query_records_provider_attempts_until_answer() {
     );
 }
 
+#[test]
+fn capability_profile_answers_before_providers() {
+    let k8s = FakeProvider::new(
+        ProviderOrigin::KubernetesOpenApi,
+        false,
+        FakeBehaviour::NotOwned,
+    )
+    .with_capability("autoscaling/v2", true)
+    .with_capability("monitoring.coreos.com/v1", false);
+    let profile = CapabilityProfile::new(None, ["monitoring.coreos.com/v1".to_string()]);
+    let chain = Chain::new(vec![Box::new(k8s)]).with_capability_profile(Arc::new(profile));
+    let traced = |api: &str| {
+        let query = ApiPresenceQuery::parse_helm_literal(api).expect("parse api presence query");
+        chain.capability_has_query_at_primary_version_traced(&query)
+    };
+
+    let listed = traced("monitoring.coreos.com/v1");
+    sim_assert_eq!(have: listed.answer, want: Some(true));
+    sim_assert_eq!(
+        have: listed.trace.entries(),
+        want: [LookupTraceEntry::ApiPresenceProfile { answer: true }].as_slice()
+    );
+    sim_assert_eq!(
+        have: traced("networking.istio.io/v1beta1").answer,
+        want: Some(false)
+    );
+    // Built-in groups the profile does not list keep the release's answer.
+    let builtin = traced("autoscaling/v2");
+    sim_assert_eq!(have: builtin.answer, want: Some(true));
+    assert!(matches!(
+        builtin.trace.entries(),
+        [LookupTraceEntry::ApiPresenceProvider {
+            provider: ProviderOrigin::KubernetesOpenApi,
+            answer: Some(true),
+        }]
+    ));
+}
+
 #[test]
 fn chain_exposes_provider_kube_version() {
     #[derive(Debug)]
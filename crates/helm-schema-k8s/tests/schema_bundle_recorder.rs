@@ -0,0 +1,110 @@
+//! `SchemaBundleRecorder` captures every cache slot the fetch-on-miss
+//! providers consult, keyed by its path in the bundle.
+
+use std::sync::Arc;
+
+use color_eyre::eyre;
+use helm_schema_core::{ApiPresenceQuery, ResourceRef, YamlPath};
+use helm_schema_k8s::{
+    CrdsCatalogSchemaProvider, K8sSchemaProvider, K8sVersionChain, KubernetesJsonSchemaProvider,
+    ProviderLookupResult, SchemaBundleEntry, SchemaBundleRecorder,
+};
+use test_util::prelude::sim_assert_eq;
+
+/// Shared provider fixtures for K8s integration tests.
+pub mod common;
+use common::MockFetcher;
+
+const K8S_BASE_URL: &str = "https://raw.githubusercontent.com/yannh/kubernetes-json-schema/master";
+const CRD_BASE_URL: &str = "https://raw.githubusercontent.com/datreeio/CRDs-catalog/main";
+
+fn path(segments: &[&str]) -> YamlPath {
+    YamlPath(segments.iter().map(ToString::to_string).collect())
+}
+
+#[test]
+fn recorder_captures_documents_ref_targets_and_absences() -> eyre::Result<()> {
+    let cache_dir = tempfile::tempdir()?;
+    let mock = Arc::new(
+        MockFetcher::new()
+            .with_body(
+                format!("{K8S_BASE_URL}/v1.35.0/configmap-v1.json"),
+                r#"{"$ref": "_definitions.json#/definitions/io.k8s.api.core.v1.ConfigMap"}"#,
+            )
+            .with_body(
+                format!("{K8S_BASE_URL}/v1.35.0/_definitions.json"),
+                r#"{"definitions": {"io.k8s.api.core.v1.ConfigMap": {"type": "object", "properties": {"immutable": {"type": "boolean"}}}}}"#,
+            ),
+    );
+    let recorder = Arc::new(SchemaBundleRecorder::new());
+    let provider = KubernetesJsonSchemaProvider::with_versions(K8sVersionChain::new(
+        vec!["v1.35.0".to_string()],
+        None,
+    ))
+    .with_cache_dir(cache_dir.path())
+    .with_allow_download(true)
+    .with_fetcher(mock)
+    .with_schema_bundle_recorder(Arc::clone(&recorder));
+
+    let config_map = ResourceRef::concrete("v1".to_string(), "ConfigMap".to_string());
+    assert!(
+        matches!(
+            provider.lookup(&config_map, &path(&["immutable"])),
+            ProviderLookupResult::Found { .. }
+        ),
+        "ConfigMap.immutable resolves through the $ref"
+    );
+    let query = ApiPresenceQuery::parse_helm_literal("autoscaling/v2beta1")
+        .expect("parse api presence query");
+    sim_assert_eq!(
+        have: provider.capability_has_query_at_primary_version(&query),
+        want: Some(false)
+    );
+
+    let entries = recorder.entries();
+    let paths: Vec<_> = entries
+        .iter()
+        .filter(|(_, entry)| matches!(entry, SchemaBundleEntry::Document(_)))
+        .map(|(path, _)| path.as_str())
+        .collect();
+    sim_assert_eq!(
+        have: paths,
+        want: vec![
+            "kubernetes/default/v1.35.0/_definitions.json",
+            "kubernetes/default/v1.35.0/configmap-v1.json",
+        ]
+    );
+    assert!(
+        entries
+            .values()
+            .any(|entry| matches!(entry, SchemaBundleEntry::NotFound)),
+        "the authoritative 404 behind the capability answer is recorded: {entries:?}"
+    );
+    Ok(())
+}
+
+#[test]
+fn recorder_keys_crd_documents_by_catalog_path() -> eyre::Result<()> {
+    let cache_dir = tempfile::tempdir()?;
+    let mock = Arc::new(MockFetcher::new().with_body(
+        format!("{CRD_BASE_URL}/example.com/widget_v1.json"),
+        r#"{"type": "object", "properties": {"spec": {"type": "object"}}}"#,
+    ));
+    let recorder = Arc::new(SchemaBundleRecorder::new());
+    let provider = CrdsCatalogSchemaProvider::new()
+        .with_cache_dir(cache_dir.path())
+        .with_allow_download(true)
+        .with_fetcher(mock)
+        .with_schema_bundle_recorder(Arc::clone(&recorder));
+
+    let widget = ResourceRef::concrete("example.com/v1".to_string(), "Widget".to_string());
+    assert!(matches!(
+        provider.lookup(&widget, &path(&["spec"])),
+        ProviderLookupResult::Found { .. }
+    ));
+    sim_assert_eq!(
+        have: recorder.entries().into_keys().collect::<Vec<_>>(),
+        want: vec!["crds/default/example.com/widget_v1.json".to_string()]
+    );
+    Ok(())
+}
@@ -3,7 +3,7 @@
 //! Set `RAW_CONTRACT=1` to dump the whole contract IR first.
 use std::path::PathBuf;
 
-use helm_schema::generation::SchemaProfile;
+use helm_schema::generation::{EnumInference, SchemaProfile};
 use helm_schema::provider::ProviderOptions;
 use helm_schema::{AnalysisSession, GenerateOptions};
 use vfs::VfsPath;
@@ -19,6 +19,8 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         include_subchart_values: true,
         values_files: Vec::new(),
         infer_required: false,
+        infer_enums: EnumInference::Off,
+        cluster_state: None,
         emission: SchemaProfile::default().into(),
         provider: ProviderOptions {
             k8s_versions: vec!["v1.29.0-standalone-strict".to_string()],
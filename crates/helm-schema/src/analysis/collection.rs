@@ -1,7 +1,9 @@
 use std::collections::BTreeSet;
+use std::sync::Arc;
 
 use helm_schema_ast::DefineIndex;
-use helm_schema_ir::{ContractIr, SymbolicIrContext, SymbolicPolicy};
+use helm_schema_core::ClusterStateOracle;
+use helm_schema_ir::{ContractIr, SymbolicPolicy};
 use helm_schema_k8s::LocalSchemaUniverse;
 
 use super::local_crd_projection::collect_static_crd_universe;
@@ -9,6 +11,7 @@ use super::manifest_contract::{
     DefineCorpus, ManifestContractAnalysis, collect_manifest_contract_for_chart,
     optional_dependency_helpers_for_chart,
 };
+use super::template_cache::{AnalysisCache, HelperSummaries, TemplateEvaluator};
 use super::values_seed::seed_top_level_values_yaml_keys;
 use crate::chart;
 use crate::error::EngineResult;
@@ -21,13 +24,17 @@ pub(crate) struct ChartAnalysis {
     pub(crate) shadowed_input_paths: BTreeSet<String>,
 }
 
+/// Analyze every chart, reusing per-template results from `cache` when the
+/// template and the helpers it reaches are unchanged.
 #[tracing::instrument(skip_all)]
-pub(crate) fn analyze_charts(
+pub(crate) fn analyze_charts_cached(
     charts: &[chart::ChartContext],
     defines: &DefineIndex,
     include_tests: bool,
     values_roots: &ValuesRoots,
     kubernetes_version: Option<&str>,
+    cluster_state: Option<&Arc<dyn ClusterStateOracle>>,
+    cache: Option<&AnalysisCache>,
 ) -> EngineResult<ChartAnalysis> {
     let mut contract = ContractIr::default();
     if charts.iter().any(|chart| !chart.values_prefix.is_empty()) {
@@ -53,18 +60,22 @@ pub(crate) fn analyze_charts(
 
     let corpus = DefineCorpus::build(charts, defines);
     let dependency_global_ownership = chart::build_dependency_global_ownership(charts)?;
+    let helper_summaries = cache.map(|cache| HelperSummaries::build(cache, defines));
+    let cache = cache.zip(helper_summaries.as_ref());
     for chart in charts {
         if chart.is_library {
             continue;
         }
-        let symbolic_context = SymbolicIrContext::with_policy(
+        let evaluator = TemplateEvaluator::new(
             defines,
             SymbolicPolicy {
                 chart_default_strings: values_roots
                     .string_defaults_for_prefix(&chart.values_prefix),
                 kubernetes_version: kubernetes_version.map(str::to_string),
                 static_root_strings: chart.static_root_strings.clone(),
+                cluster_state: cluster_state.cloned(),
             },
+            cache,
         );
         let optional_helpers = optional_dependency_helpers_for_chart(chart, charts, &corpus);
         let ManifestContractAnalysis {
@@ -72,7 +83,7 @@ pub(crate) fn analyze_charts(
             local_resource_schemas,
         } = collect_manifest_contract_for_chart(
             chart,
-            &symbolic_context,
+            &evaluator,
             include_tests,
             &optional_helpers,
             &corpus,
@@ -83,6 +94,13 @@ pub(crate) fn analyze_charts(
         }
     }
 
+    // Imported tables are schema'd at the parent path too, deepest
+    // dependencies first so chained imports carry through.
+    for (source, target) in chart::ChartContext::ordered_value_imports(charts) {
+        let imported = contract.project_value_subtree(&source, &target);
+        contract.append(imported);
+    }
+
     let dependency_root_paths = charts
         .iter()
         .filter_map(|chart| chart.values_prefix.first().cloned())
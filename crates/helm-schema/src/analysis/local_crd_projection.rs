@@ -1,4 +1,10 @@
-use helm_schema_ast::{ParseError, parse_helm_template};
+use std::borrow::Cow;
+use std::ops::Range;
+
+use helm_schema_ast::{
+    Literal, ParseError, TemplateExpr, parse_expr_text, parse_go_template, parse_helm_template,
+};
+use helm_schema_core::{ConditionalGuard, GuardValue, ResourceRef};
 use helm_schema_k8s::{
     LocalResourceSchema, LocalSchemaUniverse, resource_schemas_from_crd_document_with_source,
 };
@@ -44,9 +50,23 @@ pub(crate) fn local_resource_schemas_from_template_source(
         return resource_schemas_from_literal_documents(source, TEMPLATE_CRD_SOURCE_ID, filename);
     }
 
-    let tree = parse_helm_template(source).ok_or(ParseError::TreeSitterParseFailed)?;
+    // The fused YAML grammar reads control actions as comments, so branch
+    // structure comes from the Go-template parse: dead arms are blanked out
+    // before the YAML parse, and live arms guard the CRDs they contain.
+    let control = parse_go_template(source).ok_or(ParseError::TreeSitterParseFailed)?;
+    let mut arms = ControlArms::default();
+    collect_control_arms(control.root_node(), source, &[], &mut arms);
+    let live_source = arms.blank_dead_arms(source);
+
+    let tree = parse_helm_template(&live_source).ok_or(ParseError::TreeSitterParseFailed)?;
     let mut resource_schemas = Vec::new();
-    collect_template_crd_schemas(tree.root_node(), source, filename, &mut resource_schemas);
+    collect_template_crd_schemas(
+        tree.root_node(),
+        &live_source,
+        filename,
+        &arms,
+        &mut resource_schemas,
+    );
     Ok(resource_schemas)
 }
 
@@ -74,6 +94,7 @@ fn collect_template_crd_schemas(
     node: tree_sitter::Node<'_>,
     source: &str,
     filename: &str,
+    arms: &ControlArms,
     resource_schemas: &mut Vec<LocalResourceSchema>,
 ) {
     if let Some(document) = crd_document_from_node(node, source) {
@@ -83,14 +104,249 @@ fn collect_template_crd_schemas(
             filename.to_string(),
         );
         if !schemas.is_empty() {
-            resource_schemas.extend(schemas);
+            let guards = arms.guards_at(node.start_byte());
+            resource_schemas.extend(schemas.into_iter().map(|mut schema| {
+                schema.guards = guards.to_vec();
+                schema
+            }));
             return;
         }
     }
 
     let mut cursor = node.walk();
     for child in node.named_children(&mut cursor) {
-        collect_template_crd_schemas(child, source, filename, resource_schemas);
+        collect_template_crd_schemas(child, source, filename, arms, resource_schemas);
+    }
+}
+
+/// Branch arms of a template's `if`/`with` chains, evaluated symbolically.
+#[derive(Debug, Default)]
+struct ControlArms {
+    /// Arms that can render, with the conditions under which they do.
+    live: Vec<(Range<usize>, Vec<String>)>,
+    /// Arms a literal condition rules out.
+    dead: Vec<Range<usize>>,
+}
+
+impl ControlArms {
+    /// Conditions of the innermost live arm containing `offset`; empty
+    /// outside every arm.
+    fn guards_at(&self, offset: usize) -> &[String] {
+        self.live
+            .iter()
+            .filter(|(span, _)| span.contains(&offset))
+            .min_by_key(|(span, _)| span.len())
+            .map_or(&[], |(_, guards)| guards.as_slice())
+    }
+
+    /// `source` with every dead arm replaced by spaces. Line breaks and
+    /// byte offsets are kept, so live nodes keep their arm spans.
+    fn blank_dead_arms<'source>(&self, source: &'source str) -> Cow<'source, str> {
+        if self.dead.is_empty() {
+            return Cow::Borrowed(source);
+        }
+        let mut bytes = source.as_bytes().to_vec();
+        for span in &self.dead {
+            for byte in bytes.get_mut(span.clone()).into_iter().flatten() {
+                if *byte != b'\n' {
+                    *byte = b' ';
+                }
+            }
+        }
+        // Arms span whole nodes, so blanking never splits a character.
+        String::from_utf8(bytes).map_or(Cow::Borrowed(source), Cow::Owned)
+    }
+}
+
+/// Record the arms of every `if`/`with` chain under `node`. An arm's guards
+/// are the enclosing guards, the negation of every earlier undecided
+/// condition of its chain, and its own condition.
+fn collect_control_arms(
+    node: tree_sitter::Node<'_>,
+    source: &str,
+    guards: &[String],
+    arms: &mut ControlArms,
+) {
+    if !matches!(node.kind(), "if_action" | "with_action") {
+        let mut cursor = node.walk();
+        for child in node.named_children(&mut cursor) {
+            collect_control_arms(child, source, guards, arms);
+        }
+        return;
+    }
+
+    let mut decided = false;
+    let mut earlier_negated = Vec::new();
+    for (condition, children) in chain_arms(node, source) {
+        let (Some(first), Some(last)) = (children.first(), children.last()) else {
+            continue;
+        };
+        let span = first.start_byte()..last.end_byte();
+        if decided {
+            arms.dead.push(span);
+            continue;
+        }
+        let mut arm_guards = guards.to_vec();
+        arm_guards.extend(earlier_negated.iter().cloned());
+        match condition.as_deref().map(literal_truth) {
+            Some(Some(false)) => {
+                arms.dead.push(span);
+                continue;
+            }
+            Some(None) => {
+                let condition = condition.unwrap_or_default();
+                earlier_negated.push(format!("not ({condition})"));
+                arm_guards.push(condition);
+            }
+            // A literal-true condition or the final `else`: later arms
+            // never render.
+            Some(Some(true)) | None => decided = true,
+        }
+        for child in &children {
+            collect_control_arms(*child, source, &arm_guards, arms);
+        }
+        arms.live.push((span, arm_guards));
+    }
+}
+
+/// The arms of one `if`/`with` chain in source order: each `if` and
+/// `else if` condition with its body, then the `else` body under `None`.
+fn chain_arms<'tree>(
+    node: tree_sitter::Node<'tree>,
+    source: &str,
+) -> Vec<(Option<String>, Vec<tree_sitter::Node<'tree>>)> {
+    let mut arms: Vec<(Option<String>, Vec<tree_sitter::Node<'tree>>)> = Vec::new();
+    let mut cursor = node.walk();
+    if !cursor.goto_first_child() {
+        return arms;
+    }
+    loop {
+        let child = cursor.node();
+        match cursor.field_name() {
+            Some("condition") => {
+                let condition = child
+                    .utf8_text(source.as_bytes())
+                    .map_or_else(|_| String::new(), |text| text.trim().to_string());
+                arms.push((Some(condition), Vec::new()));
+            }
+            Some("consequence" | "option") => {
+                if let Some((_, children)) = arms.last_mut() {
+                    children.push(child);
+                }
+            }
+            Some("alternative") => {
+                if arms.last().is_none_or(|(condition, _)| condition.is_some()) {
+                    arms.push((None, Vec::new()));
+                }
+                if let Some((_, children)) = arms.last_mut() {
+                    children.push(child);
+                }
+            }
+            _ => {}
+        }
+        if !cursor.goto_next_sibling() {
+            break;
+        }
+    }
+    arms
+}
+
+/// Helm truthiness of a condition that is a single literal; `None` when
+/// the condition depends on values or other render-time state.
+fn literal_truth(condition: &str) -> Option<bool> {
+    let exprs = parse_expr_text(condition);
+    let [expr] = exprs.as_slice() else {
+        return None;
+    };
+    let mut expr = expr;
+    while let TemplateExpr::Parenthesized(inner) = expr {
+        expr = inner;
+    }
+    let TemplateExpr::Literal(literal) = expr else {
+        return None;
+    };
+    Some(match literal {
+        Literal::Bool(value) => *value,
+        Literal::String(value) | Literal::RawString(value) => !value.is_empty(),
+        Literal::Int(value) => *value != 0,
+        Literal::Float(value) => *value != 0.0,
+        Literal::Nil => false,
+    })
+}
+
+/// Decoded guards of the chart's definition of `resource`: empty when the
+/// chart defines it unconditionally or not at all, `None` when one of its
+/// install conditions does not decode.
+pub(crate) fn resource_definition_guards(
+    universe: &LocalSchemaUniverse,
+    resource: &ResourceRef,
+) -> Option<Vec<ConditionalGuard>> {
+    universe
+        .guards_for_resource(resource)
+        .unwrap_or_default()
+        .iter()
+        .map(|condition| {
+            let exprs = parse_expr_text(condition);
+            let [expr] = exprs.as_slice() else {
+                return None;
+            };
+            condition_guard(expr)
+        })
+        .collect()
+}
+
+/// The values-decidable guard a CRD install condition tests: values
+/// truthiness combined through `not`, `and`, and `or`, or string equality.
+fn condition_guard(expr: &TemplateExpr) -> Option<ConditionalGuard> {
+    let TemplateExpr::Call { function, args } = expr.deparen() else {
+        return values_path(expr).map(|path| ConditionalGuard::Truthy { path });
+    };
+    match (function.as_str(), args.as_slice()) {
+        ("not", [operand]) => Some(ConditionalGuard::Not(Box::new(condition_guard(operand)?))),
+        ("and", [_, _, ..]) => args
+            .iter()
+            .map(condition_guard)
+            .collect::<Option<_>>()
+            .map(ConditionalGuard::AllOf),
+        ("or", [_, _, ..]) => args
+            .iter()
+            .map(condition_guard)
+            .collect::<Option<_>>()
+            .map(ConditionalGuard::AnyOf),
+        ("eq" | "ne", [left, right]) => {
+            let (path, literal) = match (left.deparen(), right.deparen()) {
+                (TemplateExpr::Literal(literal), subject)
+                | (subject, TemplateExpr::Literal(literal)) => (values_path(subject)?, literal),
+                _ => return None,
+            };
+            let (Literal::String(value) | Literal::RawString(value)) = literal else {
+                return None;
+            };
+            let value = GuardValue::String(value.clone());
+            Some(if function == "eq" {
+                ConditionalGuard::Eq { path, value }
+            } else {
+                ConditionalGuard::NotEq { path, value }
+            })
+        }
+        _ => None,
+    }
+}
+
+/// The values path `expr` selects: `.Values.a.b` or `$.Values.a.b`.
+fn values_path(expr: &TemplateExpr) -> Option<String> {
+    let segments = match expr.deparen() {
+        TemplateExpr::Field(segments) => segments,
+        TemplateExpr::Selector { operand, path } if matches!(operand.deparen(), TemplateExpr::Variable(name) if name.is_empty()) => {
+            path
+        }
+        _ => return None,
+    };
+    match segments.split_first() {
+        Some((root, rest)) if root == "Values" && !rest.is_empty() => {
+            Some(helm_schema_core::join_value_path(rest.iter().cloned()))
+        }
+        _ => None,
     }
 }
 
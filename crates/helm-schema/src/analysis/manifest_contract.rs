@@ -4,13 +4,14 @@ use helm_schema_k8s::LocalResourceSchema;
 use vfs::VfsPath;
 
 use super::local_crd_projection::local_resource_schemas_from_template_source;
+use super::template_cache::{TemplateAnalysis, TemplateEvaluator};
 use crate::chart;
 use crate::error::EngineResult;
 
 #[tracing::instrument(skip_all, fields(prefix_len = chart.values_prefix.len()))]
 pub(crate) fn collect_manifest_contract_for_chart(
     chart: &chart::ChartContext,
-    symbolic_context: &SymbolicIrContext,
+    evaluator: &TemplateEvaluator<'_>,
     include_tests: bool,
     optional_helpers: &[OptionalDependencyHelpers],
     corpus: &DefineCorpus,
@@ -26,8 +27,12 @@ pub(crate) fn collect_manifest_contract_for_chart(
     )?;
     for path in manifests {
         let source = path.read_to_string()?;
-        let (mut manifest_contract, template_local_resource_schemas) =
-            collect_manifest_contract_for_template(&source, &path, symbolic_context)?;
+        let TemplateAnalysis {
+            contract: mut manifest_contract,
+            local_resource_schemas: template_local_resource_schemas,
+        } = evaluator.evaluate("manifests", &source, path.as_str(), |context| {
+            collect_manifest_contract_for_template(&source, &path, context)
+        })?;
         manifest_contract
             .map_value_paths(|path| chart::scope_values_path(path, &chart.values_prefix));
         manifest_contract.project_dependency_global_contracts(&chart.values_prefix);
@@ -64,7 +69,9 @@ pub(crate) fn collect_manifest_contract_for_chart(
     for path in notes {
         let source = path.read_to_string()?;
         let mut notes_contract =
-            symbolic_context.generate_contract_ir_for_source(&source, path.as_str());
+            evaluator.evaluate("notes", &source, path.as_str(), |context| {
+                Ok(context.generate_contract_ir_for_source(&source, path.as_str()))
+            })?;
         // NOTES is a Go-template text program, not YAML. Direct holes use
         // Go's textual formatting and therefore impose no input shape; real
         // strict calls and terminal effects remain in their own channels.
@@ -91,14 +98,17 @@ fn collect_manifest_contract_for_template(
     source: &str,
     path: &VfsPath,
     symbolic_context: &SymbolicIrContext,
-) -> EngineResult<(ContractIr, Vec<LocalResourceSchema>)> {
+) -> EngineResult<TemplateAnalysis> {
     let contract = symbolic_context.generate_contract_ir_for_source(source, path.as_str());
     let local_resource_schemas = local_resource_schemas_from_template_source(
         source,
         path.as_str(),
         contains_template_action(source)?,
     )?;
-    Ok((contract, local_resource_schemas))
+    Ok(TemplateAnalysis {
+        contract,
+        local_resource_schemas,
+    })
 }
 
 fn apply_chart_activation_guard_sets(
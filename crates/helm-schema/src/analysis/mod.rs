@@ -1,6 +1,9 @@
 mod collection;
 mod local_crd_projection;
 mod manifest_contract;
+mod template_cache;
 mod values_seed;
 
-pub(crate) use collection::analyze_charts;
+pub(crate) use collection::analyze_charts_cached;
+pub(crate) use local_crd_projection::resource_definition_guards;
+pub use template_cache::AnalysisCache;
@@ -0,0 +1,371 @@
+use std::cell::OnceCell;
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::Write as _;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+
+use helm_schema_ast::{DefineIndex, template_dependencies};
+use helm_schema_ir::{ContractIr, SymbolicIrContext, SymbolicPolicy};
+use helm_schema_k8s::LocalResourceSchema;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+/// Version of the cached entry layout. Together with the crate version it
+/// keys every entry, so a release whose analysis changes never reads results
+/// an older analysis produced.
+const ANALYSIS_CACHE_FORMAT: &str = "3";
+
+/// Content-addressed cache of per-template analysis results.
+///
+/// Template entries are keyed by the template source, the bodies of every
+/// helper the template can reach, and the symbolic policy it was evaluated
+/// under. After a one-file edit only templates whose key changed, the edited
+/// template and the callers of an edited helper, are re-evaluated. Templates
+/// that reach helpers or files by computed name (`tpl`, `.Files`, dynamic
+/// `include`) are keyed by the whole define index instead.
+///
+/// Helper summaries (define names, body digests, and the helpers each body
+/// includes) are cached per define-index file, so unchanged helper files are
+/// not re-parsed either. Entries are only written when they round-trip
+/// losslessly; the cache is best-effort and unreadable entries are misses.
+///
+/// The cache lives either on disk ([`Self::new`]) or in memory for the
+/// lifetime of one process ([`Self::in_memory`]); clones share their
+/// entries.
+#[derive(Debug, Clone)]
+pub struct AnalysisCache {
+    store: Store,
+}
+
+/// Latest entry per `(kind, source path)`: its key and serialized value.
+type MemoryEntries = BTreeMap<(String, String), (String, Vec<u8>)>;
+
+#[derive(Debug, Clone)]
+enum Store {
+    Disk(PathBuf),
+    /// Keeps only the latest entry of each file, so a long-running process
+    /// holds one result per template however often it is edited.
+    Memory(Arc<Mutex<MemoryEntries>>),
+}
+
+impl AnalysisCache {
+    /// Creates a cache rooted at `root`; the directory is created on first
+    /// write.
+    #[must_use]
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Self {
+            store: Store::Disk(root.into()),
+        }
+    }
+
+    /// Creates a cache that keeps its entries in memory.
+    #[must_use]
+    pub fn in_memory() -> Self {
+        Self {
+            store: Store::Memory(Arc::default()),
+        }
+    }
+
+    /// The cache root used when the caller configures none:
+    /// `HELM_SCHEMA_ANALYSIS_CACHE`, else `helm-schema/analysis` under the
+    /// per-user cache directory.
+    #[must_use]
+    pub fn default_root() -> PathBuf {
+        helm_schema_k8s::cache::default_cache_dir("HELM_SCHEMA_ANALYSIS_CACHE", "analysis")
+    }
+
+    /// Directory holding the cache entries, or `None` for an in-memory
+    /// cache.
+    #[must_use]
+    pub fn root(&self) -> Option<&Path> {
+        match &self.store {
+            Store::Disk(root) => Some(root),
+            Store::Memory(_) => None,
+        }
+    }
+
+    /// Drops the in-memory entries of every template and helper file whose
+    /// chart path `changed` accepts.
+    ///
+    /// Keys already cover the file contents, so this only releases entries
+    /// that can no longer hit; an on-disk cache is left untouched.
+    pub fn invalidate(&self, changed: impl Fn(&str) -> bool) {
+        if let Store::Memory(entries) = &self.store {
+            entries
+                .lock()
+                .unwrap_or_else(std::sync::PoisonError::into_inner)
+                .retain(|(_, source_path), _| !changed(source_path));
+        }
+    }
+
+    fn read<T: DeserializeOwned>(&self, kind: &str, source_path: &str, key: &str) -> Option<T> {
+        match &self.store {
+            Store::Disk(root) => {
+                let bytes = std::fs::read(entry_path(root, kind, key)).ok()?;
+                serde_json::from_slice(&bytes).ok()
+            }
+            Store::Memory(entries) => {
+                let entries = entries
+                    .lock()
+                    .unwrap_or_else(std::sync::PoisonError::into_inner);
+                let (entry_key, bytes) =
+                    entries.get(&(kind.to_string(), source_path.to_string()))?;
+                (entry_key == key)
+                    .then(|| serde_json::from_slice(bytes).ok())
+                    .flatten()
+            }
+        }
+    }
+
+    /// Writes an entry unless it would not read back as `value`.
+    fn write<T: Serialize + DeserializeOwned + PartialEq>(
+        &self,
+        kind: &str,
+        source_path: &str,
+        key: &str,
+        value: &T,
+    ) {
+        let Ok(bytes) = serde_json::to_vec(value) else {
+            return;
+        };
+        if serde_json::from_slice::<T>(&bytes).ok().as_ref() != Some(value) {
+            tracing::debug!(kind, key, "analysis result does not round-trip; not cached");
+            return;
+        }
+        match &self.store {
+            Store::Disk(root) => {
+                let path = entry_path(root, kind, key);
+                if let Err(err) = write_atomic(&path, &bytes) {
+                    tracing::debug!(path = %path.display(), %err, "failed to write analysis cache entry");
+                }
+            }
+            Store::Memory(entries) => {
+                entries
+                    .lock()
+                    .unwrap_or_else(std::sync::PoisonError::into_inner)
+                    .insert(
+                        (kind.to_string(), source_path.to_string()),
+                        (key.to_string(), bytes),
+                    );
+            }
+        }
+    }
+}
+
+fn entry_path(root: &Path, kind: &str, key: &str) -> PathBuf {
+    root.join(kind).join(format!("{key}.json"))
+}
+
+fn write_atomic(path: &Path, bytes: &[u8]) -> std::io::Result<()> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    let tmp = path.with_extension(format!("json.tmp.{}", std::process::id()));
+    std::fs::write(&tmp, bytes)?;
+    std::fs::rename(&tmp, path).or_else(|err| {
+        let _ = std::fs::remove_file(&tmp);
+        if path.exists() { Ok(()) } else { Err(err) }
+    })
+}
+
+/// Length-prefixed SHA-256 over a sequence of fields, hex encoded.
+fn digest<'a>(fields: impl IntoIterator<Item = &'a [u8]>) -> String {
+    let mut hasher = Sha256::new();
+    for field in fields {
+        hasher.update((field.len() as u64).to_le_bytes());
+        hasher.update(field);
+    }
+    let mut hex = String::with_capacity(64);
+    for byte in hasher.finalize() {
+        let _ = write!(hex, "{byte:02x}");
+    }
+    hex
+}
+
+/// One `define` of a helper file, as cached per file.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+struct DefineSummary {
+    name: String,
+    body_digest: String,
+    helper_names: BTreeSet<String>,
+    dynamic: bool,
+}
+
+/// Define-index summary: what each helper name resolves to and reaches.
+pub(crate) struct HelperSummaries {
+    helpers: BTreeMap<String, DefineSummary>,
+    /// Digest of every define-index source, for templates whose reach
+    /// cannot be bounded by literal helper names.
+    index_digest: String,
+}
+
+impl HelperSummaries {
+    pub(crate) fn build(cache: &AnalysisCache, defines: &DefineIndex) -> Self {
+        let mut helpers = BTreeMap::new();
+        let mut index_fields: Vec<&[u8]> = vec![ANALYSIS_CACHE_FORMAT.as_bytes()];
+        for (path, source) in defines.file_sources() {
+            index_fields.extend([path.as_bytes(), source.as_bytes()]);
+            let key = digest([
+                b"helpers".as_slice(),
+                ANALYSIS_CACHE_FORMAT.as_bytes(),
+                env!("CARGO_PKG_VERSION").as_bytes(),
+                source.as_bytes(),
+            ]);
+            let summaries = cache
+                .read::<Vec<DefineSummary>>("helpers", path, &key)
+                .unwrap_or_else(|| {
+                    let summaries = summarize_defines(source);
+                    cache.write("helpers", path, &key, &summaries);
+                    summaries
+                });
+            // Later files win, as in Helm's global define namespace.
+            for summary in summaries {
+                helpers.insert(summary.name.clone(), summary);
+            }
+        }
+        Self {
+            helpers,
+            index_digest: digest(index_fields),
+        }
+    }
+
+    /// Digest of everything `source` can reach through helpers.
+    fn reach_digest(&self, source: &str) -> String {
+        let dependencies = template_dependencies(source);
+        let mut dynamic = dependencies.dynamic;
+        let mut reached = BTreeMap::new();
+        let mut queue: Vec<String> = dependencies.helper_names.into_iter().collect();
+        while let Some(name) = queue.pop() {
+            if dynamic {
+                break;
+            }
+            if reached.contains_key(&name) {
+                continue;
+            }
+            // An unknown name may resolve to an implicit file template or
+            // start resolving once a helper file adds it.
+            let Some(summary) = self.helpers.get(&name) else {
+                dynamic = true;
+                break;
+            };
+            dynamic |= summary.dynamic;
+            queue.extend(summary.helper_names.iter().cloned());
+            reached.insert(name, summary.body_digest.as_str());
+        }
+        if dynamic {
+            return self.index_digest.clone();
+        }
+        digest(
+            reached
+                .iter()
+                .flat_map(|(name, body)| [name.as_bytes(), body.as_bytes()]),
+        )
+    }
+}
+
+fn summarize_defines(source: &str) -> Vec<DefineSummary> {
+    helm_schema_ir::define_bodies_in_source(source)
+        .into_iter()
+        .map(|(name, body)| {
+            let dependencies = template_dependencies(&body);
+            DefineSummary {
+                body_digest: digest([body.as_bytes()]),
+                helper_names: dependencies.helper_names,
+                dynamic: dependencies.dynamic,
+                name,
+            }
+        })
+        .collect()
+}
+
+/// Contract and chart-local resource schemas of one manifest template.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub(crate) struct TemplateAnalysis {
+    pub(crate) contract: ContractIr,
+    pub(crate) local_resource_schemas: Vec<LocalResourceSchema>,
+}
+
+/// Per-chart template evaluation, answered from the analysis cache when the
+/// template and everything it reaches are unchanged.
+///
+/// The symbolic context (and with it every helper parse) is only built on
+/// the first cache miss.
+pub(crate) struct TemplateEvaluator<'a> {
+    defines: &'a DefineIndex,
+    policy: SymbolicPolicy,
+    context: OnceCell<SymbolicIrContext>,
+    cache: Option<(&'a AnalysisCache, &'a HelperSummaries)>,
+    policy_digest: String,
+}
+
+impl<'a> TemplateEvaluator<'a> {
+    pub(crate) fn new(
+        defines: &'a DefineIndex,
+        policy: SymbolicPolicy,
+        cache: Option<(&'a AnalysisCache, &'a HelperSummaries)>,
+    ) -> Self {
+        // A policy that cannot be fingerprinted must not share entries; a
+        // cluster state is an opaque oracle, so runs that resolve `lookup`
+        // never touch the cache.
+        let policy_digest = serde_json::to_vec(&(
+            &policy.chart_default_strings,
+            &policy.kubernetes_version,
+            policy.static_root_strings.iter().collect::<Vec<_>>(),
+        ))
+        .ok()
+        .filter(|_| policy.cluster_state.is_none())
+        .map(|bytes| digest([bytes.as_slice()]));
+        let cache = cache.filter(|_| policy_digest.is_some());
+        Self {
+            defines,
+            policy,
+            context: OnceCell::new(),
+            cache,
+            policy_digest: policy_digest.unwrap_or_default(),
+        }
+    }
+
+    fn context(&self) -> &SymbolicIrContext {
+        self.context
+            .get_or_init(|| SymbolicIrContext::with_policy(self.defines, self.policy.clone()))
+    }
+
+    /// Evaluates one template of `lane` (`manifest` or `notes`), or reads
+    /// the result a previous run cached for the same inputs.
+    pub(crate) fn evaluate<T>(
+        &self,
+        lane: &str,
+        source: &str,
+        source_path: &str,
+        evaluate: impl FnOnce(&SymbolicIrContext) -> crate::error::EngineResult<T>,
+    ) -> crate::error::EngineResult<T>
+    where
+        T: Serialize + DeserializeOwned + PartialEq,
+    {
+        let Some((cache, helpers)) = self.cache else {
+            return evaluate(self.context());
+        };
+        let reach = helpers.reach_digest(source);
+        let key = digest([
+            lane.as_bytes(),
+            ANALYSIS_CACHE_FORMAT.as_bytes(),
+            env!("CARGO_PKG_VERSION").as_bytes(),
+            source_path.as_bytes(),
+            source.as_bytes(),
+            reach.as_bytes(),
+            self.policy_digest.as_bytes(),
+        ]);
+        if let Some(cached) = cache.read(lane, source_path, &key) {
+            tracing::debug!(source_path, "analysis cache hit");
+            return Ok(cached);
+        }
+        let result = evaluate(self.context())?;
+        cache.write(lane, source_path, &key, &result);
+        Ok(result)
+    }
+}
+
+#[cfg(test)]
+#[path = "tests/template_cache.rs"]
+mod tests;
@@ -4,7 +4,10 @@ use indoc::indoc;
 use serde_json::json;
 use test_util::prelude::sim_assert_eq;
 
-use super::local_resource_schemas_from_template_source;
+use helm_schema_core::{ConditionalGuard, GuardValue, ResourceRef};
+use helm_schema_k8s::{LocalResourceSchema, LocalSchemaUniverse};
+
+use super::{local_resource_schemas_from_template_source, resource_definition_guards};
 
 #[test]
 fn templated_metadata_crd_still_projects_local_schema() -> eyre::Result<()> {
@@ -77,3 +80,158 @@ fn dynamic_schema_subtree_is_not_projected() -> eyre::Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn install_wrapped_crd_projects_guarded_by_its_condition() -> eyre::Result<()> {
+    let source = indoc! {r#"
+        {{- if .Values.crds.install }}
+        apiVersion: apiextensions.k8s.io/v1
+        kind: CustomResourceDefinition
+        metadata:
+          name: widgets.example.com
+          labels:
+            {{- include "chart.labels" . | nindent 4 }}
+          annotations:
+            {{- with .Values.crds.annotations }}
+            {{- toYaml . | nindent 4 }}
+            {{- end }}
+        spec:
+          group: example.com
+          names:
+            kind: Widget
+            plural: widgets
+          scope: Namespaced
+          versions:
+            - name: v1
+              served: true
+              storage: true
+              schema:
+                openAPIV3Schema:
+                  type: object
+                  properties:
+                    spec:
+                      type: object
+                      properties:
+                        size:
+                          type: integer
+        {{- end }}
+    "#};
+
+    let schemas = local_resource_schemas_from_template_source(
+        source,
+        "/chart/templates/crds/widgets.yaml",
+        true,
+    )?;
+
+    sim_assert_eq!(have: schemas.len(), want: 1);
+    sim_assert_eq!(have: schemas[0].kind, want: "Widget");
+    sim_assert_eq!(have: schemas[0].guards, want: vec![".Values.crds.install".to_string()]);
+    sim_assert_eq!(
+        have: schemas[0]
+            .schema
+            .pointer("/properties/spec/properties/size"),
+        want: Some(&json!({"type": "integer"}))
+    );
+
+    Ok(())
+}
+
+#[test]
+fn statically_dead_branch_crd_is_not_projected() -> eyre::Result<()> {
+    let source = indoc! {r"
+        {{- if false }}
+        apiVersion: apiextensions.k8s.io/v1
+        kind: CustomResourceDefinition
+        spec:
+          group: example.com
+          names:
+            kind: Gadget
+          versions:
+            - name: v1
+              served: true
+              schema:
+                openAPIV3Schema:
+                  type: object
+        {{- else if .Values.widgets.enabled }}
+        apiVersion: apiextensions.k8s.io/v1
+        kind: CustomResourceDefinition
+        spec:
+          group: example.com
+          names:
+            kind: Widget
+          versions:
+            - name: v1
+              served: true
+              schema:
+                openAPIV3Schema:
+                  type: object
+        {{- end }}
+    "};
+
+    let schemas =
+        local_resource_schemas_from_template_source(source, "/chart/templates/crd.yaml", true)?;
+
+    sim_assert_eq!(
+        have: schemas
+            .iter()
+            .map(|schema| (schema.kind.as_str(), schema.guards.clone()))
+            .collect::<Vec<_>>(),
+        want: vec![("Widget", vec![".Values.widgets.enabled".to_string()])]
+    );
+
+    Ok(())
+}
+
+#[test]
+fn install_conditions_decode_into_definition_guards() {
+    let mut universe = LocalSchemaUniverse::default();
+    for (kind, guards) in [
+        (
+            "Widget",
+            vec![".Values.crds.install", "not (.Values.legacy)"],
+        ),
+        ("Gadget", vec!["and $.Values.a (eq .Values.mode \"full\")"]),
+        ("Gizmo", vec!["include \"chart.crds\" ."]),
+    ] {
+        universe.insert_resource_schema(LocalResourceSchema {
+            api_version: "example.com/v1".to_string(),
+            kind: kind.to_string(),
+            schema: json!({"type": "object"}),
+            source_id: "chart-template-crd".to_string(),
+            filename: "crds.yaml".to_string(),
+            guards: guards.into_iter().map(ToString::to_string).collect(),
+        });
+    }
+    let guards = |kind: &str| {
+        resource_definition_guards(
+            &universe,
+            &ResourceRef::concrete("example.com/v1".to_string(), kind.to_string()),
+        )
+    };
+
+    sim_assert_eq!(
+        have: guards("Widget"),
+        want: Some(vec![
+            ConditionalGuard::Truthy {
+                path: "crds.install".to_string(),
+            },
+            ConditionalGuard::Not(Box::new(ConditionalGuard::Truthy {
+                path: "legacy".to_string(),
+            })),
+        ])
+    );
+    sim_assert_eq!(
+        have: guards("Gadget"),
+        want: Some(vec![ConditionalGuard::AllOf(vec![
+            ConditionalGuard::Truthy {
+                path: "a".to_string(),
+            },
+            ConditionalGuard::Eq {
+                path: "mode".to_string(),
+                value: GuardValue::String("full".to_string()),
+            },
+        ])])
+    );
+    sim_assert_eq!(have: guards("Gizmo"), want: None);
+    sim_assert_eq!(have: guards("Sprocket"), want: Some(Vec::new()));
+}
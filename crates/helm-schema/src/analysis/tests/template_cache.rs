@@ -0,0 +1,146 @@
+use std::cell::Cell;
+
+use color_eyre::eyre;
+use helm_schema_ast::DefineIndex;
+use helm_schema_ir::SymbolicPolicy;
+use indoc::indoc;
+use test_util::prelude::sim_assert_eq;
+
+use super::{AnalysisCache, HelperSummaries, TemplateEvaluator};
+
+const HELPERS: &str = indoc! {r#"
+    {{- define "chart.name" -}}{{ .Chart.Name }}{{- end -}}
+    {{- define "chart.labels" -}}app: {{ include "chart.name" . }}{{- end -}}
+    {{- define "chart.port" -}}{{ .Values.port }}{{- end -}}
+"#};
+
+fn defines(helpers: &str) -> DefineIndex {
+    let mut defines = DefineIndex::new();
+    defines.add_file_source("templates/_helpers.tpl", helpers);
+    defines
+}
+
+#[test]
+fn helper_edit_only_changes_the_reach_of_its_callers() -> eyre::Result<()> {
+    let dir = tempfile::tempdir()?;
+    let cache = AnalysisCache::new(dir.path());
+    let labels_user = r#"labels: {{ include "chart.labels" . }}"#;
+    let port_user = r#"port: {{ include "chart.port" . }}"#;
+
+    let before = HelperSummaries::build(&cache, &defines(HELPERS));
+    let after = HelperSummaries::build(
+        &cache,
+        &defines(&HELPERS.replace(".Chart.Name", ".Release.Name")),
+    );
+
+    assert_ne!(
+        before.reach_digest(labels_user),
+        after.reach_digest(labels_user),
+        "editing a transitively included helper must invalidate its callers"
+    );
+    sim_assert_eq!(
+        have: after.reach_digest(port_user),
+        want: before.reach_digest(port_user)
+    );
+    Ok(())
+}
+
+#[test]
+fn computed_helper_names_fall_back_to_the_whole_define_index() -> eyre::Result<()> {
+    let dir = tempfile::tempdir()?;
+    let cache = AnalysisCache::new(dir.path());
+    let summaries = HelperSummaries::build(&cache, &defines(HELPERS));
+
+    sim_assert_eq!(
+        have: summaries.reach_digest("{{ tpl .Values.extra . }}"),
+        want: summaries.index_digest.clone()
+    );
+    sim_assert_eq!(
+        have: summaries.reach_digest(r#"{{ include (printf "chart.%s" .Values.kind) . }}"#),
+        want: summaries.index_digest.clone()
+    );
+    sim_assert_eq!(
+        have: summaries.reach_digest(r#"{{ include "chart.missing" . }}"#),
+        want: summaries.index_digest.clone()
+    );
+    Ok(())
+}
+
+#[test]
+fn unchanged_template_is_read_back_without_evaluation() -> eyre::Result<()> {
+    let dir = tempfile::tempdir()?;
+    let cache = AnalysisCache::new(dir.path());
+    let defines = defines(HELPERS);
+    let summaries = HelperSummaries::build(&cache, &defines);
+    let source = r#"port: {{ include "chart.port" . }}"#;
+    let evaluations = Cell::new(0);
+
+    for _ in 0..2 {
+        let evaluator = TemplateEvaluator::new(
+            &defines,
+            SymbolicPolicy::default(),
+            Some((&cache, &summaries)),
+        );
+        let value: Vec<String> =
+            evaluator.evaluate("manifests", source, "templates/svc.yaml", |_| {
+                evaluations.set(evaluations.get() + 1);
+                Ok(vec!["port".to_string()])
+            })?;
+        sim_assert_eq!(have: value, want: vec!["port".to_string()]);
+    }
+    sim_assert_eq!(have: evaluations.get(), want: 1);
+
+    let evaluator = TemplateEvaluator::new(
+        &defines,
+        SymbolicPolicy {
+            kubernetes_version: Some("1.30.0".to_string()),
+            ..SymbolicPolicy::default()
+        },
+        Some((&cache, &summaries)),
+    );
+    let _: Vec<String> = evaluator.evaluate("manifests", source, "templates/svc.yaml", |_| {
+        evaluations.set(evaluations.get() + 1);
+        Ok(Vec::new())
+    })?;
+    sim_assert_eq!(
+        have: evaluations.get(),
+        want: 2,
+        "a different evaluation policy must not share entries"
+    );
+    Ok(())
+}
+
+#[test]
+fn in_memory_cache_re_evaluates_only_invalidated_templates() -> eyre::Result<()> {
+    let cache = AnalysisCache::in_memory();
+    let defines = defines(HELPERS);
+    let summaries = HelperSummaries::build(&cache, &defines);
+    let evaluator = TemplateEvaluator::new(
+        &defines,
+        SymbolicPolicy::default(),
+        Some((&cache, &summaries)),
+    );
+    let evaluations = Cell::new(0);
+    let evaluate_all = || -> eyre::Result<()> {
+        for path in ["/templates/svc.yaml", "/templates/deploy.yaml"] {
+            let _: Vec<String> = evaluator.evaluate("manifests", "port: 80", path, |_| {
+                evaluations.set(evaluations.get() + 1);
+                Ok(Vec::new())
+            })?;
+        }
+        Ok(())
+    };
+
+    evaluate_all()?;
+    evaluate_all()?;
+    sim_assert_eq!(have: evaluations.get(), want: 2);
+
+    cache.invalidate(|path| path == "/templates/svc.yaml");
+    evaluate_all()?;
+    sim_assert_eq!(
+        have: evaluations.get(),
+        want: 3,
+        "only the invalidated template is evaluated again"
+    );
+    Ok(())
+}
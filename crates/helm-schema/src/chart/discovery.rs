@@ -1,13 +1,19 @@
 use std::collections::{BTreeMap, BTreeSet};
-use std::path::{Component, Path};
+use std::path::{Component, Path, PathBuf};
 
 use flate2::read::GzDecoder;
 use serde::Deserialize;
+use serde_yaml::Value as YamlValue;
 use tracing::instrument;
 use vfs::VfsPath;
 
 use super::paths::scope_values_path;
-use super::types::{ChartContext, ChartDependencyActivation};
+use super::repository_cache::ChartRepositoryCache;
+use super::types::{
+    ChartContext, ChartDependencyActivation, ChartValueImport, UnreadableChartLock,
+    UnresolvedDependency,
+};
+use super::values::{coalesce_defaults_at, value_at_path};
 use crate::error::{CliError, EngineResult};
 use crate::load_budget::{LoadBudget, read_to_end_capped};
 
@@ -40,9 +46,48 @@ struct ChartYaml {
 #[derive(Debug, Deserialize)]
 struct ChartDependency {
     name: String,
+    version: Option<String>,
+    repository: Option<String>,
     alias: Option<String>,
     condition: Option<String>,
     tags: Option<Vec<String>>,
+    #[serde(rename = "import-values", default)]
+    import_values: Vec<ImportValue>,
+}
+
+/// A Chart.yaml `import-values` entry in either of Helm's spellings.
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum ImportValue {
+    /// `- data` imports the dependency's `exports.data` into the parent root.
+    Exported(String),
+    /// `- child: a.b` / `parent: c` copies one table to a parent path.
+    Mapped { child: String, parent: String },
+}
+
+impl ImportValue {
+    fn chart_value_import(&self) -> ChartValueImport {
+        match self {
+            ImportValue::Exported(name) => ChartValueImport {
+                child_path: std::iter::once("exports".to_string())
+                    .chain(import_path_segments(name))
+                    .collect(),
+                parent_path: Vec::new(),
+            },
+            ImportValue::Mapped { child, parent } => ChartValueImport {
+                child_path: import_path_segments(child).collect(),
+                parent_path: import_path_segments(parent).collect(),
+            },
+        }
+    }
+}
+
+/// Segments of an `import-values` path; Helm reads `.` as the values root.
+fn import_path_segments(path: &str) -> impl Iterator<Item = String> + '_ {
+    path.split('.')
+        .map(str::trim)
+        .filter(|segment| !segment.is_empty())
+        .map(str::to_string)
 }
 
 #[derive(Debug, Deserialize)]
@@ -50,82 +95,265 @@ struct RequirementsYaml {
     dependencies: Option<Vec<ChartDependency>>,
 }
 
+/// `Chart.lock` (or Helm 2 `requirements.lock`): the exact dependency
+/// versions `helm dependency update` resolved.
+#[derive(Debug, Deserialize)]
+struct ChartLock {
+    #[serde(default)]
+    dependencies: Vec<LockedDependency>,
+}
+
+#[derive(Debug, Deserialize)]
+struct LockedDependency {
+    name: String,
+    version: String,
+    repository: Option<String>,
+}
+
 #[derive(Debug, Clone)]
 struct DependencyMetadata {
     values_key: String,
     activation: ChartDependencyActivation,
+    value_imports: Vec<ChartValueImport>,
 }
 
+/// Charts found by discovery, plus the declared dependencies it could not
+/// load and the lock files it could not read.
+#[derive(Debug, Default)]
+pub(crate) struct ChartDiscovery {
+    pub charts: Vec<ChartContext>,
+    pub unresolved_dependencies: Vec<UnresolvedDependency>,
+    pub unreadable_chart_locks: Vec<UnreadableChartLock>,
+}
+
+#[cfg(test)]
 #[instrument(skip_all)]
 pub fn discover_chart_contexts(root_chart_dir: &VfsPath) -> EngineResult<Vec<ChartContext>> {
     discover_chart_contexts_with_budget(root_chart_dir, LoadBudget::default())
 }
 
+#[cfg(test)]
 #[instrument(skip_all)]
 pub(crate) fn discover_chart_contexts_with_budget(
     root_chart_dir: &VfsPath,
     load_budget: LoadBudget,
 ) -> EngineResult<Vec<ChartContext>> {
-    let mut out = Vec::new();
-    discover_chart_contexts_inner(root_chart_dir, &[], &[], load_budget, &mut out)?;
-    Ok(out)
+    discover_charts(root_chart_dir, None, load_budget, None).map(|discovery| discovery.charts)
 }
 
-fn discover_chart_contexts_inner(
-    chart_dir: &VfsPath,
-    parent_prefix: &[String],
-    dependency_activation_chain: &[ChartDependencyActivation],
+/// Discovers the root chart and its dependencies, loading dependencies that
+/// `charts/` does not vendor from their `file://` directory or from
+/// `repository_cache` at their locked version.
+///
+/// `root_chart_location` is the root chart's directory on disk, when it has
+/// one; `file://` dependencies outside the chart resolve against it.
+#[instrument(skip_all)]
+pub(crate) fn discover_charts(
+    root_chart_dir: &VfsPath,
+    root_chart_location: Option<&Path>,
     load_budget: LoadBudget,
-    out: &mut Vec<ChartContext>,
-) -> EngineResult<()> {
-    let chart_yaml = read_chart_yaml(chart_dir)?;
+    repository_cache: Option<&ChartRepositoryCache>,
+) -> EngineResult<ChartDiscovery> {
+    let mut walk = DiscoveryWalk {
+        load_budget,
+        repository_cache,
+        defaults: YamlValue::Mapping(serde_yaml::Mapping::default()),
+        out: ChartDiscovery::default(),
+    };
+    walk.discover(root_chart_dir, root_chart_location, &[], &[], Vec::new())?;
+    Ok(walk.out)
+}
 
-    let is_library = chart_yaml
-        .chart_type
-        .as_deref()
-        .is_some_and(|chart_type| chart_type.eq_ignore_ascii_case("library"));
-    let static_root_strings = chart_static_root_strings(&chart_yaml);
-
-    out.push(ChartContext {
-        chart_dir: chart_dir.clone(),
-        values_prefix: parent_prefix.to_vec(),
-        is_library,
-        static_root_strings,
-        dependency_activation_chain: dependency_activation_chain.to_vec(),
-    });
+struct DiscoveryWalk<'a> {
+    load_budget: LoadBudget,
+    repository_cache: Option<&'a ChartRepositoryCache>,
+    /// The `values.yaml` defaults of every chart discovered so far, coalesced
+    /// under their value prefixes: what a dependency's condition and tags
+    /// read when no user values are given.
+    defaults: YamlValue,
+    out: ChartDiscovery,
+}
 
-    let dependency_metadata_by_name = dependency_metadata_map(&chart_yaml, parent_prefix);
+impl DiscoveryWalk<'_> {
+    fn discover(
+        &mut self,
+        chart_dir: &VfsPath,
+        chart_location: Option<&Path>,
+        parent_prefix: &[String],
+        dependency_activation_chain: &[ChartDependencyActivation],
+        value_imports: Vec<ChartValueImport>,
+    ) -> EngineResult<()> {
+        let chart_yaml = read_chart_yaml(chart_dir)?;
+        coalesce_defaults_at(
+            &mut self.defaults,
+            parent_prefix,
+            read_default_values(chart_dir)?,
+        );
 
-    let vendor_charts_dir = chart_dir.join("charts")?;
-    if !vendor_charts_dir.is_dir()? {
-        return Ok(());
-    }
+        let is_library = chart_yaml
+            .chart_type
+            .as_deref()
+            .is_some_and(|chart_type| chart_type.eq_ignore_ascii_case("library"));
+        let static_root_strings = chart_static_root_strings(&chart_yaml);
+
+        self.out.charts.push(ChartContext {
+            chart_dir: chart_dir.clone(),
+            values_prefix: parent_prefix.to_vec(),
+            is_library,
+            static_root_strings,
+            dependency_activation_chain: dependency_activation_chain.to_vec(),
+            value_imports,
+        });
+
+        let dependency_metadata_by_name = dependency_metadata_map(&chart_yaml, parent_prefix);
+        let parent = DependencyParent {
+            prefix: parent_prefix,
+            chain: dependency_activation_chain,
+            metadata: &dependency_metadata_by_name,
+        };
 
-    // VFS backends do not promise read_dir ordering. Keep dependency traversal
-    // stable because DefineIndex and helper graph construction use
-    // last-write-wins semantics for duplicate helper definitions.
-    let mut vendor_entries: Vec<VfsPath> = vendor_charts_dir.read_dir()?.collect();
-    vendor_entries.sort_by_key(VfsPath::filename);
-
-    for entry in vendor_entries {
-        let sub_dir = if entry.is_dir()? {
-            let chart_yaml_path = entry.join("Chart.yaml")?;
-            let chart_template_yaml_path = entry.join("Chart.template.yaml")?;
-            if !chart_yaml_path.is_file()? && !chart_template_yaml_path.is_file()? {
-                continue;
+        let mut vendored = BTreeSet::new();
+        let vendor_charts_dir = chart_dir.join("charts")?;
+        if vendor_charts_dir.is_dir()? {
+            // VFS backends do not promise read_dir ordering. Keep dependency
+            // traversal stable because DefineIndex and helper graph
+            // construction use last-write-wins semantics for duplicate
+            // helper definitions.
+            let mut vendor_entries: Vec<VfsPath> = vendor_charts_dir.read_dir()?.collect();
+            vendor_entries.sort_by_key(VfsPath::filename);
+
+            for entry in vendor_entries {
+                let (sub_dir, sub_location) = if entry.is_dir()? {
+                    if !has_chart_yaml(&entry)? {
+                        continue;
+                    }
+                    let sub_location = chart_location
+                        .map(|location| location.join("charts").join(entry.filename()));
+                    (entry, sub_location)
+                } else if entry.is_file()? {
+                    if !is_chart_archive(&entry.filename()) {
+                        continue;
+                    }
+
+                    (extract_chart_archive(&entry, self.load_budget)?, None)
+                } else {
+                    continue;
+                };
+
+                vendored.insert(self.discover_dependency(
+                    &sub_dir,
+                    sub_location.as_deref(),
+                    &parent,
+                )?);
             }
-            entry
-        } else if entry.is_file()? {
-            if !is_chart_archive(&entry.filename()) {
+        }
+
+        // Declared dependencies `charts/` lacks come from their `file://`
+        // directory or the repository cache, after the vendored ones so a
+        // vendored copy's helpers keep their established definition order.
+        let locked = self.read_chart_lock(chart_dir, chart_location, &chart_yaml)?;
+        for dependency in chart_yaml.dependencies.as_deref().unwrap_or_default() {
+            if vendored.contains(&dependency.name) {
                 continue;
             }
+            let resolved = match local_dependency_path(dependency) {
+                Some(local_path) => Self::local_dependency_dir(
+                    &chart_yaml,
+                    dependency,
+                    chart_dir,
+                    chart_location,
+                    local_path,
+                )?,
+                None => self
+                    .cached_dependency_archive(&chart_yaml, dependency, &locked)?
+                    .map(|sub_dir| (sub_dir, None)),
+            };
+            match resolved {
+                Ok((sub_dir, sub_location)) => {
+                    vendored.insert(self.discover_dependency(
+                        &sub_dir,
+                        sub_location.as_deref(),
+                        &parent,
+                    )?);
+                }
+                // Helm never renders a dependency its condition or tags
+                // disable, so one that is also unavailable loses nothing
+                // the default install would consume.
+                Err(_)
+                    if parent
+                        .metadata
+                        .get(&dependency.name)
+                        .is_some_and(|metadata| self.disabled_by_default(&metadata.activation)) => {
+                }
+                Err(unresolved) => self.out.unresolved_dependencies.push(unresolved),
+            }
+        }
 
-            extract_chart_archive(&entry, load_budget)?
-        } else {
-            continue;
+        Ok(())
+    }
+
+    /// Whether the coalesced defaults disable a dependency the way Helm
+    /// decides it: the first condition path holding a boolean wins; without
+    /// one, the tags disable it when some tag is `false` and none is `true`.
+    fn disabled_by_default(&self, activation: &ChartDependencyActivation) -> bool {
+        let flag = |path: &str| {
+            let segments = helm_schema_core::split_value_path(path);
+            value_at_path(&self.defaults, &segments).and_then(YamlValue::as_bool)
         };
+        if let Some(enabled) = activation
+            .condition_paths
+            .iter()
+            .find_map(|path| flag(path))
+        {
+            return !enabled;
+        }
+        let tags: Vec<bool> = activation
+            .tag_paths
+            .iter()
+            .filter_map(|path| flag(path))
+            .collect();
+        tags.contains(&false) && !tags.contains(&true)
+    }
+
+    /// The parsed `Chart.lock` (or `requirements.lock`) of `chart_dir`. A lock
+    /// file that does not parse is reported and treated as absent.
+    fn read_chart_lock(
+        &mut self,
+        chart_dir: &VfsPath,
+        chart_location: Option<&Path>,
+        chart_yaml: &ChartYaml,
+    ) -> EngineResult<Vec<LockedDependency>> {
+        for file_name in ["Chart.lock", "requirements.lock"] {
+            let path = chart_dir.join(file_name)?;
+            if !path.is_file()? {
+                continue;
+            }
+            return match serde_yaml::from_str::<ChartLock>(&path.read_to_string()?) {
+                Ok(lock) => Ok(lock.dependencies),
+                Err(error) => {
+                    self.out.unreadable_chart_locks.push(UnreadableChartLock {
+                        chart: chart_yaml.name.clone().unwrap_or_default(),
+                        path: chart_location.map_or_else(
+                            || path.as_str().to_string(),
+                            |location| location.join(file_name).display().to_string(),
+                        ),
+                        error: error.to_string(),
+                    });
+                    Ok(Vec::new())
+                }
+            };
+        }
+        Ok(Vec::new())
+    }
 
-        let sub_chart_yaml = read_chart_yaml(&sub_dir)?;
+    /// Discovers one dependency chart beneath its parent and returns its name.
+    fn discover_dependency(
+        &mut self,
+        sub_dir: &VfsPath,
+        sub_location: Option<&Path>,
+        parent: &DependencyParent<'_>,
+    ) -> EngineResult<String> {
+        let sub_chart_yaml = read_chart_yaml(sub_dir)?;
         let sub_name = sub_chart_yaml
             .name
             .clone()
@@ -137,30 +365,152 @@ fn discover_chart_contexts_inner(
                 path: sub_dir.as_str().to_string(),
             })?;
 
-        let dependency_metadata = dependency_metadata_by_name
-            .get(&sub_name)
-            .cloned()
-            .unwrap_or_else(|| DependencyMetadata {
-                values_key: sub_name.clone(),
-                activation: ChartDependencyActivation::default(),
-            });
+        let declared = parent.metadata.get(&sub_name).cloned();
+        let dependency_metadata = declared.unwrap_or_else(|| DependencyMetadata {
+            values_key: sub_name.clone(),
+            activation: ChartDependencyActivation::default(),
+            value_imports: Vec::new(),
+        });
 
-        let mut prefix = parent_prefix.to_vec();
+        let mut prefix = parent.prefix.to_vec();
         prefix.push(dependency_metadata.values_key);
 
         // Only condition/tag-carrying edges add an activation level; an
         // unconditional dependency keeps its parent's chain, so a child's
         // chain always extends its parent's as a prefix.
-        let mut chain = dependency_activation_chain.to_vec();
+        let mut chain = parent.chain.to_vec();
         let activation = dependency_metadata.activation;
         if !activation.condition_paths.is_empty() || !activation.tag_paths.is_empty() {
             chain.push(activation);
         }
 
-        discover_chart_contexts_inner(&sub_dir, &prefix, &chain, load_budget, out)?;
+        self.discover(
+            sub_dir,
+            sub_location,
+            &prefix,
+            &chain,
+            dependency_metadata.value_imports,
+        )?;
+        Ok(sub_name)
+    }
+
+    /// The cached archive of a dependency `charts/` does not vendor, opened
+    /// at its `Chart.lock` version (or an exact `Chart.yaml` version).
+    fn cached_dependency_archive(
+        &self,
+        chart_yaml: &ChartYaml,
+        dependency: &ChartDependency,
+        locked: &[LockedDependency],
+    ) -> EngineResult<Result<VfsPath, UnresolvedDependency>> {
+        let version = locked
+            .iter()
+            .find(|lock| {
+                lock.name == dependency.name
+                    && (lock.repository.is_none()
+                        || dependency.repository.is_none()
+                        || lock.repository == dependency.repository)
+            })
+            .map(|lock| lock.version.clone())
+            .or_else(|| {
+                dependency
+                    .version
+                    .as_deref()
+                    .filter(|version| is_exact_version(version))
+                    .map(str::to_string)
+            });
+
+        let mut locations_tried = Vec::new();
+        if let (Some(cache), Some(version)) = (self.repository_cache, version.as_deref()) {
+            let file_name = ChartRepositoryCache::archive_file_name(&dependency.name, version);
+            let archive_path = cache.root().join(&file_name);
+            if archive_path.is_file() {
+                let archive = VfsPath::new(vfs::PhysicalFS::new(cache.root())).join(&file_name)?;
+                return extract_chart_archive(&archive, self.load_budget).map(Ok);
+            }
+            locations_tried.push(archive_path.display().to_string());
+        }
+
+        Ok(Err(UnresolvedDependency {
+            chart: chart_yaml.name.clone().unwrap_or_default(),
+            name: dependency.name.clone(),
+            version,
+            repository: dependency.repository.clone(),
+            locations_tried,
+        }))
     }
 
-    Ok(())
+    /// The chart directory a `file://` dependency names, relative to its
+    /// declaring chart as Helm resolves it: inside the chart's own tree when
+    /// the path stays there, else on disk beside the chart's location.
+    fn local_dependency_dir(
+        chart_yaml: &ChartYaml,
+        dependency: &ChartDependency,
+        chart_dir: &VfsPath,
+        chart_location: Option<&Path>,
+        local_path: &str,
+    ) -> EngineResult<Result<(VfsPath, Option<PathBuf>), UnresolvedDependency>> {
+        let mut locations_tried = Vec::new();
+        if !Path::new(local_path).is_absolute()
+            && let Ok(sub_dir) = chart_dir.join(local_path)
+        {
+            if has_chart_yaml(&sub_dir)? {
+                let sub_location = chart_location.map(|location| location.join(local_path));
+                return Ok(Ok((sub_dir, sub_location)));
+            }
+            if chart_location.is_none() {
+                locations_tried.push(sub_dir.as_str().to_string());
+            }
+        }
+        if let Some(location) = chart_location {
+            let sub_location = location.join(local_path);
+            let sub_dir = VfsPath::new(vfs::PhysicalFS::new(&sub_location));
+            if sub_location.is_dir() && has_chart_yaml(&sub_dir)? {
+                return Ok(Ok((sub_dir, Some(sub_location))));
+            }
+            locations_tried.push(sub_location.display().to_string());
+        }
+
+        Ok(Err(UnresolvedDependency {
+            chart: chart_yaml.name.clone().unwrap_or_default(),
+            name: dependency.name.clone(),
+            version: dependency.version.clone(),
+            repository: dependency.repository.clone(),
+            locations_tried,
+        }))
+    }
+}
+
+/// The path of a dependency whose repository is a local `file://` directory.
+fn local_dependency_path(dependency: &ChartDependency) -> Option<&str> {
+    dependency
+        .repository
+        .as_deref()
+        .and_then(|repository| repository.trim().strip_prefix("file://"))
+        .filter(|path| !path.is_empty())
+}
+
+/// Whether `dir` holds a `Chart.yaml` (or `Chart.template.yaml`).
+fn has_chart_yaml(dir: &VfsPath) -> EngineResult<bool> {
+    Ok(dir.join("Chart.yaml")?.is_file()? || dir.join("Chart.template.yaml")?.is_file()?)
+}
+
+/// The parent side of a dependency edge during discovery.
+struct DependencyParent<'a> {
+    prefix: &'a [String],
+    chain: &'a [ChartDependencyActivation],
+    metadata: &'a BTreeMap<String, DependencyMetadata>,
+}
+
+/// Whether a `Chart.yaml` dependency version names one release rather than
+/// a semver range, so it can locate an archive without a `Chart.lock`.
+fn is_exact_version(version: &str) -> bool {
+    let version = version.trim();
+    let core = version.split(['-', '+']).next().unwrap_or_default();
+    !version.is_empty()
+        && core.split('.').count() == 3
+        && core
+            .split('.')
+            .all(|part| !part.is_empty() && part.chars().all(|c| c.is_ascii_digit()))
 }
 
 fn chart_static_root_strings(chart: &ChartYaml) -> BTreeMap<Vec<String>, String> {
@@ -322,6 +672,11 @@ fn dependency_metadata_map(
             DependencyMetadata {
                 values_key,
                 activation: dependency_activation(dependency, parent_prefix),
+                value_imports: dependency
+                    .import_values
+                    .iter()
+                    .map(ImportValue::chart_value_import)
+                    .collect(),
             },
         );
     }
@@ -408,3 +763,13 @@ fn read_chart_yaml(chart_dir: &VfsPath) -> EngineResult<ChartYaml> {
     }
     Ok(metadata)
 }
+
+/// A chart's own `values.yaml`; one that does not parse contributes no
+/// defaults here and fails values composition later with its real error.
+fn read_default_values(chart_dir: &VfsPath) -> EngineResult<YamlValue> {
+    let path = chart_dir.join("values.yaml")?;
+    if !path.is_file()? {
+        return Ok(YamlValue::Null);
+    }
+    Ok(serde_yaml::from_str(&path.read_to_string()?).unwrap_or(YamlValue::Null))
+}
@@ -2,13 +2,17 @@ mod define_index;
 pub(crate) mod discovery;
 mod file_roles;
 mod paths;
+mod repository_cache;
 mod types;
 mod values;
 
 pub use define_index::build_define_index;
+#[cfg(test)]
 pub use discovery::discover_chart_contexts;
+pub(crate) use discovery::discover_charts;
 pub(crate) use file_roles::{FileRole, files_with_role, list_chart_files};
 pub(crate) use paths::scope_values_path;
+pub use repository_cache::ChartRepositoryCache;
 pub use types::{ChartContext, ChartDependencyActivation};
 pub(crate) use values::build_dependency_global_ownership;
 pub use values::{
@@ -0,0 +1,74 @@
+use std::path::{Path, PathBuf};
+
+/// A local Helm repository cache: the directory `helm dependency update`
+/// and `helm pull` download chart archives into, named
+/// `<name>-<version>.tgz`.
+///
+/// Discovery consults it for dependencies that `Chart.yaml` declares but
+/// `charts/` does not vendor, so a chart checked out with only its
+/// `Chart.lock` analyzes fully without network access.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChartRepositoryCache {
+    root: PathBuf,
+}
+
+impl ChartRepositoryCache {
+    /// Uses `root` as the repository cache directory.
+    #[must_use]
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Self { root: root.into() }
+    }
+
+    /// Helm's own repository cache for the current user, resolved the way
+    /// the Helm CLI resolves it, or `None` when no cache home is known.
+    #[must_use]
+    pub fn helm_default() -> Option<Self> {
+        helm_repository_cache_for(std::env::consts::OS, env_path).map(Self::new)
+    }
+
+    /// The cache directory.
+    #[must_use]
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    /// File name Helm stores the archive of `name` at `version` under.
+    pub(crate) fn archive_file_name(name: &str, version: &str) -> String {
+        format!("{name}-{version}.tgz")
+    }
+}
+
+/// Helm's repository cache directory on `os`.
+///
+/// Follows Helm's `helmpath` lookup: `HELM_REPOSITORY_CACHE` wins, then
+/// `repository` under `HELM_CACHE_HOME`, then `helm/repository` under
+/// `XDG_CACHE_HOME` or the platform cache home. The operating system and
+/// environment reader are parameters so every platform branch is tested on
+/// every host.
+pub(crate) fn helm_repository_cache_for(
+    os: &str,
+    get: impl Fn(&str) -> Option<PathBuf>,
+) -> Option<PathBuf> {
+    if let Some(path) = get("HELM_REPOSITORY_CACHE") {
+        return Some(path);
+    }
+    if let Some(home) = get("HELM_CACHE_HOME") {
+        return Some(home.join("repository"));
+    }
+    let cache_home = get("XDG_CACHE_HOME").or_else(|| match os {
+        "macos" => get("HOME").map(|home| home.join("Library").join("Caches")),
+        "windows" => get("TEMP"),
+        _ => get("HOME").map(|home| home.join(".cache")),
+    })?;
+    Some(cache_home.join("helm").join("repository"))
+}
+
+/// A path from the environment, treating unset and empty alike.
+fn env_path(var: &str) -> Option<PathBuf> {
+    let value = std::env::var_os(var)?;
+    (!value.is_empty()).then(|| PathBuf::from(value))
+}
+
+#[cfg(test)]
+#[path = "tests/repository_cache.rs"]
+mod tests;
@@ -0,0 +1,53 @@
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+
+use super::*;
+use test_util::prelude::sim_assert_eq;
+
+fn resolve(os: &str, vars: &[(&str, &str)]) -> Option<PathBuf> {
+    let vars: BTreeMap<&str, PathBuf> = vars
+        .iter()
+        .map(|(name, value)| (*name, PathBuf::from(value)))
+        .collect();
+    helm_repository_cache_for(os, |name| vars.get(name).cloned())
+}
+
+#[test]
+fn explicit_helm_variables_take_precedence() {
+    sim_assert_eq!(
+        have: resolve(
+            "linux",
+            &[
+                ("HELM_REPOSITORY_CACHE", "/repo-cache"),
+                ("HELM_CACHE_HOME", "/helm-cache"),
+                ("HOME", "/home/u"),
+            ],
+        ),
+        want: Some(PathBuf::from("/repo-cache"))
+    );
+    sim_assert_eq!(
+        have: resolve("linux", &[("HELM_CACHE_HOME", "/helm-cache"), ("HOME", "/home/u")]),
+        want: Some(PathBuf::from("/helm-cache/repository"))
+    );
+}
+
+#[test]
+fn platform_cache_homes_match_helm() {
+    sim_assert_eq!(
+        have: resolve("linux", &[("XDG_CACHE_HOME", "/xdg"), ("HOME", "/home/u")]),
+        want: Some(PathBuf::from("/xdg/helm/repository"))
+    );
+    sim_assert_eq!(
+        have: resolve("linux", &[("HOME", "/home/u")]),
+        want: Some(PathBuf::from("/home/u/.cache/helm/repository"))
+    );
+    sim_assert_eq!(
+        have: resolve("macos", &[("HOME", "/Users/u")]),
+        want: Some(PathBuf::from("/Users/u/Library/Caches/helm/repository"))
+    );
+    sim_assert_eq!(
+        have: resolve("windows", &[("TEMP", "/temp")]),
+        want: Some(PathBuf::from("/temp/helm/repository"))
+    );
+    sim_assert_eq!(have: resolve("linux", &[]), want: None);
+}
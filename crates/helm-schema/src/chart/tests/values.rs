@@ -277,3 +277,104 @@ fn dependency_global_ownership_keeps_chart_identity() -> eyre::Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn composed_values_honor_both_import_values_forms() -> eyre::Result<()> {
+    let chart_dir = VfsPath::new(vfs::MemoryFS::new());
+    test_util::write(
+        &chart_dir.join("Chart.yaml")?,
+        indoc! {"
+            apiVersion: v2
+            name: root
+            version: 0.1.0
+            dependencies:
+              - name: child
+                alias: backend
+                import-values:
+                  - data
+                  - child: service
+                    parent: backendService
+        "},
+    )?;
+    test_util::write(
+        &chart_dir.join("values.yaml")?,
+        indoc! {"
+            backendService:
+              port: 8443
+        "},
+    )?;
+    test_util::write(
+        &chart_dir.join("charts/child/Chart.yaml")?,
+        indoc! {"
+            apiVersion: v2
+            name: child
+            version: 0.1.0
+        "},
+    )?;
+    test_util::write(
+        &chart_dir.join("charts/child/values.yaml")?,
+        indoc! {"
+            exports:
+              data:
+                logLevel: info
+            service:
+              port: 8080
+              type: ClusterIP
+        "},
+    )?;
+
+    let composed = build_composed_values_yaml(&discover(&chart_dir)?, true)?
+        .ok_or_eyre("composed values yaml")?;
+    let doc: serde_yaml::Value = serde_yaml::from_str(&composed)?;
+
+    sim_assert_eq!(
+        have: yaml_pointer(&doc, &["logLevel"]),
+        want: Some(&serde_yaml::Value::from("info"))
+    );
+    sim_assert_eq!(
+        have: yaml_pointer(&doc, &["backendService", "type"]),
+        want: Some(&serde_yaml::Value::from("ClusterIP"))
+    );
+    sim_assert_eq!(
+        have: yaml_pointer(&doc, &["backendService", "port"]),
+        want: Some(&serde_yaml::Value::from(8443)),
+        "the parent's own values outrank imported ones"
+    );
+    sim_assert_eq!(
+        have: yaml_pointer(&doc, &["backend", "service", "port"]),
+        want: Some(&serde_yaml::Value::from(8080))
+    );
+    Ok(())
+}
+
+#[test]
+fn value_imports_apply_deepest_first_then_by_values_prefix() {
+    let importing = |prefix: &[&str], child: &str| ChartContext {
+        chart_dir: VfsPath::new(vfs::MemoryFS::new()),
+        values_prefix: prefix.iter().map(ToString::to_string).collect(),
+        is_library: false,
+        static_root_strings: std::collections::BTreeMap::new(),
+        dependency_activation_chain: Vec::new(),
+        value_imports: vec![crate::chart::types::ChartValueImport {
+            child_path: vec![child.to_string()],
+            parent_path: Vec::new(),
+        }],
+    };
+    let charts = [
+        importing(&["zeta"], "data"),
+        importing(&["alpha"], "data"),
+        importing(&["zeta", "nested"], "data"),
+    ];
+
+    sim_assert_eq!(
+        have: ChartContext::ordered_value_imports(&charts),
+        want: vec![
+            (
+                vec!["zeta".to_string(), "nested".to_string(), "data".to_string()],
+                vec!["zeta".to_string()],
+            ),
+            (vec!["alpha".to_string(), "data".to_string()], Vec::new()),
+            (vec!["zeta".to_string(), "data".to_string()], Vec::new()),
+        ]
+    );
+}
@@ -15,6 +15,53 @@ pub struct ChartContext {
     /// a doubly-nested chart like signoz's clickhouse→zookeeper is gated on
     /// `clickhouse.enabled` AND `clickhouse.zookeeper.enabled`.
     pub dependency_activation_chain: Vec<ChartDependencyActivation>,
+    /// The parent's `import-values` entries for this dependency, in
+    /// declaration order.
+    pub value_imports: Vec<ChartValueImport>,
+}
+
+impl ChartContext {
+    /// Absolute `(source, target)` values paths of this chart's
+    /// `import-values` entries.
+    #[must_use]
+    fn value_import_paths(&self) -> Vec<(Vec<String>, Vec<String>)> {
+        let Some((_, parent_prefix)) = self.values_prefix.split_last() else {
+            return Vec::new();
+        };
+        self.value_imports
+            .iter()
+            .map(|import| {
+                (
+                    [self.values_prefix.as_slice(), &import.child_path].concat(),
+                    [parent_prefix, import.parent_path.as_slice()].concat(),
+                )
+            })
+            .collect()
+    }
+
+    /// Absolute `(source, target)` values paths of every `import-values`
+    /// entry in `charts`, in the order Helm applies them: deepest
+    /// dependencies first, so a table imported into a subchart can be
+    /// imported again by its own parent; charts at the same depth by values
+    /// prefix; and each chart's entries in declaration order.
+    #[must_use]
+    pub fn ordered_value_imports(charts: &[Self]) -> Vec<(Vec<String>, Vec<String>)> {
+        let mut importing = charts
+            .iter()
+            .filter(|chart| !chart.value_imports.is_empty())
+            .collect::<Vec<_>>();
+        importing.sort_by(|left, right| {
+            right
+                .values_prefix
+                .len()
+                .cmp(&left.values_prefix.len())
+                .then_with(|| left.values_prefix.cmp(&right.values_prefix))
+        });
+        importing
+            .into_iter()
+            .flat_map(Self::value_import_paths)
+            .collect()
+    }
 }
 
 #[derive(Debug, Clone, Default)]
@@ -22,3 +69,43 @@ pub struct ChartDependencyActivation {
     pub condition_paths: Vec<String>,
     pub tag_paths: Vec<String>,
 }
+
+/// One Chart.yaml `import-values` entry: Helm copies the dependency's
+/// values table at `child_path` into its parent at `parent_path`, below the
+/// parent's own values. The `exports.<name>` shorthand imports into the
+/// parent's root (an empty `parent_path`).
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ChartValueImport {
+    /// Path relative to the dependency's values.
+    pub child_path: Vec<String>,
+    /// Path relative to the parent's values.
+    pub parent_path: Vec<String>,
+}
+
+/// A `Chart.yaml` dependency discovery could not load: `charts/` does not
+/// vendor it and its pinned archive is absent from the repository cache.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct UnresolvedDependency {
+    /// Name of the chart declaring the dependency.
+    pub chart: String,
+    /// Dependency chart name.
+    pub name: String,
+    /// Version pinned by `Chart.lock`, or an exact `Chart.yaml` version.
+    pub version: Option<String>,
+    /// Repository the dependency is fetched from.
+    pub repository: Option<String>,
+    /// Archive paths consulted.
+    pub locations_tried: Vec<String>,
+}
+
+/// A `Chart.lock` (or `requirements.lock`) that does not parse; discovery
+/// resolves its chart's dependencies as if it were absent.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct UnreadableChartLock {
+    /// Name of the chart the lock file belongs to.
+    pub chart: String,
+    /// Path of the lock file.
+    pub path: String,
+    /// Human-readable parse failure.
+    pub error: String,
+}
@@ -209,6 +209,7 @@ pub fn build_composed_values_descriptions(
                 &mut descriptions,
             )?;
         }
+        add_imported_descriptions(charts, &mut descriptions);
     }
 
     for path in values_files {
@@ -251,9 +252,34 @@ fn compose_subchart_values(charts: &[ChartContext], doc: &mut YamlValue) -> Engi
         coalesce_chart_values(target, defaults, &dependency_keys);
     }
 
+    import_dependency_values(charts, doc);
     Ok(())
 }
 
+/// Applies every dependency's `import-values` to the composed document,
+/// deepest dependencies first as Helm does, so a table imported into a
+/// subchart can be imported again by its own parent. Imported tables rank
+/// below the parent's values; a source that is missing or not a table is
+/// skipped, as Helm skips it with a warning.
+fn import_dependency_values(charts: &[ChartContext], doc: &mut YamlValue) {
+    for (source, target) in ChartContext::ordered_value_imports(charts) {
+        let Some(YamlValue::Mapping(table)) = value_at_path(doc, &source).cloned() else {
+            continue;
+        };
+        let imported = target.iter().rev().fold(table, |table, segment| {
+            let mut parent = serde_yaml::Mapping::new();
+            parent.insert(
+                YamlValue::String(segment.clone()),
+                YamlValue::Mapping(table),
+            );
+            parent
+        });
+        if let YamlValue::Mapping(doc) = doc {
+            merge_mapping_existing_prefers_left(doc, imported, false);
+        }
+    }
+}
+
 fn add_values_file_descriptions(
     chart_dir: &VfsPath,
     prefix: &[String],
@@ -274,6 +300,28 @@ fn add_values_file_descriptions(
     Ok(())
 }
 
+/// Imported tables keep the descriptions their dependency documents.
+fn add_imported_descriptions(charts: &[ChartContext], out: &mut BTreeMap<String, String>) {
+    for (source, target) in ChartContext::ordered_value_imports(charts) {
+        let imported = out
+            .iter()
+            .filter_map(|(path, description)| {
+                let segments = helm_schema_core::split_value_path(path);
+                let relative = segments.strip_prefix(source.as_slice())?;
+                (!relative.is_empty()).then(|| {
+                    (
+                        helm_schema_core::join_value_path(target.iter().chain(relative)),
+                        description.clone(),
+                    )
+                })
+            })
+            .collect::<Vec<_>>();
+        for (path, description) in imported {
+            out.entry(path).or_insert(description);
+        }
+    }
+}
+
 fn add_layered_values_file_descriptions(
     values_path: &Path,
     out: &mut BTreeMap<String, String>,
@@ -288,7 +336,7 @@ fn add_layered_values_file_descriptions(
     Ok(())
 }
 
-fn value_at_path<'a>(root: &'a YamlValue, path: &[String]) -> Option<&'a YamlValue> {
+pub(super) fn value_at_path<'a>(root: &'a YamlValue, path: &[String]) -> Option<&'a YamlValue> {
     let mut current = root;
     for segment in path {
         current = current
@@ -375,6 +423,20 @@ fn coalesce_chart_values(
     }
 }
 
+/// Layers `defaults` below whatever `doc` already sets under `prefix`, the
+/// way Helm coalesces a chart's `values.yaml` beneath its parent's values.
+pub(super) fn coalesce_defaults_at(doc: &mut YamlValue, prefix: &[String], defaults: YamlValue) {
+    let YamlValue::Mapping(defaults) = defaults else {
+        return;
+    };
+    if value_at_path(doc, prefix).is_some_and(|existing| !existing.is_mapping()) {
+        return;
+    }
+    if let YamlValue::Mapping(target) = ensure_mapping_path(doc, prefix) {
+        merge_mapping_existing_prefers_left(target, defaults, false);
+    }
+}
+
 fn ensure_mapping_path<'a>(root: &'a mut YamlValue, path: &[String]) -> &'a mut YamlValue {
     let mut current = root;
 
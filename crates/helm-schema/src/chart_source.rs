@@ -1,4 +1,4 @@
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
 use vfs::VfsPath;
 
@@ -6,10 +6,13 @@ use crate::chart::discovery::{extract_chart_archive, is_chart_archive};
 use crate::error::{CliError, EngineResult};
 use crate::load_budget::LoadBudget;
 
+pub use crate::chart::ChartRepositoryCache;
+
 /// Open root chart directory shared by config discovery and chart analysis.
 #[derive(Debug, Clone)]
 pub struct RootChartSource {
     chart_dir: VfsPath,
+    location: Option<PathBuf>,
 }
 
 impl RootChartSource {
@@ -21,6 +24,7 @@ impl RootChartSource {
     /// directory nor a supported chart archive, or archive extraction fails.
     pub fn open(path: &Path, load_budget: LoadBudget) -> EngineResult<Self> {
         let metadata = std::fs::metadata(path)?;
+        let location = metadata.is_dir().then(|| path.to_path_buf());
         let chart_dir = if metadata.is_dir() {
             let path = path.to_string_lossy();
             VfsPath::new(vfs::PhysicalFS::new(path.as_ref()))
@@ -49,7 +53,10 @@ impl RootChartSource {
             )));
         };
 
-        Ok(Self { chart_dir })
+        Ok(Self {
+            chart_dir,
+            location,
+        })
     }
 
     /// Returns the root VFS directory used for both config and chart loading.
@@ -58,6 +65,12 @@ impl RootChartSource {
         &self.chart_dir
     }
 
+    /// The chart directory on disk, or `None` for a packaged chart archive.
+    #[must_use]
+    pub fn location(&self) -> Option<&Path> {
+        self.location.as_deref()
+    }
+
     /// Consumes the source and returns its root VFS directory.
     #[must_use]
     pub fn into_chart_dir(self) -> VfsPath {
@@ -0,0 +1,97 @@
+use std::path::{Path, PathBuf};
+
+use serde::Deserialize;
+use serde_json::Value;
+
+use crate::error::{CliError, EngineResult};
+
+pub use helm_schema_core::{ClusterLookupQuery, ClusterStateOracle, ClusterStateSnapshot};
+
+/// Load every object of the `.yaml`, `.yml`, and `.json` manifests beneath
+/// `dir` as one cluster state. Multi-document files and lists (`kubectl get
+/// -o yaml` output, or any `*List` carrying `items`) contribute each member
+/// object.
+///
+/// # Errors
+///
+/// Returns an error when the directory cannot be walked or a manifest is not
+/// valid YAML.
+pub fn load_cluster_state(dir: &Path) -> EngineResult<ClusterStateSnapshot> {
+    let mut files = Vec::new();
+    collect_manifest_files(dir, &mut files)?;
+    files.sort();
+    let mut objects = Vec::new();
+    for path in files {
+        let source =
+            std::fs::read_to_string(&path).map_err(|source| CliError::ReadClusterState {
+                path: path.clone(),
+                source,
+            })?;
+        for document in serde_yaml::Deserializer::from_str(&source) {
+            let value =
+                Value::deserialize(document).map_err(|source| CliError::InvalidClusterState {
+                    path: path.clone(),
+                    source,
+                })?;
+            push_manifest_objects(value, &mut objects);
+        }
+    }
+    Ok(ClusterStateSnapshot::new(objects))
+}
+
+fn collect_manifest_files(dir: &Path, files: &mut Vec<PathBuf>) -> EngineResult<()> {
+    let read_error = |source| CliError::ReadClusterState {
+        path: dir.to_path_buf(),
+        source,
+    };
+    for entry in std::fs::read_dir(dir).map_err(read_error)? {
+        let path = entry.map_err(read_error)?.path();
+        if path.is_dir() {
+            collect_manifest_files(&path, files)?;
+        } else if path
+            .extension()
+            .and_then(|extension| extension.to_str())
+            .is_some_and(|extension| matches!(extension, "yaml" | "yml" | "json"))
+        {
+            files.push(path);
+        }
+    }
+    Ok(())
+}
+
+fn push_manifest_objects(value: Value, objects: &mut Vec<Value>) {
+    let Value::Object(mut fields) = value else {
+        return;
+    };
+    let list_kind = fields
+        .get("kind")
+        .and_then(Value::as_str)
+        .and_then(|kind| kind.strip_suffix("List"))
+        .map(str::to_string);
+    let Some(list_kind) = list_kind.filter(|_| fields.get("items").is_some_and(Value::is_array))
+    else {
+        objects.push(Value::Object(fields));
+        return;
+    };
+    // Typed lists (`SecretList`) from the raw API leave their items'
+    // `apiVersion` and `kind` implied by the list itself.
+    let api_version = fields.get("apiVersion").cloned();
+    let Some(Value::Array(items)) = fields.remove("items") else {
+        return;
+    };
+    for mut item in items {
+        if let Value::Object(item_fields) = &mut item
+            && !list_kind.is_empty()
+        {
+            item_fields
+                .entry("kind")
+                .or_insert_with(|| Value::String(list_kind.clone()));
+            if let Some(api_version) = &api_version {
+                item_fields
+                    .entry("apiVersion")
+                    .or_insert_with(|| api_version.clone());
+            }
+        }
+        push_manifest_objects(item, objects);
+    }
+}
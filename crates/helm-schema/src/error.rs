@@ -131,6 +131,198 @@ pub enum CliError {
         entry_path: String,
     },
 
+    /// A values file supplied for validation could not be read.
+    #[error("failed to read values file {path}: {source}")]
+    ReadValuesFile {
+        /// Values file path.
+        path: PathBuf,
+        /// Underlying filesystem failure.
+        #[source]
+        source: std::io::Error,
+    },
+
+    /// A values file supplied for validation is not valid YAML.
+    #[error("invalid values file {path}: {source}")]
+    InvalidValuesFile {
+        /// Values file path as given by the caller.
+        path: String,
+        /// YAML decoding failure.
+        #[source]
+        source: serde_yaml::Error,
+    },
+
+    /// A cluster-state manifest could not be read.
+    #[error("failed to read cluster state {path}: {source}")]
+    ReadClusterState {
+        /// Manifest file or directory path.
+        path: PathBuf,
+        /// Underlying filesystem failure.
+        #[source]
+        source: std::io::Error,
+    },
+
+    /// A cluster-state manifest is not valid YAML.
+    #[error("invalid cluster state manifest {path}: {source}")]
+    InvalidClusterState {
+        /// Manifest file path.
+        path: PathBuf,
+        /// YAML decoding failure.
+        #[source]
+        source: serde_yaml::Error,
+    },
+
+    /// A cluster `OpenAPI` discovery document could not be read.
+    #[error("failed to read cluster OpenAPI dump {path}: {source}")]
+    ReadClusterOpenApi {
+        /// Document file or directory path.
+        path: PathBuf,
+        /// Underlying filesystem failure.
+        #[source]
+        source: std::io::Error,
+    },
+
+    /// A cluster `OpenAPI` discovery document is not valid JSON.
+    #[error("invalid cluster OpenAPI document {path}: {source}")]
+    InvalidClusterOpenApi {
+        /// Document file path.
+        path: PathBuf,
+        /// JSON decoding failure.
+        #[source]
+        source: serde_json::Error,
+    },
+
+    /// A capability profile could not be read.
+    #[error("failed to read capability profile {path}: {source}")]
+    ReadCapabilityProfile {
+        /// Profile file path.
+        path: PathBuf,
+        /// Underlying filesystem failure.
+        #[source]
+        source: std::io::Error,
+    },
+
+    /// A capability profile is not a valid `kubeVersion`/`apiVersions` map.
+    #[error("invalid capability profile {path}: {source}")]
+    InvalidCapabilityProfile {
+        /// Profile file path.
+        path: PathBuf,
+        /// YAML decoding failure.
+        #[source]
+        source: serde_yaml::Error,
+    },
+
+    /// A field schema override file could not be read.
+    #[error("failed to read field schema overrides {path}: {source}")]
+    ReadFieldSchemaOverrides {
+        /// Override file path.
+        path: PathBuf,
+        /// Underlying filesystem failure.
+        #[source]
+        source: std::io::Error,
+    },
+
+    /// A field schema override file is not a valid `fields` list.
+    #[error("invalid field schema overrides {path}: {source}")]
+    InvalidFieldSchemaOverrides {
+        /// Override file path.
+        path: PathBuf,
+        /// YAML decoding failure.
+        #[source]
+        source: serde_yaml::Error,
+    },
+
+    /// A schema bundle could not be read or unpacked.
+    #[error("failed to read schema bundle {path}: {source}")]
+    ReadSchemaBundle {
+        /// Bundle archive path.
+        path: PathBuf,
+        /// Underlying filesystem, decompression, or archive failure.
+        #[source]
+        source: std::io::Error,
+    },
+
+    /// A schema bundle could not be written.
+    #[error("failed to write schema bundle {path}: {source}")]
+    WriteSchemaBundle {
+        /// Bundle archive path.
+        path: PathBuf,
+        /// Underlying filesystem or compression failure.
+        #[source]
+        source: std::io::Error,
+    },
+
+    /// A managed schema cache could not be walked or pruned.
+    #[error("failed to maintain schema cache {path}: {source}")]
+    SchemaCacheMaintenance {
+        /// Cache root.
+        path: PathBuf,
+        /// Underlying filesystem failure.
+        #[source]
+        source: std::io::Error,
+    },
+
+    /// `cache verify` found damaged entries; they were already reported.
+    #[error("{count} schema cache entry(ies) failed verification")]
+    SchemaCacheDamaged {
+        /// Number of reported damaged entries.
+        count: usize,
+    },
+
+    /// `cache prefetch` could not resolve some resources; they were already
+    /// reported.
+    #[error("failed to prefetch {count} resource schema(s)")]
+    SchemaPrefetchIncomplete {
+        /// Number of reported unresolved resources.
+        count: usize,
+    },
+
+    /// The emitted schema could not be compiled into a validator.
+    #[error("schema cannot be compiled for validation: {0}")]
+    InvalidValidationSchema(String),
+
+    /// The committed schema checked by `--check` could not be read.
+    #[error("failed to read committed schema {path}: {source}")]
+    ReadCommittedSchema {
+        /// Committed schema path.
+        path: PathBuf,
+        /// Underlying filesystem failure.
+        #[source]
+        source: std::io::Error,
+    },
+
+    /// The committed schema checked by `--check` is not valid JSON.
+    #[error("committed schema {path} is not valid JSON: {source}")]
+    InvalidCommittedSchema {
+        /// Committed schema path.
+        path: PathBuf,
+        /// JSON parse failure.
+        #[source]
+        source: serde_json::Error,
+    },
+
+    /// The committed schema differs from the regenerated one; the drift was
+    /// already reported.
+    #[error("{path} is out of date with the chart")]
+    SchemaDrift {
+        /// Committed schema path.
+        path: PathBuf,
+    },
+
+    /// `diff` found changes that can break existing values overrides; they
+    /// were already reported.
+    #[error("found {count} breaking values contract change(s)")]
+    BreakingSchemaChanges {
+        /// Number of reported breaking changes.
+        count: usize,
+    },
+
+    /// Values validation found violations; they were already reported.
+    #[error("values validation failed with {count} violation(s)")]
+    ValuesValidationFailed {
+        /// Number of reported violations.
+        count: usize,
+    },
+
     /// Mutually-exclusive CLI flags or otherwise-invalid combination
     /// detected after `clap` parsing succeeded.
     #[error("invalid CLI options: {0}")]
@@ -6,6 +6,7 @@ pub use options::EmissionPolicy;
 pub use options::EmissionPolicyDelta;
 pub use options::EmissionReport;
 pub use options::EmissionSelection;
+pub use options::EnumInference;
 pub use options::GenerateOptions;
 pub use options::GeneratedSchema;
 pub use options::InvalidEmissionPolicy;
@@ -1,10 +1,13 @@
 use std::path::PathBuf;
+use std::sync::Arc;
 
 use serde_json::Value;
 use vfs::VfsPath;
 
 use crate::provider_builder::ProviderOptions;
+use helm_schema_core::ClusterStateOracle;
 
+pub use helm_schema_gen::enum_inference::EnumInference;
 pub use helm_schema_gen::{
     ConditionalAnchors, EmissionClassKind, EmissionPolicy, EmissionPolicyDelta, EmissionReport,
     EmissionSelection, InvalidEmissionPolicy, ResolvedEmissionPolicy, SchemaProfile,
@@ -23,10 +26,16 @@ pub struct GenerateOptions {
     pub values_files: Vec<PathBuf>,
     /// Whether the optional required-property heuristic runs.
     pub infer_required: bool,
+    /// How literals compared against values paths are published as
+    /// `examples` or `enum`.
+    pub infer_enums: EnumInference,
     /// Amount of analyzed contract evidence emitted into the schema.
     pub emission: EmissionSelection,
     /// Kubernetes and CRD schema-provider policy.
     pub provider: ProviderOptions,
+    /// Cluster state `lookup` calls resolve against; `None` keeps their
+    /// results opaque.
+    pub cluster_state: Option<Arc<dyn ClusterStateOracle>>,
 }
 
 /// Provider-resolved values contract prior to heuristic required-inference
@@ -35,7 +44,7 @@ pub struct GenerateOptions {
 /// The resolved contract contains facts inferred from templates, helpers,
 /// composed values defaults/descriptions, and provider schemas. The later
 /// `GeneratedSchema` stage is reserved for additional synthesized mutations
-/// like the optional `--infer-required` heuristic.
+/// like the optional `--infer-required` heuristic and `--infer-enums` policy.
 #[derive(Debug, Clone)]
 pub struct ResolvedContract {
     /// JSON Schema lowered from structural contract evidence.
@@ -4,6 +4,8 @@ mod analysis;
 mod chart;
 /// Root chart source opening for directories and packaged archives.
 pub mod chart_source;
+/// Cluster state that `lookup` calls resolve against.
+pub mod cluster_state;
 mod error;
 mod fetch_policy;
 /// JSON Schema reference bundling and inlining.
@@ -13,15 +15,34 @@ pub mod generation;
 mod load_budget;
 mod output_pipeline;
 mod provider_builder;
+mod schema_bundle;
+mod schema_diff;
 /// Deterministic merge policy for caller-supplied override schemas.
 pub mod schema_override;
 mod session;
+mod source_map;
+mod values_reference;
 mod values_roots;
+mod values_validation;
 
 #[cfg(test)]
 #[path = "tests/mod.rs"]
 mod tests;
 
+/// Persistent per-template analysis cache and maintenance of the managed
+/// Kubernetes and CRD catalog schema caches.
+pub mod cache {
+    pub use crate::analysis::AnalysisCache;
+    pub use helm_schema_k8s::cache::{
+        CacheEntry, CacheEntryKind, CacheVerification, SourceMeta, cache_entries, prune_cache,
+        verify_cache_entry,
+    };
+    pub use helm_schema_k8s::{
+        default_crd_schema_cache_dir, default_k8s_schema_cache_dir, default_source_id,
+        source_id_for_url,
+    };
+}
+
 /// Runtime diagnostics produced by Kubernetes and CRD schema lookup.
 pub mod diagnostics {
     pub use helm_schema_k8s::{
@@ -31,8 +52,12 @@ pub mod diagnostics {
 
 /// Stable inspection types for the recovered Helm contract.
 pub mod contract {
+    pub use crate::source_map::{SourceLocation, SourceMap};
+    pub use helm_schema_core::{append_value_path, split_value_path};
     pub use helm_schema_ir::{
-        ContractDocument, ContractProvenance, ContractUse, Guard, SourceSpan, ValueKind,
+        ConditionalGuard, ContractDocument, ContractProvenance, ContractUse,
+        ContractValuePathFacts, Guard, GuardValue, MetadataFieldKind, ResourceRef, SourceSpan,
+        ValueKind, YamlPath,
     };
 }
 
@@ -42,14 +67,49 @@ pub mod output {
     pub use crate::load_budget::LoadBudget;
     pub use crate::output_pipeline::{
         EmitRequest, FinalOutputMetrics, JsonOutputFormat, OutputPipelineOptions,
-        PolicyInputOptions, ReferencePolicy, write_schema_json,
+        PolicyInputOptions, ReferencePolicy, SchemaDialect, write_schema_json,
+    };
+}
+
+/// Semantic comparison of two emitted values schemas.
+pub mod diff {
+    pub use crate::schema_diff::{
+        SchemaChange, SchemaChangeKind, diff_guards, diff_schemas, generator_version,
+        policy_fingerprint,
+    };
+}
+
+/// Values reference rows for chart documentation.
+pub mod reference {
+    pub use crate::values_reference::ValuesReferenceEntry;
+}
+
+/// Typed TypeScript, Go, Python, CUE, and KCL bindings of the values
+/// contract.
+pub mod bindings {
+    pub use helm_schema_gen::{
+        BindingLanguage, BindingOptions, FieldType, ObjectType, ValueType, ValuesTypeModel,
+        VariantCase, VariantSet, VariantTag, render_type_bindings,
     };
 }
 
+/// Validation of user values files against the emitted schema.
+pub mod validation {
+    pub use crate::values_validation::{UnknownValuesKey, ValuesDocument, ValuesViolation};
+    pub use helm_schema_ast::extract_values_yaml_key_lines;
+}
+
 /// Kubernetes and CRD provider configuration types.
 pub mod provider {
-    pub use crate::provider_builder::ProviderOptions;
-    pub use helm_schema_k8s::{K8sVersionChain, LocalSchemaUniverse};
+    pub use crate::provider_builder::{
+        ProviderOptions, load_capability_profile, load_cluster_openapi,
+        load_field_schema_overrides, prefetch_resource_schemas,
+    };
+    pub use crate::schema_bundle::{SchemaBundle, load_schema_bundle, write_schema_bundle};
+    pub use helm_schema_k8s::{
+        CapabilityProfile, ClusterOpenApiDump, FieldSchemaOverride, FieldSchemaOverrides,
+        K8sVersionChain, LocalSchemaUniverse, SchemaBundleRecorder,
+    };
 }
 
 pub use session::{Analysis, AnalysisSession, ValuePathExplanation};
@@ -1,12 +1,13 @@
 use std::fmt::Write as _;
 
+use helm_schema_gen::enum_inference::EnumInference;
 use helm_schema_gen::{POLICY_VOCABULARY_VERSION, ResolvedEmissionPolicy, SchemaProfile};
 use serde_json::{Map, Value};
 use sha2::{Digest, Sha256};
 
 use crate::error::{CliError, EngineResult};
-use crate::output_pipeline::ReferencePolicy;
 use crate::output_pipeline::overrides::PreparedOverridesIdentity;
+use crate::output_pipeline::{ReferencePolicy, SchemaDialect};
 
 const ANNOTATION_FORMAT_VERSION: u64 = 1;
 const GENERATED_SCHEMA_MARKER_KEY: &str = "x-helm-schema-generated";
@@ -18,6 +19,7 @@ pub(crate) struct FinalOutputPolicy {
     requested_profile: Option<SchemaProfile>,
     resolved: ResolvedEmissionPolicy,
     infer_required: bool,
+    infer_enums: EnumInference,
 }
 
 impl FinalOutputPolicy {
@@ -26,9 +28,15 @@ impl FinalOutputPolicy {
             requested_profile: resolved.requested_profile(),
             resolved,
             infer_required,
+            infer_enums: EnumInference::Off,
         }
     }
 
+    pub(crate) const fn with_infer_enums(mut self, infer_enums: EnumInference) -> Self {
+        self.infer_enums = infer_enums;
+        self
+    }
+
     #[cfg(test)]
     pub(crate) const fn for_profile(profile: SchemaProfile, infer_required: bool) -> Self {
         Self::new(profile.resolved_policy(), infer_required)
@@ -40,20 +48,43 @@ pub(crate) fn annotate_final_schema(
     policy: FinalOutputPolicy,
     overrides: &PreparedOverridesIdentity,
     reference_policy: ReferencePolicy,
+    dialect: SchemaDialect,
 ) -> EngineResult<Value> {
     let resolved = serde_json::to_value(policy.resolved.policy())?;
-    let narrowing = if policy.infer_required {
-        vec![Value::String("infer-required".to_string())]
-    } else {
-        Vec::new()
-    };
-    let modifiers = serde_json::json!({
+    let mut narrowing = Vec::new();
+    if policy.infer_required {
+        narrowing.push(Value::String("infer-required".to_string()));
+    }
+    if policy.infer_enums == EnumInference::Strict {
+        narrowing.push(Value::String("infer-enums".to_string()));
+    }
+    let mut modifiers = serde_json::json!({
         "overrides": {
             "count": overrides.count,
             "digest": overrides.digest,
         },
         "reference-mode": reference_policy.annotation_name(),
     });
+    // Inferred examples are annotations only, so they modify the output
+    // without narrowing what it accepts.
+    if policy.infer_enums == EnumInference::Examples
+        && let Some(modifiers) = modifiers.as_object_mut()
+    {
+        modifiers.insert(
+            "infer-enums".to_string(),
+            Value::String(policy.infer_enums.as_str().to_string()),
+        );
+    }
+    // Draft-07 is the native dialect and stays implicit, so fingerprints of
+    // Draft-07 output do not move.
+    if dialect != SchemaDialect::Draft07
+        && let Some(modifiers) = modifiers.as_object_mut()
+    {
+        modifiers.insert(
+            "dialect".to_string(),
+            Value::String(dialect.annotation_name().to_string()),
+        );
+    }
     let fingerprint_input = serde_json::json!({
         "policy-vocabulary-version": POLICY_VOCABULARY_VERSION,
         "resolved": resolved.clone(),
@@ -69,6 +100,7 @@ pub(crate) fn annotate_final_schema(
 
     let annotation = serde_json::json!({
         "annotation-format-version": ANNOTATION_FORMAT_VERSION,
+        "generator-version": env!("CARGO_PKG_VERSION"),
         "policy-vocabulary-version": POLICY_VOCABULARY_VERSION,
         "requested-profile": policy.requested_profile.map(SchemaProfile::as_str),
         "resolved": resolved,
@@ -0,0 +1,316 @@
+//! Draft 2020-12 rendering of the generator's Draft-07 output.
+//!
+//! Schema generation and every earlier output transform speak Draft-07.
+//! This pass rewrites the finished document keyword by keyword into the
+//! requested dialect without changing which values it accepts, except where
+//! 2020-12 can say what Draft-07 cannot: a closed object whose in-place
+//! branches declare further properties.
+
+use std::collections::BTreeSet;
+
+use helm_schema_json_schema_walk::{
+    SchemaTraversalContext, escape_json_pointer_segment, schema_child_context_for_keyword,
+    visit_subschemas_mut,
+};
+use serde_json::{Map, Value};
+
+use crate::output_pipeline::SchemaDialect;
+
+/// Keywords whose subschemas apply to the same instance location, so their
+/// evaluated properties are visible to `unevaluatedProperties`.
+const IN_PLACE_APPLICATORS: [&str; 7] = [
+    "allOf",
+    "anyOf",
+    "oneOf",
+    "if",
+    "then",
+    "else",
+    "dependentSchemas",
+];
+
+/// Keywords Draft-07 honors beside `$ref`: annotations and definition
+/// containers. Every other sibling was ignored and must not start to apply.
+const REF_SIBLINGS: [&str; 9] = [
+    "title",
+    "description",
+    "default",
+    "examples",
+    "deprecated",
+    "readOnly",
+    "writeOnly",
+    "$defs",
+    "definitions",
+];
+
+/// Rewrites a Draft-07 `schema` into `dialect`.
+pub(crate) fn convert_schema_dialect(schema: &mut Value, dialect: SchemaDialect) {
+    match dialect {
+        SchemaDialect::Draft07 => {}
+        SchemaDialect::Draft2020_12 => {
+            let renamed_definitions = rename_root_definitions(schema);
+            upgrade_keywords(schema, renamed_definitions);
+            let mut closed = Vec::new();
+            collect_unevaluated_closures(schema, schema, "", &mut closed);
+            for pointer in closed {
+                if let Some(object) = schema.pointer_mut(&pointer).and_then(Value::as_object_mut) {
+                    close_with_unevaluated_properties(object);
+                }
+            }
+            if let Some(object) = schema.as_object_mut() {
+                object.insert(
+                    "$schema".to_string(),
+                    Value::String(dialect.uri().to_string()),
+                );
+            }
+        }
+    }
+}
+
+/// Moves root `definitions` into `$defs`, unless a name collides with an
+/// existing `$defs` entry. Returns whether references need rewriting.
+fn rename_root_definitions(schema: &mut Value) -> bool {
+    let Some(object) = schema.as_object_mut() else {
+        return false;
+    };
+    let Some(Value::Object(definitions)) = object.get("definitions") else {
+        return false;
+    };
+    let defs = object.get("$defs").and_then(Value::as_object);
+    if defs.is_some_and(|defs| definitions.keys().any(|name| defs.contains_key(name))) {
+        return false;
+    }
+    let Some(Value::Object(definitions)) = object.remove("definitions") else {
+        return false;
+    };
+    let defs = object
+        .entry("$defs")
+        .or_insert_with(|| Value::Object(Map::new()));
+    if let Value::Object(defs) = defs {
+        defs.extend(definitions);
+    }
+    true
+}
+
+/// Renames the Draft-07 keywords 2020-12 replaced, in every schema object.
+fn upgrade_keywords(schema: &mut Value, renamed_definitions: bool) {
+    if let Some(object) = schema.as_object_mut() {
+        if let Some(Value::String(reference)) = object.get_mut("$ref") {
+            if renamed_definitions && let Some(name) = reference.strip_prefix("#/definitions/") {
+                *reference = format!("#/$defs/{name}");
+            }
+            object.retain(|key, _| {
+                key == "$ref"
+                    || key.starts_with("x-")
+                    || key.starts_with('$')
+                    || REF_SIBLINGS.contains(&key.as_str())
+            });
+        }
+
+        // A tuple `items` array is `prefixItems`; the Draft-07 `additionalItems`
+        // beside it becomes `items`. Beside a single-schema `items` it never
+        // applied.
+        let additional_items = object.remove("additionalItems");
+        if let Some(Value::Array(_)) = object.get("items") {
+            if let Some(prefix) = object.remove("items") {
+                object.insert("prefixItems".to_string(), prefix);
+            }
+            if let Some(additional) = additional_items {
+                object.insert("items".to_string(), additional);
+            }
+        }
+
+        // `dependencies` split into `dependentRequired` (property lists) and
+        // `dependentSchemas` (schemas).
+        if let Some(Value::Object(dependencies)) = object.remove("dependencies") {
+            let (required, schemas): (Map<_, _>, Map<_, _>) = dependencies
+                .into_iter()
+                .partition(|(_, dependency)| dependency.is_array());
+            if !required.is_empty() {
+                object.insert("dependentRequired".to_string(), Value::Object(required));
+            }
+            if !schemas.is_empty() {
+                object.insert("dependentSchemas".to_string(), Value::Object(schemas));
+            }
+        }
+
+        // Only the document root carries `$schema`; a bundled Draft-07
+        // document's marker would otherwise contradict the new dialect.
+        object.remove("$schema");
+    }
+    visit_subschemas_mut(schema, &mut |child| {
+        upgrade_keywords(child, renamed_definitions);
+    });
+}
+
+/// Collects the pointers of closed objects that can trade
+/// `additionalProperties: false` for `unevaluatedProperties: false`.
+///
+/// Draft-07 closes an object against its own `properties` only, so a key an
+/// `allOf`/`if`/`then` branch declares is rejected even when that branch
+/// applies. `unevaluatedProperties` also sees keys the applying branches
+/// evaluate. The trade is made only when every branch is itself closed or
+/// open without an `additionalProperties` schema: such a schema would
+/// evaluate every remaining key and silently reopen the object.
+fn collect_unevaluated_closures(
+    root: &Value,
+    schema: &Value,
+    pointer: &str,
+    out: &mut Vec<String>,
+) {
+    let Some(object) = schema.as_object() else {
+        return;
+    };
+    let closed = object.get("additionalProperties") == Some(&Value::Bool(false));
+    let has_branches = IN_PLACE_APPLICATORS
+        .iter()
+        .any(|keyword| object.contains_key(*keyword));
+    if closed && has_branches && branches_keep_closure(root, object, &mut BTreeSet::new()) {
+        out.push(pointer.to_string());
+    }
+    if object.contains_key("$ref") {
+        return;
+    }
+
+    for (key, value) in object {
+        let key_pointer = format!("{pointer}/{}", escape_json_pointer_segment(key));
+        match (schema_child_context_for_keyword(key), value) {
+            (SchemaTraversalContext::Schema, _) => {
+                collect_unevaluated_closures(root, value, &key_pointer, out);
+            }
+            (SchemaTraversalContext::SchemaArray, Value::Array(values)) => {
+                for (index, value) in values.iter().enumerate() {
+                    collect_unevaluated_closures(
+                        root,
+                        value,
+                        &format!("{key_pointer}/{index}"),
+                        out,
+                    );
+                }
+            }
+            (SchemaTraversalContext::SchemaMapValues, Value::Object(values)) => {
+                for (name, value) in values {
+                    let child_pointer =
+                        format!("{key_pointer}/{}", escape_json_pointer_segment(name));
+                    collect_unevaluated_closures(root, value, &child_pointer, out);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Whether no in-place branch of `object` evaluates keys beyond those it
+/// names. Inline branches may carry a trivial `additionalProperties`
+/// (`true` or `{}`), which the conversion drops; referenced branches are
+/// shared and must carry none.
+fn branches_keep_closure(
+    root: &Value,
+    object: &Map<String, Value>,
+    visited: &mut BTreeSet<String>,
+) -> bool {
+    in_place_branches(object)
+        .into_iter()
+        .all(|branch| branch_keeps_closure(root, branch, false, visited))
+}
+
+fn branch_keeps_closure(
+    root: &Value,
+    branch: &Value,
+    referenced: bool,
+    visited: &mut BTreeSet<String>,
+) -> bool {
+    let Some(object) = branch.as_object() else {
+        return true;
+    };
+    if object.contains_key("unevaluatedProperties") {
+        return false;
+    }
+    match object.get("additionalProperties") {
+        None | Some(Value::Bool(false)) => {}
+        Some(additional) if !referenced && is_trivial_schema(additional) => {}
+        Some(_) => return false,
+    }
+    if let Some(reference) = object.get("$ref").and_then(Value::as_str) {
+        let Some(target) = reference
+            .strip_prefix('#')
+            .and_then(|pointer| root.pointer(pointer))
+        else {
+            return false;
+        };
+        if !visited.insert(reference.to_string()) {
+            return true;
+        }
+        return branch_keeps_closure(root, target, true, visited);
+    }
+    in_place_branches(object)
+        .into_iter()
+        .all(|child| branch_keeps_closure(root, child, referenced, visited))
+}
+
+fn in_place_branches(object: &Map<String, Value>) -> Vec<&Value> {
+    let mut branches = Vec::new();
+    for keyword in IN_PLACE_APPLICATORS {
+        match (keyword, object.get(keyword)) {
+            (_, None) => {}
+            ("allOf" | "anyOf" | "oneOf", Some(Value::Array(values))) => branches.extend(values),
+            ("dependentSchemas", Some(Value::Object(schemas))) => branches.extend(schemas.values()),
+            (_, Some(value)) => branches.push(value),
+        }
+    }
+    branches
+}
+
+fn is_trivial_schema(schema: &Value) -> bool {
+    match schema {
+        Value::Bool(accepts) => *accepts,
+        Value::Object(object) => object.is_empty(),
+        _ => false,
+    }
+}
+
+fn close_with_unevaluated_properties(object: &mut Map<String, Value>) {
+    object.remove("additionalProperties");
+    object.insert("unevaluatedProperties".to_string(), Value::Bool(false));
+    drop_trivial_branch_additional_properties(object);
+}
+
+/// Drops the no-op `additionalProperties` of inline branches: in 2020-12 it
+/// would mark every key evaluated and reopen the enclosing object.
+fn drop_trivial_branch_additional_properties(object: &mut Map<String, Value>) {
+    for keyword in IN_PLACE_APPLICATORS {
+        match (keyword, object.get_mut(keyword)) {
+            (_, None) => {}
+            ("allOf" | "anyOf" | "oneOf", Some(Value::Array(values))) => {
+                values
+                    .iter_mut()
+                    .for_each(drop_trivial_additional_properties);
+            }
+            ("dependentSchemas", Some(Value::Object(schemas))) => {
+                schemas
+                    .values_mut()
+                    .for_each(drop_trivial_additional_properties);
+            }
+            (_, Some(value)) => drop_trivial_additional_properties(value),
+        }
+    }
+}
+
+fn drop_trivial_additional_properties(branch: &mut Value) {
+    let Some(object) = branch.as_object_mut() else {
+        return;
+    };
+    if object.contains_key("$ref") {
+        return;
+    }
+    if object
+        .get("additionalProperties")
+        .is_some_and(is_trivial_schema)
+    {
+        object.remove("additionalProperties");
+    }
+    drop_trivial_branch_additional_properties(object);
+}
+
+#[cfg(test)]
+#[path = "tests/dialect.rs"]
+mod tests;
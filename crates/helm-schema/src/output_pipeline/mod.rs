@@ -6,16 +6,20 @@
 
 mod annotation;
 mod descriptions;
+mod dialect;
 mod format;
 mod options;
 mod overrides;
 mod reachability;
+mod sources;
 mod transforms;
 
 pub(crate) use annotation::FinalOutputPolicy;
 pub use format::{FinalOutputMetrics, write_schema_json};
 pub use options::{
     EmitRequest, JsonOutputFormat, OutputPipelineOptions, PolicyInputOptions, ReferencePolicy,
+    SchemaDialect,
 };
 pub(crate) use overrides::{PreparedEmitRequest, load_emit_request, prepare_emit_request};
+pub(crate) use sources::SchemaSources;
 pub(crate) use transforms::apply_schema_output_pipeline;
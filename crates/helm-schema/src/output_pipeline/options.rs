@@ -11,6 +11,45 @@ pub struct OutputPipelineOptions {
     pub strip_descriptions: bool,
     /// Whether redundant schema structure is minimized.
     pub minimize: bool,
+    /// Whether property schemas carry `x-helm-schema-sources` annotations
+    /// naming the template locations that read them.
+    pub source_annotations: bool,
+    /// JSON Schema dialect of the final document.
+    pub dialect: SchemaDialect,
+}
+
+/// JSON Schema dialect the final document is written in.
+///
+/// Generation always works in Draft-07; other dialects are a final output
+/// rewrite that keeps the accepted values unchanged, except where the
+/// dialect can express a constraint more exactly.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum SchemaDialect {
+    /// JSON Schema Draft-07.
+    #[default]
+    Draft07,
+    /// JSON Schema 2020-12: `$defs`, `prefixItems`, `dependentRequired`,
+    /// and `unevaluatedProperties` closing objects over the properties their
+    /// applying branches declare.
+    Draft2020_12,
+}
+
+impl SchemaDialect {
+    /// The dialect's `$schema` meta-schema URI.
+    #[must_use]
+    pub const fn uri(self) -> &'static str {
+        match self {
+            Self::Draft07 => "http://json-schema.org/draft-07/schema#",
+            Self::Draft2020_12 => "https://json-schema.org/draft/2020-12/schema",
+        }
+    }
+
+    pub(crate) const fn annotation_name(self) -> &'static str {
+        match self {
+            Self::Draft07 => "draft-07",
+            Self::Draft2020_12 => "2020-12",
+        }
+    }
 }
 
 /// Input-loading policy for schema documents that must be prepared before
@@ -7,7 +7,7 @@ use sha2::{Digest, Sha256};
 use crate::error::EngineResult;
 use crate::flatten;
 use crate::load_budget::read_to_end_capped;
-use crate::output_pipeline::{EmitRequest, PolicyInputOptions, ReferencePolicy};
+use crate::output_pipeline::{EmitRequest, PolicyInputOptions, ReferencePolicy, SchemaSources};
 use crate::schema_override::{PreparedOverride, UnpreparedOverride};
 
 /// Output policy inputs validated before chart generation begins.
@@ -36,6 +36,9 @@ pub(crate) struct PreparedEmitRequest {
     /// stay separate.
     prepared_override_schemas: Vec<PreparedOverride>,
     pub(super) request: EmitRequest,
+    /// Template locations for `x-helm-schema-sources`; empty unless the
+    /// request enables source annotations.
+    pub(super) sources: SchemaSources,
 }
 
 pub(super) struct PreparedOverridesIdentity {
@@ -48,9 +51,15 @@ impl PreparedEmitRequest {
         Self {
             prepared_override_schemas: Vec::new(),
             request,
+            sources: SchemaSources::default(),
         }
     }
 
+    pub(crate) fn with_sources(mut self, sources: SchemaSources) -> Self {
+        self.sources = sources;
+        self
+    }
+
     pub(super) fn override_count(&self) -> usize {
         self.prepared_override_schemas.len()
     }
@@ -149,6 +158,7 @@ pub(crate) fn prepare_emit_request(
     Ok(PreparedEmitRequest {
         prepared_override_schemas,
         request,
+        sources: SchemaSources::default(),
     })
 }
 
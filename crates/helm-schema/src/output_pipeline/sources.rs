@@ -0,0 +1,90 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use helm_schema_core::{ContractUse, append_value_path};
+use serde_json::Value;
+
+use crate::source_map::{SourceLocation, SourceMap};
+
+const SOURCES_ANNOTATION_KEY: &str = "x-helm-schema-sources";
+
+/// Template locations of the contract uses behind each values path.
+///
+/// Collected from contract provenance before the output pipeline runs, so
+/// annotating the final schema never re-enters template analysis.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct SchemaSources {
+    by_path: BTreeMap<String, BTreeSet<SourceLocation>>,
+}
+
+impl SchemaSources {
+    /// Resolves every use's provenance through `source_map`. Provenance that
+    /// does not resolve to a chart file location is skipped.
+    pub(crate) fn collect(uses: &[ContractUse], source_map: &mut SourceMap) -> Self {
+        let mut by_path: BTreeMap<String, BTreeSet<SourceLocation>> = BTreeMap::new();
+        for use_ in uses {
+            let locations: BTreeSet<_> = use_
+                .provenance
+                .iter()
+                .filter_map(|provenance| source_map.locate(provenance))
+                .collect();
+            if !locations.is_empty() {
+                by_path
+                    .entry(use_.source_expr.clone())
+                    .or_default()
+                    .extend(locations);
+            }
+        }
+        Self { by_path }
+    }
+
+    /// Resolved locations of the uses that read exactly `path`.
+    pub(crate) fn locations(&self, path: &str) -> Option<&BTreeSet<SourceLocation>> {
+        self.by_path.get(path)
+    }
+
+    /// Attaches `x-helm-schema-sources` to every property schema in the
+    /// values tree whose path some template reads.
+    ///
+    /// Only the base `properties`/`items`/`additionalProperties` tree is
+    /// annotated; conditional overlays restate the same paths and would
+    /// only repeat the locations.
+    pub(super) fn annotate(&self, schema: &mut Value) {
+        if !self.by_path.is_empty() {
+            self.annotate_at(schema, "");
+        }
+    }
+
+    fn annotate_at(&self, schema: &mut Value, path: &str) {
+        let Some(object) = schema.as_object_mut() else {
+            return;
+        };
+        if let Some(properties) = object.get_mut("properties").and_then(Value::as_object_mut) {
+            for (name, property) in properties {
+                let property_path = append_value_path(path, name);
+                self.annotate_at(property, &property_path);
+                self.insert(property, &property_path);
+            }
+        }
+        for keyword in ["items", "additionalProperties"] {
+            if let Some(member) = object.get_mut(keyword) {
+                let member_path = append_value_path(path, "*");
+                self.annotate_at(member, &member_path);
+                self.insert(member, &member_path);
+            }
+        }
+    }
+
+    fn insert(&self, schema: &mut Value, path: &str) {
+        let (Some(object), Some(locations)) = (schema.as_object_mut(), self.by_path.get(path))
+        else {
+            return;
+        };
+        if let Ok(locations) = serde_json::to_value(locations) {
+            object.insert(SOURCES_ANNOTATION_KEY.to_string(), locations);
+        }
+    }
+}
+
+#[cfg(test)]
+#[path = "tests/sources.rs"]
+mod tests;
@@ -5,7 +5,7 @@ use test_util::prelude::sim_assert_eq;
 use crate::generation::SchemaProfile;
 use crate::output_pipeline::{
     EmitRequest, FinalOutputPolicy, OutputPipelineOptions, PreparedEmitRequest, ReferencePolicy,
-    apply_schema_output_pipeline,
+    SchemaDialect, apply_schema_output_pipeline,
 };
 
 fn request(reference_policy: ReferencePolicy) -> PreparedEmitRequest {
@@ -14,6 +14,8 @@ fn request(reference_policy: ReferencePolicy) -> PreparedEmitRequest {
         output: OutputPipelineOptions {
             strip_descriptions: false,
             minimize: false,
+            source_annotations: false,
+            dialect: SchemaDialect::Draft07,
         },
     })
 }
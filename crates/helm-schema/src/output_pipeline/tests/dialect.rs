@@ -0,0 +1,136 @@
+use serde_json::json;
+use test_util::prelude::sim_assert_eq;
+
+use super::*;
+
+fn convert(mut schema: Value) -> Value {
+    convert_schema_dialect(&mut schema, SchemaDialect::Draft2020_12);
+    schema
+}
+
+#[test]
+fn draft_07_output_is_left_untouched() {
+    let schema = json!({
+        "$schema": "http://json-schema.org/draft-07/schema#",
+        "definitions": { "a": { "type": "string" } },
+        "items": [{ "$ref": "#/definitions/a" }],
+    });
+    let mut converted = schema.clone();
+    convert_schema_dialect(&mut converted, SchemaDialect::Draft07);
+    sim_assert_eq!(have: converted, want: schema);
+}
+
+#[test]
+fn replaced_keywords_are_renamed() {
+    sim_assert_eq!(
+        have: convert(json!({
+            "$schema": "http://json-schema.org/draft-07/schema#",
+            "definitions": { "port": { "type": "integer" } },
+            "properties": {
+                "pair": {
+                    "items": [{ "$ref": "#/definitions/port" }, { "type": "string" }],
+                    "additionalItems": false,
+                },
+                "list": { "items": { "type": "string" }, "additionalItems": false },
+                "tls": {
+                    "dependencies": {
+                        "cert": ["key"],
+                        "ca": { "required": ["verify"] },
+                    },
+                },
+                "ref": { "$ref": "#/definitions/port", "type": "string", "description": "kept" },
+            },
+        })),
+        want: json!({
+            "$schema": "https://json-schema.org/draft/2020-12/schema",
+            "$defs": { "port": { "type": "integer" } },
+            "properties": {
+                "pair": {
+                    "prefixItems": [{ "$ref": "#/$defs/port" }, { "type": "string" }],
+                    "items": false,
+                },
+                "list": { "items": { "type": "string" } },
+                "tls": {
+                    "dependentRequired": { "cert": ["key"] },
+                    "dependentSchemas": { "ca": { "required": ["verify"] } },
+                },
+                "ref": { "$ref": "#/$defs/port", "description": "kept" },
+            },
+        })
+    );
+}
+
+#[test]
+fn closed_objects_with_branches_close_over_evaluated_properties() {
+    sim_assert_eq!(
+        have: convert(json!({
+            "type": "object",
+            "additionalProperties": false,
+            "properties": { "mode": { "type": "string" } },
+            "allOf": [{
+                "if": { "properties": { "mode": { "const": "advanced" } } },
+                "then": { "additionalProperties": {}, "properties": { "advanced": {} } },
+            }],
+        })),
+        want: json!({
+            "$schema": "https://json-schema.org/draft/2020-12/schema",
+            "type": "object",
+            "unevaluatedProperties": false,
+            "properties": { "mode": { "type": "string" } },
+            "allOf": [{
+                "if": { "properties": { "mode": { "const": "advanced" } } },
+                "then": { "properties": { "advanced": {} } },
+            }],
+        })
+    );
+}
+
+#[test]
+fn branches_that_evaluate_every_key_keep_additional_properties() {
+    let open_map = json!({
+        "additionalProperties": false,
+        "properties": { "a": {} },
+        "anyOf": [{ "additionalProperties": { "type": "string" } }],
+    });
+    sim_assert_eq!(
+        have: convert(open_map.clone()).get("additionalProperties"),
+        want: Some(&json!(false))
+    );
+
+    // A shared definition cannot drop its no-op `additionalProperties` for
+    // one referrer's sake.
+    let referenced = json!({
+        "$defs": { "open": { "additionalProperties": {} } },
+        "additionalProperties": false,
+        "allOf": [{ "$ref": "#/$defs/open" }],
+    });
+    sim_assert_eq!(
+        have: convert(referenced).get("additionalProperties"),
+        want: Some(&json!(false))
+    );
+}
+
+#[test]
+fn converted_schema_accepts_branch_keys_only_where_the_branch_applies()
+-> color_eyre::eyre::Result<()> {
+    let draft_07 = json!({
+        "$schema": "http://json-schema.org/draft-07/schema#",
+        "type": "object",
+        "additionalProperties": false,
+        "properties": { "mode": { "type": "string" } },
+        "if": { "properties": { "mode": { "const": "advanced" } }, "required": ["mode"] },
+        "then": { "properties": { "advanced": { "type": "object" } } },
+    });
+    let converted = convert(draft_07.clone());
+    let draft_07 = jsonschema::validator_for(&draft_07)?;
+    let converted = jsonschema::validator_for(&converted)?;
+
+    let advanced = json!({ "mode": "advanced", "advanced": {} });
+    let misplaced = json!({ "mode": "simple", "advanced": {} });
+    let unknown = json!({ "mode": "advanced", "other": 1 });
+    sim_assert_eq!(have: draft_07.is_valid(&advanced), want: false);
+    sim_assert_eq!(have: converted.is_valid(&advanced), want: true);
+    sim_assert_eq!(have: converted.is_valid(&misplaced), want: false);
+    sim_assert_eq!(have: converted.is_valid(&unknown), want: false);
+    Ok(())
+}
@@ -8,7 +8,8 @@ use serde_json::Value;
 
 use crate::output_pipeline::{
     EmitRequest, FinalOutputPolicy, OutputPipelineOptions, PolicyInputOptions, PreparedEmitRequest,
-    ReferencePolicy, apply_schema_output_pipeline, load_emit_request, prepare_emit_request,
+    ReferencePolicy, SchemaDialect, apply_schema_output_pipeline, load_emit_request,
+    prepare_emit_request,
 };
 
 fn test_temp_dir(name: &str) -> PathBuf {
@@ -31,6 +32,8 @@ fn request(reference_policy: ReferencePolicy) -> EmitRequest {
         output: OutputPipelineOptions {
             strip_descriptions: false,
             minimize: false,
+            source_annotations: false,
+            dialect: SchemaDialect::Draft07,
         },
     }
 }
@@ -0,0 +1,75 @@
+use color_eyre::eyre;
+use helm_schema_core::{ContractProvenance, SourceSpan, ValueKind, YamlPath};
+use test_util::prelude::sim_assert_eq;
+use vfs::VfsPath;
+
+use super::*;
+
+fn use_at(source_expr: &str, template_path: &str, offset: usize, helpers: &[&str]) -> ContractUse {
+    ContractUse::with_provenances(
+        source_expr.to_string(),
+        YamlPath::default(),
+        ValueKind::Scalar,
+        Vec::new(),
+        None,
+        [ContractProvenance::new(
+            template_path,
+            SourceSpan::new(offset, offset + 1),
+            helpers.iter().map(ToString::to_string).collect(),
+        )],
+    )
+}
+
+#[test]
+fn annotates_properties_and_collection_members_with_resolved_locations() -> eyre::Result<()> {
+    let root = VfsPath::new(vfs::MemoryFS::new());
+    test_util::write(
+        &root.join("templates/cm.yaml")?,
+        "data:\n  a: {{ .Values.name }}\n  b: {{ .Values.ports }}\n",
+    )?;
+    let uses = [
+        use_at("name", "/templates/cm.yaml", 11, &[]),
+        use_at("ports.*", "/templates/cm.yaml", 35, &["chart.port"]),
+        use_at("name", "@tpl", 0, &[]),
+    ];
+    let sources = SchemaSources::collect(&uses, &mut SourceMap::new(root));
+    let mut schema = serde_json::json!({
+        "type": "object",
+        "properties": {
+            "name": { "type": "string" },
+            "ports": { "type": "array", "items": { "type": "integer" } },
+            "unused": { "type": "boolean" }
+        }
+    });
+
+    sources.annotate(&mut schema);
+
+    sim_assert_eq!(
+        have: schema,
+        want: serde_json::json!({
+            "type": "object",
+            "properties": {
+                "name": {
+                    "type": "string",
+                    "x-helm-schema-sources": [
+                        { "file": "templates/cm.yaml", "line": 2, "column": 6 }
+                    ]
+                },
+                "ports": {
+                    "type": "array",
+                    "items": {
+                        "type": "integer",
+                        "x-helm-schema-sources": [{
+                            "file": "templates/cm.yaml",
+                            "line": 3,
+                            "column": 6,
+                            "helper_chain": ["chart.port"]
+                        }]
+                    }
+                },
+                "unused": { "type": "boolean" }
+            }
+        })
+    );
+    Ok(())
+}
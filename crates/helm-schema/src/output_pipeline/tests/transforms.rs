@@ -4,7 +4,7 @@ use test_util::prelude::sim_assert_eq;
 
 use crate::output_pipeline::{
     EmitRequest, FinalOutputPolicy, OutputPipelineOptions, PreparedEmitRequest, ReferencePolicy,
-    apply_schema_output_pipeline,
+    SchemaDialect, apply_schema_output_pipeline,
 };
 
 fn output_policy() -> FinalOutputPolicy {
@@ -17,6 +17,8 @@ fn request(reference_policy: ReferencePolicy) -> PreparedEmitRequest {
         output: OutputPipelineOptions {
             strip_descriptions: false,
             minimize: false,
+            source_annotations: false,
+            dialect: SchemaDialect::Draft07,
         },
     })
 }
@@ -7,8 +7,11 @@ use crate::error::EngineResult;
 use crate::flatten;
 use crate::output_pipeline::annotation::{FinalOutputPolicy, annotate_final_schema};
 use crate::output_pipeline::descriptions::strip_schema_descriptions;
+use crate::output_pipeline::dialect::convert_schema_dialect;
 use crate::output_pipeline::reachability::{OwnedDefinitions, prune_unreachable_owned_definitions};
-use crate::output_pipeline::{OutputPipelineOptions, PreparedEmitRequest, ReferencePolicy};
+use crate::output_pipeline::{
+    OutputPipelineOptions, PreparedEmitRequest, ReferencePolicy, SchemaSources,
+};
 use crate::schema_override;
 
 /// Applies overrides, reference policy, and final minimization.
@@ -24,16 +27,19 @@ use crate::schema_override;
         reference_policy = ?prepared.request.reference_policy,
         strip_descriptions = prepared.request.output.strip_descriptions,
         minimize = prepared.request.output.minimize,
+        source_annotations = prepared.request.output.source_annotations,
+        dialect = prepared.request.output.dialect.annotation_name(),
     )
 )]
 pub(crate) fn apply_schema_output_pipeline(
     mut schema: Value,
-    prepared: PreparedEmitRequest,
+    mut prepared: PreparedEmitRequest,
     base_dir: &Path,
     policy: FinalOutputPolicy,
 ) -> EngineResult<Value> {
     let options = prepared.request.output;
     let reference_policy = prepared.request.reference_policy;
+    let sources = std::mem::take(&mut prepared.sources);
     let generated_definitions = OwnedDefinitions::capture(&schema);
     let override_identity = prepared.identity();
     for override_schema in prepared.into_prepared_override_schemas() {
@@ -41,9 +47,17 @@ pub(crate) fn apply_schema_output_pipeline(
     }
     let generated_definitions = generated_definitions.retain_unchanged(&schema);
 
-    schema = apply_output_transforms(schema, base_dir, reference_policy, options)?;
+    schema = apply_output_transforms(schema, base_dir, reference_policy, options, &sources)?;
     prune_unreachable_owned_definitions(&mut schema, &generated_definitions);
-    annotate_final_schema(schema, policy, &override_identity, reference_policy)
+    schema = annotate_final_schema(
+        schema,
+        policy,
+        &override_identity,
+        reference_policy,
+        options.dialect,
+    )?;
+    convert_schema_dialect(&mut schema, options.dialect);
+    Ok(schema)
 }
 
 #[tracing::instrument(
@@ -52,6 +66,7 @@ pub(crate) fn apply_schema_output_pipeline(
         reference_policy = ?reference_policy,
         strip_descriptions = options.strip_descriptions,
         minimize = options.minimize,
+        source_annotations = options.source_annotations,
     )
 )]
 fn apply_output_transforms(
@@ -59,6 +74,7 @@ fn apply_output_transforms(
     base_dir: &Path,
     reference_policy: ReferencePolicy,
     options: OutputPipelineOptions,
+    sources: &SchemaSources,
 ) -> EngineResult<Value> {
     match reference_policy {
         ReferencePolicy::SelfContained => schema = flatten::bundle_prepared_refs(schema, base_dir)?,
@@ -72,6 +88,10 @@ fn apply_output_transforms(
         strip_schema_descriptions(&mut schema);
     }
 
+    if options.source_annotations {
+        sources.annotate(&mut schema);
+    }
+
     if options.minimize {
         schema = minimize_schema(schema);
     }
@@ -1,13 +1,19 @@
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
+use helm_schema_core::{ResourceRef, YamlPath};
 use helm_schema_k8s::{
-    Chain, ChartLocalCrdSchemaProvider, CrdsCatalogSchemaProvider, DiagnosticSink,
+    CapabilityProfile, Chain, ChartLocalCrdSchemaProvider, ClusterOpenApiDump,
+    ClusterOpenApiSchemaProvider, CrdsCatalogSchemaProvider, DiagnosticSink, FieldSchemaOverrides,
     K8sSchemaProvider, K8sVersionChain, KubernetesJsonSchemaProvider, LayoutChecker,
-    LocalSchemaProvider, LocalSchemaUniverse, NegativeCache,
+    LocalSchemaProvider, LocalSchemaUniverse, NegativeCache, SchemaBundleRecorder,
 };
+use serde_json::Value;
 use tracing::instrument;
 
+use crate::error::{CliError, EngineResult};
+use crate::schema_bundle::SchemaBundle;
+
 /// Options consumed by `build_provider`. Comes from the CLI surface or
 /// from library callers.
 #[derive(Debug, Clone, Default)]
@@ -37,6 +43,9 @@ pub struct ProviderOptions {
     pub crd_catalog_cache_dir: Option<PathBuf>,
     /// Hand-maintained CRD override root.
     pub crd_override_dir: Option<PathBuf>,
+    /// Saved `/openapi/v3` discovery of the target cluster, consulted right
+    /// after the override root.
+    pub cluster_openapi: Option<Arc<ClusterOpenApiDump>>,
     /// Chart-local schema universe built from sources such as static `crds/`.
     pub local_schema_universe: LocalSchemaUniverse,
     /// Write `.meta` sidecars next to CRD cache entries.
@@ -44,6 +53,132 @@ pub struct ProviderOptions {
 
     /// Enable Feature D apiVersion inference.
     pub api_version_guess: bool,
+
+    /// Target-cluster `.Capabilities`: decides `APIVersions.Has` ahead of
+    /// the providers, and its `kubeVersion` replaces the primary K8s
+    /// version.
+    pub capability_profile: Option<Arc<CapabilityProfile>>,
+
+    /// Schemas merged onto the provider fragments of `(group, kind, path)`
+    /// fields, whichever provider resolves them.
+    pub field_schema_overrides: Option<Arc<FieldSchemaOverrides>>,
+
+    /// Unpacked schema bundle serving every `Kubernetes OpenAPI` and CRD
+    /// catalog lookup in place of the managed caches, with downloads off.
+    pub schema_bundle: Option<Arc<SchemaBundle>>,
+    /// Records the cache-backed documents the lookups consult, for
+    /// `bundle export`.
+    pub schema_bundle_recorder: Option<Arc<SchemaBundleRecorder>>,
+}
+
+impl ProviderOptions {
+    /// K8s version list after the capability profile's `kubeVersion`, if
+    /// any, took the primary slot.
+    pub(crate) fn effective_k8s_versions(&self) -> Vec<String> {
+        let mut versions = self.k8s_versions.clone();
+        let Some(kube_version) = self
+            .capability_profile
+            .as_deref()
+            .and_then(CapabilityProfile::kube_version)
+        else {
+            return versions;
+        };
+        let primary = if kube_version.starts_with('v') {
+            kube_version.to_string()
+        } else {
+            format!("v{kube_version}")
+        };
+        match versions.first_mut() {
+            Some(first) => *first = primary,
+            None => versions.push(primary),
+        }
+        versions
+    }
+}
+
+/// Read a `.Capabilities` profile (`kubeVersion` plus `apiVersions`) from a
+/// YAML or JSON file.
+///
+/// # Errors
+///
+/// Returns an error when the file cannot be read or is not a valid profile.
+pub fn load_capability_profile(path: &Path) -> EngineResult<CapabilityProfile> {
+    let source =
+        std::fs::read_to_string(path).map_err(|source| CliError::ReadCapabilityProfile {
+            path: path.to_path_buf(),
+            source,
+        })?;
+    serde_yaml::from_str(&source).map_err(|source| CliError::InvalidCapabilityProfile {
+        path: path.to_path_buf(),
+        source,
+    })
+}
+
+/// Read `(group, kind, path)` field schema overrides from a YAML or JSON
+/// file.
+///
+/// # Errors
+///
+/// Returns an error when the file cannot be read or is not a valid
+/// override list.
+pub fn load_field_schema_overrides(path: &Path) -> EngineResult<FieldSchemaOverrides> {
+    let source =
+        std::fs::read_to_string(path).map_err(|source| CliError::ReadFieldSchemaOverrides {
+            path: path.to_path_buf(),
+            source,
+        })?;
+    serde_yaml::from_str(&source).map_err(|source| CliError::InvalidFieldSchemaOverrides {
+        path: path.to_path_buf(),
+        source,
+    })
+}
+
+/// Load a cluster's `/openapi/v3` discovery saved beneath `dir`: the index
+/// and the per-group/version documents `kubectl get --raw` returns, as
+/// `.json` files in any layout.
+///
+/// # Errors
+///
+/// Returns an error when the directory cannot be walked or a document is not
+/// valid JSON.
+pub fn load_cluster_openapi(dir: &Path) -> EngineResult<ClusterOpenApiDump> {
+    let mut files = Vec::new();
+    collect_json_files(dir, &mut files)?;
+    files.sort();
+    let mut dump = ClusterOpenApiDump::new(dir.display().to_string());
+    for path in files {
+        let bytes = std::fs::read(&path).map_err(|source| CliError::ReadClusterOpenApi {
+            path: path.clone(),
+            source,
+        })?;
+        let document: Value =
+            serde_json::from_slice(&bytes).map_err(|source| CliError::InvalidClusterOpenApi {
+                path: path.clone(),
+                source,
+            })?;
+        let filename = path.strip_prefix(dir).unwrap_or(&path);
+        dump.insert_document(filename.display().to_string(), &document);
+    }
+    Ok(dump)
+}
+
+fn collect_json_files(dir: &Path, files: &mut Vec<PathBuf>) -> EngineResult<()> {
+    let read_error = |source| CliError::ReadClusterOpenApi {
+        path: dir.to_path_buf(),
+        source,
+    };
+    for entry in std::fs::read_dir(dir).map_err(read_error)? {
+        let path = entry.map_err(read_error)?.path();
+        if path.is_dir() {
+            collect_json_files(&path, files)?;
+        } else if path
+            .extension()
+            .is_some_and(|extension| extension == "json")
+        {
+            files.push(path);
+        }
+    }
+    Ok(())
 }
 
 #[instrument(skip_all)]
@@ -58,41 +193,60 @@ pub fn build_provider(opts: &ProviderOptions, diagnostic_sink: Option<&Diagnosti
         ));
     }
 
+    if let Some(dump) = &opts.cluster_openapi {
+        providers.push(Box::new(ClusterOpenApiSchemaProvider::new(Arc::clone(
+            dump,
+        ))));
+    }
+
     let chart_local_provider = ChartLocalCrdSchemaProvider::new(opts.local_schema_universe.clone())
         .with_api_version_guess(opts.api_version_guess);
     if !chart_local_provider.is_empty() {
         providers.push(Box::new(chart_local_provider));
     }
 
+    let allow_net = opts.allow_net && opts.schema_bundle.is_none();
     let mut crds_catalog = CrdsCatalogSchemaProvider::new()
-        .with_allow_download(opts.allow_net)
+        .with_allow_download(allow_net)
         .with_mirrors(opts.crd_catalog_mirrors.clone())
         .with_loose(opts.crd_lookup_loose)
         .with_api_version_guess(opts.api_version_guess)
         .with_negative_cache(Arc::clone(&negative_cache))
         .with_layout_checker(Arc::clone(&layout_checker))
         .with_record_source(opts.crd_cache_record_source);
-    if let Some(dir) = &opts.crd_catalog_cache_dir {
+    if let Some(bundle) = &opts.schema_bundle {
+        crds_catalog = crds_catalog.with_cache_dir(bundle.crd_catalog_cache_dir());
+    } else if let Some(dir) = &opts.crd_catalog_cache_dir {
         crds_catalog = crds_catalog.with_cache_dir(dir.clone());
     }
+    if let Some(recorder) = &opts.schema_bundle_recorder {
+        crds_catalog = crds_catalog.with_schema_bundle_recorder(Arc::clone(recorder));
+    }
     if let Some(sink) = diagnostic_sink {
         crds_catalog = crds_catalog.with_diagnostic_sink(sink.clone());
     }
     providers.push(Box::new(crds_catalog));
 
     if !opts.disable_k8s_schemas {
-        let versions =
-            K8sVersionChain::new(opts.k8s_versions.clone(), opts.k8s_version_fallback_window);
+        let versions = K8sVersionChain::new(
+            opts.effective_k8s_versions(),
+            opts.k8s_version_fallback_window,
+        );
         let mut k8s = KubernetesJsonSchemaProvider::with_versions(versions)
-            .with_allow_download(opts.allow_net)
-            .with_use_cache(!opts.no_cache)
+            .with_allow_download(allow_net)
+            .with_use_cache(!opts.no_cache || opts.schema_bundle.is_some())
             .with_mirrors(opts.k8s_schema_mirrors.clone())
             .with_api_version_guess(opts.api_version_guess)
             .with_negative_cache(Arc::clone(&negative_cache))
             .with_layout_checker(Arc::clone(&layout_checker));
-        if let Some(dir) = &opts.k8s_schema_cache_dir {
+        if let Some(bundle) = &opts.schema_bundle {
+            k8s = k8s.with_cache_dir(bundle.k8s_schema_cache_dir());
+        } else if let Some(dir) = &opts.k8s_schema_cache_dir {
             k8s = k8s.with_cache_dir(dir.clone());
         }
+        if let Some(recorder) = &opts.schema_bundle_recorder {
+            k8s = k8s.with_schema_bundle_recorder(Arc::clone(recorder));
+        }
         if let Some(sink) = diagnostic_sink {
             k8s = k8s.with_diagnostic_sink(sink.clone());
         }
@@ -100,8 +254,35 @@ pub fn build_provider(opts: &ProviderOptions, diagnostic_sink: Option<&Diagnosti
     }
 
     let mut chain = Chain::new(providers).with_inference_enabled(opts.api_version_guess);
+    if let Some(profile) = &opts.capability_profile {
+        chain = chain.with_capability_profile(Arc::clone(profile));
+    }
+    if let Some(overrides) = &opts.field_schema_overrides {
+        chain = chain.with_field_schema_overrides(Arc::clone(overrides));
+    }
     if let Some(sink) = diagnostic_sink {
         chain = chain.with_diagnostic_sink(sink.clone());
     }
     chain
 }
+
+/// Look up the whole schema of each resource through the provider chain, so
+/// the fetch-on-miss providers download and cache its document and every
+/// `$ref` target it reaches. Returns, per resource, whether a provider
+/// resolved it.
+#[must_use]
+pub fn prefetch_resource_schemas(
+    opts: &ProviderOptions,
+    resources: &[ResourceRef],
+    diagnostic_sink: Option<&DiagnosticSink>,
+) -> Vec<bool> {
+    let chain = build_provider(opts, diagnostic_sink);
+    resources
+        .iter()
+        .map(|resource| {
+            chain
+                .schema_fragment_for_resource_path(resource, &YamlPath(Vec::new()))
+                .is_some()
+        })
+        .collect()
+}
@@ -0,0 +1,123 @@
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use std::path::{Path, PathBuf};
+
+use helm_schema_k8s::{
+    CACHE_LAYOUT_VERSION, LAYOUT_MARKER_FILENAME, SCHEMA_BUNDLE_CRD_DIR, SCHEMA_BUNDLE_K8S_DIR,
+    SchemaBundleEntry, SchemaBundleRecorder,
+};
+
+use crate::error::{CliError, EngineResult};
+
+/// A schema bundle unpacked for one run.
+///
+/// The bundle holds the `Kubernetes OpenAPI` and CRD catalog cache trees
+/// restricted to the documents one chart consulted; the providers read them
+/// as their caches, with downloads disabled. The unpacked copy is removed
+/// when the bundle is dropped.
+#[derive(Debug)]
+pub struct SchemaBundle {
+    dir: tempfile::TempDir,
+}
+
+impl SchemaBundle {
+    /// `Kubernetes OpenAPI` cache root inside the bundle.
+    #[must_use]
+    pub fn k8s_schema_cache_dir(&self) -> PathBuf {
+        self.dir.path().join(SCHEMA_BUNDLE_K8S_DIR)
+    }
+
+    /// CRD catalog cache root inside the bundle.
+    #[must_use]
+    pub fn crd_catalog_cache_dir(&self) -> PathBuf {
+        self.dir.path().join(SCHEMA_BUNDLE_CRD_DIR)
+    }
+}
+
+/// Unpack a `tar.zst` schema bundle written by [`write_schema_bundle`].
+///
+/// # Errors
+///
+/// Returns an error when the file cannot be read, is not a zstd-compressed
+/// tar archive, or cannot be unpacked.
+pub fn load_schema_bundle(path: &Path) -> EngineResult<SchemaBundle> {
+    let read_error = |source| CliError::ReadSchemaBundle {
+        path: path.to_path_buf(),
+        source,
+    };
+    let dir = tempfile::Builder::new()
+        .prefix("helm-schema-bundle.")
+        .tempdir()
+        .map_err(read_error)?;
+    let decoder = zstd::Decoder::new(File::open(path).map_err(read_error)?).map_err(read_error)?;
+    tar::Archive::new(decoder)
+        .unpack(dir.path())
+        .map_err(read_error)?;
+    Ok(SchemaBundle { dir })
+}
+
+/// Write the documents `recorder` captured as a `tar.zst` schema bundle:
+/// both cache trees in their on-disk layout, each with its layout marker.
+/// Entries carry fixed metadata, so the same documents always produce the
+/// same archive.
+///
+/// Returns the number of schema documents written.
+///
+/// # Errors
+///
+/// Returns an error when the archive cannot be created or written.
+pub fn write_schema_bundle(recorder: &SchemaBundleRecorder, out: &Path) -> EngineResult<usize> {
+    let write_error = |source| CliError::WriteSchemaBundle {
+        path: out.to_path_buf(),
+        source,
+    };
+    if let Some(parent) = out.parent().filter(|parent| !parent.as_os_str().is_empty()) {
+        std::fs::create_dir_all(parent).map_err(write_error)?;
+    }
+    let file = File::create(out).map_err(write_error)?;
+    let encoder = zstd::Encoder::new(BufWriter::new(file), 0).map_err(write_error)?;
+    let mut builder = tar::Builder::new(encoder);
+
+    let marker = format!("{CACHE_LAYOUT_VERSION}\n");
+    for root in [SCHEMA_BUNDLE_K8S_DIR, SCHEMA_BUNDLE_CRD_DIR] {
+        append_file(
+            &mut builder,
+            &format!("{root}/{LAYOUT_MARKER_FILENAME}"),
+            marker.as_bytes(),
+        )
+        .map_err(write_error)?;
+    }
+    let mut documents = 0;
+    for (path, entry) in recorder.entries() {
+        match entry {
+            SchemaBundleEntry::Document(schema) => {
+                let bytes = serde_json::to_vec(schema.as_ref())?;
+                append_file(&mut builder, &path, &bytes).map_err(write_error)?;
+                documents += 1;
+            }
+            SchemaBundleEntry::NotFound => {
+                append_file(&mut builder, &format!("{path}.not-found"), b"not-found\n")
+                    .map_err(write_error)?;
+            }
+        }
+    }
+
+    let encoder = builder.into_inner().map_err(write_error)?;
+    encoder
+        .finish()
+        .and_then(|mut out| out.flush())
+        .map_err(write_error)?;
+    Ok(documents)
+}
+
+fn append_file<W: Write>(
+    builder: &mut tar::Builder<W>,
+    path: &str,
+    contents: &[u8],
+) -> io::Result<()> {
+    let mut header = tar::Header::new_gnu();
+    header.set_size(contents.len() as u64);
+    header.set_mode(0o644);
+    header.set_mtime(0);
+    builder.append_data(&mut header, path, contents)
+}
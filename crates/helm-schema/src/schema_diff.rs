@@ -0,0 +1,909 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use helm_schema_core::{Guard, append_value_path};
+use helm_schema_ir::ContractUse;
+use serde::Serialize;
+use serde_json::Value;
+
+/// Maximum `$ref` hops, composition levels, or nested values paths followed
+/// while comparing one schema node.
+const MAX_REF_DEPTH: usize = 32;
+
+/// Numeric validation keywords, the JSON type each one bounds, and which side
+/// of the range it closes.
+const BOUND_KEYWORDS: [(&str, &str, BoundSide); 10] = [
+    ("minLength", "string", BoundSide::Lower),
+    ("maxLength", "string", BoundSide::Upper),
+    ("minimum", "number", BoundSide::Lower),
+    ("exclusiveMinimum", "number", BoundSide::Lower),
+    ("maximum", "number", BoundSide::Upper),
+    ("exclusiveMaximum", "number", BoundSide::Upper),
+    ("minItems", "array", BoundSide::Lower),
+    ("maxItems", "array", BoundSide::Upper),
+    ("minProperties", "object", BoundSide::Lower),
+    ("maxProperties", "object", BoundSide::Upper),
+];
+
+/// Keywords that document a schema without changing what it accepts.
+const ANNOTATION_KEYWORDS: [&str; 4] = ["$comment", "description", "errorMessage", "title"];
+
+/// Policy fingerprint stamped into a generated schema's
+/// `x-helm-schema-policy` annotation, if any.
+#[must_use]
+pub fn policy_fingerprint(schema: &Value) -> Option<&str> {
+    schema
+        .get("x-helm-schema-policy")?
+        .get("policy-fingerprint")?
+        .as_str()
+}
+
+/// Version of helm-schema that generated a schema, as stamped into its
+/// `x-helm-schema-policy` annotation, if any.
+#[must_use]
+pub fn generator_version(schema: &Value) -> Option<&str> {
+    schema
+        .get("x-helm-schema-policy")?
+        .get("generator-version")?
+        .as_str()
+}
+
+/// One semantic difference between two values schemas at a values path.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct SchemaChange {
+    /// Dotted values path; `*` stands for every collection member.
+    pub path: String,
+    /// Whether the change sits in an `if`/`then`/`else` branch, so it only
+    /// applies to values that meet the branch's condition.
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub conditional: bool,
+    /// What changed at the path.
+    #[serde(flatten)]
+    pub kind: SchemaChangeKind,
+}
+
+/// Classification of a [`SchemaChange`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "change", rename_all = "snake_case")]
+pub enum SchemaChangeKind {
+    /// The new schema declares a property the old one did not.
+    PropertyAdded,
+    /// The old schema declared a property the new one does not.
+    PropertyRemoved,
+    /// The new schema accepts every type the old one did, and more.
+    TypeWidened {
+        /// Types accepted before; empty means any type.
+        from: Vec<String>,
+        /// Types accepted now; empty means any type.
+        to: Vec<String>,
+    },
+    /// The new schema rejects some type the old one accepted.
+    TypeNarrowed {
+        /// Types accepted before; empty means any type.
+        from: Vec<String>,
+        /// Types accepted now; empty means any type.
+        to: Vec<String>,
+    },
+    /// The property became required.
+    RequiredAdded,
+    /// The property is no longer required.
+    RequiredRemoved,
+    /// The new schema drops allowed literal values (or introduces an enum).
+    EnumShrunk {
+        /// Literals accepted before and rejected now; empty when the enum
+        /// was introduced.
+        removed: Vec<Value>,
+    },
+    /// The new schema accepts more literal values (or drops the enum).
+    EnumGrown {
+        /// Literals accepted now and rejected before; empty when the enum
+        /// was removed entirely.
+        added: Vec<Value>,
+    },
+    /// A `pattern`, length, size, or numeric bound appeared or tightened.
+    ConstraintNarrowed {
+        /// Validation keyword, such as `maxLength` or `pattern`.
+        keyword: String,
+        /// Effective bound before; `None` when the keyword did not apply.
+        from: Option<Value>,
+        /// Effective bound now; `None` when the keyword no longer applies.
+        to: Option<Value>,
+    },
+    /// A `pattern`, length, size, or numeric bound disappeared or loosened.
+    ConstraintWidened {
+        /// Validation keyword, such as `maxLength` or `pattern`.
+        keyword: String,
+        /// Effective bound before; `None` when the keyword did not apply.
+        from: Option<Value>,
+        /// Effective bound now; `None` when the keyword no longer applies.
+        to: Option<Value>,
+    },
+    /// The new schema adds an `if`/`then`/`else` overlay with a condition
+    /// the old one did not check.
+    ConditionalAdded,
+    /// The old schema's `if`/`then`/`else` overlay with this condition is
+    /// gone.
+    ConditionalRemoved,
+    /// Templates that read the path unconditionally now read it only under
+    /// guards, so an override of it can silently stop taking effect.
+    GuardAdded {
+        /// Guards the new chart reads the path under.
+        guards: Vec<Guard>,
+    },
+}
+
+impl SchemaChangeKind {
+    /// Whether the change can break a values override that worked before:
+    /// the new schema rejects it, or the new templates may ignore it.
+    ///
+    /// Acceptance is ordered the way schema profiles are: dropping a
+    /// constraint, a required key, or a conditional overlay only widens it,
+    /// and adding or tightening one narrows it. A reduced profile may only
+    /// widen the full one, so comparing a chart's full output with its lean
+    /// output never reports a breaking change.
+    #[must_use]
+    pub fn is_breaking(&self) -> bool {
+        match self {
+            Self::PropertyRemoved
+            | Self::TypeNarrowed { .. }
+            | Self::RequiredAdded
+            | Self::EnumShrunk { .. }
+            | Self::ConstraintNarrowed { .. }
+            | Self::ConditionalAdded
+            | Self::GuardAdded { .. } => true,
+            Self::PropertyAdded
+            | Self::TypeWidened { .. }
+            | Self::RequiredRemoved
+            | Self::EnumGrown { .. }
+            | Self::ConstraintWidened { .. }
+            | Self::ConditionalRemoved => false,
+        }
+    }
+}
+
+/// Compares the values trees of two schemas property by property.
+///
+/// Each values path is compared as everything that applies there: local
+/// `$ref`s are resolved so interned (`--minimize`) and inline output compare
+/// equal, `allOf` arms add to the node, and `anyOf`/`oneOf` arms contribute
+/// their union. `if`/`then`/`else` overlays are paired by condition; the
+/// branches of a paired overlay are compared like any other node, and an
+/// unpaired overlay is reported as added or removed.
+#[must_use]
+pub fn diff_schemas(old: &Value, new: &Value) -> Vec<SchemaChange> {
+    let mut differ = Differ::default();
+    differ.diff_views(&View::of(old, old), &View::of(new, new), "", false, 0);
+    let mut changes = differ.changes;
+    changes.sort_by(|left, right| left.path.cmp(&right.path));
+    changes
+}
+
+/// Reports values paths that `old` templates read unconditionally and `new`
+/// templates still read, but only under guards.
+///
+/// Only uses with template provenance count as reads; synthetic rows (for
+/// example the ones seeded from `values.yaml` roots) carry no guards.
+#[must_use]
+pub fn diff_guards(old: &[ContractUse], new: &[ContractUse]) -> Vec<SchemaChange> {
+    let always_read: BTreeSet<&str> = old
+        .iter()
+        .filter(|use_| !use_.provenance.is_empty() && use_.condition.is_unconditional())
+        .map(|use_| use_.source_expr.as_str())
+        .collect();
+    let mut guarded: BTreeMap<&str, Option<BTreeSet<Guard>>> = BTreeMap::new();
+    for use_ in new {
+        if use_.provenance.is_empty() || !always_read.contains(use_.source_expr.as_str()) {
+            continue;
+        }
+        let entry = guarded
+            .entry(use_.source_expr.as_str())
+            .or_insert_with(|| Some(BTreeSet::new()));
+        if use_.condition.is_unconditional() {
+            *entry = None;
+        } else if let Some(guards) = entry {
+            guards.extend(use_.condition.guard_conjunctions().into_iter().flatten());
+        }
+    }
+    guarded
+        .into_iter()
+        .filter_map(|(path, guards)| {
+            Some(SchemaChange {
+                path: path.to_string(),
+                conditional: false,
+                kind: SchemaChangeKind::GuardAdded {
+                    guards: guards?.into_iter().collect(),
+                },
+            })
+        })
+        .collect()
+}
+
+#[derive(Default)]
+struct Differ {
+    changes: Vec<SchemaChange>,
+}
+
+impl Differ {
+    fn record(&mut self, path: &str, conditional: bool, kind: SchemaChangeKind) {
+        self.changes.push(SchemaChange {
+            path: path.to_string(),
+            conditional,
+            kind,
+        });
+    }
+
+    fn diff_views(
+        &mut self,
+        old: &View<'_>,
+        new: &View<'_>,
+        path: &str,
+        conditional: bool,
+        depth: usize,
+    ) {
+        if depth > MAX_REF_DEPTH {
+            return;
+        }
+
+        self.diff_accepted_values(old, new, path, conditional);
+        self.diff_constraints(old, new, path, conditional);
+
+        let (old_required, new_required) = (old.required(), new.required());
+        let (old_properties, new_properties) = (old.properties(), new.properties());
+        let names: BTreeSet<&str> = old_properties
+            .keys()
+            .chain(new_properties.keys())
+            .chain(old_required.iter())
+            .chain(new_required.iter())
+            .copied()
+            .collect();
+        for name in names {
+            let child_path = append_value_path(path, name);
+            match (old_properties.get(name), new_properties.get(name)) {
+                (Some(old_child), Some(new_child)) => {
+                    self.diff_views(old_child, new_child, &child_path, conditional, depth + 1);
+                }
+                (Some(_), None) => {
+                    self.record(&child_path, conditional, SchemaChangeKind::PropertyRemoved);
+                }
+                (None, Some(_)) => {
+                    self.record(&child_path, conditional, SchemaChangeKind::PropertyAdded);
+                }
+                (None, None) => {}
+            }
+            match (old_required.contains(name), new_required.contains(name)) {
+                (false, true) => {
+                    self.record(&child_path, conditional, SchemaChangeKind::RequiredAdded);
+                }
+                (true, false) => {
+                    self.record(&child_path, conditional, SchemaChangeKind::RequiredRemoved);
+                }
+                _ => {}
+            }
+        }
+
+        for (keyword, family) in [("items", "array"), ("additionalProperties", "object")] {
+            let (old_member, new_member) =
+                (old.member(keyword, family), new.member(keyword, family));
+            if old_member.is_none() && new_member.is_none() {
+                continue;
+            }
+            let member_path = append_value_path(path, "*");
+            self.diff_views(
+                &old_member.unwrap_or_else(|| View::any(old.root)),
+                &new_member.unwrap_or_else(|| View::any(new.root)),
+                &member_path,
+                conditional,
+                depth + 1,
+            );
+        }
+
+        self.diff_conditionals(old, new, path, conditional, depth);
+    }
+
+    /// Types and literals the position accepts.
+    fn diff_accepted_values(
+        &mut self,
+        old: &View<'_>,
+        new: &View<'_>,
+        path: &str,
+        conditional: bool,
+    ) {
+        let (old_types, new_types) = (old.types(), new.types());
+        if old_types != new_types {
+            let from = type_names(old_types.as_ref());
+            let to = type_names(new_types.as_ref());
+            let kind = if accepts_all(new_types.as_ref(), old_types.as_ref()) {
+                SchemaChangeKind::TypeWidened { from, to }
+            } else {
+                SchemaChangeKind::TypeNarrowed { from, to }
+            };
+            self.record(path, conditional, kind);
+        }
+
+        match (old.enum_values(), new.enum_values()) {
+            (Some(old_enum), Some(new_enum)) => {
+                let removed: Vec<Value> = old_enum
+                    .iter()
+                    .filter(|value| !new_enum.contains(value))
+                    .cloned()
+                    .collect();
+                let added: Vec<Value> = new_enum
+                    .iter()
+                    .filter(|value| !old_enum.contains(value))
+                    .cloned()
+                    .collect();
+                if !removed.is_empty() {
+                    self.record(path, conditional, SchemaChangeKind::EnumShrunk { removed });
+                }
+                if !added.is_empty() {
+                    self.record(path, conditional, SchemaChangeKind::EnumGrown { added });
+                }
+            }
+            (None, Some(_)) => self.record(
+                path,
+                conditional,
+                SchemaChangeKind::EnumShrunk {
+                    removed: Vec::new(),
+                },
+            ),
+            (Some(_), None) => self.record(
+                path,
+                conditional,
+                SchemaChangeKind::EnumGrown { added: Vec::new() },
+            ),
+            (None, None) => {}
+        }
+    }
+
+    /// `pattern`s and the length, size, and numeric bounds of
+    /// [`BOUND_KEYWORDS`].
+    fn diff_constraints(&mut self, old: &View<'_>, new: &View<'_>, path: &str, conditional: bool) {
+        for (keyword, family, side) in BOUND_KEYWORDS {
+            let from = old.bound(keyword, family, side);
+            let to = new.bound(keyword, family, side);
+            let narrowed = match (from, to) {
+                (None, Some(_)) => true,
+                (Some(_), None) => false,
+                (Some(from), Some(to)) if side.is_tighter(from.0, to.0) => true,
+                (Some(from), Some(to)) if side.is_tighter(to.0, from.0) => false,
+                _ => continue,
+            };
+            let (from, to) = (
+                from.map(|(_, bound)| bound.clone()),
+                to.map(|(_, bound)| bound.clone()),
+            );
+            self.record(
+                path,
+                conditional,
+                constraint_change(keyword, narrowed, from, to),
+            );
+        }
+
+        let (old_patterns, new_patterns) = (old.patterns(), new.patterns());
+        if old_patterns != new_patterns {
+            let narrowed = match (&old_patterns, &new_patterns) {
+                (Some(old_patterns), Some(new_patterns)) => !old_patterns.iter().all(|old_all| {
+                    new_patterns
+                        .iter()
+                        .any(|new_all| new_all.is_subset(old_all))
+                }),
+                (None, _) => true,
+                (_, None) => false,
+            };
+            let from = old_patterns.as_ref().map(pattern_value);
+            let to = new_patterns.as_ref().map(pattern_value);
+            self.record(
+                path,
+                conditional,
+                constraint_change("pattern", narrowed, from, to),
+            );
+        }
+    }
+
+    /// Pairs overlays whose conditions accept the same documents and
+    /// compares their branches; the rest were added or removed.
+    fn diff_conditionals(
+        &mut self,
+        old: &View<'_>,
+        new: &View<'_>,
+        path: &str,
+        conditional: bool,
+        depth: usize,
+    ) {
+        let mut unpaired: Vec<&Value> = old.conditionals().collect();
+        for new_overlay in new.conditionals() {
+            let paired = unpaired.iter().position(|old_overlay| {
+                same_branch_shapes(old_overlay, new_overlay)
+                    && same_schema(
+                        old.root,
+                        &old_overlay["if"],
+                        new.root,
+                        &new_overlay["if"],
+                        0,
+                    )
+            });
+            let Some(index) = paired else {
+                self.record(path, conditional, SchemaChangeKind::ConditionalAdded);
+                continue;
+            };
+            let old_overlay = unpaired.remove(index);
+            for branch in ["then", "else"] {
+                if let (Some(old_branch), Some(new_branch)) = (
+                    old_overlay.get(branch).filter(|branch| branch.is_object()),
+                    new_overlay.get(branch).filter(|branch| branch.is_object()),
+                ) {
+                    self.diff_views(
+                        &View::of(old.root, old_branch),
+                        &View::of(new.root, new_branch),
+                        path,
+                        true,
+                        depth + 1,
+                    );
+                }
+            }
+        }
+        for _ in unpaired {
+            self.record(path, conditional, SchemaChangeKind::ConditionalRemoved);
+        }
+    }
+}
+
+/// Every subschema that applies at one position of a schema.
+///
+/// All of `nodes` apply at once, with `allOf` arms flattened in. Each entry
+/// of `alternatives` is one `anyOf`/`oneOf`, of which a value has to satisfy
+/// at least one arm. A view without either accepts anything.
+#[derive(Clone)]
+struct View<'a> {
+    root: &'a Value,
+    nodes: Vec<&'a Value>,
+    alternatives: Vec<Vec<View<'a>>>,
+}
+
+impl<'a> View<'a> {
+    fn any(root: &'a Value) -> Self {
+        Self {
+            root,
+            nodes: Vec::new(),
+            alternatives: Vec::new(),
+        }
+    }
+
+    fn of(root: &'a Value, node: &'a Value) -> Self {
+        let mut view = Self::any(root);
+        view.add(node, 0);
+        view
+    }
+
+    fn add(&mut self, node: &'a Value, depth: usize) {
+        if depth > MAX_REF_DEPTH {
+            return;
+        }
+        let node = resolve(self.root, node);
+        self.nodes.push(node);
+        for arm in node
+            .get("allOf")
+            .and_then(Value::as_array)
+            .into_iter()
+            .flatten()
+        {
+            self.add(arm, depth + 1);
+        }
+        for keyword in ["anyOf", "oneOf"] {
+            if let Some(arms) = node.get(keyword).and_then(Value::as_array) {
+                let arms = arms
+                    .iter()
+                    .map(|arm| {
+                        let mut view = Self::any(self.root);
+                        view.add(arm, depth + 1);
+                        view
+                    })
+                    .collect();
+                self.alternatives.push(arms);
+            }
+        }
+    }
+
+    /// Accepted types; `None` when the position accepts any type.
+    fn types(&self) -> Option<BTreeSet<String>> {
+        let own = self.nodes.iter().map(|node| own_types(node));
+        let alternatives = self.alternatives.iter().map(|arms| {
+            arms.iter()
+                .map(View::types)
+                .try_fold(BTreeSet::new(), |mut union, types| {
+                    union.extend(types?);
+                    Some(union)
+                })
+        });
+        own.chain(alternatives).fold(None, intersect_types)
+    }
+
+    /// Accepted literals; `None` when the position is not an enum.
+    fn enum_values(&self) -> Option<Vec<Value>> {
+        let own = self.nodes.iter().map(|node| own_enum(node));
+        let alternatives = self.alternatives.iter().map(|arms| {
+            arms.iter()
+                .map(View::enum_values)
+                .try_fold(Vec::new(), |mut union, values| {
+                    for value in values? {
+                        if !union.contains(&value) {
+                            union.push(value);
+                        }
+                    }
+                    Some(union)
+                })
+        });
+        own.chain(alternatives)
+            .fold(None, |accepted, values| match (accepted, values) {
+                (Some(accepted), Some(values)) => Some(
+                    accepted
+                        .into_iter()
+                        .filter(|value| values.contains(value))
+                        .collect(),
+                ),
+                (accepted, values) => accepted.or(values),
+            })
+    }
+
+    /// Property names every accepted object has to carry.
+    fn required(&self) -> BTreeSet<&'a str> {
+        let mut required: BTreeSet<&str> = self
+            .nodes
+            .iter()
+            .filter_map(|node| node.get("required").and_then(Value::as_array))
+            .flatten()
+            .filter_map(Value::as_str)
+            .collect();
+        for arms in &self.alternatives {
+            let common = arms
+                .iter()
+                .filter(|arm| accepts(arm.types().as_ref(), "object"))
+                .map(View::required)
+                .reduce(|common, arm| common.intersection(&arm).copied().collect());
+            required.extend(common.into_iter().flatten());
+        }
+        required
+    }
+
+    /// Declared properties by name; a property declared by only some object
+    /// arms of an alternative accepts anything through the others.
+    fn properties(&self) -> BTreeMap<&'a str, View<'a>> {
+        let mut children: BTreeMap<&str, View<'a>> = BTreeMap::new();
+        for node in &self.nodes {
+            let declared = node.get("properties").and_then(Value::as_object);
+            for (name, schema) in declared.into_iter().flatten() {
+                children
+                    .entry(name.as_str())
+                    .or_insert_with(|| Self::any(self.root))
+                    .add(schema, 0);
+            }
+        }
+        for arms in &self.alternatives {
+            let arms: Vec<_> = arms
+                .iter()
+                .filter(|arm| accepts(arm.types().as_ref(), "object"))
+                .map(View::properties)
+                .collect();
+            let names: BTreeSet<&str> = arms.iter().flat_map(BTreeMap::keys).copied().collect();
+            for name in names {
+                let alternative = arms
+                    .iter()
+                    .map(|properties| {
+                        properties
+                            .get(name)
+                            .cloned()
+                            .unwrap_or_else(|| Self::any(self.root))
+                    })
+                    .collect();
+                children
+                    .entry(name)
+                    .or_insert_with(|| Self::any(self.root))
+                    .alternatives
+                    .push(alternative);
+            }
+        }
+        children
+    }
+
+    /// Collection members (`items` or `additionalProperties`); `None` when
+    /// no node constrains them.
+    fn member(&self, keyword: &str, family: &str) -> Option<View<'a>> {
+        let mut member: Option<View<'a>> = None;
+        for node in &self.nodes {
+            if let Some(schema) = node.get(keyword).filter(|schema| schema.is_object()) {
+                member
+                    .get_or_insert_with(|| Self::any(self.root))
+                    .add(schema, 0);
+            }
+        }
+        for arms in &self.alternatives {
+            let arms: Vec<_> = arms
+                .iter()
+                .filter(|arm| accepts(arm.types().as_ref(), family))
+                .map(|arm| arm.member(keyword, family))
+                .collect();
+            if arms.iter().all(Option::is_none) {
+                continue;
+            }
+            let alternative = arms
+                .into_iter()
+                .map(|arm| arm.unwrap_or_else(|| Self::any(self.root)))
+                .collect();
+            member
+                .get_or_insert_with(|| Self::any(self.root))
+                .alternatives
+                .push(alternative);
+        }
+        member
+    }
+
+    /// Effective `keyword` bound on values of type `family`, as its number
+    /// and the literal that declared it; `None` when unbounded.
+    fn bound(&self, keyword: &str, family: &str, side: BoundSide) -> Option<(f64, &'a Value)> {
+        let own = self.nodes.iter().map(|node| {
+            let bound = node.get(keyword)?;
+            Some((bound.as_f64()?, bound))
+        });
+        let alternatives = self.alternatives.iter().map(|arms| {
+            arms.iter()
+                .filter(|arm| accepts(arm.types().as_ref(), family))
+                .map(|arm| arm.bound(keyword, family, side))
+                .collect::<Option<Vec<_>>>()?
+                .into_iter()
+                .reduce(|left, right| side.loosest(left, right))
+        });
+        own.chain(alternatives)
+            .flatten()
+            .reduce(|left, right| side.tightest(left, right))
+    }
+
+    /// `pattern`s an accepted string has to match, as alternative sets that
+    /// each have to match in full; `None` when strings are unconstrained.
+    fn patterns(&self) -> Option<BTreeSet<BTreeSet<&'a str>>> {
+        let own: BTreeSet<&str> = self
+            .nodes
+            .iter()
+            .filter_map(|node| node.get("pattern").and_then(Value::as_str))
+            .collect();
+        let mut patterns = (!own.is_empty()).then(|| BTreeSet::from([own]));
+        for arms in &self.alternatives {
+            let arms: Vec<_> = arms
+                .iter()
+                .filter(|arm| accepts(arm.types().as_ref(), "string"))
+                .collect();
+            if arms.is_empty() {
+                continue;
+            }
+            let Some(alternative) = arms
+                .iter()
+                .map(|arm| arm.patterns())
+                .collect::<Option<Vec<_>>>()
+            else {
+                continue;
+            };
+            let alternative: BTreeSet<BTreeSet<&str>> = alternative.into_iter().flatten().collect();
+            patterns = Some(match patterns {
+                None => alternative,
+                Some(current) => current
+                    .iter()
+                    .flat_map(|left| {
+                        alternative
+                            .iter()
+                            .map(move |right| left.union(right).copied().collect())
+                    })
+                    .collect(),
+            });
+        }
+        patterns
+    }
+
+    /// `if`/`then`/`else` overlays that apply at this position.
+    fn conditionals(&self) -> impl Iterator<Item = &'a Value> + '_ {
+        self.nodes
+            .iter()
+            .copied()
+            .filter(|node| node.get("if").is_some())
+    }
+}
+
+/// The end of a range a bound keyword closes.
+#[derive(Debug, Clone, Copy)]
+enum BoundSide {
+    Lower,
+    Upper,
+}
+
+impl BoundSide {
+    /// Whether `to` rejects values `from` accepted.
+    fn is_tighter(self, from: f64, to: f64) -> bool {
+        match self {
+            Self::Lower => to > from,
+            Self::Upper => to < from,
+        }
+    }
+
+    fn tightest<T>(self, left: (f64, T), right: (f64, T)) -> (f64, T) {
+        if self.is_tighter(left.0, right.0) {
+            right
+        } else {
+            left
+        }
+    }
+
+    fn loosest<T>(self, left: (f64, T), right: (f64, T)) -> (f64, T) {
+        if self.is_tighter(left.0, right.0) {
+            left
+        } else {
+            right
+        }
+    }
+}
+
+fn constraint_change(
+    keyword: &str,
+    narrowed: bool,
+    from: Option<Value>,
+    to: Option<Value>,
+) -> SchemaChangeKind {
+    let keyword = keyword.to_string();
+    if narrowed {
+        SchemaChangeKind::ConstraintNarrowed { keyword, from, to }
+    } else {
+        SchemaChangeKind::ConstraintWidened { keyword, from, to }
+    }
+}
+
+/// A single pattern as itself, and alternatives as a list whose entries are
+/// a pattern or the list of patterns that all have to match.
+fn pattern_value(patterns: &BTreeSet<BTreeSet<&str>>) -> Value {
+    let mut alternatives: Vec<Value> = patterns
+        .iter()
+        .map(|all| match all.iter().collect::<Vec<_>>().as_slice() {
+            [pattern] => Value::from(**pattern),
+            all => Value::from(all.iter().map(|pattern| **pattern).collect::<Vec<_>>()),
+        })
+        .collect();
+    if alternatives.len() == 1 {
+        alternatives.remove(0)
+    } else {
+        Value::Array(alternatives)
+    }
+}
+
+/// Whether two overlays branch the same way: the same branches exist, and
+/// Boolean branches agree.
+fn same_branch_shapes(old: &Value, new: &Value) -> bool {
+    ["then", "else"]
+        .into_iter()
+        .all(|branch| match (old.get(branch), new.get(branch)) {
+            (Some(old), Some(new)) => old.as_bool() == new.as_bool(),
+            (old, new) => old.is_none() && new.is_none(),
+        })
+}
+
+/// Structural equality after resolving local `$ref`s on both sides and
+/// ignoring annotation keywords.
+fn same_schema(old_root: &Value, old: &Value, new_root: &Value, new: &Value, depth: usize) -> bool {
+    if depth > MAX_REF_DEPTH {
+        return false;
+    }
+    match (resolve(old_root, old), resolve(new_root, new)) {
+        (Value::Object(old), Value::Object(new)) => {
+            let keyword_count = |schema: &'_ serde_json::Map<String, Value>| {
+                schema
+                    .keys()
+                    .filter(|keyword| !ANNOTATION_KEYWORDS.contains(&keyword.as_str()))
+                    .count()
+            };
+            keyword_count(old) == keyword_count(new)
+                && old
+                    .iter()
+                    .filter(|(keyword, _)| !ANNOTATION_KEYWORDS.contains(&keyword.as_str()))
+                    .all(|(keyword, old)| {
+                        new.get(keyword)
+                            .is_some_and(|new| same_schema(old_root, old, new_root, new, depth + 1))
+                    })
+        }
+        (Value::Array(old), Value::Array(new)) => {
+            old.len() == new.len()
+                && old
+                    .iter()
+                    .zip(new)
+                    .all(|(old, new)| same_schema(old_root, old, new_root, new, depth + 1))
+        }
+        (old, new) => old == new,
+    }
+}
+
+fn resolve<'a>(root: &'a Value, mut node: &'a Value) -> &'a Value {
+    for _ in 0..MAX_REF_DEPTH {
+        let Some(target) = node
+            .get("$ref")
+            .and_then(Value::as_str)
+            .and_then(|reference| reference.strip_prefix('#'))
+            .and_then(|pointer| root.pointer(pointer))
+        else {
+            break;
+        };
+        node = target;
+    }
+    node
+}
+
+fn own_enum(node: &Value) -> Option<Vec<Value>> {
+    if let Some(constant) = node.get("const") {
+        return Some(vec![constant.clone()]);
+    }
+    node.get("enum").and_then(Value::as_array).cloned()
+}
+
+/// Declared `type` set of one node; `None` when it accepts any type, empty
+/// for the `false` schema.
+fn own_types(node: &Value) -> Option<BTreeSet<String>> {
+    match node {
+        Value::Bool(false) => return Some(BTreeSet::new()),
+        Value::Object(_) => {}
+        _ => return None,
+    }
+    match node.get("type")? {
+        Value::String(ty) => Some(BTreeSet::from([ty.clone()])),
+        Value::Array(types) => Some(
+            types
+                .iter()
+                .filter_map(Value::as_str)
+                .map(str::to_string)
+                .collect(),
+        ),
+        _ => None,
+    }
+}
+
+/// Types both sets accept, counting `integer` as a subset of `number`.
+fn intersect_types(
+    left: Option<BTreeSet<String>>,
+    right: Option<BTreeSet<String>>,
+) -> Option<BTreeSet<String>> {
+    let (Some(left), Some(right)) = (&left, &right) else {
+        return left.or(right);
+    };
+    let within = |ty: &String, types: &BTreeSet<String>| {
+        types.contains(ty) || (ty == "integer" && types.contains("number"))
+    };
+    Some(
+        left.iter()
+            .filter(|ty| within(ty, right))
+            .chain(right.iter().filter(|ty| within(ty, left)))
+            .cloned()
+            .collect(),
+    )
+}
+
+/// Whether a position with `types` accepts some values of type `family`.
+fn accepts(types: Option<&BTreeSet<String>>, family: &str) -> bool {
+    types.is_none_or(|types| {
+        types.contains(family) || (family == "number" && types.contains("integer"))
+    })
+}
+
+fn type_names(types: Option<&BTreeSet<String>>) -> Vec<String> {
+    types
+        .map(|types| types.iter().cloned().collect())
+        .unwrap_or_default()
+}
+
+/// Whether `wider` accepts every type `narrower` accepts, counting
+/// `integer` as a subset of `number`.
+fn accepts_all(wider: Option<&BTreeSet<String>>, narrower: Option<&BTreeSet<String>>) -> bool {
+    let Some(wider) = wider else {
+        return true;
+    };
+    let Some(narrower) = narrower else {
+        return false;
+    };
+    narrower
+        .iter()
+        .all(|ty| wider.contains(ty) || (ty == "integer" && wider.contains("number")))
+}
+
+#[cfg(test)]
+#[path = "tests/schema_diff.rs"]
+mod tests;
@@ -5,21 +5,30 @@ use std::sync::{Arc, Mutex};
 use helm_schema_core::{
     ConditionalGuard, ContractSchemaSignals, ContractUse, ContractValuePathFacts, MetadataFieldKind,
 };
-use helm_schema_gen::{ValuesSchemaInput, generate_values_schema_with_report};
+use helm_schema_gen::{
+    ValuesSchemaInput, ValuesTypeModel, generate_values_schema_with_report,
+    generate_values_type_model,
+};
 use helm_schema_ir::{ContractDocument, ContractIr, FinalizedContract};
 use helm_schema_k8s::{Diagnostic, DiagnosticSink, LocalSchemaUniverse};
+use serde::Serialize;
 use serde_json::Value;
 
-use crate::analysis::analyze_charts;
-use crate::chart;
+use crate::analysis::{AnalysisCache, analyze_charts_cached, resource_definition_guards};
+use crate::chart::{self, ChartRepositoryCache};
 use crate::error::EngineResult;
 use crate::generation::{GenerateOptions, GeneratedSchema, ResolvedContract};
+use crate::load_budget::LoadBudget;
 use crate::output_pipeline::{
-    EmitRequest, FinalOutputPolicy, PolicyInputOptions, PreparedEmitRequest,
+    EmitRequest, FinalOutputPolicy, PolicyInputOptions, PreparedEmitRequest, SchemaSources,
     apply_schema_output_pipeline, load_emit_request, prepare_emit_request,
 };
 use crate::provider_builder;
+use crate::schema_diff::{self, SchemaChange};
+use crate::source_map::SourceMap;
+use crate::values_reference::{self, ValuesReferenceEntry};
 use crate::values_roots;
+use crate::values_validation::{self, UnknownValuesKey, ValuesDocument, ValuesViolation};
 
 /// Public analysis artifact produced by [`AnalysisSession`].
 ///
@@ -37,7 +46,7 @@ pub struct Analysis {
 }
 
 /// Session-level explanation for one values path.
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct ValuePathExplanation {
     /// Canonical values path described by the explanation.
     pub path: String,
@@ -68,8 +77,36 @@ struct PreparedSession {
 }
 
 impl PreparedSession {
-    fn from_generate_options(opts: &GenerateOptions) -> EngineResult<Self> {
-        let charts = &chart::discover_chart_contexts(&opts.chart_dir)?;
+    fn from_generate_options(
+        opts: &GenerateOptions,
+        cache: Option<&AnalysisCache>,
+        chart_location: Option<&Path>,
+        repository_cache: Option<&ChartRepositoryCache>,
+        diagnostics: &DiagnosticSink,
+    ) -> EngineResult<Self> {
+        let discovery = chart::discover_charts(
+            &opts.chart_dir,
+            chart_location,
+            LoadBudget::default(),
+            repository_cache,
+        )?;
+        for lock in discovery.unreadable_chart_locks {
+            diagnostics.push(Diagnostic::UnreadableChartLock {
+                chart: lock.chart,
+                path: lock.path,
+                error: lock.error,
+            });
+        }
+        for dependency in discovery.unresolved_dependencies {
+            diagnostics.push(Diagnostic::UnresolvedChartDependency {
+                chart: dependency.chart,
+                dependency: dependency.name,
+                version: dependency.version,
+                repository: dependency.repository,
+                locations_tried: dependency.locations_tried,
+            });
+        }
+        let charts = &discovery.charts;
 
         let defines = chart::build_define_index(charts, opts.include_tests)?;
         let values_yaml = chart::build_composed_values_yaml(charts, opts.include_subchart_values)?;
@@ -98,12 +135,14 @@ impl PreparedSession {
             &opts.values_files,
         )?;
         let kubernetes_version = primary_kubernetes_version(opts);
-        let chart_analysis = analyze_charts(
+        let chart_analysis = analyze_charts_cached(
             charts,
             &defines,
             opts.include_tests,
             &values_roots,
             kubernetes_version.as_deref(),
+            opts.cluster_state.as_ref(),
+            cache,
         )?;
         let shadowed_input_paths = chart_analysis.shadowed_input_paths;
 
@@ -130,6 +169,9 @@ impl PreparedSession {
 pub struct AnalysisSession {
     opts: GenerateOptions,
     diagnostics: DiagnosticSink,
+    analysis_cache: Option<AnalysisCache>,
+    chart_repository_cache: Option<ChartRepositoryCache>,
+    chart_location: Option<PathBuf>,
     prepared: SessionCache<PreparedSession>,
     finalized_contract: SessionCache<FinalizedContract>,
     resolved_contract: SessionCache<ResolvedContract>,
@@ -181,6 +223,9 @@ impl AnalysisSession {
         Self {
             opts,
             diagnostics,
+            analysis_cache: None,
+            chart_repository_cache: None,
+            chart_location: None,
             prepared: SessionCache::new(),
             finalized_contract: SessionCache::new(),
             resolved_contract: SessionCache::new(),
@@ -189,6 +234,38 @@ impl AnalysisSession {
         }
     }
 
+    /// Reuse per-template analysis results from `cache` across sessions.
+    ///
+    /// Templates whose source, reachable helpers, and evaluation policy are
+    /// unchanged since a previous session are read back instead of
+    /// re-evaluated; the results are identical either way.
+    #[must_use]
+    pub fn with_analysis_cache(mut self, cache: AnalysisCache) -> Self {
+        self.analysis_cache = Some(cache);
+        self
+    }
+
+    /// Load dependencies that `charts/` does not vendor from `cache`.
+    ///
+    /// Each such dependency is opened from its `Chart.lock` version's
+    /// archive in the cache; one that is missing there is reported as an
+    /// `UnresolvedChartDependency` diagnostic instead of being analyzed.
+    #[must_use]
+    pub fn with_chart_repository_cache(mut self, cache: ChartRepositoryCache) -> Self {
+        self.chart_repository_cache = Some(cache);
+        self
+    }
+
+    /// The root chart's directory on disk.
+    ///
+    /// `file://` dependencies that `charts/` does not vendor and whose path
+    /// leaves the chart directory (`file://../common`) resolve against it.
+    #[must_use]
+    pub fn with_chart_location(mut self, location: impl Into<PathBuf>) -> Self {
+        self.chart_location = Some(location.into());
+        self
+    }
+
     /// Return the memoized chart analysis artifact.
     ///
     /// # Errors
@@ -234,7 +311,8 @@ impl AnalysisSession {
     }
 
     /// Return the memoized generated values schema: the resolved contract
-    /// schema plus the optional `--infer-required` post-pass.
+    /// schema plus the optional `--infer-required` and `--infer-enums`
+    /// post-passes.
     ///
     /// # Errors
     ///
@@ -252,6 +330,12 @@ impl AnalysisSession {
                     &self.prepared()?.explicit_value_paths,
                 );
             }
+            helm_schema_gen::enum_inference::apply_enum_inference(
+                &mut schema,
+                self.prepared()?.analysis.contract.uses(),
+                self.finalized_contract()?.schema_signals(),
+                self.opts.infer_enums,
+            );
             Ok(GeneratedSchema {
                 schema,
                 emission_report: resolved.emission_report.clone(),
@@ -275,9 +359,10 @@ impl AnalysisSession {
         let generated = self.generated_schema()?;
         apply_schema_output_pipeline(
             generated.schema,
-            PreparedEmitRequest::empty(request),
+            PreparedEmitRequest::empty(request).with_sources(self.schema_sources(request)?),
             self.chart_base_dir(),
-            FinalOutputPolicy::new(self.resolved_emission_policy()?, self.opts.infer_required),
+            FinalOutputPolicy::new(self.resolved_emission_policy()?, self.opts.infer_required)
+                .with_infer_enums(self.opts.infer_enums),
         )
     }
 
@@ -295,12 +380,14 @@ impl AnalysisSession {
     ) -> EngineResult<Value> {
         let loaded = load_emit_request(override_paths, &policy_input_options, request)?;
         let generated = self.generated_schema()?;
-        let prepared = prepare_emit_request(loaded, &policy_input_options, &generated.schema)?;
+        let prepared = prepare_emit_request(loaded, &policy_input_options, &generated.schema)?
+            .with_sources(self.schema_sources(request)?);
         apply_schema_output_pipeline(
             generated.schema,
             prepared,
             self.chart_base_dir(),
-            FinalOutputPolicy::new(self.resolved_emission_policy()?, self.opts.infer_required),
+            FinalOutputPolicy::new(self.resolved_emission_policy()?, self.opts.infer_required)
+                .with_infer_enums(self.opts.infer_enums),
         )
     }
 
@@ -361,19 +448,202 @@ impl AnalysisSession {
         })
     }
 
+    /// Report values paths that `baseline` read unconditionally and this
+    /// session reads only under guards.
+    ///
+    /// Combined with [`diff_schemas`](crate::diff::diff_schemas) over both
+    /// sessions' emitted schemas, this classifies the breaking changes
+    /// between two versions of a chart.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when chart analysis or contract finalization fails
+    /// for either session.
+    pub fn guard_changes_since(&self, baseline: &Self) -> EngineResult<Vec<SchemaChange>> {
+        Ok(schema_diff::diff_guards(
+            baseline.finalized_contract()?.uses(),
+            self.finalized_contract()?.uses(),
+        ))
+    }
+
+    /// Build a values reference (path, type, default, description,
+    /// requiredness, and read guards) from an emitted schema.
+    ///
+    /// `schema` should keep its descriptions so values comments and provider
+    /// descriptions reach the rows. Paths that templates read but no
+    /// `values.yaml` declares are listed without a default.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when chart analysis fails or the composed defaults
+    /// are not valid YAML.
+    pub fn values_reference(&self, schema: &Value) -> EngineResult<Vec<ValuesReferenceEntry>> {
+        let prepared = self.prepared()?;
+        let defaults = prepared
+            .values_yaml
+            .as_deref()
+            .map(serde_yaml::from_str::<Value>)
+            .transpose()?;
+        Ok(values_reference::build_values_reference(
+            schema,
+            defaults.as_ref(),
+            self.finalized_contract()?.uses(),
+        ))
+    }
+
+    /// Render an annotated `values.yaml` covering every path of an emitted
+    /// schema's values tree.
+    ///
+    /// Composed chart defaults are kept as-is; paths templates read without
+    /// a default become commented placeholders, and schema descriptions
+    /// become comments above their key.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when chart analysis fails or the composed defaults
+    /// cannot be parsed or re-serialized.
+    pub fn values_skeleton(&self, schema: &Value) -> EngineResult<String> {
+        let prepared = self.prepared()?;
+        let defaults = prepared
+            .values_yaml
+            .as_deref()
+            .map(serde_yaml::from_str::<serde_yaml::Value>)
+            .transpose()?;
+        Ok(helm_schema_gen::render_values_skeleton(
+            defaults.as_ref(),
+            schema,
+        )?)
+    }
+
+    /// Build the language-neutral binding model of the chart's values.
+    ///
+    /// The model reads the same contract, defaults, and emission policy as
+    /// [`AnalysisSession::resolved_contract`]; render it with
+    /// [`bindings::render_type_bindings`](crate::bindings::render_type_bindings).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when chart analysis fails or the emission policy is
+    /// invalid.
+    pub fn values_type_model(&self) -> EngineResult<ValuesTypeModel> {
+        let prepared = self.prepared()?;
+        let finalized_contract = self.finalized_contract()?;
+        let mut provider_options = self.opts.provider.clone();
+        provider_options.local_schema_universe = prepared.analysis.local_schemas.clone();
+        let provider = provider_builder::build_provider(&provider_options, Some(&self.diagnostics));
+        Ok(generate_values_type_model(
+            ValuesSchemaInput::new(finalized_contract.schema_signals(), &provider)
+                .with_values_yaml(prepared.values_yaml.as_deref())
+                .with_dependency_values_yaml(prepared.dependency_values_yaml.as_deref())
+                .with_dependency_refill_values_yaml(
+                    prepared.dependency_refill_values_yaml.as_deref(),
+                )
+                .with_shadowed_input_paths(&prepared.shadowed_input_paths)
+                .with_values_descriptions(&prepared.values_descriptions)
+                .with_emission_policy(self.resolved_emission_policy()?.policy()),
+        ))
+    }
+
+    /// Validate user values files against an emitted schema.
+    ///
+    /// `documents` are coalesced over the chart's composed defaults in order,
+    /// as `helm install -f` would, and the result is validated against
+    /// `schema` (normally the output of [`AnalysisSession::emit`]). Each
+    /// violation names the values file line that supplied the offending
+    /// value and the template locations that read it.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when a values file is not valid YAML, the schema
+    /// cannot be compiled, or chart analysis fails.
+    pub fn validate_values(
+        &self,
+        schema: &Value,
+        documents: &[ValuesDocument],
+    ) -> EngineResult<Vec<ValuesViolation>> {
+        let prepared = self.prepared()?;
+        let chart_values = self
+            .opts
+            .chart_dir
+            .join("values.yaml")
+            .and_then(|path| path.read_to_string())
+            .ok();
+        let sources =
+            SchemaSources::collect(self.finalized_contract()?.uses(), &mut self.source_map());
+        values_validation::validate_values(
+            schema,
+            prepared.values_yaml.as_deref(),
+            chart_values.as_deref(),
+            documents,
+            &sources,
+        )
+    }
+
+    /// Keys of a values file that no template reads and the chart's
+    /// composed defaults do not declare.
+    ///
+    /// Unlike the `additionalProperties` violations of
+    /// [`AnalysisSession::validate_values`], the check compares keys with the
+    /// contract directly, so it also covers mappings the emitted schema
+    /// leaves open.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when chart analysis fails.
+    pub fn unknown_values_keys(&self, source: &str) -> EngineResult<Vec<UnknownValuesKey>> {
+        let prepared = self.prepared()?;
+        Ok(values_validation::unknown_keys(
+            source,
+            self.finalized_contract()?.uses(),
+            &prepared.explicit_value_paths,
+        ))
+    }
+
+    /// Return a source map that resolves contract provenance spans against
+    /// this session's chart sources.
+    #[must_use]
+    pub fn source_map(&self) -> SourceMap {
+        SourceMap::new(self.opts.chart_dir.clone())
+    }
+
     fn prepared(&self) -> EngineResult<Arc<PreparedSession>> {
-        self.prepared
-            .get_or_try_init(|| PreparedSession::from_generate_options(&self.opts))
+        self.prepared.get_or_try_init(|| {
+            PreparedSession::from_generate_options(
+                &self.opts,
+                self.analysis_cache.as_ref(),
+                self.chart_location.as_deref(),
+                self.chart_repository_cache.as_ref(),
+                &self.diagnostics,
+            )
+        })
     }
 
     fn chart_base_dir(&self) -> &Path {
         Path::new(self.opts.chart_dir.as_str())
     }
 
+    fn schema_sources(&self, request: EmitRequest) -> EngineResult<SchemaSources> {
+        if !request.output.source_annotations {
+            return Ok(SchemaSources::default());
+        }
+        Ok(SchemaSources::collect(
+            self.finalized_contract()?.uses(),
+            &mut self.source_map(),
+        ))
+    }
+
     fn finalized_contract(&self) -> EngineResult<Arc<FinalizedContract>> {
         self.finalized_contract.get_or_try_init(|| {
             let prepared = self.prepared()?;
-            let finalized = prepared.analysis.contract.clone().finalize();
+            let local_schemas = &prepared.analysis.local_schemas;
+            let finalized = prepared
+                .analysis
+                .contract
+                .clone()
+                .finalize()
+                .with_resource_definition_guards(|resource| {
+                    resource_definition_guards(local_schemas, resource)
+                });
             emit_input_channel_diagnostics(finalized.schema_signals(), &self.diagnostics);
             Ok(finalized)
         })
@@ -474,7 +744,8 @@ fn normalize_values_path(path: &str) -> String {
 /// run's provider policy. `None` when no version is configured — the
 /// capabilities lanes then abstain instead of guessing a cluster.
 fn primary_kubernetes_version(opts: &GenerateOptions) -> Option<String> {
-    let token = opts.provider.k8s_versions.first()?;
+    let versions = opts.provider.effective_k8s_versions();
+    let token = versions.first()?;
     let token = token.trim().strip_prefix('v').unwrap_or(token.trim());
     let core: String = token
         .chars()
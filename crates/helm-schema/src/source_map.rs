@@ -0,0 +1,125 @@
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use helm_schema_core::ContractProvenance;
+use serde::Serialize;
+use vfs::VfsPath;
+
+/// Line and column position of one provenance span in its template source.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize)]
+pub struct SourceLocation {
+    /// Template file relative to the analyzed chart root.
+    pub file: String,
+    /// One-based line of the span start.
+    pub line: usize,
+    /// One-based character column of the span start.
+    pub column: usize,
+    /// Named helpers traversed from the template to the expression.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub helper_chain: Vec<String>,
+}
+
+/// Resolves byte-offset provenance spans into line/column locations.
+///
+/// Template sources are read lazily from the chart filesystem and their line
+/// starts are cached, so resolving every use of a large chart reads each file
+/// once. Provenance from sources outside the chart filesystem (packaged
+/// dependency archives, `tpl` programs) resolves to `None`.
+#[derive(Debug)]
+pub struct SourceMap {
+    chart_dir: VfsPath,
+    files: BTreeMap<String, Option<Arc<LineIndex>>>,
+}
+
+#[derive(Debug)]
+struct LineIndex {
+    source: String,
+    line_starts: Vec<usize>,
+}
+
+impl LineIndex {
+    fn new(source: String) -> Self {
+        let line_starts = std::iter::once(0)
+            .chain(
+                source
+                    .bytes()
+                    .enumerate()
+                    .filter(|(_, byte)| *byte == b'\n')
+                    .map(|(offset, _)| offset + 1),
+            )
+            .collect();
+        Self {
+            source,
+            line_starts,
+        }
+    }
+
+    fn position(&self, offset: usize) -> Option<(usize, usize)> {
+        if offset > self.source.len() {
+            return None;
+        }
+        let line = self
+            .line_starts
+            .partition_point(|start| *start <= offset)
+            .saturating_sub(1);
+        let line_start = *self.line_starts.get(line)?;
+        let column = self.source.get(line_start..offset)?.chars().count();
+        Some((line + 1, column + 1))
+    }
+}
+
+impl SourceMap {
+    /// Creates a source map over the chart directory the contract was
+    /// recovered from.
+    #[must_use]
+    pub fn new(chart_dir: VfsPath) -> Self {
+        Self {
+            chart_dir,
+            files: BTreeMap::new(),
+        }
+    }
+
+    /// Chart-relative spelling of a provenance template path.
+    #[must_use]
+    pub fn relative_file(&self, template_path: &str) -> String {
+        let root = self.chart_dir.as_str();
+        template_path
+            .strip_prefix(root)
+            .filter(|relative| root.is_empty() || relative.starts_with('/'))
+            .unwrap_or(template_path)
+            .trim_start_matches('/')
+            .to_string()
+    }
+
+    /// Resolves the start of a provenance span, or `None` when its template
+    /// cannot be read or the span lies outside the source.
+    pub fn locate(&mut self, provenance: &ContractProvenance) -> Option<SourceLocation> {
+        let index = self.line_index(&provenance.template_path)?;
+        let (line, column) = index.position(provenance.span.start)?;
+        Some(SourceLocation {
+            file: self.relative_file(&provenance.template_path),
+            line,
+            column,
+            helper_chain: provenance.helper_chain.clone(),
+        })
+    }
+
+    fn line_index(&mut self, template_path: &str) -> Option<Arc<LineIndex>> {
+        if let Some(cached) = self.files.get(template_path) {
+            return cached.clone();
+        }
+        let index = self
+            .chart_dir
+            .root()
+            .join(template_path.trim_start_matches('/'))
+            .and_then(|path| path.read_to_string())
+            .ok()
+            .map(|source| Arc::new(LineIndex::new(source)));
+        self.files.insert(template_path.to_string(), index.clone());
+        index
+    }
+}
+
+#[cfg(test)]
+#[path = "tests/source_map.rs"]
+mod tests;
@@ -6,7 +6,8 @@ use helm_schema_k8s::{ChartLocalCrdSchemaProvider, Diagnostic, DiagnosticSink, K
 use serde_json::json;
 use vfs::VfsPath;
 
-use crate::analysis::analyze_charts;
+use crate::analysis::analyze_charts_cached;
+use crate::generation::EnumInference;
 use test_util::prelude::sim_assert_eq;
 
 use crate::chart;
@@ -69,12 +70,14 @@ fn airflow_break_scopes_the_deprecated_security_context_candidate() -> eyre::Res
     let chart_dir = VfsPath::new(vfs::PhysicalFS::new(&chart_dir_str));
     let charts = chart::discover_chart_contexts(&chart_dir)?;
     let defines = chart::build_define_index(&charts, false)?;
-    let collection = analyze_charts(
+    let collection = analyze_charts_cached(
         &charts,
         &defines,
         false,
         &crate::values_roots::ValuesRoots::from_values_yaml(None),
         None,
+        None,
+        None,
     )?;
     let signals = contract_schema_signals!(collection);
     let evidence = signals
@@ -125,7 +128,8 @@ fn loki_selected_htpasswd_default_program_reaches_required_credentials() -> eyre
             .contains_key("gateway.basicAuth.htpasswd"),
         "the composed values document must preserve the chart-authored program"
     );
-    let collection = analyze_charts(&charts, &defines, false, &values_roots, None)?;
+    let collection =
+        analyze_charts_cached(&charts, &defines, false, &values_roots, None, None, None)?;
     let signals = contract_schema_signals!(collection);
 
     for path in ["gateway.basicAuth.username", "gateway.basicAuth.password"] {
@@ -196,12 +200,14 @@ fn subchart_helper_render_with_guard_surfaces_scoped_self_guarded_fact() -> eyre
 
     let charts = chart::discover_chart_contexts(&chart_dir)?;
     let defines = chart::build_define_index(&charts, false)?;
-    let collection = analyze_charts(
+    let collection = analyze_charts_cached(
         &charts,
         &defines,
         false,
         &crate::values_roots::ValuesRoots::from_values_yaml(None),
         None,
+        None,
+        None,
     )?;
     let path = "kid.controller.ingressClassResource.parameters";
 
@@ -226,12 +232,14 @@ fn signoz_zookeeper_name_override_string_contract_stays_branch_scoped() -> eyre:
     let chart_dir = VfsPath::new(vfs::PhysicalFS::new(&chart_dir_str));
     let charts = chart::discover_chart_contexts(&chart_dir)?;
     let defines = chart::build_define_index(&charts, false)?;
-    let collection = analyze_charts(
+    let collection = analyze_charts_cached(
         &charts,
         &defines,
         false,
         &crate::values_roots::ValuesRoots::from_values_yaml(None),
         None,
+        None,
+        None,
     )?;
     let path = "clickhouse.zookeeper.nameOverride";
 
@@ -289,12 +297,14 @@ fn bitnami_redis_existing_secret_string_contract_stays_branch_scoped() -> eyre::
     let chart_dir = VfsPath::new(vfs::PhysicalFS::new(&chart_dir_str));
     let charts = chart::discover_chart_contexts(&chart_dir)?;
     let defines = chart::build_define_index(&charts, false)?;
-    let collection = analyze_charts(
+    let collection = analyze_charts_cached(
         &charts,
         &defines,
         false,
         &crate::values_roots::ValuesRoots::from_values_yaml(None),
         None,
+        None,
+        None,
     )?;
     let path = "auth.existingSecret";
     let signals = contract_schema_signals!(collection);
@@ -345,12 +355,14 @@ fn selected_string_contract_preserves_serialized_and_plain_provider_routes() ->
         let chart_dir = VfsPath::new(vfs::PhysicalFS::new(&chart_dir_str));
         let charts = chart::discover_chart_contexts(&chart_dir)?;
         let defines = chart::build_define_index(&charts, false)?;
-        let collection = analyze_charts(
+        let collection = analyze_charts_cached(
             &charts,
             &defines,
             false,
             &crate::values_roots::ValuesRoots::from_values_yaml(None),
             None,
+            None,
+            None,
         )?;
         let signals = contract_schema_signals!(collection);
         let Some(evidence) = signals.evidence_for("nameOverride") else {
@@ -397,12 +409,14 @@ fn harbor_defaulted_secret_string_contract_keeps_its_truthy_tooth() -> eyre::Res
     let chart_dir = VfsPath::new(vfs::PhysicalFS::new(&chart_dir_str));
     let charts = chart::discover_chart_contexts(&chart_dir)?;
     let defines = chart::build_define_index(&charts, false)?;
-    let collection = analyze_charts(
+    let collection = analyze_charts_cached(
         &charts,
         &defines,
         false,
         &crate::values_roots::ValuesRoots::from_values_yaml(None),
         None,
+        None,
+        None,
     )?;
     let signals = contract_schema_signals!(collection);
 
@@ -445,12 +459,14 @@ fn signoz_clickhouse_operator_image_helper_printf_binds_no_string_contract() ->
     let chart_dir = VfsPath::new(vfs::PhysicalFS::new(&chart_dir_str));
     let charts = chart::discover_chart_contexts(&chart_dir)?;
     let defines = chart::build_define_index(&charts, false)?;
-    let collection = analyze_charts(
+    let collection = analyze_charts_cached(
         &charts,
         &defines,
         false,
         &crate::values_roots::ValuesRoots::from_values_yaml(None),
         None,
+        None,
+        None,
     )?;
     let path = "clickhouse.clickhouseOperator.image.repository";
 
@@ -477,12 +493,14 @@ fn promtail_helper_string_consumer_reaches_the_image_tag_contract() -> eyre::Res
     let chart_dir = VfsPath::new(vfs::PhysicalFS::new(&chart_dir_str));
     let charts = chart::discover_chart_contexts(&chart_dir)?;
     let defines = chart::build_define_index(&charts, false)?;
-    let collection = analyze_charts(
+    let collection = analyze_charts_cached(
         &charts,
         &defines,
         false,
         &crate::values_roots::ValuesRoots::from_values_yaml(None),
         None,
+        None,
+        None,
     )?;
     let path = "image.tag";
     let signals = contract_schema_signals!(collection);
@@ -516,12 +534,14 @@ fn signoz_smtp_existing_secret_name_is_rendered_as_secret_ref_name() -> eyre::Re
     let chart_dir = VfsPath::new(vfs::PhysicalFS::new(&chart_dir_str));
     let charts = chart::discover_chart_contexts(&chart_dir)?;
     let defines = chart::build_define_index(&charts, false)?;
-    let collection = analyze_charts(
+    let collection = analyze_charts_cached(
         &charts,
         &defines,
         false,
         &crate::values_roots::ValuesRoots::from_values_yaml(None),
         None,
+        None,
+        None,
     )?;
     let projection = collection.contract.clone().finalize();
     let path = "signoz.smtpVars.existingSecret.name";
@@ -627,12 +647,14 @@ fn signoz_clickhouse_operator_service_account_name_keeps_helper_and_else_branch_
     let chart_dir = VfsPath::new(vfs::PhysicalFS::new(&chart_dir_str));
     let charts = chart::discover_chart_contexts(&chart_dir)?;
     let defines = chart::build_define_index(&charts, false)?;
-    let collection = analyze_charts(
+    let collection = analyze_charts_cached(
         &charts,
         &defines,
         false,
         &crate::values_roots::ValuesRoots::from_values_yaml(None),
         None,
+        None,
+        None,
     )?;
     let projection = collection.contract.clone().finalize();
     let path = "clickhouse.clickhouseOperator.serviceAccount.name";
@@ -720,12 +742,14 @@ fn traefik_host_users_keeps_provider_sink_under_invalid_kind_guard() -> eyre::Re
     let chart_dir = VfsPath::new(vfs::PhysicalFS::new(&chart_dir_str));
     let charts = chart::discover_chart_contexts(&chart_dir)?;
     let defines = chart::build_define_index(&charts, false)?;
-    let collection = analyze_charts(
+    let collection = analyze_charts_cached(
         &charts,
         &defines,
         false,
         &crate::values_roots::ValuesRoots::from_values_yaml(None),
         None,
+        None,
+        None,
     )?;
     let path = "deployment.hostUsers";
     let signals = contract_schema_signals!(collection);
@@ -754,12 +778,14 @@ fn prometheus_namespace_helper_keeps_join_conversion_boundary() -> eyre::Result<
     let chart_dir = VfsPath::new(vfs::PhysicalFS::new(&chart_dir_str));
     let charts = chart::discover_chart_contexts(&chart_dir)?;
     let defines = chart::build_define_index(&charts, false)?;
-    let collection = analyze_charts(
+    let collection = analyze_charts_cached(
         &charts,
         &defines,
         false,
         &crate::values_roots::ValuesRoots::from_values_yaml(None),
         None,
+        None,
+        None,
     )?;
     let path = "server.namespaces";
     let signals = contract_schema_signals!(collection);
@@ -785,12 +811,14 @@ fn signoz_root_service_account_name_keeps_resource_scope_and_default_semantics()
     let chart_dir = VfsPath::new(vfs::PhysicalFS::new(&chart_dir_str));
     let charts = chart::discover_chart_contexts(&chart_dir)?;
     let defines = chart::build_define_index(&charts, false)?;
-    let collection = analyze_charts(
+    let collection = analyze_charts_cached(
         &charts,
         &defines,
         false,
         &crate::values_roots::ValuesRoots::from_values_yaml(None),
         None,
+        None,
+        None,
     )?;
     let projection = collection.contract.clone().finalize();
     let path = "signoz.serviceAccount.name";
@@ -858,12 +886,14 @@ fn signoz_otel_gateway_service_account_name_keeps_helper_default_nullability() -
     let charts = chart::discover_chart_contexts(&chart_dir)?;
     let defines = chart::build_define_index(&charts, false)?;
     let values_yaml = chart::build_composed_values_yaml(&charts, true)?;
-    let collection = analyze_charts(
+    let collection = analyze_charts_cached(
         &charts,
         &defines,
         false,
         &crate::values_roots::ValuesRoots::from_values_yaml(values_yaml.as_deref()),
         None,
+        None,
+        None,
     )?;
     let projection = collection.contract.clone().finalize();
     let path = "signoz-otel-gateway.serviceAccount.name";
@@ -913,12 +943,14 @@ fn signoz_clickhouse_security_context_records_fragment_fact() -> eyre::Result<()
     let charts = chart::discover_chart_contexts(&chart_dir)?;
     let defines = chart::build_define_index(&charts, false)?;
     let values_yaml = chart::build_composed_values_yaml(&charts, true)?;
-    let collection = analyze_charts(
+    let collection = analyze_charts_cached(
         &charts,
         &defines,
         false,
         &crate::values_roots::ValuesRoots::from_values_yaml(values_yaml.as_deref()),
         None,
+        None,
+        None,
     )?;
     let projection = collection.contract.clone().finalize();
     let path = "clickhouse.securityContext";
@@ -1030,12 +1062,14 @@ fn transitive_library_helper_default_flows_into_contract_requiredness_evidence()
 
     let charts = chart::discover_chart_contexts(&chart_dir)?;
     let defines = chart::build_define_index(&charts, false)?;
-    let collection = analyze_charts(
+    let collection = analyze_charts_cached(
         &charts,
         &defines,
         false,
         &crate::values_roots::ValuesRoots::from_values_yaml(None),
         None,
+        None,
+        None,
     )?;
     let projection = collection.contract.clone().finalize();
     let name_override_uses = projection
@@ -1068,12 +1102,14 @@ fn cert_manager_fullname_override_records_self_guarded_render_evidence() -> eyre
     let chart_dir = VfsPath::new(vfs::PhysicalFS::new(&chart_dir_str));
     let charts = chart::discover_chart_contexts(&chart_dir)?;
     let defines = chart::build_define_index(&charts, false)?;
-    let collection = analyze_charts(
+    let collection = analyze_charts_cached(
         &charts,
         &defines,
         false,
         &crate::values_roots::ValuesRoots::from_values_yaml(None),
         None,
+        None,
+        None,
     )?;
     let path = "fullnameOverride";
     let projection = collection.contract.clone().finalize();
@@ -1107,12 +1143,14 @@ fn cert_manager_webhook_values_root_is_seeded_without_dependency_fragment() -> e
     let charts = chart::discover_chart_contexts(&chart_dir)?;
     let defines = chart::build_define_index(&charts, false)?;
     let values_yaml = chart::build_composed_values_yaml(&charts, true)?;
-    let collection = analyze_charts(
+    let collection = analyze_charts_cached(
         &charts,
         &defines,
         false,
         &crate::values_roots::ValuesRoots::from_values_yaml(values_yaml.as_deref()),
         None,
+        None,
+        None,
     )?;
     let path = "webhook";
     let signals = contract_schema_signals!(collection);
@@ -1143,6 +1181,8 @@ fn cert_manager_webhook_values_root_is_seeded_without_dependency_fragment() -> e
         include_subchart_values: true,
         values_files: Vec::new(),
         infer_required: false,
+        infer_enums: EnumInference::Off,
+        cluster_state: None,
         emission: crate::generation::SchemaProfile::default().into(),
         provider: crate::provider::ProviderOptions {
             k8s_versions: vec!["v1.29.0-standalone-strict".to_string()],
@@ -1221,12 +1261,14 @@ fn dependency_activation_guards_subchart_contract_uses() -> eyre::Result<()> {
 
     let charts = chart::discover_chart_contexts(&chart_dir)?;
     let defines = chart::build_define_index(&charts, false)?;
-    let collection = analyze_charts(
+    let collection = analyze_charts_cached(
         &charts,
         &defines,
         false,
         &crate::values_roots::ValuesRoots::from_values_yaml(None),
         None,
+        None,
+        None,
     )?;
     let projection = collection.contract.finalize();
     let uses = projection
@@ -1373,12 +1415,14 @@ fn nested_dependency_activation_carries_the_ancestor_conditions() -> eyre::Resul
 
     let charts = chart::discover_chart_contexts(&chart_dir)?;
     let defines = chart::build_define_index(&charts, false)?;
-    let collection = analyze_charts(
+    let collection = analyze_charts_cached(
         &charts,
         &defines,
         false,
         &crate::values_roots::ValuesRoots::from_values_yaml(None),
         None,
+        None,
+        None,
     )?;
     let projection = collection.contract.finalize();
     let uses = projection
@@ -1493,12 +1537,14 @@ fn nested_dependency_global_defaults_keep_null_fallback_contracts() -> eyre::Res
 
     let charts = chart::discover_chart_contexts(&chart_dir)?;
     let defines = chart::build_define_index(&charts, false)?;
-    let collection = analyze_charts(
+    let collection = analyze_charts_cached(
         &charts,
         &defines,
         false,
         &crate::values_roots::ValuesRoots::from_values_yaml(None),
         None,
+        None,
+        None,
     )?;
     let paths = collection
         .contract
@@ -1582,12 +1628,14 @@ fn literal_crd_template_populates_chart_local_schema_universe() -> eyre::Result<
 
     let charts = chart::discover_chart_contexts(&chart_dir)?;
     let defines = chart::build_define_index(&charts, false)?;
-    let collection = analyze_charts(
+    let collection = analyze_charts_cached(
         &charts,
         &defines,
         false,
         &crate::values_roots::ValuesRoots::from_values_yaml(None),
         None,
+        None,
+        None,
     )?;
     let provider = ChartLocalCrdSchemaProvider::new(collection.local_schema_universe);
     let resource = ResourceRef::concrete("example.com/v1".to_string(), "Widget".to_string());
@@ -1668,12 +1716,14 @@ fn templated_crd_template_populates_chart_local_schema_universe() -> eyre::Resul
 
     let charts = chart::discover_chart_contexts(&chart_dir)?;
     let defines = chart::build_define_index(&charts, false)?;
-    let collection = analyze_charts(
+    let collection = analyze_charts_cached(
         &charts,
         &defines,
         false,
         &crate::values_roots::ValuesRoots::from_values_yaml(None),
         None,
+        None,
+        None,
     )?;
     let provider = ChartLocalCrdSchemaProvider::new(collection.local_schema_universe);
     let resource = ResourceRef::concrete("example.com/v1".to_string(), "Widget".to_string());
@@ -1770,12 +1820,14 @@ fn joined_validator_messages_do_not_become_activation_terminals() -> eyre::Resul
 
     let charts = chart::discover_chart_contexts(&chart_dir)?;
     let defines = chart::build_define_index(&charts, false)?;
-    let collection = analyze_charts(
+    let collection = analyze_charts_cached(
         &charts,
         &defines,
         false,
         &crate::values_roots::ValuesRoots::from_values_yaml(None),
         None,
+        None,
+        None,
     )?;
 
     // The validator's own operand is `auth.user`; the member-access lane
@@ -1844,6 +1896,8 @@ fn tpl_executes_only_the_selected_chart_authored_default_program() -> eyre::Resu
         include_subchart_values: true,
         values_files: Vec::new(),
         infer_required: false,
+        infer_enums: EnumInference::Off,
+        cluster_state: None,
         emission: crate::generation::SchemaProfile::default().into(),
         provider: crate::provider::ProviderOptions {
             disable_k8s_schemas: true,
@@ -12,10 +12,10 @@ use sha2::{Digest, Sha256};
 use vfs::VfsPath;
 
 use crate::AnalysisSession;
-use crate::generation::GenerateOptions;
+use crate::generation::{EnumInference, GenerateOptions};
 use crate::output_pipeline::{
     EmitRequest, FinalOutputPolicy, JsonOutputFormat, OutputPipelineOptions, PreparedEmitRequest,
-    ReferencePolicy, apply_schema_output_pipeline, write_schema_json,
+    ReferencePolicy, SchemaDialect, apply_schema_output_pipeline, write_schema_json,
 };
 use crate::provider_builder::ProviderOptions;
 
@@ -45,6 +45,8 @@ fn emission_profile_release_benchmark() -> eyre::Result<()> {
         output: OutputPipelineOptions {
             strip_descriptions: true,
             minimize: true,
+            source_annotations: false,
+            dialect: SchemaDialect::Draft07,
         },
     };
     let chart_dir = temporal_chart_path();
@@ -204,6 +206,8 @@ fn temporal_session() -> AnalysisSession {
         include_subchart_values: true,
         values_files: Vec::new(),
         infer_required: false,
+        infer_enums: EnumInference::Off,
+        cluster_state: None,
         emission: SchemaProfile::Full.into(),
         provider: ProviderOptions {
             k8s_versions: vec!["v1.29.0-standalone-strict".to_string()],
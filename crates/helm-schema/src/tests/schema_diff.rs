@@ -0,0 +1,324 @@
+use helm_schema_core::{ContractProvenance, SourceSpan, ValueKind, YamlPath};
+use test_util::prelude::sim_assert_eq;
+
+use super::*;
+
+fn read(path: &str, guards: Vec<Guard>) -> ContractUse {
+    ContractUse::with_provenances(
+        path.to_string(),
+        YamlPath::default(),
+        ValueKind::Scalar,
+        guards,
+        None,
+        [ContractProvenance::new(
+            "/templates/cm.yaml",
+            SourceSpan::new(0, 1),
+            Vec::new(),
+        )],
+    )
+}
+
+fn change(path: &str, kind: SchemaChangeKind) -> SchemaChange {
+    SchemaChange {
+        path: path.to_string(),
+        conditional: false,
+        kind,
+    }
+}
+
+fn conditional_change(path: &str, kind: SchemaChangeKind) -> SchemaChange {
+    SchemaChange {
+        conditional: true,
+        ..change(path, kind)
+    }
+}
+
+#[test]
+fn classifies_property_type_and_required_changes() {
+    let old = serde_json::json!({
+        "type": "object",
+        "required": ["image"],
+        "properties": {
+            "image": { "type": "string" },
+            "replicas": { "type": "integer" },
+            "mode": { "enum": ["a", "b"] },
+            "legacy": { "type": "boolean" }
+        }
+    });
+    let new = serde_json::json!({
+        "type": "object",
+        "required": ["replicas"],
+        "properties": {
+            "image": { "type": ["object", "string"] },
+            "replicas": { "type": "string" },
+            "mode": { "enum": ["a", "c"] },
+            "ports": { "type": "array" }
+        }
+    });
+
+    sim_assert_eq!(
+        have: diff_schemas(&old, &new),
+        want: vec![
+            change("image", SchemaChangeKind::TypeWidened {
+                from: vec!["string".to_string()],
+                to: vec!["object".to_string(), "string".to_string()],
+            }),
+            change("image", SchemaChangeKind::RequiredRemoved),
+            change("legacy", SchemaChangeKind::PropertyRemoved),
+            change("mode", SchemaChangeKind::EnumShrunk {
+                removed: vec![serde_json::json!("b")],
+            }),
+            change("mode", SchemaChangeKind::EnumGrown {
+                added: vec![serde_json::json!("c")],
+            }),
+            change("ports", SchemaChangeKind::PropertyAdded),
+            change("replicas", SchemaChangeKind::TypeNarrowed {
+                from: vec!["integer".to_string()],
+                to: vec!["string".to_string()],
+            }),
+            change("replicas", SchemaChangeKind::RequiredAdded),
+        ]
+    );
+}
+
+#[test]
+fn follows_local_refs_and_collection_members() {
+    let inline = serde_json::json!({
+        "type": "object",
+        "properties": {
+            "ports": {
+                "type": "array",
+                "items": { "type": "object", "properties": { "port": { "type": "integer" } } }
+            }
+        }
+    });
+    let interned = serde_json::json!({
+        "type": "object",
+        "$defs": {
+            "port": { "type": "object", "properties": { "port": { "type": "number" } } }
+        },
+        "properties": {
+            "ports": { "type": "array", "items": { "$ref": "#/$defs/port" } }
+        }
+    });
+
+    sim_assert_eq!(
+        have: diff_schemas(&inline, &interned),
+        want: vec![change("ports.*.port", SchemaChangeKind::TypeWidened {
+            from: vec!["integer".to_string()],
+            to: vec!["number".to_string()],
+        })]
+    );
+    sim_assert_eq!(have: diff_schemas(&interned, &interned), want: Vec::new());
+}
+
+#[test]
+fn unions_composed_arm_types_across_refs() {
+    let old = serde_json::json!({
+        "$defs": { "count": { "type": "integer" } },
+        "properties": {
+            "replicas": { "anyOf": [{ "$ref": "#/$defs/count" }, { "type": "string" }] },
+            "port": { "type": ["integer", "string"], "allOf": [{ "type": "integer" }] },
+            "mode": { "oneOf": [{ "type": "string" }, {}] }
+        }
+    });
+    let new = serde_json::json!({
+        "properties": {
+            "replicas": { "anyOf": [{ "type": "integer" }, { "type": "null" }] },
+            "port": { "anyOf": [{ "type": "integer" }, { "type": "string" }] },
+            "mode": { "oneOf": [{ "type": "string" }, { "type": "boolean" }] }
+        }
+    });
+
+    sim_assert_eq!(
+        have: diff_schemas(&old, &new),
+        want: vec![
+            change("mode", SchemaChangeKind::TypeNarrowed {
+                from: Vec::new(),
+                to: vec!["boolean".to_string(), "string".to_string()],
+            }),
+            change("port", SchemaChangeKind::TypeWidened {
+                from: vec!["integer".to_string()],
+                to: vec!["integer".to_string(), "string".to_string()],
+            }),
+            change("replicas", SchemaChangeKind::TypeNarrowed {
+                from: vec!["integer".to_string(), "string".to_string()],
+                to: vec!["integer".to_string(), "null".to_string()],
+            }),
+        ]
+    );
+}
+
+#[test]
+fn classifies_constraints_inside_any_of_arms() {
+    let old = serde_json::json!({
+        "properties": {
+            "name": { "anyOf": [{ "type": "integer" }, { "type": "string" }] },
+            "tag": { "anyOf": [{ "type": "null" }, { "type": "string", "pattern": "^v" }] }
+        }
+    });
+    let new = serde_json::json!({
+        "properties": {
+            "name": {
+                "anyOf": [{ "type": "integer" }, { "type": "string", "pattern": "^[a-z]+$" }]
+            },
+            "tag": {
+                "anyOf": [
+                    { "type": "null" },
+                    { "type": "string", "pattern": "^v" },
+                    { "type": "string", "pattern": "^[0-9]" }
+                ]
+            }
+        }
+    });
+
+    sim_assert_eq!(
+        have: diff_schemas(&old, &new),
+        want: vec![
+            change("name", constraint_change("pattern", true, None, Some(serde_json::json!("^[a-z]+$")))),
+            change("tag", constraint_change(
+                "pattern",
+                false,
+                Some(serde_json::json!("^v")),
+                Some(serde_json::json!(["^[0-9]", "^v"])),
+            )),
+        ]
+    );
+}
+
+#[test]
+fn classifies_length_size_and_numeric_bounds() {
+    let old = serde_json::json!({
+        "properties": {
+            "name": { "type": "string", "maxLength": 63 },
+            "replicas": { "type": "integer", "minimum": 0, "maximum": 10 },
+            "ports": { "type": "array" },
+            "labels": { "type": "object", "maxProperties": 8 }
+        }
+    });
+    let new = serde_json::json!({
+        "properties": {
+            "name": { "type": "string", "maxLength": 253, "minLength": 1 },
+            "replicas": { "type": "integer", "allOf": [{ "minimum": 1 }] },
+            "ports": { "anyOf": [{ "type": "null" }, { "type": "array", "maxItems": 4 }] },
+            "labels": { "type": "object" }
+        }
+    });
+
+    sim_assert_eq!(
+        have: diff_schemas(&old, &new),
+        want: vec![
+            change("labels", constraint_change("maxProperties", false, Some(serde_json::json!(8)), None)),
+            change("name", constraint_change("minLength", true, None, Some(serde_json::json!(1)))),
+            change("name", constraint_change("maxLength", false, Some(serde_json::json!(63)), Some(serde_json::json!(253)))),
+            change("ports", SchemaChangeKind::TypeWidened {
+                from: vec!["array".to_string()],
+                to: vec!["array".to_string(), "null".to_string()],
+            }),
+            change("ports", constraint_change("maxItems", true, None, Some(serde_json::json!(4)))),
+            change("replicas", constraint_change("minimum", true, Some(serde_json::json!(0)), Some(serde_json::json!(1)))),
+            change("replicas", constraint_change("maximum", false, Some(serde_json::json!(10)), None)),
+        ]
+    );
+}
+
+#[test]
+fn compares_all_of_refinements_and_paired_conditional_overlays() {
+    let enabled = serde_json::json!({
+        "properties": { "enabled": { "const": true } },
+        "required": ["enabled"]
+    });
+    let old = serde_json::json!({
+        "properties": { "ingress": { "type": "object" } },
+        "allOf": [
+            { "properties": { "version": { "type": "string" } } },
+            {
+                "if": { "properties": { "ingress": enabled.clone() } },
+                "then": { "properties": { "ingress": { "properties": { "host": {} } } } }
+            },
+            { "if": { "required": ["legacy"] }, "then": false }
+        ]
+    });
+    let new = serde_json::json!({
+        "$defs": { "enabled": enabled },
+        "properties": { "ingress": { "type": "object" } },
+        "allOf": [
+            { "properties": { "version": { "type": "string", "pattern": "^v[0-9]+$" } } },
+            {
+                "if": {
+                    "properties": { "ingress": { "$ref": "#/$defs/enabled" } },
+                    "description": "ingress enabled"
+                },
+                "then": {
+                    "properties": {
+                        "ingress": {
+                            "required": ["host"],
+                            "properties": { "host": { "type": "string", "maxLength": 253 } }
+                        }
+                    }
+                }
+            },
+            { "if": { "required": ["strict"] }, "then": false }
+        ]
+    });
+
+    sim_assert_eq!(
+        have: diff_schemas(&old, &new),
+        want: vec![
+            change("", SchemaChangeKind::ConditionalAdded),
+            change("", SchemaChangeKind::ConditionalRemoved),
+            conditional_change("ingress.host", SchemaChangeKind::TypeNarrowed {
+                from: Vec::new(),
+                to: vec!["string".to_string()],
+            }),
+            conditional_change(
+                "ingress.host",
+                constraint_change("maxLength", true, None, Some(serde_json::json!(253))),
+            ),
+            conditional_change("ingress.host", SchemaChangeKind::RequiredAdded),
+            change("version", constraint_change("pattern", true, None, Some(serde_json::json!("^v[0-9]+$")))),
+        ]
+    );
+}
+
+#[test]
+fn reads_the_policy_fingerprint_annotation() {
+    let schema = serde_json::json!({
+        "x-helm-schema-policy": { "policy-fingerprint": "abc" }
+    });
+
+    sim_assert_eq!(have: policy_fingerprint(&schema), want: Some("abc"));
+    sim_assert_eq!(have: policy_fingerprint(&serde_json::json!({})), want: None);
+}
+
+#[test]
+fn reports_paths_that_lost_every_unconditional_read() {
+    let enabled = Guard::Truthy {
+        path: "ingress.enabled".to_string(),
+    };
+    let old = [
+        read("ingress.host", Vec::new()),
+        read("name", Vec::new()),
+        read("port", vec![enabled.clone()]),
+    ];
+    let new = [
+        read("ingress.host", vec![enabled.clone()]),
+        read("name", Vec::new()),
+        read("name", vec![enabled.clone()]),
+        read("port", vec![enabled.clone()]),
+        ContractUse::new(
+            "ingress.host".to_string(),
+            YamlPath::default(),
+            ValueKind::Scalar,
+            Vec::new(),
+            None,
+        ),
+    ];
+
+    sim_assert_eq!(
+        have: diff_guards(&old, &new),
+        want: vec![change("ingress.host", SchemaChangeKind::GuardAdded {
+            guards: vec![enabled],
+        })]
+    );
+}
@@ -0,0 +1,63 @@
+use color_eyre::eyre;
+use helm_schema_core::SourceSpan;
+use indoc::indoc;
+use test_util::prelude::sim_assert_eq;
+
+use super::*;
+
+#[test]
+fn locates_span_starts_as_one_based_lines_and_character_columns() -> eyre::Result<()> {
+    let root = VfsPath::new(vfs::MemoryFS::new());
+    let chart_dir = root.join("chart")?;
+    let source = indoc! {r"
+        # ünïcode comment
+        replicas: {{ .Values.replicas }}
+    "};
+    test_util::write(&chart_dir.join("templates/deployment.yaml")?, source)?;
+    let offset = source
+        .find("{{")
+        .ok_or_else(|| eyre::eyre!("missing action"))?;
+
+    let mut source_map = SourceMap::new(chart_dir);
+    let location = source_map.locate(&ContractProvenance::new(
+        "/chart/templates/deployment.yaml",
+        SourceSpan::new(offset, offset + 2),
+        vec!["chart.replicas".to_string()],
+    ));
+
+    sim_assert_eq!(
+        have: location,
+        want: Some(SourceLocation {
+            file: "templates/deployment.yaml".to_string(),
+            line: 2,
+            column: 11,
+            helper_chain: vec!["chart.replicas".to_string()],
+        })
+    );
+    Ok(())
+}
+
+#[test]
+fn unreadable_templates_and_out_of_range_spans_do_not_resolve() -> eyre::Result<()> {
+    let root = VfsPath::new(vfs::MemoryFS::new());
+    test_util::write(&root.join("templates/cm.yaml")?, "a: b\n")?;
+
+    let mut source_map = SourceMap::new(root);
+    sim_assert_eq!(
+        have: source_map.locate(&ContractProvenance::new(
+            "@tpl",
+            SourceSpan::new(0, 1),
+            Vec::new(),
+        )),
+        want: None
+    );
+    sim_assert_eq!(
+        have: source_map.locate(&ContractProvenance::new(
+            "/templates/cm.yaml",
+            SourceSpan::new(64, 65),
+            Vec::new(),
+        )),
+        want: None
+    );
+    Ok(())
+}
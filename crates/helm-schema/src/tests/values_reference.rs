@@ -0,0 +1,96 @@
+use helm_schema_core::{ContractProvenance, SourceSpan, ValueKind, YamlPath};
+use test_util::prelude::sim_assert_eq;
+
+use super::*;
+
+fn read(path: &str, guards: Vec<Guard>) -> ContractUse {
+    ContractUse::with_provenances(
+        path.to_string(),
+        YamlPath::default(),
+        ValueKind::Scalar,
+        guards,
+        None,
+        [ContractProvenance::new(
+            "/templates/cm.yaml",
+            SourceSpan::new(0, 1),
+            Vec::new(),
+        )],
+    )
+}
+
+#[test]
+fn lists_leaves_with_defaults_descriptions_and_read_guards() {
+    let schema = serde_json::json!({
+        "type": "object",
+        "$defs": {
+            "resources": {
+                "type": "object",
+                "description": "Compute resources.",
+                "properties": { "limits": { "type": "object" } }
+            }
+        },
+        "properties": {
+            "image": {
+                "type": "object",
+                "required": ["tag"],
+                "properties": {
+                    "tag": { "type": "string", "description": "Image tag." },
+                    "pullPolicy": { "enum": ["Always", "IfNotPresent"], "type": "string" }
+                }
+            },
+            "resources": { "$ref": "#/$defs/resources" },
+            "host": { "anyOf": [{ "type": "string" }, { "type": "null" }] }
+        }
+    });
+    let defaults = serde_json::json!({
+        "image": { "tag": "1.0" },
+        "resources": {}
+    });
+    let enabled = Guard::Truthy {
+        path: "ingress.enabled".to_string(),
+    };
+    let uses = [
+        read("image.tag", Vec::new()),
+        read("host", vec![enabled.clone()]),
+    ];
+
+    let entries = build_values_reference(&schema, Some(&defaults), &uses);
+
+    sim_assert_eq!(
+        have: entries,
+        want: vec![
+            ValuesReferenceEntry {
+                path: "host".to_string(),
+                types: vec!["null".to_string(), "string".to_string()],
+                default: None,
+                description: None,
+                required: false,
+                conditions: vec![vec![enabled]],
+            },
+            ValuesReferenceEntry {
+                path: "image.pullPolicy".to_string(),
+                types: vec!["string".to_string()],
+                default: None,
+                description: None,
+                required: false,
+                conditions: Vec::new(),
+            },
+            ValuesReferenceEntry {
+                path: "image.tag".to_string(),
+                types: vec!["string".to_string()],
+                default: Some(serde_json::json!("1.0")),
+                description: Some("Image tag.".to_string()),
+                required: true,
+                conditions: Vec::new(),
+            },
+            ValuesReferenceEntry {
+                path: "resources".to_string(),
+                types: vec!["object".to_string()],
+                default: Some(serde_json::json!({})),
+                description: Some("Compute resources.".to_string()),
+                required: false,
+                conditions: Vec::new(),
+            },
+        ]
+    );
+}
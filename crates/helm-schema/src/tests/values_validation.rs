@@ -0,0 +1,176 @@
+use color_eyre::eyre;
+use helm_schema_core::{ContractProvenance, SourceSpan, ValueKind, YamlPath};
+use indoc::indoc;
+use test_util::prelude::sim_assert_eq;
+
+use super::*;
+
+fn schema() -> Value {
+    serde_json::json!({
+        "type": "object",
+        "additionalProperties": false,
+        "properties": {
+            "replicas": { "type": "integer" },
+            "image": {
+                "type": "object",
+                "required": ["tag"],
+                "properties": {
+                    "repository": { "type": "string" },
+                    "tag": { "type": "string" }
+                }
+            },
+            "ports": {
+                "type": "array",
+                "items": {
+                    "type": "object",
+                    "properties": { "port": { "type": "integer" } }
+                }
+            }
+        }
+    })
+}
+
+#[test]
+fn attributes_violations_to_the_overriding_values_file_line() -> eyre::Result<()> {
+    let defaults = "replicas: 1\nimage:\n  repository: nginx\n  tag: latest\n";
+    let documents = [
+        ValuesDocument {
+            name: "base.yaml".to_string(),
+            source: "replicas: two\n".to_string(),
+        },
+        ValuesDocument {
+            name: "prod.yaml".to_string(),
+            source: indoc! {"
+                image:
+                  tag: null
+                ports:
+                  - port: http
+                replica: 3
+            "}
+            .to_string(),
+        },
+    ];
+
+    let violations = validate_values(
+        &schema(),
+        Some(defaults),
+        Some(defaults),
+        &documents,
+        &SchemaSources::default(),
+    )?;
+
+    let summary: Vec<_> = violations
+        .iter()
+        .map(|violation| {
+            (
+                violation.path.as_str(),
+                violation.keyword.as_str(),
+                violation.file.as_deref(),
+                violation.line,
+            )
+        })
+        .collect();
+    sim_assert_eq!(
+        have: summary,
+        want: vec![
+            ("replicas", "type", Some("base.yaml"), Some(1)),
+            ("image", "required", Some("prod.yaml"), Some(1)),
+            ("ports.*.port", "type", Some("prod.yaml"), Some(4)),
+            ("replica", "additionalProperties", Some("prod.yaml"), Some(5)),
+        ]
+    );
+    Ok(())
+}
+
+#[test]
+fn empty_values_files_keep_chart_defaults() -> eyre::Result<()> {
+    let documents = [ValuesDocument {
+        name: "empty.yaml".to_string(),
+        source: String::new(),
+    }];
+
+    let violations = validate_values(
+        &schema(),
+        Some("replicas: 1\nimage:\n  tag: latest\n"),
+        None,
+        &documents,
+        &SchemaSources::default(),
+    )?;
+
+    sim_assert_eq!(have: violations, want: Vec::new());
+    Ok(())
+}
+
+#[test]
+fn invalid_yaml_names_the_values_file() {
+    let documents = [ValuesDocument {
+        name: "broken.yaml".to_string(),
+        source: "a: [".to_string(),
+    }];
+
+    let err = validate_values(&schema(), None, None, &documents, &SchemaSources::default())
+        .expect_err("broken YAML must fail");
+
+    assert!(
+        matches!(&err, CliError::InvalidValuesFile { path, .. } if path == "broken.yaml"),
+        "{err}"
+    );
+}
+
+#[test]
+fn reports_keys_the_contract_does_not_know() {
+    let read = |path: &str| {
+        ContractUse::with_provenances(
+            path.to_string(),
+            YamlPath::default(),
+            ValueKind::Scalar,
+            Vec::new(),
+            None,
+            [ContractProvenance::new(
+                "/templates/deployment.yaml",
+                SourceSpan::new(0, 1),
+                Vec::new(),
+            )],
+        )
+    };
+    let uses = [
+        read("image.tag"),
+        read("podAnnotations"),
+        read("ports.*.port"),
+        ContractUse::new(
+            "resources".to_string(),
+            YamlPath::default(),
+            ValueKind::Scalar,
+            Vec::new(),
+            None,
+        ),
+    ];
+    let declared = BTreeSet::from(["image".to_string(), "image.repository".to_string()]);
+    let source = indoc! {"
+        image:
+          repository: nginx
+          tga: latest
+        podAnnotations:
+          team: web
+        ports:
+          - port: 80
+            protocol: TCP
+        resources:
+          limits:
+            cpu: 1
+        replica: 3
+        extra:
+          nested: true
+    "};
+
+    sim_assert_eq!(
+        have: unknown_keys(source, &uses, &declared),
+        want: vec![
+            UnknownValuesKey { path: "image.tga".to_string(), line: 3 },
+            UnknownValuesKey { path: "ports.*.protocol".to_string(), line: 8 },
+            UnknownValuesKey { path: "resources.limits".to_string(), line: 10 },
+            UnknownValuesKey { path: "replica".to_string(), line: 12 },
+            UnknownValuesKey { path: "extra".to_string(), line: 13 },
+        ]
+    );
+}
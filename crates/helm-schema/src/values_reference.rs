@@ -0,0 +1,218 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use helm_schema_core::{Guard, append_value_path, split_value_path};
+use helm_schema_ir::ContractUse;
+use serde::Serialize;
+use serde_json::Value;
+
+/// Maximum `$ref` hops followed while resolving one schema node.
+const MAX_REF_DEPTH: usize = 32;
+
+/// One row of a chart's values reference.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ValuesReferenceEntry {
+    /// Dotted values path.
+    pub path: String,
+    /// JSON Schema types the emitted schema accepts; empty means any type.
+    pub types: Vec<String>,
+    /// Composed chart default; `None` when no `values.yaml` declares the
+    /// path and only templates read it.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default: Option<Value>,
+    /// Schema description, from values comments or provider schemas.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// Whether the parent object requires the key.
+    pub required: bool,
+    /// Guard conjunctions under which templates read the path; empty when a
+    /// template reads it unconditionally.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub conditions: Vec<Vec<Guard>>,
+}
+
+/// Builds the values reference rows from the emitted `schema`, the composed
+/// chart defaults, and the contract uses.
+///
+/// Rows cover the leaves of the values tree plus every intermediate path a
+/// template reads directly. Provider-resolved `$ref`s contribute type and
+/// description but are not expanded into rows, so a `resources` value stays
+/// one row instead of the whole Kubernetes `ResourceRequirements` tree.
+pub(crate) fn build_values_reference(
+    schema: &Value,
+    defaults: Option<&Value>,
+    uses: &[ContractUse],
+) -> Vec<ValuesReferenceEntry> {
+    let conditions = read_conditions(uses);
+    let mut entries = Vec::new();
+    let walker = Walker {
+        root: schema,
+        defaults,
+        conditions: &conditions,
+    };
+    walker.visit_children(schema, "", 0, &mut entries);
+    entries
+}
+
+struct Walker<'a> {
+    root: &'a Value,
+    defaults: Option<&'a Value>,
+    conditions: &'a BTreeMap<String, Vec<Vec<Guard>>>,
+}
+
+impl Walker<'_> {
+    fn visit_children(
+        &self,
+        node: &Value,
+        path: &str,
+        depth: usize,
+        entries: &mut Vec<ValuesReferenceEntry>,
+    ) {
+        if depth > MAX_REF_DEPTH {
+            return;
+        }
+        let Some(properties) = node.get("properties").and_then(Value::as_object) else {
+            return;
+        };
+        let required: BTreeSet<&str> = node
+            .get("required")
+            .and_then(Value::as_array)
+            .into_iter()
+            .flatten()
+            .filter_map(Value::as_str)
+            .collect();
+        for (name, child) in properties {
+            let child_path = append_value_path(path, name);
+            let resolved = resolve(self.root, child);
+            let expands = child.get("$ref").is_none()
+                && resolved
+                    .get("properties")
+                    .and_then(Value::as_object)
+                    .is_some_and(|properties| !properties.is_empty());
+            if !expands || self.conditions.contains_key(&child_path) {
+                entries.push(ValuesReferenceEntry {
+                    types: schema_types(self.root, child),
+                    default: self.default_at(&child_path),
+                    description: child
+                        .get("description")
+                        .or_else(|| resolved.get("description"))
+                        .and_then(Value::as_str)
+                        .map(str::to_string),
+                    required: required.contains(name.as_str()),
+                    conditions: self
+                        .conditions
+                        .get(&child_path)
+                        .cloned()
+                        .unwrap_or_default(),
+                    path: child_path.clone(),
+                });
+            }
+            if expands {
+                self.visit_children(resolved, &child_path, depth + 1, entries);
+            }
+        }
+    }
+
+    fn default_at(&self, path: &str) -> Option<Value> {
+        split_value_path(path)
+            .iter()
+            .try_fold(self.defaults?, |value, segment| value.get(segment))
+            .cloned()
+    }
+}
+
+/// Guard conjunctions per path read by at least one template; a path with an
+/// unconditional read maps to no conjunctions.
+fn read_conditions(uses: &[ContractUse]) -> BTreeMap<String, Vec<Vec<Guard>>> {
+    let mut conditions: BTreeMap<String, Option<BTreeSet<Vec<Guard>>>> = BTreeMap::new();
+    for use_ in uses.iter().filter(|use_| !use_.provenance.is_empty()) {
+        let entry = conditions
+            .entry(use_.source_expr.clone())
+            .or_insert_with(|| Some(BTreeSet::new()));
+        // Guards on the read path itself (`default`, `if .Values.x`
+        // around `.Values.x`) only restate that the value must be set.
+        let conjunctions: Vec<Vec<Guard>> = use_
+            .condition
+            .guard_conjunctions()
+            .into_iter()
+            .map(|conjunction| {
+                conjunction
+                    .into_iter()
+                    .filter(|guard| {
+                        guard
+                            .value_paths()
+                            .iter()
+                            .any(|path| *path != use_.source_expr)
+                    })
+                    .collect()
+            })
+            .collect();
+        if use_.condition.is_unconditional() || conjunctions.iter().any(Vec::is_empty) {
+            *entry = None;
+        } else if let Some(known) = entry {
+            known.extend(conjunctions);
+        }
+    }
+    conditions
+        .into_iter()
+        .map(|(path, conjunctions)| (path, conjunctions.into_iter().flatten().collect::<Vec<_>>()))
+        .collect()
+}
+
+fn resolve<'a>(root: &'a Value, mut node: &'a Value) -> &'a Value {
+    for _ in 0..MAX_REF_DEPTH {
+        let Some(target) = node
+            .get("$ref")
+            .and_then(Value::as_str)
+            .and_then(|reference| reference.strip_prefix('#'))
+            .and_then(|pointer| root.pointer(pointer))
+        else {
+            break;
+        };
+        node = target;
+    }
+    node
+}
+
+/// Declared types of a node, unioned over `anyOf`/`oneOf` branches when the
+/// node itself declares none.
+fn schema_types(root: &Value, node: &Value) -> Vec<String> {
+    let mut types = BTreeSet::new();
+    collect_types(root, node, 0, &mut types);
+    types.into_iter().collect()
+}
+
+fn collect_types(root: &Value, node: &Value, depth: usize, types: &mut BTreeSet<String>) {
+    if depth > MAX_REF_DEPTH {
+        return;
+    }
+    let node = resolve(root, node);
+    match node.get("type") {
+        Some(Value::String(ty)) => {
+            types.insert(ty.clone());
+        }
+        Some(Value::Array(declared)) => {
+            types.extend(
+                declared
+                    .iter()
+                    .filter_map(Value::as_str)
+                    .map(str::to_string),
+            );
+        }
+        _ => {
+            for keyword in ["anyOf", "oneOf"] {
+                for branch in node
+                    .get(keyword)
+                    .and_then(Value::as_array)
+                    .into_iter()
+                    .flatten()
+                {
+                    collect_types(root, branch, depth + 1, types);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+#[path = "tests/values_reference.rs"]
+mod tests;
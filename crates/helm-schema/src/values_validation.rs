@@ -0,0 +1,292 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use helm_schema_ast::extract_values_yaml_key_lines;
+use helm_schema_core::{join_value_path, split_value_path};
+use helm_schema_ir::ContractUse;
+use jsonschema::error::ValidationErrorKind;
+use jsonschema::paths::LocationSegment;
+use serde::Serialize;
+use serde_json::Value;
+use serde_yaml::Value as YamlValue;
+
+use crate::error::{CliError, EngineResult};
+use crate::output_pipeline::SchemaSources;
+use crate::source_map::SourceLocation;
+
+/// One values file supplied by the user, in `-f` precedence order.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ValuesDocument {
+    /// Display name of the file, used in violation locations.
+    pub name: String,
+    /// Raw YAML source.
+    pub source: String,
+}
+
+/// One schema violation of the coalesced chart values.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ValuesViolation {
+    /// Dotted values path of the offending value; empty for the root.
+    pub path: String,
+    /// JSON Schema keyword that rejected the value.
+    pub keyword: String,
+    /// Validator message.
+    pub message: String,
+    /// Values file that supplied the offending value, when a file declares it.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub file: Option<String>,
+    /// One-based line of the offending key in `file`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub line: Option<usize>,
+    /// Template locations that read the path or its nearest read ancestor.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub template_sources: Vec<SourceLocation>,
+}
+
+/// A values-file key the chart's contract does not account for.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct UnknownValuesKey {
+    /// Dotted values path of the key; `*` stands for sequence items.
+    pub path: String,
+    /// One-based line of the key.
+    pub line: usize,
+}
+
+/// Values-file side of a violation lookup, in ascending precedence.
+struct KeyedDocument {
+    name: String,
+    key_lines: BTreeMap<String, usize>,
+}
+
+/// Coalesces `documents` over the chart defaults the way Helm does, validates
+/// the result against `schema`, and attributes each violation to the values
+/// file and template locations behind it.
+///
+/// `chart_values` is the root chart's own `values.yaml`; it only attributes
+/// violations of values no user file overrides.
+pub(crate) fn validate_values(
+    schema: &Value,
+    defaults_yaml: Option<&str>,
+    chart_values: Option<&str>,
+    documents: &[ValuesDocument],
+    sources: &SchemaSources,
+) -> EngineResult<Vec<ValuesViolation>> {
+    let mut values = match defaults_yaml {
+        Some(defaults) => serde_yaml::from_str(defaults)?,
+        None => YamlValue::Mapping(serde_yaml::Mapping::new()),
+    };
+    let mut keyed = Vec::with_capacity(documents.len() + 1);
+    if let Some(chart_values) = chart_values {
+        keyed.push(KeyedDocument {
+            name: "values.yaml".to_string(),
+            key_lines: extract_values_yaml_key_lines(chart_values),
+        });
+    }
+    for document in documents {
+        let overlay: YamlValue = serde_yaml::from_str(&document.source).map_err(|source| {
+            CliError::InvalidValuesFile {
+                path: document.name.clone(),
+                source,
+            }
+        })?;
+        if !overlay.is_null() {
+            coalesce_user_values(&mut values, overlay);
+        }
+        keyed.push(KeyedDocument {
+            name: document.name.clone(),
+            key_lines: extract_values_yaml_key_lines(&document.source),
+        });
+    }
+    let instance = serde_json::to_value(&values)?;
+
+    let validator = jsonschema::validator_for(schema)
+        .map_err(|err| CliError::InvalidValidationSchema(err.to_string()))?;
+    let mut violations = Vec::new();
+    for error in validator.iter_errors(&instance) {
+        let segments: Vec<Segment> = error
+            .instance_path()
+            .iter()
+            .map(|segment| match segment {
+                LocationSegment::Property(name) => Segment::Key(name.into_owned()),
+                LocationSegment::Index(_) => Segment::Item,
+            })
+            .collect();
+        let keyword = error.kind().keyword().to_string();
+        if let ValidationErrorKind::AdditionalProperties { unexpected } = error.kind() {
+            for key in unexpected {
+                let mut segments = segments.clone();
+                segments.push(Segment::Key(key.clone()));
+                violations.push(violation(
+                    &segments,
+                    keyword.clone(),
+                    format!("unknown key {key:?}"),
+                    &keyed,
+                    sources,
+                ));
+            }
+            continue;
+        }
+        violations.push(violation(
+            &segments,
+            keyword,
+            error.to_string(),
+            &keyed,
+            sources,
+        ));
+    }
+    violations.sort_by(|left, right| {
+        (&left.file, left.line, &left.path).cmp(&(&right.file, right.line, &right.path))
+    });
+    violations.dedup();
+    Ok(violations)
+}
+
+/// Keys of the values file `source` that the contract does not know, in
+/// source order; keys below an unknown key are not reported again.
+///
+/// A key is known when a contract use or a `declared` default sits at or
+/// below it, or when a template reads a value above it as a whole.
+pub(crate) fn unknown_keys(
+    source: &str,
+    uses: &[ContractUse],
+    declared: &BTreeSet<String>,
+) -> Vec<UnknownValuesKey> {
+    let known: Vec<Vec<String>> = uses
+        .iter()
+        .map(|use_| use_.source_expr.as_str())
+        .chain(declared.iter().map(String::as_str))
+        .map(split_value_path)
+        .collect();
+    let read: Vec<Vec<String>> = uses
+        .iter()
+        .filter(|use_| !use_.provenance.is_empty())
+        .map(|use_| split_value_path(&use_.source_expr))
+        .collect();
+    let mut unknown: Vec<(Vec<String>, usize)> = Vec::new();
+    for (key, line) in extract_values_yaml_key_lines(source) {
+        let segments = split_value_path(&key.replace("[*]", ".*"));
+        let below_unknown = unknown
+            .iter()
+            .any(|(reported, _)| segments.starts_with(reported));
+        let is_known = known.iter().any(|path| is_path_prefix(&segments, path))
+            || read.iter().any(|path| is_path_prefix(path, &segments));
+        if !below_unknown && !is_known {
+            unknown.push((segments, line));
+        }
+    }
+    unknown.sort_by_key(|(_, line)| *line);
+    unknown
+        .into_iter()
+        .map(|(segments, line)| UnknownValuesKey {
+            path: join_value_path(segments),
+            line,
+        })
+        .collect()
+}
+
+/// Whether `prefix` leads `path`, with `*` matching any one segment on
+/// either side.
+fn is_path_prefix(prefix: &[String], path: &[String]) -> bool {
+    prefix.len() <= path.len()
+        && prefix
+            .iter()
+            .zip(path)
+            .all(|(left, right)| left == right || left == "*" || right == "*")
+}
+
+#[derive(Debug, Clone)]
+enum Segment {
+    Key(String),
+    Item,
+}
+
+fn violation(
+    segments: &[Segment],
+    keyword: String,
+    message: String,
+    keyed: &[KeyedDocument],
+    sources: &SchemaSources,
+) -> ValuesViolation {
+    let (file, line) = values_file_location(segments, keyed).unzip();
+    ValuesViolation {
+        path: join_value_path(contract_segments(segments)),
+        keyword,
+        message,
+        file,
+        line,
+        template_sources: template_sources(segments, sources),
+    }
+}
+
+/// Finds the highest-precedence file declaring the path, falling back to the
+/// nearest declared ancestor (a `required` violation names the parent).
+fn values_file_location(segments: &[Segment], keyed: &[KeyedDocument]) -> Option<(String, usize)> {
+    (0..=segments.len()).rev().find_map(|len| {
+        let key = values_file_key(segments.get(..len)?);
+        keyed.iter().rev().find_map(|document| {
+            document
+                .key_lines
+                .get(&key)
+                .map(|line| (document.name.clone(), *line))
+        })
+    })
+}
+
+fn template_sources(segments: &[Segment], sources: &SchemaSources) -> Vec<SourceLocation> {
+    let segments = contract_segments(segments);
+    (0..=segments.len())
+        .rev()
+        .find_map(|len| sources.locations(&join_value_path(segments.get(..len)?)))
+        .map(|locations| locations.iter().cloned().collect())
+        .unwrap_or_default()
+}
+
+/// Contract spelling of an instance path: sequence items become `*`.
+fn contract_segments(segments: &[Segment]) -> Vec<&str> {
+    segments
+        .iter()
+        .map(|segment| match segment {
+            Segment::Key(key) => key.as_str(),
+            Segment::Item => "*",
+        })
+        .collect()
+}
+
+/// Values-file spelling of an instance path: sequence items suffix `[*]`.
+fn values_file_key(segments: &[Segment]) -> String {
+    let mut keys: Vec<String> = Vec::new();
+    for segment in segments {
+        match (segment, keys.last_mut()) {
+            (Segment::Item, Some(last)) => {
+                if !last.ends_with("[*]") {
+                    last.push_str("[*]");
+                }
+            }
+            (Segment::Item, None) => keys.push("*".to_string()),
+            (Segment::Key(key), _) => keys.push(key.clone()),
+        }
+    }
+    join_value_path(keys)
+}
+
+/// Helm's user-values coalescing: mappings merge recursively, an explicit
+/// `null` deletes the default, and any other value replaces it.
+fn coalesce_user_values(base: &mut YamlValue, overlay: YamlValue) {
+    match (base, overlay) {
+        (YamlValue::Mapping(base), YamlValue::Mapping(overlay)) => {
+            for (key, value) in overlay {
+                if value.is_null() {
+                    base.remove(&key);
+                } else if let Some(existing) = base.get_mut(&key) {
+                    coalesce_user_values(existing, value);
+                } else {
+                    base.insert(key, value);
+                }
+            }
+        }
+        (base, overlay) => *base = overlay,
+    }
+}
+
+#[cfg(test)]
+#[path = "tests/values_validation.rs"]
+mod tests;
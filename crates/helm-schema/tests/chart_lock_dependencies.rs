@@ -0,0 +1,289 @@
+//! Dependencies declared in Chart.yaml but not vendored under `charts/` load
+//! from their `file://` directory or from the local Helm repository cache at
+//! their Chart.lock version, and a missing archive surfaces as a diagnostic
+//! unless the dependency's condition disables it.
+
+use std::path::Path;
+
+use color_eyre::eyre;
+use helm_schema::AnalysisSession;
+use helm_schema::chart_source::ChartRepositoryCache;
+use helm_schema::diagnostics::{Diagnostic, DiagnosticSink};
+use helm_schema::generation::{EnumInference, GenerateOptions, SchemaProfile};
+use helm_schema::provider::ProviderOptions;
+use indoc::indoc;
+use test_util::prelude::sim_assert_eq;
+use vfs::VfsPath;
+
+const CHART_YAML: &str = indoc! {"
+    apiVersion: v2
+    name: root
+    version: 0.1.0
+    dependencies:
+      - name: cache
+        version: ~1.2.0
+        repository: https://charts.example.com
+        alias: redis
+"};
+
+const CHART_LOCK: &str = indoc! {"
+    dependencies:
+      - name: cache
+        repository: https://charts.example.com
+        version: 1.2.3
+    digest: sha256:0000000000000000000000000000000000000000000000000000000000000000
+    generated: \"2026-01-01T00:00:00Z\"
+"};
+
+fn build_chart() -> eyre::Result<VfsPath> {
+    chart_with(&[
+        ("Chart.yaml", CHART_YAML),
+        ("Chart.lock", CHART_LOCK),
+        ("values.yaml", "{}\n"),
+    ])
+}
+
+fn chart_with(files: &[(&str, &str)]) -> eyre::Result<VfsPath> {
+    let chart_dir = VfsPath::new(vfs::MemoryFS::new());
+    for (path, source) in files {
+        test_util::write(&chart_dir.join(path)?, source)?;
+    }
+    Ok(chart_dir)
+}
+
+/// A packaged `cache` chart whose template ranges over `.Values.ports`.
+fn dependency_archive() -> eyre::Result<Vec<u8>> {
+    let files = [
+        (
+            "cache/Chart.yaml",
+            "apiVersion: v2\nname: cache\nversion: 1.2.3\n",
+        ),
+        ("cache/values.yaml", "ports: []\n"),
+        (
+            "cache/templates/configmap.yaml",
+            indoc! {r#"
+                apiVersion: v1
+                kind: ConfigMap
+                metadata:
+                  name: cache
+                data:
+                  {{- range .Values.ports }}
+                  port-{{ . }}: "open"
+                  {{- end }}
+            "#},
+        ),
+    ];
+    let mut bytes = Vec::new();
+    {
+        let encoder = flate2::write::GzEncoder::new(&mut bytes, flate2::Compression::default());
+        let mut builder = tar::Builder::new(encoder);
+        for (path, source) in files {
+            let mut header = tar::Header::new_gnu();
+            header.set_size(source.len() as u64);
+            header.set_mode(0o644);
+            header.set_cksum();
+            builder.append_data(&mut header, path, source.as_bytes())?;
+        }
+        builder.into_inner()?.finish()?;
+    }
+    Ok(bytes)
+}
+
+fn session(
+    chart_dir: VfsPath,
+    repository_cache: &Path,
+    diagnostics: &DiagnosticSink,
+) -> AnalysisSession {
+    let opts = GenerateOptions {
+        chart_dir,
+        include_tests: false,
+        include_subchart_values: true,
+        values_files: Vec::new(),
+        infer_required: false,
+        infer_enums: EnumInference::Off,
+        cluster_state: None,
+        emission: SchemaProfile::default().into(),
+        provider: ProviderOptions {
+            k8s_versions: vec!["v1.35.0".to_string()],
+            allow_net: false,
+            k8s_schema_cache_dir: Some(test_util::cold_provider_cache_root("k8s")),
+            crd_catalog_cache_dir: Some(test_util::cold_provider_cache_root("crd")),
+            disable_k8s_schemas: true,
+            ..Default::default()
+        },
+    };
+    AnalysisSession::with_diagnostics(opts, diagnostics.clone())
+        .with_chart_repository_cache(ChartRepositoryCache::new(repository_cache))
+}
+
+#[test]
+fn locked_dependency_loads_from_the_repository_cache() -> eyre::Result<()> {
+    let repository_cache = tempfile::tempdir()?;
+    std::fs::write(
+        repository_cache.path().join("cache-1.2.3.tgz"),
+        dependency_archive()?,
+    )?;
+    let diagnostics = DiagnosticSink::new();
+    let schema = session(build_chart()?, repository_cache.path(), &diagnostics)
+        .generated_schema()?
+        .schema;
+
+    // The archive's template is analyzed under the dependency's alias.
+    let port_types = schema
+        .pointer("/allOf/0/properties/redis/properties/ports/type")
+        .and_then(serde_json::Value::as_array);
+    assert!(
+        port_types.is_some_and(|types| types.contains(&serde_json::json!("array"))),
+        "redis.ports must carry the cached chart's range evidence: {schema:#}"
+    );
+    assert!(
+        !diagnostics
+            .snapshot()
+            .iter()
+            .any(|diagnostic| matches!(diagnostic, Diagnostic::UnresolvedChartDependency { .. })),
+        "a cached dependency is resolved"
+    );
+    Ok(())
+}
+
+#[test]
+fn missing_archive_reports_the_unresolved_dependency() -> eyre::Result<()> {
+    let repository_cache = tempfile::tempdir()?;
+    let diagnostics = DiagnosticSink::new();
+    let schema = session(build_chart()?, repository_cache.path(), &diagnostics)
+        .generated_schema()?
+        .schema;
+
+    sim_assert_eq!(have: schema.pointer("/properties/redis"), want: None);
+    sim_assert_eq!(
+        have: diagnostics.snapshot(),
+        want: vec![Diagnostic::UnresolvedChartDependency {
+            chart: "root".to_string(),
+            dependency: "cache".to_string(),
+            version: Some("1.2.3".to_string()),
+            repository: Some("https://charts.example.com".to_string()),
+            locations_tried: vec![
+                repository_cache
+                    .path()
+                    .join("cache-1.2.3.tgz")
+                    .display()
+                    .to_string()
+            ],
+        }]
+    );
+    Ok(())
+}
+
+#[test]
+fn dependency_disabled_by_its_condition_is_not_reported() -> eyre::Result<()> {
+    let repository_cache = tempfile::tempdir()?;
+    let diagnostics = DiagnosticSink::new();
+    let conditional_chart_yaml = CHART_YAML.replace(
+        "    alias: redis\n",
+        "    alias: redis\n    condition: redis.enabled\n",
+    );
+    let chart = chart_with(&[
+        ("Chart.yaml", &conditional_chart_yaml),
+        ("Chart.lock", CHART_LOCK),
+        ("values.yaml", "redis:\n  enabled: false\n"),
+    ])?;
+    session(chart, repository_cache.path(), &diagnostics).generated_schema()?;
+
+    sim_assert_eq!(have: diagnostics.snapshot(), want: vec![]);
+    Ok(())
+}
+
+#[test]
+fn malformed_chart_lock_is_reported_instead_of_failing() -> eyre::Result<()> {
+    let repository_cache = tempfile::tempdir()?;
+    let diagnostics = DiagnosticSink::new();
+    let chart = chart_with(&[
+        ("Chart.yaml", CHART_YAML),
+        ("Chart.lock", "dependencies: cache\n"),
+        ("values.yaml", "{}\n"),
+    ])?;
+    session(chart, repository_cache.path(), &diagnostics).generated_schema()?;
+
+    let snapshot = diagnostics.snapshot();
+    assert!(
+        snapshot.iter().any(|diagnostic| matches!(
+            diagnostic,
+            Diagnostic::UnreadableChartLock { chart, path, .. }
+                if chart == "root" && path.ends_with("Chart.lock")
+        )),
+        "the malformed lock file is reported: {snapshot:?}"
+    );
+    // Without a readable lock, the `~1.2.0` range pins no archive.
+    assert!(
+        snapshot.iter().any(|diagnostic| matches!(
+            diagnostic,
+            Diagnostic::UnresolvedChartDependency { version: None, .. }
+        )),
+        "the dependency stays unresolved: {snapshot:?}"
+    );
+    Ok(())
+}
+
+#[test]
+fn file_dependency_resolves_relative_to_the_chart() -> eyre::Result<()> {
+    let workspace = tempfile::tempdir()?;
+    let app = workspace.path().join("app");
+    let common = workspace.path().join("common");
+    std::fs::create_dir_all(&app)?;
+    std::fs::create_dir_all(common.join("templates"))?;
+    std::fs::write(
+        app.join("Chart.yaml"),
+        indoc! {"
+            apiVersion: v2
+            name: app
+            version: 0.1.0
+            dependencies:
+              - name: common
+                version: 0.1.0
+                repository: file://../common
+        "},
+    )?;
+    std::fs::write(app.join("values.yaml"), "{}\n")?;
+    std::fs::write(
+        common.join("Chart.yaml"),
+        "apiVersion: v2\nname: common\nversion: 0.1.0\n",
+    )?;
+    std::fs::write(common.join("values.yaml"), "ports: []\n")?;
+    std::fs::write(
+        common.join("templates/configmap.yaml"),
+        indoc! {r#"
+            apiVersion: v1
+            kind: ConfigMap
+            metadata:
+              name: common
+            data:
+              {{- range .Values.ports }}
+              port-{{ . }}: "open"
+              {{- end }}
+        "#},
+    )?;
+
+    let repository_cache = tempfile::tempdir()?;
+    let diagnostics = DiagnosticSink::new();
+    let chart_dir = VfsPath::new(vfs::PhysicalFS::new(&app));
+    let schema = session(chart_dir, repository_cache.path(), &diagnostics)
+        .with_chart_location(&app)
+        .generated_schema()?
+        .schema;
+
+    let port_types = schema
+        .pointer("/allOf/0/properties/common/properties/ports/type")
+        .and_then(serde_json::Value::as_array);
+    assert!(
+        port_types.is_some_and(|types| types.contains(&serde_json::json!("array"))),
+        "common.ports must carry the sibling chart's range evidence: {schema:#}"
+    );
+    assert!(
+        !diagnostics
+            .snapshot()
+            .iter()
+            .any(|diagnostic| matches!(diagnostic, Diagnostic::UnresolvedChartDependency { .. })),
+        "a file:// dependency is resolved"
+    );
+    Ok(())
+}
@@ -5,7 +5,7 @@ use std::path::{Component, Path};
 use color_eyre::eyre::{self, WrapErr as _};
 use flate2::read::GzDecoder;
 use helm_schema::AnalysisSession;
-use helm_schema::generation::{GenerateOptions, GeneratedSchema, SchemaProfile};
+use helm_schema::generation::{EnumInference, GenerateOptions, GeneratedSchema, SchemaProfile};
 use helm_schema::provider::ProviderOptions;
 use jsonschema::Validator;
 use serde::{Deserialize, Serialize};
@@ -926,6 +926,8 @@ fn generate_options(
         include_subchart_values: true,
         values_files: Vec::new(),
         infer_required,
+        infer_enums: EnumInference::Off,
+        cluster_state: None,
         emission: profile.into(),
         provider: ProviderOptions {
             k8s_versions: vec!["v1.29.0-standalone-strict".to_string()],
@@ -8,7 +8,7 @@
 
 use color_eyre::eyre;
 use helm_schema::AnalysisSession;
-use helm_schema::generation::{GenerateOptions, SchemaProfile};
+use helm_schema::generation::{EnumInference, GenerateOptions, SchemaProfile};
 use helm_schema::provider::ProviderOptions;
 use indoc::indoc;
 use test_util::prelude::sim_assert_eq;
@@ -76,6 +76,8 @@ fn schema_for(chart_dir: VfsPath) -> eyre::Result<serde_json::Value> {
         include_subchart_values: true,
         values_files: Vec::new(),
         infer_required: false,
+        infer_enums: EnumInference::Off,
+        cluster_state: None,
         emission: SchemaProfile::default().into(),
         provider: ProviderOptions {
             k8s_versions: vec!["v1.35.0".to_string()],
@@ -3,10 +3,11 @@
 use std::path::Path;
 
 use color_eyre::eyre::{self, WrapErr as _};
-use helm_schema::generation::{GenerateOptions, SchemaProfile};
+use helm_schema::diff::{SchemaChange, diff_schemas};
+use helm_schema::generation::{EnumInference, GenerateOptions, SchemaProfile};
 use helm_schema::output::{
     EmitRequest, FetchPolicy, LoadBudget, OutputPipelineOptions, PolicyInputOptions,
-    ReferencePolicy,
+    ReferencePolicy, SchemaDialect,
 };
 use helm_schema::provider::ProviderOptions;
 use serde_json::{Value, json};
@@ -32,6 +33,17 @@ fn final_outputs_match_policy_annotation_fixtures() -> eyre::Result<()> {
 
     let repeated = lean_session.emit(emit_request(ReferencePolicy::SelfContained))?;
     sim_assert_eq!(have: repeated, want: lean);
+
+    // The lean profile may only widen the full one, so the diff between the
+    // two never breaks an override; the way back adds rejections.
+    let breaking = |old: &Value, new: &Value| -> Vec<SchemaChange> {
+        diff_schemas(old, new)
+            .into_iter()
+            .filter(|change| change.kind.is_breaking())
+            .collect()
+    };
+    sim_assert_eq!(have: breaking(&full, &lean), want: Vec::new());
+    assert!(!breaking(&lean, &full).is_empty());
     Ok(())
 }
 
@@ -144,6 +156,8 @@ fn emit_request(reference_policy: ReferencePolicy) -> EmitRequest {
         output: OutputPipelineOptions {
             strip_descriptions: false,
             minimize: true,
+            source_annotations: false,
+            dialect: SchemaDialect::Draft07,
         },
     }
 }
@@ -165,6 +179,8 @@ fn profile_session_at(
         include_subchart_values: true,
         values_files: Vec::new(),
         infer_required,
+        infer_enums: EnumInference::Off,
+        cluster_state: None,
         emission: profile.into(),
         provider: ProviderOptions {
             k8s_versions: vec!["v1.29.0-standalone-strict".to_string()],
@@ -203,6 +219,18 @@ fn assert_fixture(name: &str, actual: &Value) -> eyre::Result<()> {
             .wrap_err_with(|| format!("read {}", fixture_path.display()))?,
     )
     .wrap_err_with(|| format!("parse {}", fixture_path.display()))?;
+    // The generator version moves with every release, so fixtures leave it
+    // out and it is checked against this build's version instead.
+    let mut expected = expected;
+    if let Some(annotation) = expected
+        .get_mut("x-helm-schema-policy")
+        .and_then(Value::as_object_mut)
+    {
+        annotation.insert(
+            "generator-version".to_string(),
+            Value::String(env!("CARGO_PKG_VERSION").to_string()),
+        );
+    }
     sim_assert_eq!(have: actual, want: &expected, "{name}: final output fixture mismatch");
     Ok(())
 }
@@ -0,0 +1,129 @@
+//! Chart.yaml `import-values` surface a dependency's tables at the parent
+//! path, with the dependency templates' type evidence and defaults.
+
+use color_eyre::eyre;
+use helm_schema::AnalysisSession;
+use helm_schema::generation::{EnumInference, GenerateOptions, SchemaProfile};
+use helm_schema::provider::ProviderOptions;
+use indoc::indoc;
+use serde_json::json;
+use test_util::prelude::sim_assert_eq;
+use vfs::VfsPath;
+
+fn build_chart() -> eyre::Result<VfsPath> {
+    let chart_dir = VfsPath::new(vfs::MemoryFS::new());
+    let files = [
+        (
+            "Chart.yaml",
+            indoc! {"
+                apiVersion: v2
+                name: root
+                version: 0.1.0
+                dependencies:
+                  - name: child
+                    alias: backend
+                    import-values:
+                      - data
+                      - child: service
+                        parent: backendService
+            "},
+        ),
+        ("values.yaml", "{}\n"),
+        (
+            "charts/child/Chart.yaml",
+            indoc! {"
+                apiVersion: v2
+                name: child
+                version: 0.1.0
+            "},
+        ),
+        (
+            "charts/child/values.yaml",
+            indoc! {"
+                exports:
+                  data:
+                    # -- Labels added to the child's config map.
+                    labels:
+                      tier: backend
+                service:
+                  ports: []
+            "},
+        ),
+        (
+            "charts/child/templates/configmap.yaml",
+            indoc! {r#"
+                apiVersion: v1
+                kind: ConfigMap
+                metadata:
+                  name: child
+                data:
+                  {{- range $key, $value := .Values.exports.data.labels }}
+                  {{ $key }}: {{ $value | quote }}
+                  {{- end }}
+                  {{- range .Values.service.ports }}
+                  port-{{ . }}: "open"
+                  {{- end }}
+            "#},
+        ),
+    ];
+    for (path, source) in files {
+        test_util::write(&chart_dir.join(path)?, source)?;
+    }
+    Ok(chart_dir)
+}
+
+fn schema_for(chart_dir: VfsPath) -> eyre::Result<serde_json::Value> {
+    let opts = GenerateOptions {
+        chart_dir,
+        include_tests: false,
+        include_subchart_values: true,
+        values_files: Vec::new(),
+        infer_required: false,
+        infer_enums: EnumInference::Off,
+        cluster_state: None,
+        emission: SchemaProfile::default().into(),
+        provider: ProviderOptions {
+            k8s_versions: vec!["v1.35.0".to_string()],
+            allow_net: false,
+            k8s_schema_cache_dir: Some(test_util::cold_provider_cache_root("k8s")),
+            crd_catalog_cache_dir: Some(test_util::cold_provider_cache_root("crd")),
+            disable_k8s_schemas: true,
+            ..Default::default()
+        },
+    };
+    AnalysisSession::new(opts)
+        .generated_schema()
+        .map(|generated| generated.schema)
+        .map_err(eyre::Report::from)
+}
+
+#[test]
+fn imported_tables_are_emitted_at_the_parent_path() -> eyre::Result<()> {
+    let schema = schema_for(build_chart()?)?;
+
+    // The ranged `service.ports` evidence of the child's template types
+    // the imported `backendService.ports`, which no parent template reads.
+    let ports_arms = schema
+        .pointer("/properties/backendService/anyOf/0/properties/ports/anyOf")
+        .and_then(serde_json::Value::as_array);
+    assert!(
+        ports_arms.is_some_and(|arms| arms
+            .iter()
+            .any(|arm| { arm.get("type") == Some(&json!("array")) && arm.get("items").is_some() })),
+        "backendService.ports must carry the child's range evidence: {schema:#}"
+    );
+
+    // The exported `data` table lands at the parent root with its composed
+    // default keys and values-file description.
+    sim_assert_eq!(
+        have: schema.pointer("/properties/labels/anyOf/0/properties/tier/type"),
+        want: Some(&json!("string"))
+    );
+    sim_assert_eq!(
+        have: schema
+            .pointer("/properties/labels/description")
+            .and_then(serde_json::Value::as_str),
+        want: Some("Labels added to the child's config map.")
+    );
+    Ok(())
+}
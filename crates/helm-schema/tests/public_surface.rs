@@ -2,10 +2,12 @@
 
 use color_eyre::eyre;
 use helm_schema::generation::{
-    ConditionalAnchors, EmissionPolicy, EmissionPolicyDelta, EmissionSelection, GenerateOptions,
-    SchemaProfile,
+    ConditionalAnchors, EmissionPolicy, EmissionPolicyDelta, EmissionSelection, EnumInference,
+    GenerateOptions, SchemaProfile,
+};
+use helm_schema::output::{
+    EmitRequest, JsonOutputFormat, OutputPipelineOptions, ReferencePolicy, SchemaDialect,
 };
-use helm_schema::output::{EmitRequest, JsonOutputFormat, OutputPipelineOptions, ReferencePolicy};
 use helm_schema::provider::{K8sVersionChain, ProviderOptions};
 use helm_schema::{
     AnalysisSession,
@@ -116,6 +118,8 @@ fn facade_generates_schema_for_memory_chart() -> eyre::Result<()> {
         include_subchart_values: true,
         values_files: Vec::new(),
         infer_required: false,
+        infer_enums: EnumInference::Off,
+        cluster_state: None,
         emission: SchemaProfile::default().into(),
         provider: ProviderOptions {
             k8s_versions: vec!["v1.35.0".to_string()],
@@ -195,6 +199,8 @@ fn split_call_and_pipeline_emit_the_same_nil_strict_schema() -> eyre::Result<()>
         include_subchart_values: true,
         values_files: Vec::new(),
         infer_required: false,
+        infer_enums: EnumInference::Off,
+        cluster_state: None,
         emission: SchemaProfile::default().into(),
         provider: ProviderOptions {
             k8s_versions: vec!["v1.35.0".to_string()],
@@ -292,6 +298,8 @@ fn analysis_session_exposes_contract_and_generated_schema() -> eyre::Result<()>
         include_subchart_values: true,
         values_files: Vec::new(),
         infer_required: false,
+        infer_enums: EnumInference::Off,
+        cluster_state: None,
         emission: SchemaProfile::default().into(),
         provider: ProviderOptions {
             k8s_versions: vec!["v1.35.0".to_string()],
@@ -402,6 +410,8 @@ fn deployment_security_context_fragments_keep_nested_provider_paths() -> eyre::R
         include_subchart_values: true,
         values_files: Vec::new(),
         infer_required: false,
+        infer_enums: EnumInference::Off,
+        cluster_state: None,
         emission: SchemaProfile::default().into(),
         provider: ProviderOptions {
             k8s_versions: vec!["v1.35.0".to_string()],
@@ -518,6 +528,8 @@ fn contract_document_is_byte_deterministic_across_100_runs() -> eyre::Result<()>
         include_subchart_values: true,
         values_files: Vec::new(),
         infer_required: false,
+        infer_enums: EnumInference::Off,
+        cluster_state: None,
         emission: SchemaProfile::default().into(),
         provider: ProviderOptions {
             k8s_versions: vec!["v1.35.0".to_string()],
@@ -569,6 +581,8 @@ fn stage_functions_match_session_generated_schema() -> eyre::Result<()> {
         include_subchart_values: true,
         values_files: Vec::new(),
         infer_required: false,
+        infer_enums: EnumInference::Off,
+        cluster_state: None,
         emission: SchemaProfile::default().into(),
         provider: ProviderOptions {
             k8s_versions: vec!["v1.35.0".to_string()],
@@ -646,6 +660,8 @@ fn analysis_session_exposes_resolved_contract_before_required_inference() -> eyr
         include_subchart_values: true,
         values_files: Vec::new(),
         infer_required: true,
+        infer_enums: EnumInference::Off,
+        cluster_state: None,
         emission: SchemaProfile::default().into(),
         provider: ProviderOptions {
             k8s_versions: vec!["v1.35.0".to_string()],
@@ -776,6 +792,8 @@ fn analysis_session_emits_final_schema_through_output_pipeline() -> eyre::Result
         include_subchart_values: true,
         values_files: Vec::new(),
         infer_required: false,
+        infer_enums: EnumInference::Off,
+        cluster_state: None,
         emission: SchemaProfile::default().into(),
         provider: ProviderOptions {
             k8s_versions: vec!["v1.35.0".to_string()],
@@ -792,6 +810,8 @@ fn analysis_session_emits_final_schema_through_output_pipeline() -> eyre::Result
         output: OutputPipelineOptions {
             strip_descriptions: false,
             minimize: false,
+            source_annotations: false,
+            dialect: SchemaDialect::Draft07,
         },
     })?;
 
@@ -811,6 +831,75 @@ fn analysis_session_emits_final_schema_through_output_pipeline() -> eyre::Result
     Ok(())
 }
 
+#[test]
+fn analysis_session_annotates_properties_with_template_sources() -> eyre::Result<()> {
+    let chart_dir = VfsPath::new(vfs::MemoryFS::new());
+    test_util::write(
+        &chart_dir.join("Chart.yaml")?,
+        "apiVersion: v2\nname: root\nversion: 0.1.0\n",
+    )?;
+    test_util::write(&chart_dir.join("values.yaml")?, "name: root\n")?;
+    test_util::write(
+        &chart_dir.join("templates/_helpers.tpl")?,
+        indoc! {r#"
+            {{- define "root.name" -}}
+            {{ .Values.name }}
+            {{- end -}}
+        "#},
+    )?;
+    test_util::write(
+        &chart_dir.join("templates/configmap.yaml")?,
+        indoc! {r#"
+            apiVersion: v1
+            kind: ConfigMap
+            metadata:
+              name: root
+            data:
+              name: {{ include "root.name" . }}
+        "#},
+    )?;
+
+    let session = AnalysisSession::new(GenerateOptions {
+        chart_dir,
+        include_tests: false,
+        include_subchart_values: true,
+        values_files: Vec::new(),
+        infer_required: false,
+        infer_enums: EnumInference::Off,
+        cluster_state: None,
+        emission: SchemaProfile::default().into(),
+        provider: ProviderOptions {
+            allow_net: false,
+            disable_k8s_schemas: true,
+            ..Default::default()
+        },
+    });
+    let request = |source_annotations| EmitRequest {
+        reference_policy: ReferencePolicy::SelfContained,
+        output: OutputPipelineOptions {
+            strip_descriptions: false,
+            minimize: true,
+            source_annotations,
+            dialect: SchemaDialect::Draft07,
+        },
+    };
+
+    let plain = session.emit(request(false))?;
+    sim_assert_eq!(
+        have: plain.pointer("/properties/name/x-helm-schema-sources"),
+        want: None
+    );
+    let annotated = session.emit(request(true))?;
+    sim_assert_eq!(
+        have: annotated.pointer("/properties/name/x-helm-schema-sources"),
+        want: Some(&json!([
+            { "file": "templates/_helpers.tpl", "line": 2, "column": 1, "helper_chain": ["root.name"] },
+            { "file": "templates/configmap.yaml", "line": 6, "column": 9 }
+        ]))
+    );
+    Ok(())
+}
+
 #[test]
 #[expect(
     clippy::too_many_lines,
@@ -865,6 +954,8 @@ fn analysis_session_explains_values_path() -> eyre::Result<()> {
         include_subchart_values: true,
         values_files: Vec::new(),
         infer_required: false,
+        infer_enums: EnumInference::Off,
+        cluster_state: None,
         emission: SchemaProfile::default().into(),
         provider: ProviderOptions {
             k8s_versions: vec!["v1.35.0".to_string()],
@@ -987,6 +1078,8 @@ fn contract_document_json_round_trip_preserves_provenance_and_guards() -> eyre::
             include_subchart_values: true,
             values_files: Vec::new(),
             infer_required: false,
+            infer_enums: EnumInference::Off,
+            cluster_state: None,
             emission: SchemaProfile::default().into(),
             provider: ProviderOptions {
                 k8s_versions: vec!["v1.35.0".to_string()],
@@ -1053,6 +1146,8 @@ fn analysis_session_explains_helper_origin_provenance() -> eyre::Result<()> {
         include_subchart_values: true,
         values_files: Vec::new(),
         infer_required: false,
+        infer_enums: EnumInference::Off,
+        cluster_state: None,
         emission: SchemaProfile::default().into(),
         provider: ProviderOptions {
             k8s_versions: vec!["v1.35.0".to_string()],
@@ -1126,6 +1221,8 @@ fn dependency_activation_guards_lower_with_helm_precedence() -> eyre::Result<()>
         include_subchart_values: true,
         values_files: Vec::new(),
         infer_required: false,
+        infer_enums: EnumInference::Off,
+        cluster_state: None,
         emission: SchemaProfile::default().into(),
         provider: ProviderOptions {
             k8s_versions: vec!["v1.35.0".to_string()],
@@ -1226,6 +1323,8 @@ fn sibling_values_schema_file_is_not_inference_evidence() -> eyre::Result<()> {
         include_subchart_values: true,
         values_files: Vec::new(),
         infer_required: false,
+        infer_enums: EnumInference::Off,
+        cluster_state: None,
         emission: SchemaProfile::default().into(),
         provider: ProviderOptions {
             k8s_versions: vec!["v1.35.0".to_string()],
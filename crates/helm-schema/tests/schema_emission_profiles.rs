@@ -787,6 +787,8 @@ fn temporal_middle_policy_measurements() -> eyre::Result<()> {
         output: helm_schema::output::OutputPipelineOptions {
             strip_descriptions: false,
             minimize: true,
+            source_annotations: false,
+            dialect: helm_schema::output::SchemaDialect::Draft07,
         },
     };
     let full_schema = full_session.emit(emit_request)?;